@@ -16,13 +16,14 @@ mod net_config;
 mod flag;
 
 use dmbc::config;
+use dmbc::currency::api::access::AccessControl;
 use dmbc::currency::Service;
 use exonum::blockchain;
 use exonum::blockchain::{ConsensusConfig, GenesisConfig, TimeoutAdjusterConfig, ValidatorKeys};
 use exonum::crypto::PublicKey;
 use exonum::encoding::serialize::FromHex;
 use exonum::node::{Node, NodeApiConfig, NodeConfig};
-use exonum::storage::{RocksDB, RocksDBOptions};
+use exonum::storage::{Database, RocksDB, RocksDBOptions};
 use exonum_configuration::ConfigurationService;
 
 #[cfg(not(target_env = "msvc"))]
@@ -47,6 +48,11 @@ fn main() {
 
     exonum::helpers::init_logger().unwrap();
 
+    if let Err(e) = AccessControl::from_config() {
+        eprintln!("{}", e);
+        ::std::process::exit(1);
+    }
+
     /** Create Keys */
     println!(
         "Initializing node version: v{}",
@@ -141,7 +147,12 @@ fn main() {
         options.set_compression_type(DBCompressionType::Zlib);
     }
     let path = config::config().db().path();
-    let db = Box::new(RocksDB::open(path, &options).unwrap());
+    let mut db = Box::new(RocksDB::open(path, &options).unwrap());
+
+    // Upgrade the database
+    let mut fork = db.fork();
+    Service::upgrade(&mut fork);
+    db.merge(fork.into_patch()).unwrap();
 
     // Initialize services
     let services: Vec<Box<blockchain::Service>> = vec![
@@ -3,10 +3,13 @@
 extern crate toml;
 
 use std::env;
+use std::error::Error;
+use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
 use std::net::{SocketAddr, ToSocketAddrs};
 use std::path::Path;
+use std::str::FromStr;
 
 /// Representation of configuration file contents.
 #[derive(Deserialize, Clone)]
@@ -16,6 +19,8 @@ pub struct Config {
     db: Db,
     nats: Nats,
     service_discovery: ServiceDiscovery,
+    metadata: Option<Metadata>,
+    access: Option<Access>,
 }
 
 /// Node communications configuration.
@@ -28,6 +33,7 @@ pub struct Api {
     peer_address: Option<String>,
     peers: Option<Vec<String>>,
     is_validator: Option<bool>,
+    batch_max_size: Option<usize>,
 }
 
 /// Database configuration.
@@ -44,12 +50,72 @@ pub struct Nats {
     queuename: Option<String>,
 }
 
+/// Configuration of the node-local store of off-chain asset metadata.
+#[derive(Deserialize, Clone, Default)]
+pub struct Metadata {
+    path: Option<String>,
+}
+
+/// Access control of the public API.
+#[derive(Deserialize, Clone, Default)]
+pub struct Access {
+    groups: Option<Vec<RouteGroup>>,
+    ip_rate: Option<f64>,
+    ip_burst: Option<f64>,
+    key_rate: Option<f64>,
+    key_burst: Option<f64>,
+    cors_origins: Option<Vec<String>>,
+}
+
+/// Routes open only to the listed API keys and signing keys.
+#[derive(Deserialize, Clone)]
+pub struct RouteGroup {
+    routes: Vec<String>,
+    api_keys: Option<Vec<String>>,
+    public_keys: Option<Vec<String>>,
+}
+
 /// Configuration for communicating with a global service discovery.
 #[derive(Deserialize, Clone)]
 pub struct ServiceDiscovery {
     address: Option<String>,
 }
 
+/// A configuration entry with a value that can't be used.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigError {
+    /// Name of the entry, or of the environment variable setting it.
+    pub entry: String,
+    /// The value found.
+    pub value: String,
+}
+
+impl ConfigError {
+    /// Create an error for `value` of `entry`.
+    pub fn new(entry: &str, value: &str) -> Self {
+        ConfigError {
+            entry: entry.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid value `{}` of `{}` in the configuration", self.value, self.entry)
+    }
+}
+
+impl Error for ConfigError {
+    fn description(&self) -> &str {
+        "Invalid configuration entry"
+    }
+}
+
+fn parse_env<T: FromStr>(name: &str, value: &str) -> Result<T, ConfigError> {
+    value.parse::<T>().map_err(|_| ConfigError::new(name, value))
+}
+
 impl Config {
     /// Get `Api` configuration from the config file.
     pub fn api(self) -> Api {
@@ -70,6 +136,16 @@ impl Config {
     pub fn service_discovery(self) -> ServiceDiscovery {
         self.service_discovery
     }
+
+    /// Get `Metadata` configuration from the config file.
+    pub fn metadata(self) -> Metadata {
+        self.metadata.unwrap_or_default()
+    }
+
+    /// Get `Access` configuration from the config file.
+    pub fn access(self) -> Access {
+        self.access.unwrap_or_default()
+    }
 }
 
 impl Api {
@@ -140,6 +216,14 @@ impl Api {
             Err(_) => self.is_validator.unwrap_or(true),
         }
     }
+
+    /// Maximum number of transactions accepted in one batch submission.
+    pub fn batch_max_size(self) -> usize {
+        match env::var("API_BATCH_MAX_SIZE") {
+            Ok(value) => value.parse::<usize>().unwrap(),
+            Err(_) => self.batch_max_size.unwrap_or(1000),
+        }
+    }
 }
 
 impl Db {
@@ -197,6 +281,85 @@ impl ServiceDiscovery {
     }
 }
 
+impl Metadata {
+    /// Path to the directory of the metadata store.
+    pub fn path(self) -> String {
+        match env::var("METADATA_PATH") {
+            Ok(value) => value,
+            Err(_) => self.path.unwrap_or("./var/metadata".to_string()),
+        }
+    }
+}
+
+impl Access {
+    /// Route groups that require authentication.
+    pub fn groups(self) -> Vec<RouteGroup> {
+        self.groups.unwrap_or_default()
+    }
+
+    /// Requests per second allowed from one IP address, unlimited if unset.
+    pub fn ip_rate(self) -> Result<Option<f64>, ConfigError> {
+        match env::var("ACCESS_IP_RATE") {
+            Ok(value) => parse_env("ACCESS_IP_RATE", &value).map(Some),
+            Err(_) => Ok(self.ip_rate),
+        }
+    }
+
+    /// Requests one IP address may make at once, defaults to the rate.
+    pub fn ip_burst(self) -> Result<Option<f64>, ConfigError> {
+        match env::var("ACCESS_IP_BURST") {
+            Ok(value) => parse_env("ACCESS_IP_BURST", &value).map(Some),
+            Err(_) => Ok(self.ip_burst),
+        }
+    }
+
+    /// Requests per second allowed with one key, unlimited if unset.
+    pub fn key_rate(self) -> Result<Option<f64>, ConfigError> {
+        match env::var("ACCESS_KEY_RATE") {
+            Ok(value) => parse_env("ACCESS_KEY_RATE", &value).map(Some),
+            Err(_) => Ok(self.key_rate),
+        }
+    }
+
+    /// Requests one key may make at once, defaults to the rate.
+    pub fn key_burst(self) -> Result<Option<f64>, ConfigError> {
+        match env::var("ACCESS_KEY_BURST") {
+            Ok(value) => parse_env("ACCESS_KEY_BURST", &value).map(Some),
+            Err(_) => Ok(self.key_burst),
+        }
+    }
+
+    /// Origins allowed to make cross-origin requests, any if empty.
+    pub fn cors_origins(self) -> Vec<String> {
+        match env::var("ACCESS_CORS_ORIGINS") {
+            Ok(origins) => origins
+                .split(',')
+                .filter(|o| !o.is_empty())
+                .map(|o| o.to_string())
+                .collect(),
+            Err(_) => self.cors_origins.unwrap_or_default(),
+        }
+    }
+}
+
+impl RouteGroup {
+    /// Routes of the group, as `METHOD /path` or `/path` for any method.
+    /// A route also covers the paths below it.
+    pub fn routes(self) -> Vec<String> {
+        self.routes
+    }
+
+    /// API keys accepted in the `X-Api-Key` header.
+    pub fn api_keys(self) -> Vec<String> {
+        self.api_keys.unwrap_or_default()
+    }
+
+    /// Hex public keys accepted for signed requests.
+    pub fn public_keys(self) -> Vec<String> {
+        self.public_keys.unwrap_or_default()
+    }
+}
+
 lazy_static! {
     static ref CONFIG: Config = {
         let mut content = String::new();
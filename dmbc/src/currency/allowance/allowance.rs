@@ -0,0 +1,62 @@
+use exonum::crypto::PublicKey;
+
+use currency::assets::AssetBundle;
+use currency::error::Error;
+
+encoding_struct! {
+    /// Coins and assets a spender may move out of the owner's wallet.
+    ///
+    /// Expiry height of zero means the allowance never expires.
+    struct Allowance {
+        owner:      &PublicKey,
+        spender:    &PublicKey,
+        amount:     u64,
+        assets:     Vec<AssetBundle>,
+        expires_at: u64,
+    }
+}
+
+impl Allowance {
+    /// Check if the allowance can no longer be used at the given height.
+    pub fn is_expired(&self, height: u64) -> bool {
+        self.expires_at() != 0 && height >= self.expires_at()
+    }
+
+    /// Check if nothing is left to spend.
+    pub fn is_empty(&self) -> bool {
+        self.amount() == 0 && self.assets().iter().all(|asset| asset.amount() == 0)
+    }
+
+    /// Deduct coins and assets from the allowance.
+    ///
+    /// # Errors
+    /// Returns `AllowanceExceeded` if the allowance does not cover the
+    /// requested coins or assets.
+    pub fn spend(self, amount: u64, assets: &[AssetBundle]) -> Result<Self, Error> {
+        let left_amount = self.amount()
+            .checked_sub(amount)
+            .ok_or_else(|| Error::AllowanceExceeded)?;
+
+        let mut left_assets = self.assets();
+        for asset in assets {
+            let index = left_assets
+                .iter()
+                .position(|allowed| allowed.id() == asset.id())
+                .ok_or_else(|| Error::AllowanceExceeded)?;
+            let left = left_assets[index]
+                .amount()
+                .checked_sub(asset.amount())
+                .ok_or_else(|| Error::AllowanceExceeded)?;
+            left_assets[index] = AssetBundle::new(asset.id(), left);
+        }
+        left_assets.retain(|asset| asset.amount() > 0);
+
+        Ok(Allowance::new(
+            self.owner(),
+            self.spender(),
+            left_amount,
+            left_assets,
+            self.expires_at(),
+        ))
+    }
+}
@@ -0,0 +1,7 @@
+//! Spending allowances granted by wallets to other keys.
+
+mod allowance;
+mod schema;
+
+pub use currency::allowance::allowance::Allowance;
+pub use currency::allowance::schema::Schema;
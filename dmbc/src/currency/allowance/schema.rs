@@ -0,0 +1,62 @@
+use exonum::crypto;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::storage::{Fork, MapIndex, Snapshot};
+
+use currency::allowance::Allowance;
+use currency::SERVICE_NAME;
+
+/// Schema for accessing spending allowances.
+pub struct Schema<S>(pub S)
+where
+    S: AsRef<Snapshot>;
+
+fn allowance_key(owner: &PublicKey, spender: &PublicKey) -> Hash {
+    let mut bytes = owner.as_ref().to_vec();
+    bytes.extend_from_slice(spender.as_ref());
+    crypto::hash(&bytes)
+}
+
+impl<S> Schema<S>
+where
+    S: AsRef<Snapshot>,
+{
+    /// Internal `MapIndex` of allowances, keyed by the owner and spender pair.
+    pub fn allowances(self) -> MapIndex<S, Hash, Allowance> {
+        let key = SERVICE_NAME.to_string() + ".allowances";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Fetch the allowance granted by the owner to the spender.
+    pub fn fetch(self, owner: &PublicKey, spender: &PublicKey) -> Option<Allowance> {
+        self.allowances().get(&allowance_key(owner, spender))
+    }
+
+    /// Fetch all allowances granted by the owner.
+    pub fn fetch_wallet_allowances(self, owner: &PublicKey) -> Vec<Allowance> {
+        let allowances = self.allowances();
+        let wallet_allowances = allowances
+            .values()
+            .filter(|allowance| allowance.owner() == owner)
+            .collect();
+        wallet_allowances
+    }
+}
+
+impl<'a> Schema<&'a mut Fork> {
+    /// Internal `MapIndex` of allowances, with mutable access.
+    pub fn allowances_mut(&mut self) -> MapIndex<&mut Fork, Hash, Allowance> {
+        let key = SERVICE_NAME.to_string() + ".allowances";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Store an allowance, replacing the previous one for the same pair.
+    pub fn store(&mut self, allowance: Allowance) {
+        let key = allowance_key(allowance.owner(), allowance.spender());
+        self.allowances_mut().put(&key, allowance);
+    }
+
+    /// Remove the allowance granted by the owner to the spender.
+    pub fn remove(&mut self, owner: &PublicKey, spender: &PublicKey) {
+        self.allowances_mut().remove(&allowance_key(owner, spender));
+    }
+}
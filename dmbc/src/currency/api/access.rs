@@ -0,0 +1,460 @@
+//! Access control of the public API.
+//!
+//! `AccessControl` wraps the router of the service and, before a request
+//! reaches a handler:
+//!
+//! - refuses cross-origin requests from origins outside the allowlist;
+//! - requires a credential on the routes of the configured groups, either an
+//!   API key in the `X-Api-Key` header or a signature of the request;
+//! - takes a token from the bucket of the key, or of the IP address for
+//!   anonymous requests.
+//!
+//! A signed request carries the hex public key in `X-Dmbc-Public-Key`, the
+//! unix time in seconds in `X-Dmbc-Timestamp` and, in `X-Dmbc-Signature`, the
+//! hex signature of `METHOD\npath?query\ntimestamp\n` followed by the body of
+//! JSON requests. The path is relative to the root of the service API, e.g.
+//! `/v1/transactions`.
+
+extern crate bodyparser;
+extern crate serde_json;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::net::IpAddr;
+use std::str;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use exonum::crypto::{self, PublicKey, Signature};
+use exonum::encoding::serialize::FromHex;
+use hyper::header::{ContentType, Headers, Vary};
+use hyper::method::Method;
+use hyper::mime::{Mime, SubLevel, TopLevel};
+use iron::headers::AccessControlAllowOrigin;
+use iron::middleware::{AroundMiddleware, Handler};
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use unicase::UniCase;
+
+use config::{self, ConfigError};
+use currency::api::error::ApiError;
+use currency::api::v2::{self, V2Result};
+
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+pub const PUBLIC_KEY_HEADER: &str = "X-Dmbc-Public-Key";
+pub const TIMESTAMP_HEADER: &str = "X-Dmbc-Timestamp";
+pub const SIGNATURE_HEADER: &str = "X-Dmbc-Signature";
+
+/// How far the timestamp of a signed request may be from the node clock.
+pub const SIGNATURE_MAX_AGE_SECS: u64 = 300;
+
+/// Number of buckets above which full and idle buckets are dropped.
+const MAX_BUCKETS: usize = 100_000;
+
+/// Seconds without requests after which a bucket is dropped, full or not.
+const BUCKET_MAX_IDLE_SECS: u64 = 600;
+
+lazy_static! {
+    static ref UNAUTHORIZED_REJECTIONS: IntCounter = register_int_counter!(
+        "dmbc_api_access_unauthorized_rejections_total",
+        "Requests rejected for a missing or invalid credential."
+    ).unwrap();
+    static ref RATE_LIMITED_REJECTIONS: IntCounter = register_int_counter!(
+        "dmbc_api_access_rate_limited_rejections_total",
+        "Requests rejected for exceeding the rate limit."
+    ).unwrap();
+    static ref ORIGIN_REJECTIONS: IntCounter = register_int_counter!(
+        "dmbc_api_access_origin_rejections_total",
+        "Requests rejected for an origin outside the CORS allowlist."
+    ).unwrap();
+}
+
+/// Who made a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Client {
+    ApiKey(String),
+    Signer(PublicKey),
+}
+
+impl Client {
+    fn id(&self) -> String {
+        match *self {
+            Client::ApiKey(ref key) => format!("key:{}", key),
+            Client::Signer(ref pub_key) => format!("signer:{}", pub_key.to_hex()),
+        }
+    }
+}
+
+/// A route of a group, any method if `method` is not set.
+struct Route {
+    method: Option<Method>,
+    path: String,
+}
+
+impl Route {
+    fn parse(route: &str) -> Result<Self, ConfigError> {
+        let error = || ConfigError::new("access.groups.routes", route);
+        let mut parts = route.split_whitespace();
+        let (method, path) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(path), None, None) => (None, path),
+            (Some(method), Some(path), None) => {
+                (Some(method.parse::<Method>().map_err(|_| error())?), path)
+            }
+            _ => return Err(error()),
+        };
+        Ok(Route {
+            method,
+            path: path.trim_right_matches('/').to_string(),
+        })
+    }
+
+    fn matches(&self, method: &Method, path: &str) -> bool {
+        if self.method.as_ref().map_or(false, |m| m != method) {
+            return false;
+        }
+        path == self.path || path.starts_with(&format!("{}/", self.path))
+    }
+}
+
+struct Group {
+    routes: Vec<Route>,
+    api_keys: Vec<String>,
+    public_keys: Vec<PublicKey>,
+}
+
+impl Group {
+    fn new(group: config::RouteGroup) -> Result<Self, ConfigError> {
+        Ok(Group {
+            routes: group
+                .clone()
+                .routes()
+                .iter()
+                .map(|route| Route::parse(route))
+                .collect::<Result<_, _>>()?,
+            api_keys: group.clone().api_keys(),
+            public_keys: group
+                .public_keys()
+                .iter()
+                .map(|key| {
+                    PublicKey::from_hex(key)
+                        .map_err(|_| ConfigError::new("access.groups.public_keys", key))
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn matches(&self, method: &Method, path: &str) -> bool {
+        self.routes.iter().any(|route| route.matches(method, path))
+    }
+
+    fn allows(&self, client: &Client) -> bool {
+        match *client {
+            Client::ApiKey(ref key) => self.api_keys.contains(key),
+            Client::Signer(ref pub_key) => self.public_keys.contains(pub_key),
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Token buckets refilled at `rate` tokens per second up to `burst`.
+struct Limiter<K> {
+    rate: f64,
+    burst: f64,
+    buckets: Mutex<HashMap<K, Bucket>>,
+}
+
+impl<K: Eq + Hash> Limiter<K> {
+    fn new(rate: Option<f64>, burst: Option<f64>) -> Option<Self> {
+        rate.and_then(|rate| {
+            if rate <= 0.0 {
+                return None;
+            }
+            Some(Limiter {
+                rate,
+                burst: burst.unwrap_or(rate).max(1.0),
+                buckets: Mutex::new(HashMap::new()),
+            })
+        })
+    }
+
+    /// Tokens in the bucket at `now`.
+    fn tokens(&self, bucket: &Bucket, now: Instant) -> f64 {
+        let elapsed = now.duration_since(bucket.updated);
+        let elapsed = elapsed.as_secs() as f64 + f64::from(elapsed.subsec_nanos()) * 1e-9;
+        (bucket.tokens + elapsed * self.rate).min(self.burst)
+    }
+
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        bucket.tokens = self.tokens(bucket, now);
+        bucket.updated = now;
+    }
+
+    /// Drop the buckets that are full or idle. If too many are still left,
+    /// only the most recently used half of them is kept.
+    fn evict(&self, buckets: &mut HashMap<K, Bucket>, now: Instant) {
+        let max_idle = Duration::from_secs(BUCKET_MAX_IDLE_SECS);
+        buckets.retain(|_, bucket| {
+            now.duration_since(bucket.updated) < max_idle && self.tokens(bucket, now) < self.burst
+        });
+
+        if buckets.len() >= MAX_BUCKETS {
+            let mut updated: Vec<Instant> = buckets.values().map(|bucket| bucket.updated).collect();
+            updated.sort();
+            let oldest_kept = updated[updated.len() - MAX_BUCKETS / 2];
+            buckets.retain(|_, bucket| bucket.updated >= oldest_kept);
+        }
+    }
+
+    /// Take a token for `key`, or tell in how many seconds one is available.
+    fn take(&self, key: K) -> Result<(), u64> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let now = Instant::now();
+        if buckets.len() >= MAX_BUCKETS {
+            self.evict(&mut buckets, now);
+        }
+
+        let bucket = buckets.entry(key).or_insert(Bucket {
+            tokens: self.burst,
+            updated: now,
+        });
+        self.refill(bucket, now);
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(((1.0 - bucket.tokens) / self.rate).ceil() as u64)
+        }
+    }
+}
+
+/// Authentication, rate limits and CORS allowlist of the public API.
+pub struct AccessControl {
+    groups: Vec<Group>,
+    ip_limiter: Option<Limiter<IpAddr>>,
+    key_limiter: Option<Limiter<String>>,
+    cors_origins: Vec<String>,
+}
+
+impl AccessControl {
+    /// Create the access control set in `access`. Without configuration the
+    /// API stays open to anyone.
+    ///
+    /// # Errors
+    /// Returns a `ConfigError` naming the first entry that can't be used.
+    pub fn new(access: config::Access) -> Result<Self, ConfigError> {
+        Ok(AccessControl {
+            groups: access
+                .clone()
+                .groups()
+                .into_iter()
+                .map(Group::new)
+                .collect::<Result<_, _>>()?,
+            ip_limiter: Limiter::new(access.clone().ip_rate()?, access.clone().ip_burst()?),
+            key_limiter: Limiter::new(access.clone().key_rate()?, access.clone().key_burst()?),
+            cors_origins: access.cors_origins(),
+        })
+    }
+
+    /// Create the access control set in the node configuration.
+    pub fn from_config() -> Result<Self, ConfigError> {
+        AccessControl::new(config::config().access())
+    }
+
+    fn origin_allowed(&self, origin: &str) -> bool {
+        self.cors_origins.is_empty() || self.cors_origins.iter().any(|o| o == origin)
+    }
+
+    fn check(&self, req: &mut Request, path: &str) -> Result<(), (ApiError, Option<u64>)> {
+        if let Some(origin) = header(req, "Origin") {
+            if !self.origin_allowed(&origin) {
+                return Err((ApiError::OriginNotAllowed, None));
+            }
+        }
+        // Preflight requests carry no credentials.
+        if req.method == Method::Options {
+            return Ok(());
+        }
+
+        let client = self.authenticate(req, path).map_err(|e| (e, None))?;
+        let groups: Vec<&Group> = self.groups
+            .iter()
+            .filter(|group| group.matches(&req.method, path))
+            .collect();
+        if !groups.is_empty() {
+            let allowed = client
+                .as_ref()
+                .map_or(false, |client| groups.iter().any(|group| group.allows(client)));
+            if !allowed {
+                return Err((ApiError::Unauthorized, None));
+            }
+        }
+
+        let taken = match (client, &self.key_limiter, &self.ip_limiter) {
+            (Some(client), &Some(ref limiter), _) => limiter.take(client.id()),
+            (Some(_), &None, _) => Ok(()),
+            (None, _, &Some(ref limiter)) => limiter.take(req.remote_addr.ip()),
+            (None, _, &None) => Ok(()),
+        };
+        taken.map_err(|retry_after| (ApiError::RateLimited, Some(retry_after)))
+    }
+
+    /// The client that made the request, if it presented a credential. A
+    /// credential unknown to every group is refused, so that it cannot be
+    /// used to escape the limits of anonymous requests.
+    fn authenticate(&self, req: &mut Request, path: &str) -> Result<Option<Client>, ApiError> {
+        let client = if let Some(key) = header(req, API_KEY_HEADER) {
+            Client::ApiKey(key)
+        } else if let Some(signature) = header(req, SIGNATURE_HEADER) {
+            Client::Signer(verify_signature(req, path, &signature)?)
+        } else {
+            return Ok(None);
+        };
+
+        if self.groups.iter().any(|group| group.allows(&client)) {
+            Ok(Some(client))
+        } else {
+            Err(ApiError::Unauthorized)
+        }
+    }
+
+    /// Allow the origin of the request in `headers`, unless any origin is.
+    fn set_cors(&self, origin: Option<&str>, headers: &mut Headers) {
+        if self.cors_origins.is_empty() {
+            return;
+        }
+        headers.remove::<AccessControlAllowOrigin>();
+        if let Some(origin) = origin {
+            if self.origin_allowed(origin) {
+                headers.set(AccessControlAllowOrigin::Value(origin.to_string()));
+            }
+        }
+        headers.set(Vary::Items(vec![UniCase("Origin".to_owned())]));
+    }
+}
+
+impl AroundMiddleware for AccessControl {
+    fn around(self, handler: Box<Handler>) -> Box<Handler> {
+        Box::new(AccessHandler {
+            access: self,
+            handler,
+        })
+    }
+}
+
+struct AccessHandler {
+    access: AccessControl,
+    handler: Box<Handler>,
+}
+
+impl Handler for AccessHandler {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        let origin = header(req, "Origin");
+        let path = format!("/{}", req.url.path().join("/"));
+
+        let result = match self.access.check(req, &path) {
+            Ok(()) => self.handler.handle(req),
+            Err((e, retry_after)) => Ok(reject(&path, e, retry_after)),
+        };
+        match result {
+            Ok(mut res) => {
+                self.access.set_cors(origin.as_ref().map(|o| o.as_str()), &mut res.headers);
+                Ok(res)
+            }
+            Err(mut err) => {
+                let headers = &mut err.response.headers;
+                self.access.set_cors(origin.as_ref().map(|o| o.as_str()), headers);
+                Err(err)
+            }
+        }
+    }
+}
+
+fn header(req: &Request, name: &str) -> Option<String> {
+    req.headers
+        .get_raw(name)
+        .and_then(|values| values.first())
+        .and_then(|value| str::from_utf8(value).ok())
+        .map(|value| value.trim().to_string())
+}
+
+fn verify_signature(req: &mut Request, path: &str, signature: &str) -> Result<PublicKey, ApiError> {
+    let pub_key = header(req, PUBLIC_KEY_HEADER)
+        .and_then(|key| PublicKey::from_hex(key).ok())
+        .ok_or(ApiError::Unauthorized)?;
+    let signature = Signature::from_hex(signature).map_err(|_| ApiError::Unauthorized)?;
+    let timestamp = header(req, TIMESTAMP_HEADER)
+        .and_then(|timestamp| timestamp.parse::<u64>().ok())
+        .ok_or(ApiError::Unauthorized)?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let age = if now > timestamp {
+        now - timestamp
+    } else {
+        timestamp - now
+    };
+    if age > SIGNATURE_MAX_AGE_SECS {
+        return Err(ApiError::Unauthorized);
+    }
+
+    let mut data = match req.url.query() {
+        Some(query) => format!("{}\n{}?{}\n{}\n", req.method, path, query, timestamp),
+        None => format!("{}\n{}\n{}\n", req.method, path, timestamp),
+    }.into_bytes();
+    if is_json(req) {
+        // The body stays cached for the handler to parse.
+        let body = req.get::<bodyparser::Raw>()
+            .map_err(|_| ApiError::IncorrectRequest)?;
+        data.extend(body.unwrap_or_default().into_bytes());
+    }
+
+    if crypto::verify(&signature, &data, &pub_key) {
+        Ok(pub_key)
+    } else {
+        Err(ApiError::Unauthorized)
+    }
+}
+
+fn is_json(req: &Request) -> bool {
+    match req.headers.get::<ContentType>() {
+        Some(&ContentType(Mime(TopLevel::Application, SubLevel::Json, _))) => true,
+        _ => false,
+    }
+}
+
+/// Response to a rejected request, in the format of the API version of
+/// `path`.
+fn reject(path: &str, e: ApiError, retry_after: Option<u64>) -> Response {
+    match e {
+        ApiError::Unauthorized => UNAUTHORIZED_REJECTIONS.inc(),
+        ApiError::RateLimited => RATE_LIMITED_REJECTIONS.inc(),
+        ApiError::OriginNotAllowed => ORIGIN_REJECTIONS.inc(),
+        _ => (),
+    }
+
+    let mut res = if path.starts_with("/v2/") {
+        let result: V2Result<()> = Err(e.into());
+        v2::respond(result, status::Ok)
+    } else {
+        let result: Result<(), ApiError> = Err(e);
+        let mut res = Response::with((
+            e.to_status(),
+            serde_json::to_string_pretty(&result).unwrap(),
+        ));
+        res.headers.set(ContentType::json());
+        res.headers.set(AccessControlAllowOrigin::Any);
+        res
+    };
+    if let Some(retry_after) = retry_after {
+        res.headers
+            .set_raw("Retry-After", vec![retry_after.to_string().into_bytes()]);
+    }
+    res
+}
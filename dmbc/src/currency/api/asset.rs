@@ -16,7 +16,7 @@ use router::Router;
 
 use currency::api::error::ApiError;
 use currency::assets;
-use currency::assets::{AssetId, AssetInfo};
+use currency::assets::{AssetChange, AssetId, AssetInfo, MetadataRef, Supply};
 
 #[derive(Clone)]
 pub struct AssetApi {
@@ -25,19 +25,64 @@ pub struct AssetApi {
 
 /// Shortcut to get data on wallets.
 impl AssetApi {
-    fn get_asset_info(&self, asset_id: &AssetId) -> Option<AssetInfo> {
+    pub fn get_asset_info(&self, asset_id: &AssetId) -> Option<AssetInfo> {
         let view = self.blockchain.fork();
         assets::Schema(view).fetch(asset_id)
     }
+
+    fn get_asset_history(&self, asset_id: &AssetId) -> Vec<AssetChange> {
+        let view = self.blockchain.fork();
+        assets::Schema(view).fetch_history(asset_id)
+    }
+
+    pub fn get_asset_supply(&self, asset_id: &AssetId) -> Option<Supply> {
+        let view = self.blockchain.fork();
+        assets::Schema(view).fetch_supply(asset_id)
+    }
+
+    fn get_asset_metadata(&self, asset_id: &AssetId) -> Option<MetadataRef> {
+        let view = self.blockchain.fork();
+        assets::Schema(view).fetch_metadata(asset_id)
+    }
 }
 
 pub type AssetResponse = Result<Option<AssetInfo>, ApiError>;
 
+pub type AssetHistoryResponse = Result<Vec<AssetChange>, ApiError>;
+
+pub type AssetSupplyResponse = Result<Option<Supply>, ApiError>;
+
+pub type AssetMetadataResponse = Result<Option<MetadataRef>, ApiError>;
+
 lazy_static! {
     static ref INFO_REQUESTS: IntCounter =
         register_int_counter!("dmbc_asset_api_info_requests_total", "AssetInfo requests.").unwrap();
     static ref INFO_RESPONSES: IntCounter =
         register_int_counter!("dmbc_asset_api_info_responses_total", "AssetInfo response.").unwrap();
+    static ref HISTORY_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_asset_api_history_requests_total",
+        "Asset history requests."
+    ).unwrap();
+    static ref HISTORY_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_asset_api_history_responses_total",
+        "Asset history responses."
+    ).unwrap();
+    static ref SUPPLY_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_asset_api_supply_requests_total",
+        "Asset supply requests."
+    ).unwrap();
+    static ref SUPPLY_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_asset_api_supply_responses_total",
+        "Asset supply responses."
+    ).unwrap();
+    static ref METADATA_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_asset_api_metadata_requests_total",
+        "Asset metadata reference requests."
+    ).unwrap();
+    static ref METADATA_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_asset_api_metadata_responses_total",
+        "Asset metadata reference responses."
+    ).unwrap();
 }
 
 impl Api for AssetApi {
@@ -65,10 +110,103 @@ impl Api for AssetApi {
             Ok(res)
         };
 
+        let self_ = self.clone();
+        let get_asset_history = move |req: &mut Request| -> IronResult<Response> {
+            HISTORY_REQUESTS.inc();
+
+            let asset_id_str = req.extensions
+                .get::<Router>()
+                .unwrap()
+                .find("asset_id")
+                .unwrap()
+                .to_string();
+            let a: AssetHistoryResponse = AssetId::from_hex(&asset_id_str)
+                .map_err(|_| ApiError::AssetIdInvalid)
+                .map(|asset_id| self_.get_asset_history(&asset_id));
+
+            let mut res = Response::with((
+                a.clone().err().map(|e| e.to_status()).unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&a).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            HISTORY_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        let self_ = self.clone();
+        let get_asset_supply = move |req: &mut Request| -> IronResult<Response> {
+            SUPPLY_REQUESTS.inc();
+
+            let asset_id_str = req.extensions
+                .get::<Router>()
+                .unwrap()
+                .find("asset_id")
+                .unwrap()
+                .to_string();
+            let a: AssetSupplyResponse = AssetId::from_hex(&asset_id_str)
+                .map_err(|_| ApiError::AssetIdInvalid)
+                .map(|asset_id| self_.get_asset_supply(&asset_id));
+
+            let mut res = Response::with((
+                a.clone().err().map(|e| e.to_status()).unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&a).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            SUPPLY_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        let self_ = self.clone();
+        let get_asset_metadata = move |req: &mut Request| -> IronResult<Response> {
+            METADATA_REQUESTS.inc();
+
+            let asset_id_str = req.extensions
+                .get::<Router>()
+                .unwrap()
+                .find("asset_id")
+                .unwrap()
+                .to_string();
+            let a: AssetMetadataResponse = AssetId::from_hex(&asset_id_str)
+                .map_err(|_| ApiError::AssetIdInvalid)
+                .map(|asset_id| self_.get_asset_metadata(&asset_id));
+
+            let mut res = Response::with((
+                a.clone().err().map(|e| e.to_status()).unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&a).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            METADATA_RESPONSES.inc();
+
+            Ok(res)
+        };
+
         router.get(
             "/v1/assets/:asset_id",
             get_owner_for_asset_id,
             "get_owner_for_asset_id",
         );
+        router.get(
+            "/v1/assets/:asset_id/history",
+            get_asset_history,
+            "get_asset_history",
+        );
+        router.get(
+            "/v1/assets/:asset_id/supply",
+            get_asset_supply,
+            "get_asset_supply",
+        );
+        router.get(
+            "/v1/assets/:asset_id/metadata",
+            get_asset_metadata,
+            "get_asset_metadata",
+        );
     }
 }
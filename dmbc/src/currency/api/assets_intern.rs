@@ -18,7 +18,7 @@ use router::Router;
 use percent_encoding::percent_decode;
 
 use currency::api::error::ApiError;
-use currency::assets::AssetId;
+use currency::assets::{AssetId, Collection};
 
 #[derive(Clone)]
 pub struct AssetInternApi {}
@@ -43,10 +43,17 @@ pub struct AssetIdBatchResponseBody {
     pub assets: HashMap<String, HashMap<String, String>>,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CollectionIdResponseBody {
+    pub collections: HashMap<String, String>,
+}
+
 pub type AssetIdResponse = Result<AssetIdResponseBody, ApiError>;
 
 pub type AssetIdBatchResponse = Result<AssetIdBatchResponseBody, ApiError>;
 
+pub type CollectionIdResponse = Result<CollectionIdResponseBody, ApiError>;
+
 impl Api for AssetInternApi {
     fn wire(&self, router: &mut Router) {
         let get_asset_id = move |req: &mut Request| -> IronResult<Response> {
@@ -178,6 +185,49 @@ impl Api for AssetInternApi {
             Ok(res)
         };
 
+        let get_collection_id = move |req: &mut Request| -> IronResult<Response> {
+            let public_key_result = {
+                let wallet_key = req.extensions
+                    .get::<Router>()
+                    .unwrap()
+                    .find("pub_key")
+                    .unwrap();
+                PublicKey::from_hex(wallet_key)
+            };
+            let name = {
+                req.extensions
+                    .get::<Router>()
+                    .unwrap()
+                    .find("name")
+                    .unwrap()
+            };
+            let name = percent_decode(name.as_bytes())
+                .decode_utf8_lossy()
+                .into_owned();
+            let result: CollectionIdResponse = match public_key_result {
+                Ok(public_key) => {
+                    let id = Collection::id_from(name.as_str(), &public_key);
+                    let mut collections = HashMap::<String, String>::new();
+                    collections.insert(name.to_string(), id.to_hex());
+                    Ok(CollectionIdResponseBody { collections })
+                }
+                Err(_) => Err(ApiError::WalletHexInvalid),
+            };
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            Ok(res)
+        };
+
         router.get(
             "/v1/intern/assets/:pub_key/:meta_data",
             get_asset_id,
@@ -191,5 +241,11 @@ impl Api for AssetInternApi {
         );
 
         router.post("/v1/intern/assets", get_asset_id_batch, "assets_ids_batch");
+
+        router.get(
+            "/v1/intern/collections/:pub_key/:name",
+            get_collection_id,
+            "collection_id",
+        );
     }
 }
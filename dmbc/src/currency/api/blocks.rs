@@ -13,7 +13,7 @@ use prometheus::IntCounter;
 use router::Router;
 use currency::api::params::{Params, Value};
 
-const MAX_BLOCKS_PER_REQUEST: u64 = 1000;
+pub const MAX_BLOCKS_PER_REQUEST: u64 = 1000;
 
 #[derive(Clone)]
 pub struct BlocksApi {
@@ -25,7 +25,7 @@ pub type BlockResponse = Result<Option<BlockInfo>, ApiError>;
 
 impl BlocksApi {
 
-    fn get_blocks(
+    pub fn get_blocks(
         &self,
         count: u64,
         from: Option<u64>,
@@ -38,7 +38,7 @@ impl BlocksApi {
         Ok(explorer.blocks_range(count, from, skip_empty_blocks))
     }
 
-    fn get_block(&self, height: Height) -> Result<Option<BlockInfo>, ApiError> {
+    pub fn get_block(&self, height: Height) -> Result<Option<BlockInfo>, ApiError> {
         let explorer = BlockchainExplorer::new(&self.blockchain);
         match explorer.block_info(height) {
             Some(b) => Ok(Some(b)),
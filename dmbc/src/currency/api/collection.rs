@@ -0,0 +1,237 @@
+extern crate serde_json;
+
+use exonum::api::Api;
+use exonum::blockchain::Blockchain;
+use exonum::crypto::Hash;
+use exonum::encoding::serialize::FromHex;
+use exonum::storage::Fork;
+use hyper::header::ContentType;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::api::ServiceApi;
+use currency::assets;
+use currency::assets::{AssetId, AssetInfo, Collection, CollectionSupply};
+
+#[derive(Clone)]
+pub struct CollectionApi {
+    pub blockchain: Blockchain,
+}
+
+/// Asset placed in a collection or one of its nested collections.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CollectionAsset {
+    pub id: AssetId,
+    pub collection: Hash,
+    pub info: Option<AssetInfo>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CollectionAssetsResponseBody {
+    pub total: u64,
+    pub count: u64,
+    pub assets: Vec<CollectionAsset>,
+}
+
+pub type CollectionResponse = Result<Collection, ApiError>;
+
+pub type CollectionAssetsResponse = Result<CollectionAssetsResponseBody, ApiError>;
+
+pub type CollectionSupplyResponse = Result<CollectionSupply, ApiError>;
+
+impl CollectionApi {
+    fn collection(&self, id: &Hash) -> Option<Collection> {
+        let view = self.blockchain.fork();
+        assets::Schema(view).fetch_collection(id)
+    }
+
+    /// IDs of the collection and all collections nested in it.
+    fn collection_tree(view: &Fork, id: &Hash) -> Vec<Hash> {
+        let mut tree = vec![*id];
+        let mut next = 0;
+        while next < tree.len() {
+            let children = assets::Schema(view).fetch_subcollections(&tree[next]);
+            tree.extend(children);
+            next += 1;
+        }
+        tree
+    }
+
+    /// Assets of the collection and all collections nested in it.
+    fn collection_assets(&self, id: &Hash) -> Result<Vec<CollectionAsset>, ApiError> {
+        let view = self.blockchain.fork();
+        if assets::Schema(&view).fetch_collection(id).is_none() {
+            return Err(ApiError::CollectionNotFound);
+        }
+
+        let mut assets = Vec::new();
+        for collection in CollectionApi::collection_tree(&view, id) {
+            for asset_id in assets::Schema(&view).fetch_collection_assets(&collection) {
+                assets.push(CollectionAsset {
+                    id: asset_id,
+                    collection,
+                    info: assets::Schema(&view).fetch(&asset_id),
+                });
+            }
+        }
+        Ok(assets)
+    }
+
+    fn collection_supply(&self, id: &Hash) -> Result<CollectionSupply, ApiError> {
+        let assets = self.collection_assets(id)?;
+        let amount = assets
+            .iter()
+            .filter_map(|asset| asset.info.as_ref())
+            .map(|info| info.amount())
+            .sum();
+        Ok(CollectionSupply {
+            assets: assets.len() as u64,
+            amount,
+        })
+    }
+
+    fn collection_id(req: &mut Request) -> Result<Hash, ApiError> {
+        let collection_id = req.extensions
+            .get::<Router>()
+            .unwrap()
+            .find("collection_id")
+            .unwrap();
+        Hash::from_hex(collection_id).map_err(|_| ApiError::CollectionIdInvalid)
+    }
+}
+
+lazy_static! {
+    static ref COLLECTION_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_collection_api_collection_requests_total",
+        "Collection requests."
+    ).unwrap();
+    static ref COLLECTION_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_collection_api_collection_responses_total",
+        "Collection responses."
+    ).unwrap();
+    static ref ASSETS_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_collection_api_assets_requests_total",
+        "Collection asset list requests."
+    ).unwrap();
+    static ref ASSETS_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_collection_api_assets_responses_total",
+        "Collection asset list responses."
+    ).unwrap();
+    static ref SUPPLY_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_collection_api_supply_requests_total",
+        "Collection supply requests."
+    ).unwrap();
+    static ref SUPPLY_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_collection_api_supply_responses_total",
+        "Collection supply responses."
+    ).unwrap();
+}
+
+impl Api for CollectionApi {
+    fn wire(&self, router: &mut Router) {
+        // Gets the metadata and default fees of a collection.
+        let self_ = self.clone();
+        let collection_info = move |req: &mut Request| -> IronResult<Response> {
+            COLLECTION_REQUESTS.inc();
+
+            let result: CollectionResponse = CollectionApi::collection_id(req).and_then(|id| {
+                self_
+                    .collection(&id)
+                    .ok_or(ApiError::CollectionNotFound)
+            });
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            COLLECTION_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Gets the assets of a collection, including nested collections.
+        let self_ = self.clone();
+        let collection_assets = move |req: &mut Request| -> IronResult<Response> {
+            ASSETS_REQUESTS.inc();
+
+            let result: CollectionAssetsResponse = CollectionApi::collection_id(req)
+                .and_then(|id| self_.collection_assets(&id))
+                .map(|assets| {
+                    // apply pagination parameters if they exist
+                    let assets_to_send = ServiceApi::apply_pagination(req, &assets);
+                    CollectionAssetsResponseBody {
+                        total: assets.len() as u64,
+                        count: assets_to_send.len() as u64,
+                        assets: assets_to_send.to_vec(),
+                    }
+                });
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            ASSETS_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Gets the aggregate supply of a collection, including nested
+        // collections.
+        let self_ = self.clone();
+        let collection_supply = move |req: &mut Request| -> IronResult<Response> {
+            SUPPLY_REQUESTS.inc();
+
+            let result: CollectionSupplyResponse = CollectionApi::collection_id(req)
+                .and_then(|id| self_.collection_supply(&id));
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            SUPPLY_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        router.get(
+            "/v1/collections/:collection_id",
+            collection_info,
+            "collection_info",
+        );
+        router.get(
+            "/v1/collections/:collection_id/assets",
+            collection_assets,
+            "collection_assets",
+        );
+        router.get(
+            "/v1/collections/:collection_id/supply",
+            collection_supply,
+            "collection_supply",
+        );
+    }
+}
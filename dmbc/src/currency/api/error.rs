@@ -11,6 +11,28 @@ pub enum ApiError {
     WalletHexInvalid,
     HeightIsMissing,
     BlockNotFound,
+    MultisigNotFound,
+    ProposalNotFound,
+    LoanNotFound,
+    TokenNotFound,
+    CollectionIdInvalid,
+    CollectionNotFound,
+    MetadataHashInvalid,
+    MetadataHashMismatch,
+    MetadataTooLarge,
+    MetadataNotFound,
+    MetadataStoreFailed,
+    WalletCursorInvalid,
+    BatchTooLarge,
+    TransactionHexInvalid,
+    TransactionLengthInvalid,
+    TransactionTypeUnknown,
+    TransactionSignatureInvalid,
+    TransactionMalformed,
+    TransactionPending,
+    Unauthorized,
+    RateLimited,
+    OriginNotAllowed,
 }
 
 impl ApiError {
@@ -25,6 +47,65 @@ impl ApiError {
             ApiError::WalletHexInvalid => StatusCode::BadRequest,
             ApiError::HeightIsMissing => StatusCode::BadRequest,
             ApiError::BlockNotFound => StatusCode::NotFound,
+            ApiError::MultisigNotFound => StatusCode::NotFound,
+            ApiError::ProposalNotFound => StatusCode::NotFound,
+            ApiError::LoanNotFound => StatusCode::NotFound,
+            ApiError::TokenNotFound => StatusCode::NotFound,
+            ApiError::CollectionIdInvalid => StatusCode::BadRequest,
+            ApiError::CollectionNotFound => StatusCode::NotFound,
+            ApiError::MetadataHashInvalid => StatusCode::BadRequest,
+            ApiError::MetadataHashMismatch => StatusCode::BadRequest,
+            ApiError::MetadataTooLarge => StatusCode::PayloadTooLarge,
+            ApiError::MetadataNotFound => StatusCode::NotFound,
+            ApiError::MetadataStoreFailed => StatusCode::InternalServerError,
+            ApiError::WalletCursorInvalid => StatusCode::BadRequest,
+            ApiError::BatchTooLarge => StatusCode::PayloadTooLarge,
+            ApiError::TransactionHexInvalid => StatusCode::BadRequest,
+            ApiError::TransactionLengthInvalid => StatusCode::BadRequest,
+            ApiError::TransactionTypeUnknown => StatusCode::BadRequest,
+            ApiError::TransactionSignatureInvalid => StatusCode::BadRequest,
+            ApiError::TransactionMalformed => StatusCode::BadRequest,
+            ApiError::TransactionPending => StatusCode::Accepted,
+            ApiError::Unauthorized => StatusCode::Unauthorized,
+            ApiError::RateLimited => StatusCode::TooManyRequests,
+            ApiError::OriginNotAllowed => StatusCode::Forbidden,
+        }
+    }
+
+    /// Stable code of the error for API clients.
+    pub fn code(&self) -> &'static str {
+        match *self {
+            ApiError::TransactionNotFound => "transaction_not_found",
+            ApiError::TransactionHashInvalid => "transaction_hash_invalid",
+            ApiError::AssetIdNotFound => "asset_id_not_found",
+            ApiError::AssetIdInvalid => "asset_id_invalid",
+            ApiError::EmptyRequestBody => "empty_request_body",
+            ApiError::IncorrectRequest => "incorrect_request",
+            ApiError::WalletHexInvalid => "wallet_hex_invalid",
+            ApiError::HeightIsMissing => "height_is_missing",
+            ApiError::BlockNotFound => "block_not_found",
+            ApiError::MultisigNotFound => "multisig_not_found",
+            ApiError::ProposalNotFound => "proposal_not_found",
+            ApiError::LoanNotFound => "loan_not_found",
+            ApiError::TokenNotFound => "token_not_found",
+            ApiError::CollectionIdInvalid => "collection_id_invalid",
+            ApiError::CollectionNotFound => "collection_not_found",
+            ApiError::MetadataHashInvalid => "metadata_hash_invalid",
+            ApiError::MetadataHashMismatch => "metadata_hash_mismatch",
+            ApiError::MetadataTooLarge => "metadata_too_large",
+            ApiError::MetadataNotFound => "metadata_not_found",
+            ApiError::MetadataStoreFailed => "metadata_store_failed",
+            ApiError::WalletCursorInvalid => "wallet_cursor_invalid",
+            ApiError::BatchTooLarge => "batch_too_large",
+            ApiError::TransactionHexInvalid => "transaction_hex_invalid",
+            ApiError::TransactionLengthInvalid => "transaction_length_invalid",
+            ApiError::TransactionTypeUnknown => "transaction_type_unknown",
+            ApiError::TransactionSignatureInvalid => "transaction_signature_invalid",
+            ApiError::TransactionMalformed => "transaction_malformed",
+            ApiError::TransactionPending => "transaction_pending",
+            ApiError::Unauthorized => "unauthorized",
+            ApiError::RateLimited => "rate_limited",
+            ApiError::OriginNotAllowed => "origin_not_allowed",
         }
     }
 }
@@ -0,0 +1,235 @@
+extern crate serde_json;
+
+use std::cmp;
+use std::io::{self, Write};
+
+use exonum::api::Api;
+use exonum::blockchain::{self, Blockchain};
+use exonum::crypto::{Hash, PublicKey};
+use exonum::encoding::serialize::FromHex;
+use exonum::storage::Snapshot;
+use hyper::header::{CacheControl, CacheDirective, ContentType};
+use hyper::mime::Mime;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use iron::response::WriteBody;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::api::ServiceApi;
+use currency::assets::AssetId;
+use currency::events::Subscription;
+
+const PARAMETER_BLOCKS_KEY: &str = "blocks";
+const PARAMETER_TRANSACTIONS_KEY: &str = "tx";
+const PARAMETER_WALLETS_KEY: &str = "wallet";
+const PARAMETER_ASSETS_KEY: &str = "asset";
+const PARAMETER_FROM_HEIGHT_KEY: &str = "from_height";
+const PARAMETER_TO_HEIGHT_KEY: &str = "to_height";
+
+/// Header with the id of the last event a reconnecting client has received.
+const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+
+/// Largest number of blocks told in one response.
+const BLOCKS_PER_RESPONSE: u64 = 100;
+
+/// Milliseconds a client waits before reconnecting for the next blocks.
+const RETRY_MILLIS: u64 = 1000;
+
+#[derive(Clone)]
+pub struct EventsApi {
+    pub blockchain: Blockchain,
+}
+
+pub type EventsResponse = Result<(), ApiError>;
+
+/// Server-Sent Events of the blocks from `next_height` on.
+///
+/// Every event carries the JSON of a `currency::events::Event`. A response
+/// tells the blocks committed so far and ends instead of waiting for new
+/// ones, so that it doesn't hold a worker thread of the API. Clients then
+/// reconnect after `RETRY_MILLIS` and resume after the block in the last
+/// event id, which is set to the last block told even if it had no events.
+struct EventStream {
+    blockchain: Blockchain,
+    subscription: Subscription,
+    next_height: Option<u64>,
+    to_height: Option<u64>,
+}
+
+impl EventStream {
+    fn finished(&self) -> bool {
+        match (self.next_height, self.to_height) {
+            (Some(next), Some(to)) => next > to,
+            _ => false,
+        }
+    }
+}
+
+impl WriteBody for EventStream {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        let snapshot = self.blockchain.snapshot();
+        let view: &Snapshot = &*snapshot;
+        let height = blockchain::Schema::new(view).height().0;
+        let mut next = self.next_height.unwrap_or(height + 1);
+        let mut last = cmp::min(height, next + BLOCKS_PER_RESPONSE - 1);
+        if let Some(to) = self.to_height {
+            last = cmp::min(last, to);
+        }
+
+        write!(res, "retry: {}\n\n", RETRY_MILLIS)?;
+        while next <= last {
+            for event in self.subscription.events(view, next) {
+                write!(res, "event: {}\n", event.name())?;
+                write!(res, "data: {}\n\n", serde_json::to_string(&event).unwrap())?;
+            }
+            next += 1;
+        }
+        self.next_height = Some(next);
+
+        if next > 0 {
+            write!(res, "id: {}\n\n", next - 1)?;
+        }
+        res.flush()
+    }
+}
+
+/// Parse a comma-separated list of values.
+fn parse_list<T, F>(list: &str, parse: F, error: ApiError) -> Result<Vec<T>, ApiError>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    list.split(',')
+        .filter(|item| !item.is_empty())
+        .map(|item| parse(item).ok_or(error))
+        .collect()
+}
+
+/// Build a subscription from the request parameters.
+fn subscription(
+    blocks: bool,
+    transactions: &str,
+    wallets: &str,
+    assets: &str,
+) -> Result<Subscription, ApiError> {
+    let transactions = parse_list(
+        transactions,
+        |hash| Hash::from_hex(hash).ok(),
+        ApiError::TransactionHashInvalid,
+    )?;
+    let wallets = parse_list(
+        wallets,
+        |key| PublicKey::from_hex(key).ok(),
+        ApiError::WalletHexInvalid,
+    )?;
+    let assets = parse_list(assets, |id| AssetId::from_hex(id).ok(), ApiError::AssetIdInvalid)?;
+
+    let subscription = Subscription {
+        blocks,
+        transactions: transactions.into_iter().collect(),
+        wallets: wallets.into_iter().collect(),
+        assets: assets.into_iter().collect(),
+    };
+    if subscription.is_empty() {
+        return Err(ApiError::IncorrectRequest);
+    }
+    Ok(subscription)
+}
+
+/// Parse an optional height.
+fn parse_height(height: &str) -> Result<Option<u64>, ApiError> {
+    match height {
+        "" => Ok(None),
+        height => height
+            .parse::<u64>()
+            .map(Some)
+            .map_err(|_| ApiError::IncorrectRequest),
+    }
+}
+
+lazy_static! {
+    static ref EVENTS_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_events_api_stream_requests_total",
+        "Event stream requests."
+    ).unwrap();
+    static ref EVENTS_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_events_api_stream_responses_total",
+        "Event stream responses."
+    ).unwrap();
+}
+
+impl Api for EventsApi {
+    fn wire(&self, router: &mut Router) {
+        // Streams the events of committed blocks as Server-Sent Events.
+        //
+        // `blocks=true` subscribes to new blocks, `tx`, `wallet` and `asset`
+        // to the statuses of transactions and the changes of wallets and
+        // assets, each taking a comma-separated list. The stream starts
+        // after the block in the `Last-Event-ID` header when reconnecting,
+        // else at `from_height` or after the current block, and ends after
+        // `to_height` if given, answering reconnects with `204 No Content`.
+        // Wallet and asset events are kept for the last
+        // `events::HISTORY_BLOCKS` blocks the node has committed.
+        let self_ = self.clone();
+        let events = move |req: &mut Request| -> IronResult<Response> {
+            EVENTS_REQUESTS.inc();
+
+            let blocks = ServiceApi::read_parameter(req, PARAMETER_BLOCKS_KEY, false);
+            let transactions =
+                ServiceApi::read_parameter(req, PARAMETER_TRANSACTIONS_KEY, String::new());
+            let wallets = ServiceApi::read_parameter(req, PARAMETER_WALLETS_KEY, String::new());
+            let assets = ServiceApi::read_parameter(req, PARAMETER_ASSETS_KEY, String::new());
+            let from_height =
+                ServiceApi::read_parameter(req, PARAMETER_FROM_HEIGHT_KEY, String::new());
+            let to_height = ServiceApi::read_parameter(req, PARAMETER_TO_HEIGHT_KEY, String::new());
+            let last_event_id = req.headers
+                .get_raw(LAST_EVENT_ID_HEADER)
+                .and_then(|values| values.first())
+                .map(|value| String::from_utf8_lossy(value).into_owned())
+                .unwrap_or_default();
+
+            let stream = subscription(blocks, &transactions, &wallets, &assets).and_then(
+                |subscription| {
+                    let from_height = parse_height(&from_height)?;
+                    let resumed = parse_height(&last_event_id)?.map(|id| id + 1);
+                    Ok(EventStream {
+                        blockchain: self_.blockchain.clone(),
+                        subscription,
+                        next_height: resumed.or(from_height),
+                        to_height: parse_height(&to_height)?,
+                    })
+                },
+            );
+
+            let mut res = match stream {
+                Ok(ref stream) if stream.finished() => Response::with(status::NoContent),
+                Ok(stream) => {
+                    let body: Box<WriteBody> = Box::new(stream);
+                    let mut res = Response::with((status::Ok, body));
+                    res.headers
+                        .set(ContentType("text/event-stream".parse::<Mime>().unwrap()));
+                    res.headers.set(CacheControl(vec![CacheDirective::NoCache]));
+                    res
+                }
+                Err(e) => {
+                    let s: EventsResponse = Err(e);
+                    let mut res = Response::with((
+                        e.to_status(),
+                        serde_json::to_string_pretty(&s).unwrap(),
+                    ));
+                    res.headers.set(ContentType::json());
+                    res
+                }
+            };
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            EVENTS_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        router.get("/v1/events", events, "events");
+    }
+}
@@ -20,8 +20,16 @@ use router::Router;
 use currency::api::error::ApiError;
 use currency::error::Error;
 use currency::transactions::components::FeesCalculator;
-use currency::transactions::{AddAssets, DeleteAssets, Exchange, ExchangeIntermediary, Trade,
-                             TradeIntermediary, Transfer};
+use currency::transactions::quote::{FeeQuote, FeeQuoteRequest};
+use currency::transactions::{AcceptLoan, AddAssets, AddToCollection, Approve, CancelLoan,
+                             CartCheckout, ClaimCollateral, ClaimVested, CreateCollection,
+                             CreateMultisig, CreateVesting, DeleteAssets, Exchange,
+                             ExchangeIntermediary, ExchangeTokens, FreezeWallet, MintTokens,
+                             MultiExchange, MultiTransfer, MultisigApprove, MultisigPropose,
+                             MultisigSpend, OfferLoan, ReturnLoan, RevokeApproval, SetAssetMetadata,
+                             SetAssetRestriction, SetAssetSupply, Trade, TradeIntermediary,
+                             TradeTokens, Transfer, TransferAssetCreator, TransferFrom,
+                             TransferTokens, UnfreezeWallet, UpdateAllowlist, UpdateAssetFees};
 
 #[derive(Clone)]
 pub struct FeesApi {
@@ -32,24 +40,86 @@ pub struct FeesApi {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub enum FeesRequest {
     Transfer(Transfer),
+    TransferTokens(TransferTokens),
     AddAssets(AddAssets),
     DeleteAssets(DeleteAssets),
     Trade(Trade),
+    TradeTokens(TradeTokens),
     TradeIntermediary(TradeIntermediary),
     Exchange(Exchange),
+    ExchangeTokens(ExchangeTokens),
     ExchangeIntermediary(ExchangeIntermediary),
+    CartCheckout(CartCheckout),
+    MultiExchange(MultiExchange),
+    MultiTransfer(MultiTransfer),
+    CreateMultisig(CreateMultisig),
+    MultisigSpend(MultisigSpend),
+    MultisigPropose(MultisigPropose),
+    MultisigApprove(MultisigApprove),
+    Approve(Approve),
+    RevokeApproval(RevokeApproval),
+    TransferFrom(TransferFrom),
+    CreateVesting(CreateVesting),
+    ClaimVested(ClaimVested),
+    OfferLoan(OfferLoan),
+    AcceptLoan(AcceptLoan),
+    ReturnLoan(ReturnLoan),
+    ClaimCollateral(ClaimCollateral),
+    CancelLoan(CancelLoan),
+    UpdateAssetFees(UpdateAssetFees),
+    TransferAssetCreator(TransferAssetCreator),
+    SetAssetRestriction(SetAssetRestriction),
+    SetAssetSupply(SetAssetSupply),
+    SetAssetMetadata(SetAssetMetadata),
+    UpdateAllowlist(UpdateAllowlist),
+    FreezeWallet(FreezeWallet),
+    UnfreezeWallet(UnfreezeWallet),
+    MintTokens(MintTokens),
+    CreateCollection(CreateCollection),
+    AddToCollection(AddToCollection),
 }
 
 impl Into<Box<FeesCalculator>> for FeesRequest {
     fn into(self) -> Box<FeesCalculator> {
         match self {
             FeesRequest::Transfer(trans) => Box::new(trans),
+            FeesRequest::TransferTokens(trans) => Box::new(trans),
             FeesRequest::AddAssets(trans) => Box::new(trans),
             FeesRequest::DeleteAssets(trans) => Box::new(trans),
             FeesRequest::Trade(trans) => Box::new(trans),
+            FeesRequest::TradeTokens(trans) => Box::new(trans),
             FeesRequest::TradeIntermediary(trans) => Box::new(trans),
             FeesRequest::Exchange(trans) => Box::new(trans),
+            FeesRequest::ExchangeTokens(trans) => Box::new(trans),
             FeesRequest::ExchangeIntermediary(trans) => Box::new(trans),
+            FeesRequest::CartCheckout(trans) => Box::new(trans),
+            FeesRequest::MultiExchange(trans) => Box::new(trans),
+            FeesRequest::MultiTransfer(trans) => Box::new(trans),
+            FeesRequest::CreateMultisig(trans) => Box::new(trans),
+            FeesRequest::MultisigSpend(trans) => Box::new(trans),
+            FeesRequest::MultisigPropose(trans) => Box::new(trans),
+            FeesRequest::MultisigApprove(trans) => Box::new(trans),
+            FeesRequest::Approve(trans) => Box::new(trans),
+            FeesRequest::RevokeApproval(trans) => Box::new(trans),
+            FeesRequest::TransferFrom(trans) => Box::new(trans),
+            FeesRequest::CreateVesting(trans) => Box::new(trans),
+            FeesRequest::ClaimVested(trans) => Box::new(trans),
+            FeesRequest::OfferLoan(trans) => Box::new(trans),
+            FeesRequest::AcceptLoan(trans) => Box::new(trans),
+            FeesRequest::ReturnLoan(trans) => Box::new(trans),
+            FeesRequest::ClaimCollateral(trans) => Box::new(trans),
+            FeesRequest::CancelLoan(trans) => Box::new(trans),
+            FeesRequest::UpdateAssetFees(trans) => Box::new(trans),
+            FeesRequest::TransferAssetCreator(trans) => Box::new(trans),
+            FeesRequest::SetAssetRestriction(trans) => Box::new(trans),
+            FeesRequest::SetAssetSupply(trans) => Box::new(trans),
+            FeesRequest::SetAssetMetadata(trans) => Box::new(trans),
+            FeesRequest::UpdateAllowlist(trans) => Box::new(trans),
+            FeesRequest::FreezeWallet(trans) => Box::new(trans),
+            FeesRequest::UnfreezeWallet(trans) => Box::new(trans),
+            FeesRequest::MintTokens(trans) => Box::new(trans),
+            FeesRequest::CreateCollection(trans) => Box::new(trans),
+            FeesRequest::AddToCollection(trans) => Box::new(trans),
         }
     }
 }
@@ -61,6 +131,8 @@ pub struct FeesResponseBody {
 
 pub type FeesResponse = Result<Result<FeesResponseBody, Error>, ApiError>;
 
+pub type FeeQuoteResponse = Result<Result<FeeQuote, Error>, ApiError>;
+
 impl Api for FeesApi {
     fn wire(&self, router: &mut Router) {
         let self_ = self.clone();
@@ -96,6 +168,38 @@ impl Api for FeesApi {
             Ok(res)
         };
 
+        // Quotes the fees of a transaction from its description, so that it
+        // doesn't have to be signed first.
+        let self_ = self.clone();
+        let quote = move |req: &mut Request| -> IronResult<Response> {
+            let result: FeeQuoteResponse = match req.get::<bodyparser::Struct<FeeQuoteRequest>>() {
+                Ok(Some(request)) => {
+                    let view = &mut self_.blockchain.fork();
+                    Ok(request.quote(view))
+                }
+                Ok(None) => Err(ApiError::EmptyRequestBody),
+                Err(_) => Err(ApiError::IncorrectRequest),
+            };
+
+            let status_code = result
+                .clone()
+                .ok()
+                .map(|r| {
+                    r.err()
+                        .map(|_| status::BadRequest)
+                        .unwrap_or(status::Ok)
+                })
+                .unwrap_or(status::BadRequest);
+
+            let mut res =
+                Response::with((status_code, serde_json::to_string_pretty(&result).unwrap()));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            Ok(res)
+        };
+
         router.post("/v1/fees/transactions", fees, "transaction_fee");
+        router.post("/v1/fees/quote", quote, "transaction_fee_quote");
     }
 }
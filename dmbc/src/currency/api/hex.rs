@@ -15,9 +15,17 @@ use iron::status as istatus;
 use router::Router;
 
 use currency::api::error::ApiError;
-use currency::transactions::{AddAssets, DeleteAssets, Exchange, ExchangeIntermediary, Trade,
-                             TradeIntermediary, Transfer, EXCHANGE_ID, EXCHANGE_INTERMEDIARY_ID,
-                             TRADE_ID, TRADE_INTERMEDIARY_ID};
+use currency::transactions::{AcceptLoan, AddAssets, AddToCollection, Approve, CancelLoan,
+                             CartCheckout, ClaimCollateral, ClaimVested, CreateCollection,
+                             CreateMultisig, CreateVesting, DeleteAssets, Exchange,
+                             ExchangeIntermediary, ExchangeTokens, FreezeWallet, MintTokens,
+                             MultiExchange, MultiTransfer, MultisigApprove, MultisigPropose,
+                             MultisigSpend, OfferLoan, ReturnLoan, RevokeApproval, SetAssetMetadata,
+                             SetAssetRestriction, SetAssetSupply, Trade, TradeIntermediary,
+                             TradeTokens, Transfer, TransferAssetCreator, TransferFrom,
+                             TransferTokens, UnfreezeWallet, UpdateAllowlist, UpdateAssetFees,
+                             EXCHANGE_ID, EXCHANGE_INTERMEDIARY_ID, EXCHANGE_TOKENS_ID,
+                             MULTI_EXCHANGE_ID, TRADE_ID, TRADE_INTERMEDIARY_ID, TRADE_TOKENS_ID};
 
 #[derive(Clone)]
 pub struct HexApi {}
@@ -26,24 +34,86 @@ pub struct HexApi {}
 #[derive(Clone, Serialize, Deserialize)]
 enum TransactionRequest {
     Transfer(Transfer),
+    TransferTokens(TransferTokens),
     AddAssets(AddAssets),
     DeleteAssets(DeleteAssets),
     Trade(Trade),
+    TradeTokens(TradeTokens),
     TradeIntermediary(TradeIntermediary),
     Exchange(Exchange),
+    ExchangeTokens(ExchangeTokens),
     ExchangeIntermediary(ExchangeIntermediary),
+    CartCheckout(CartCheckout),
+    MultiExchange(MultiExchange),
+    MultiTransfer(MultiTransfer),
+    CreateMultisig(CreateMultisig),
+    MultisigSpend(MultisigSpend),
+    MultisigPropose(MultisigPropose),
+    MultisigApprove(MultisigApprove),
+    Approve(Approve),
+    RevokeApproval(RevokeApproval),
+    TransferFrom(TransferFrom),
+    CreateVesting(CreateVesting),
+    ClaimVested(ClaimVested),
+    OfferLoan(OfferLoan),
+    AcceptLoan(AcceptLoan),
+    ReturnLoan(ReturnLoan),
+    ClaimCollateral(ClaimCollateral),
+    CancelLoan(CancelLoan),
+    UpdateAssetFees(UpdateAssetFees),
+    TransferAssetCreator(TransferAssetCreator),
+    SetAssetRestriction(SetAssetRestriction),
+    SetAssetSupply(SetAssetSupply),
+    SetAssetMetadata(SetAssetMetadata),
+    UpdateAllowlist(UpdateAllowlist),
+    FreezeWallet(FreezeWallet),
+    UnfreezeWallet(UnfreezeWallet),
+    MintTokens(MintTokens),
+    CreateCollection(CreateCollection),
+    AddToCollection(AddToCollection),
 }
 
 impl Into<Box<Transaction>> for TransactionRequest {
     fn into(self) -> Box<Transaction> {
         match self {
             TransactionRequest::Transfer(trans) => Box::new(trans),
+            TransactionRequest::TransferTokens(trans) => Box::new(trans),
             TransactionRequest::AddAssets(trans) => Box::new(trans),
             TransactionRequest::DeleteAssets(trans) => Box::new(trans),
             TransactionRequest::Trade(trans) => Box::new(trans),
+            TransactionRequest::TradeTokens(trans) => Box::new(trans),
             TransactionRequest::TradeIntermediary(trans) => Box::new(trans),
             TransactionRequest::Exchange(trans) => Box::new(trans),
+            TransactionRequest::ExchangeTokens(trans) => Box::new(trans),
             TransactionRequest::ExchangeIntermediary(trans) => Box::new(trans),
+            TransactionRequest::CartCheckout(trans) => Box::new(trans),
+            TransactionRequest::MultiExchange(trans) => Box::new(trans),
+            TransactionRequest::MultiTransfer(trans) => Box::new(trans),
+            TransactionRequest::CreateMultisig(trans) => Box::new(trans),
+            TransactionRequest::MultisigSpend(trans) => Box::new(trans),
+            TransactionRequest::MultisigPropose(trans) => Box::new(trans),
+            TransactionRequest::MultisigApprove(trans) => Box::new(trans),
+            TransactionRequest::Approve(trans) => Box::new(trans),
+            TransactionRequest::RevokeApproval(trans) => Box::new(trans),
+            TransactionRequest::TransferFrom(trans) => Box::new(trans),
+            TransactionRequest::CreateVesting(trans) => Box::new(trans),
+            TransactionRequest::ClaimVested(trans) => Box::new(trans),
+            TransactionRequest::OfferLoan(trans) => Box::new(trans),
+            TransactionRequest::AcceptLoan(trans) => Box::new(trans),
+            TransactionRequest::ReturnLoan(trans) => Box::new(trans),
+            TransactionRequest::ClaimCollateral(trans) => Box::new(trans),
+            TransactionRequest::CancelLoan(trans) => Box::new(trans),
+            TransactionRequest::UpdateAssetFees(trans) => Box::new(trans),
+            TransactionRequest::TransferAssetCreator(trans) => Box::new(trans),
+            TransactionRequest::SetAssetRestriction(trans) => Box::new(trans),
+            TransactionRequest::SetAssetSupply(trans) => Box::new(trans),
+            TransactionRequest::SetAssetMetadata(trans) => Box::new(trans),
+            TransactionRequest::UpdateAllowlist(trans) => Box::new(trans),
+            TransactionRequest::FreezeWallet(trans) => Box::new(trans),
+            TransactionRequest::UnfreezeWallet(trans) => Box::new(trans),
+            TransactionRequest::MintTokens(trans) => Box::new(trans),
+            TransactionRequest::CreateCollection(trans) => Box::new(trans),
+            TransactionRequest::AddToCollection(trans) => Box::new(trans),
         }
     }
 }
@@ -101,14 +171,26 @@ impl Api for HexApi {
                             Ok(exchange) => Some(exchange.offer_raw()),
                             Err(_) => None,
                         },
+                        EXCHANGE_TOKENS_ID => match ExchangeTokens::from_raw(raw_) {
+                            Ok(exchange) => Some(exchange.offer_raw()),
+                            Err(_) => None,
+                        },
                         EXCHANGE_INTERMEDIARY_ID => match ExchangeIntermediary::from_raw(raw_) {
                             Ok(exchange) => Some(exchange.offer_raw()),
                             Err(_) => None,
                         },
+                        MULTI_EXCHANGE_ID => match MultiExchange::from_raw(raw_) {
+                            Ok(exchange) => Some(exchange.offer_raw()),
+                            Err(_) => None,
+                        },
                         TRADE_ID => match Trade::from_raw(raw_) {
                             Ok(trade) => Some(trade.offer_raw()),
                             Err(_) => None,
                         },
+                        TRADE_TOKENS_ID => match TradeTokens::from_raw(raw_) {
+                            Ok(trade) => Some(trade.offer_raw()),
+                            Err(_) => None,
+                        },
                         TRADE_INTERMEDIARY_ID => match TradeIntermediary::from_raw(raw_) {
                             Ok(trade) => Some(trade.offer_raw()),
                             Err(_) => None,
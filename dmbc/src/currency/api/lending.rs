@@ -0,0 +1,178 @@
+extern crate serde_json;
+
+use exonum::api::Api;
+use exonum::blockchain::Blockchain;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::encoding::serialize::FromHex;
+use hyper::header::ContentType;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::api::ServiceApi;
+use currency::assets::AssetBundle;
+use currency::lending;
+use currency::lending::{Loan, LoanState};
+
+#[derive(Clone)]
+pub struct LendingApi {
+    pub blockchain: Blockchain,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct LoanInfo {
+    pub id: Hash,
+    pub lender: PublicKey,
+    pub borrower: Option<PublicKey>,
+    pub assets: Vec<AssetBundle>,
+    pub collateral: u64,
+    pub rental_fee: u64,
+    pub duration: u64,
+    pub deadline: Option<u64>,
+    pub state: u8,
+}
+
+impl LoanInfo {
+    pub fn from(id: Hash, loan: Loan) -> Self {
+        let accepted = loan.loan_state() != LoanState::Offered;
+        LoanInfo {
+            id,
+            lender: *loan.lender(),
+            borrower: if accepted { Some(*loan.borrower()) } else { None },
+            assets: loan.assets(),
+            collateral: loan.collateral(),
+            rental_fee: loan.rental_fee(),
+            duration: loan.duration(),
+            deadline: if accepted { Some(loan.deadline()) } else { None },
+            state: loan.state(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct LoansResponseBody {
+    pub total: u64,
+    pub count: u64,
+    pub loans: Vec<LoanInfo>,
+}
+
+pub type LoanResponse = Result<LoanInfo, ApiError>;
+
+pub type LoansResponse = Result<LoansResponseBody, ApiError>;
+
+impl LendingApi {
+    fn loan(&self, id: &Hash) -> Option<LoanInfo> {
+        let view = self.blockchain.fork();
+        lending::Schema(view)
+            .fetch(id)
+            .map(|loan| LoanInfo::from(*id, loan))
+    }
+
+    fn wallet_loans(&self, pub_key: &PublicKey) -> Vec<LoanInfo> {
+        let view = self.blockchain.fork();
+        lending::Schema(view)
+            .fetch_wallet_loans(pub_key)
+            .into_iter()
+            .map(|(id, loan)| LoanInfo::from(id, loan))
+            .collect()
+    }
+}
+
+lazy_static! {
+    static ref LOAN_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_lending_api_loan_requests_total",
+        "Loan requests."
+    ).unwrap();
+    static ref LOAN_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_lending_api_loan_responses_total",
+        "Loan responses."
+    ).unwrap();
+    static ref WALLET_LOANS_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_lending_api_wallet_loans_requests_total",
+        "Wallet loan list requests."
+    ).unwrap();
+    static ref WALLET_LOANS_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_lending_api_wallet_loans_responses_total",
+        "Wallet loan list responses."
+    ).unwrap();
+}
+
+impl Api for LendingApi {
+    fn wire(&self, router: &mut Router) {
+        // Gets a loan by the hash of its `offer_loan` transaction.
+        let self_ = self.clone();
+        let loan_info = move |req: &mut Request| -> IronResult<Response> {
+            LOAN_REQUESTS.inc();
+
+            let path = req.url.path();
+            let loan_id = path.last().unwrap();
+            let result: LoanResponse = Hash::from_hex(loan_id)
+                .map_err(|_| ApiError::TransactionHashInvalid)
+                .and_then(|id| self_.loan(&id).ok_or(ApiError::LoanNotFound));
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            LOAN_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Gets loans where the wallet is the lender or the borrower.
+        let self_ = self.clone();
+        let wallet_loans = move |req: &mut Request| -> IronResult<Response> {
+            WALLET_LOANS_REQUESTS.inc();
+
+            let public_key_result = {
+                let wallet_key = req.extensions
+                    .get::<Router>()
+                    .unwrap()
+                    .find("pub_key")
+                    .unwrap();
+                PublicKey::from_hex(wallet_key)
+            };
+            let result: LoansResponse = match public_key_result {
+                Ok(public_key) => {
+                    let loans = self_.wallet_loans(&public_key);
+                    // apply pagination parameters if they exist
+                    let loans_to_send = ServiceApi::apply_pagination(req, &loans);
+                    Ok(LoansResponseBody {
+                        total: loans.len() as u64,
+                        count: loans_to_send.len() as u64,
+                        loans: loans_to_send.to_vec(),
+                    })
+                }
+                Err(_) => Err(ApiError::WalletHexInvalid),
+            };
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            WALLET_LOANS_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        router.get("/v1/loans/:loan_id", loan_info, "loan_info");
+        router.get("/v1/wallets/:pub_key/loans", wallet_loans, "wallet_loans");
+    }
+}
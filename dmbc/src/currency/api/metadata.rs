@@ -0,0 +1,160 @@
+extern crate serde_json;
+
+use std::io::Read;
+
+use exonum::api::Api;
+use exonum::crypto::Hash;
+use exonum::encoding::serialize::FromHex;
+use hyper::header::ContentType;
+use hyper::mime::Mime;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::metadata::{Store, StoreError, CONTENT_MAX_LENGTH};
+
+#[derive(Clone)]
+pub struct MetadataApi {
+    pub store: Store,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct MetadataResponseBody {
+    pub content_hash: Hash,
+}
+
+pub type MetadataResponse = Result<MetadataResponseBody, ApiError>;
+
+impl MetadataApi {
+    fn content_hash(req: &mut Request) -> Result<Hash, ApiError> {
+        let content_hash = req.extensions
+            .get::<Router>()
+            .unwrap()
+            .find("content_hash")
+            .unwrap();
+        Hash::from_hex(content_hash).map_err(|_| ApiError::MetadataHashInvalid)
+    }
+
+    fn read_content(req: &mut Request) -> Result<Vec<u8>, ApiError> {
+        let mut content = Vec::new();
+        req.body
+            .by_ref()
+            .take(CONTENT_MAX_LENGTH as u64 + 1)
+            .read_to_end(&mut content)
+            .map_err(|_| ApiError::IncorrectRequest)?;
+        match content.len() {
+            0 => Err(ApiError::EmptyRequestBody),
+            _ => Ok(content),
+        }
+    }
+
+    fn to_api_error(e: StoreError) -> ApiError {
+        match e {
+            StoreError::HashMismatch => ApiError::MetadataHashMismatch,
+            StoreError::TooLarge => ApiError::MetadataTooLarge,
+            StoreError::Io(e) => {
+                error!("Metadata store failed: {}", e);
+                ApiError::MetadataStoreFailed
+            }
+        }
+    }
+}
+
+lazy_static! {
+    static ref UPLOAD_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_metadata_api_upload_requests_total",
+        "Metadata upload requests."
+    ).unwrap();
+    static ref UPLOAD_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_metadata_api_upload_responses_total",
+        "Metadata upload responses."
+    ).unwrap();
+    static ref FETCH_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_metadata_api_fetch_requests_total",
+        "Metadata fetch requests."
+    ).unwrap();
+    static ref FETCH_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_metadata_api_fetch_responses_total",
+        "Metadata fetch responses."
+    ).unwrap();
+}
+
+impl Api for MetadataApi {
+    fn wire(&self, router: &mut Router) {
+        // Stores the request body under its hash. The upload is refused if
+        // the body does not hash to the one in the path.
+        let self_ = self.clone();
+        let upload = move |req: &mut Request| -> IronResult<Response> {
+            UPLOAD_REQUESTS.inc();
+
+            let result: MetadataResponse = MetadataApi::content_hash(req).and_then(|hash| {
+                let content = MetadataApi::read_content(req)?;
+                self_
+                    .store
+                    .put(&hash, &content)
+                    .map_err(MetadataApi::to_api_error)?;
+                Ok(MetadataResponseBody { content_hash: hash })
+            });
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Created),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            UPLOAD_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Gets the content stored under a hash, checking it against the hash.
+        let self_ = self.clone();
+        let fetch = move |req: &mut Request| -> IronResult<Response> {
+            FETCH_REQUESTS.inc();
+
+            let result: Result<Vec<u8>, ApiError> =
+                MetadataApi::content_hash(req).and_then(|hash| {
+                    self_
+                        .store
+                        .get(&hash)
+                        .map_err(MetadataApi::to_api_error)?
+                        .ok_or(ApiError::MetadataNotFound)
+                });
+
+            let mut res = match result {
+                Ok(content) => {
+                    let mut res = Response::with((status::Ok, content));
+                    res.headers.set(ContentType(
+                        "application/octet-stream".parse::<Mime>().unwrap(),
+                    ));
+                    res
+                }
+                Err(e) => {
+                    let result: MetadataResponse = Err(e);
+                    let mut res = Response::with((
+                        e.to_status(),
+                        serde_json::to_string_pretty(&result).unwrap(),
+                    ));
+                    res.headers.set(ContentType::json());
+                    res
+                }
+            };
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            FETCH_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        router.post("/v1/metadata/:content_hash", upload, "metadata_upload");
+        router.get("/v1/metadata/:content_hash", fetch, "metadata_fetch");
+    }
+}
@@ -1,14 +1,24 @@
 // TODO: currency service API documentation.
 #![allow(missing_docs)]
 
+pub mod access;
 pub mod asset;
 pub mod assets_intern;
+pub mod collection;
 pub mod error;
+pub mod events;
 pub mod fees;
 pub mod hex;
+pub mod lending;
+pub mod metadata;
 pub mod metrics;
+pub mod multisig;
+pub mod openapi;
 pub mod db_stats;
+pub mod search;
+pub mod token;
 pub mod transaction;
+pub mod v2;
 pub mod wallet;
 pub mod blocks;
 
@@ -26,19 +36,30 @@ use router::Router;
 use std::cmp;
 use unicase::UniCase;
 
+use currency::metadata::Store;
+
 use self::asset::AssetApi;
 use self::assets_intern::AssetInternApi;
+use self::collection::CollectionApi;
+use self::events::EventsApi;
 use self::fees::FeesApi;
 use self::hex::HexApi;
+use self::lending::LendingApi;
+use self::metadata::MetadataApi;
 use self::metrics::MetricsApi;
+use self::multisig::MultisigApi;
+use self::openapi::OpenApiApi;
 use self::db_stats::DbStatsApi;
 use self::blocks::BlocksApi;
 use self::params::{FromValue, Params};
+use self::search::SearchApi;
+use self::token::TokenApi;
 use self::transaction::TransactionApi;
+use self::v2::V2Api;
 use self::wallet::WalletApi;
 
 const PARAMETER_OFFSET_KEY: &str = "offset";
-const PARAMETER_LIMIT_KEY: &str = "limit";
+pub const PARAMETER_LIMIT_KEY: &str = "limit";
 
 #[derive(Clone)]
 pub struct ServiceApi {
@@ -103,9 +124,13 @@ impl ServiceApi {
 
     pub fn add_option_headers(headers: &mut Headers) {
         headers.set(AccessControlAllowOrigin::Any);
-        headers.set(AccessControlAllowHeaders(vec![UniCase(
-            "content-type".to_owned(),
-        )]));
+        headers.set(AccessControlAllowHeaders(vec![
+            UniCase("content-type".to_owned()),
+            UniCase(access::API_KEY_HEADER.to_lowercase()),
+            UniCase(access::PUBLIC_KEY_HEADER.to_lowercase()),
+            UniCase(access::TIMESTAMP_HEADER.to_lowercase()),
+            UniCase(access::SIGNATURE_HEADER.to_lowercase()),
+        ]));
         headers.set(AccessControlAllowMethods(vec![
             Method::Get,
             Method::Post,
@@ -155,6 +180,50 @@ impl Api for ServiceApi {
         };
         api.wire(router);
 
+        let api = MultisigApi {
+            blockchain: self.clone().blockchain,
+        };
+        api.wire(router);
+
+        let api = LendingApi {
+            blockchain: self.clone().blockchain,
+        };
+        api.wire(router);
+
+        let api = TokenApi {
+            blockchain: self.clone().blockchain,
+        };
+        api.wire(router);
+
+        let api = CollectionApi {
+            blockchain: self.clone().blockchain,
+        };
+        api.wire(router);
+
+        let api = MetadataApi {
+            store: Store::from_config(),
+        };
+        api.wire(router);
+
+        let api = SearchApi {
+            blockchain: self.clone().blockchain,
+        };
+        api.wire(router);
+
+        let api = EventsApi {
+            blockchain: self.clone().blockchain,
+        };
+        api.wire(router);
+
+        let api = V2Api {
+            channel: self.clone().channel,
+            blockchain: self.clone().blockchain,
+        };
+        api.wire(router);
+
+        let api = OpenApiApi {};
+        api.wire(router);
+
         let send_option = move |_request: &mut Request| -> IronResult<Response> {
             let mut resp = Response::with(StatusCode::Ok);
             ServiceApi::add_option_headers(&mut resp.headers);
@@ -0,0 +1,239 @@
+extern crate serde_json;
+
+use exonum::api::Api;
+use exonum::blockchain::Blockchain;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::encoding::serialize::{encode_hex, FromHex};
+use hyper::header::ContentType;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::api::ServiceApi;
+use currency::multisig;
+use currency::multisig::{MultisigConfig, Proposal};
+
+#[derive(Clone)]
+pub struct MultisigApi {
+    pub blockchain: Blockchain,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct MultisigInfo {
+    pub address: PublicKey,
+    pub members: Vec<PublicKey>,
+    pub threshold: u8,
+}
+
+impl MultisigInfo {
+    pub fn from(config: MultisigConfig) -> Self {
+        MultisigInfo {
+            address: config.address(),
+            members: config.members().iter().map(|m| *m.pub_key()).collect(),
+            threshold: config.threshold(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ProposalInfo {
+    pub id: Hash,
+    pub wallet: PublicKey,
+    pub tx: String,
+    pub approvals: Vec<PublicKey>,
+}
+
+impl ProposalInfo {
+    pub fn from(id: Hash, proposal: Proposal) -> Self {
+        ProposalInfo {
+            id,
+            wallet: *proposal.wallet(),
+            tx: encode_hex(proposal.tx()),
+            approvals: proposal.approvals().iter().map(|m| *m.pub_key()).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ProposalsResponseBody {
+    pub total: u64,
+    pub count: u64,
+    pub proposals: Vec<ProposalInfo>,
+}
+
+pub type MultisigResponse = Result<MultisigInfo, ApiError>;
+
+pub type ProposalsResponse = Result<ProposalsResponseBody, ApiError>;
+
+pub type ProposalResponse = Result<ProposalInfo, ApiError>;
+
+impl MultisigApi {
+    fn config(&self, address: &PublicKey) -> Option<MultisigConfig> {
+        let view = self.blockchain.fork();
+        multisig::Schema(view).fetch(address)
+    }
+
+    fn proposals(&self, address: &PublicKey) -> Vec<ProposalInfo> {
+        let view = self.blockchain.fork();
+        multisig::Schema(view)
+            .fetch_wallet_proposals(address)
+            .into_iter()
+            .map(|(id, proposal)| ProposalInfo::from(id, proposal))
+            .collect()
+    }
+
+    fn proposal(&self, id: &Hash) -> Option<ProposalInfo> {
+        let view = self.blockchain.fork();
+        multisig::Schema(view)
+            .fetch_proposal(id)
+            .map(|proposal| ProposalInfo::from(*id, proposal))
+    }
+}
+
+lazy_static! {
+    static ref INFO_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_multisig_api_info_requests_total",
+        "Multisig wallet info requests."
+    ).unwrap();
+    static ref INFO_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_multisig_api_info_responses_total",
+        "Multisig wallet info responses."
+    ).unwrap();
+    static ref PROPOSALS_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_multisig_api_proposals_requests_total",
+        "Pending proposal list requests."
+    ).unwrap();
+    static ref PROPOSALS_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_multisig_api_proposals_responses_total",
+        "Pending proposal list responses."
+    ).unwrap();
+    static ref PROPOSAL_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_multisig_api_proposal_requests_total",
+        "Pending proposal requests."
+    ).unwrap();
+    static ref PROPOSAL_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_multisig_api_proposal_responses_total",
+        "Pending proposal responses."
+    ).unwrap();
+}
+
+impl Api for MultisigApi {
+    fn wire(&self, router: &mut Router) {
+        // Gets key set and threshold of the multisig wallet.
+        let self_ = self.clone();
+        let multisig_info = move |req: &mut Request| -> IronResult<Response> {
+            INFO_REQUESTS.inc();
+
+            let path = req.url.path();
+            let wallet_key = path.last().unwrap();
+            let result: MultisigResponse = PublicKey::from_hex(wallet_key)
+                .map_err(|_| ApiError::WalletHexInvalid)
+                .and_then(|address| {
+                    self_
+                        .config(&address)
+                        .map(MultisigInfo::from)
+                        .ok_or(ApiError::MultisigNotFound)
+                });
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            INFO_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Gets pending proposals of the multisig wallet.
+        let self_ = self.clone();
+        let multisig_proposals = move |req: &mut Request| -> IronResult<Response> {
+            PROPOSALS_REQUESTS.inc();
+
+            let public_key_result = {
+                let wallet_key = req.extensions
+                    .get::<Router>()
+                    .unwrap()
+                    .find("pub_key")
+                    .unwrap();
+                PublicKey::from_hex(wallet_key)
+            };
+            let result: ProposalsResponse = match public_key_result {
+                Ok(address) => {
+                    let proposals = self_.proposals(&address);
+                    // apply pagination parameters if they exist
+                    let proposals_to_send = ServiceApi::apply_pagination(req, &proposals);
+                    Ok(ProposalsResponseBody {
+                        total: proposals.len() as u64,
+                        count: proposals_to_send.len() as u64,
+                        proposals: proposals_to_send.to_vec(),
+                    })
+                }
+                Err(_) => Err(ApiError::WalletHexInvalid),
+            };
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            PROPOSALS_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Gets a pending proposal by the hash of its `multisig_propose` transaction.
+        let self_ = self.clone();
+        let proposal_info = move |req: &mut Request| -> IronResult<Response> {
+            PROPOSAL_REQUESTS.inc();
+
+            let path = req.url.path();
+            let proposal_id = path.last().unwrap();
+            let result: ProposalResponse = Hash::from_hex(proposal_id)
+                .map_err(|_| ApiError::TransactionHashInvalid)
+                .and_then(|id| self_.proposal(&id).ok_or(ApiError::ProposalNotFound));
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            PROPOSAL_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        router.get("/v1/multisig/:pub_key", multisig_info, "multisig_info");
+        router.get(
+            "/v1/multisig/:pub_key/proposals",
+            multisig_proposals,
+            "multisig_proposals",
+        );
+        router.get(
+            "/v1/proposals/:proposal_id",
+            proposal_info,
+            "proposal_info",
+        );
+    }
+}
@@ -0,0 +1,1973 @@
+//! OpenAPI 3 description of the service API.
+//!
+//! The document is built from the tables below: `ROUTES` lists every route
+//! wired by `ServiceApi`, `STRUCTS` and `MESSAGES` the fields of the
+//! `encoding_struct!` and `message!` types as they appear in JSON. Keep them
+//! in step with the handlers, `tests/openapi.rs` fails on routes missing here.
+
+extern crate serde_json;
+
+use exonum::api::Api;
+use hyper::header::ContentType;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use iron::status;
+use router::Router;
+use serde_json::{Map, Value};
+
+use currency::error::Error;
+use currency::transactions::{ACCEPT_LOAN_ID, ADD_ASSETS_ID, ADD_TO_COLLECTION_ID, APPROVE_ID,
+                             CANCEL_LOAN_ID, CART_CHECKOUT_ID, CLAIM_COLLATERAL_ID, CLAIM_VESTED_ID,
+                             CREATE_COLLECTION_ID, CREATE_MULTISIG_ID, CREATE_VESTING_ID,
+                             DELETE_ASSETS_ID, EXCHANGE_ID, EXCHANGE_INTERMEDIARY_ID,
+                             EXCHANGE_TOKENS_ID, FREEZE_WALLET_ID, MINT_TOKENS_ID,
+                             MULTISIG_APPROVE_ID, MULTISIG_PROPOSE_ID, MULTISIG_SPEND_ID,
+                             MULTI_EXCHANGE_ID, MULTI_TRANSFER_ID, OFFER_LOAN_ID, RETURN_LOAN_ID,
+                             REVOKE_APPROVAL_ID, SET_ASSET_METADATA_ID, SET_ASSET_RESTRICTION_ID,
+                             SET_ASSET_SUPPLY_ID, TRADE_ID, TRADE_INTERMEDIARY_ID, TRADE_TOKENS_ID,
+                             TRANSFER_ASSET_CREATOR_ID, TRANSFER_FROM_ID, TRANSFER_ID,
+                             TRANSFER_TOKENS_ID, UNFREEZE_WALLET_ID, UPDATE_ALLOWLIST_ID,
+                             UPDATE_ASSET_FEES_ID};
+use currency::{SERVICE_ID, SERVICE_NAME};
+
+pub const OPENAPI_VERSION: &str = "3.0.0";
+
+#[derive(Clone)]
+pub struct OpenApiApi {}
+
+/// JSON type of a field of an Exonum structure.
+#[derive(Clone, Copy, Debug)]
+enum Field {
+    /// 64-bit integer, written as a decimal string by Exonum.
+    U64,
+    U8,
+    Bool,
+    Str,
+    /// Byte array as hex.
+    Bytes,
+    PublicKey,
+    Hash,
+    Signature,
+    AssetId,
+    /// `decimal::UFract64` as a decimal fraction string.
+    Fraction,
+    Struct(&'static str),
+    List(&'static str),
+}
+
+type Fields = &'static [(&'static str, Field)];
+
+use self::Field::*;
+
+/// `encoding_struct!` types, by name.
+const STRUCTS: &[(&str, Fields)] = &[
+    ("AssetBundle", &[("id", AssetId), ("amount", U64)]),
+    (
+        "MetaAsset",
+        &[
+            ("receiver", PublicKey),
+            ("data", Str),
+            ("amount", U64),
+            ("fees", Struct("Fees")),
+        ],
+    ),
+    (
+        "AssetInfo",
+        &[
+            ("creator", PublicKey),
+            ("origin", Hash),
+            ("amount", U64),
+            ("fees", Struct("Fees")),
+            ("data", Str),
+        ],
+    ),
+    (
+        "AssetChange",
+        &[
+            ("tx_hash", Hash),
+            ("height", U64),
+            ("old_creator", PublicKey),
+            ("new_creator", PublicKey),
+            ("old_fees", Struct("Fees")),
+            ("new_fees", Struct("Fees")),
+        ],
+    ),
+    ("Fee", &[("fixed", U64), ("fraction", Fraction)]),
+    (
+        "Fees",
+        &[
+            ("trade", Struct("Fee")),
+            ("exchange", Struct("Fee")),
+            ("transfer", Struct("Fee")),
+        ],
+    ),
+    (
+        "SupplyPolicy",
+        &[("id", AssetId), ("max_supply", U64), ("sealed", Bool)],
+    ),
+    (
+        "Supply",
+        &[("max_supply", U64), ("sealed", Bool), ("minted", U64)],
+    ),
+    (
+        "MetadataRef",
+        &[("content_hash", Hash), ("media_type", Str), ("uri", Str)],
+    ),
+    (
+        "Collection",
+        &[
+            ("creator", PublicKey),
+            ("parent", Hash),
+            ("name", Str),
+            ("data", Str),
+            ("fees", Struct("Fees")),
+        ],
+    ),
+    (
+        "CollectionMember",
+        &[("id", AssetId), ("collection", Hash), ("own_fees", Bool)],
+    ),
+    (
+        "TradeAsset",
+        &[("id", AssetId), ("amount", U64), ("price", U64)],
+    ),
+    ("TokenId", &[("asset_id", AssetId), ("serial", U64)]),
+    (
+        "Token",
+        &[
+            ("id", Struct("TokenId")),
+            ("creator", PublicKey),
+            ("owner", PublicKey),
+            ("origin", Hash),
+            ("data", Str),
+        ],
+    ),
+    ("MetaToken", &[("serial", U64), ("data", Str)]),
+    ("TradeToken", &[("id", Struct("TokenId")), ("price", U64)]),
+    ("Intermediary", &[("wallet", PublicKey), ("commission", U64)]),
+    (
+        "PartySignature",
+        &[("pub_key", PublicKey), ("signature", Signature)],
+    ),
+    (
+        "TradeOffer",
+        &[
+            ("buyer", PublicKey),
+            ("seller", PublicKey),
+            ("assets", List("TradeAsset")),
+            ("fee_strategy", U8),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "TradeTokensOffer",
+        &[
+            ("buyer", PublicKey),
+            ("seller", PublicKey),
+            ("assets", List("TradeAsset")),
+            ("tokens", List("TradeToken")),
+            ("fee_strategy", U8),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "TradeOfferIntermediary",
+        &[
+            ("intermediary", Struct("Intermediary")),
+            ("buyer", PublicKey),
+            ("seller", PublicKey),
+            ("assets", List("TradeAsset")),
+            ("fee_strategy", U8),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "CartItem",
+        &[
+            ("offer", Struct("TradeOffer")),
+            ("seller_signature", Signature),
+        ],
+    ),
+    (
+        "ExchangeOffer",
+        &[
+            ("sender", PublicKey),
+            ("sender_assets", List("AssetBundle")),
+            ("sender_value", U64),
+            ("recipient", PublicKey),
+            ("recipient_assets", List("AssetBundle")),
+            ("fee_strategy", U8),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "ExchangeTokensOffer",
+        &[
+            ("sender", PublicKey),
+            ("sender_assets", List("AssetBundle")),
+            ("sender_tokens", List("TokenId")),
+            ("sender_value", U64),
+            ("recipient", PublicKey),
+            ("recipient_assets", List("AssetBundle")),
+            ("recipient_tokens", List("TokenId")),
+            ("fee_strategy", U8),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "ExchangeOfferIntermediary",
+        &[
+            ("intermediary", Struct("Intermediary")),
+            ("sender", PublicKey),
+            ("sender_assets", List("AssetBundle")),
+            ("sender_value", U64),
+            ("recipient", PublicKey),
+            ("recipient_assets", List("AssetBundle")),
+            ("fee_strategy", U8),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "ExchangeLeg",
+        &[
+            ("from", PublicKey),
+            ("to", PublicKey),
+            ("value", U64),
+            ("assets", List("AssetBundle")),
+        ],
+    ),
+    (
+        "MultiExchangeOffer",
+        &[
+            ("legs", List("ExchangeLeg")),
+            ("fee_strategy", U8),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "TransferOutput",
+        &[
+            ("to", PublicKey),
+            ("amount", U64),
+            ("assets", List("AssetBundle")),
+            ("data_info", Str),
+        ],
+    ),
+    ("MultisigMember", &[("pub_key", PublicKey)]),
+    (
+        "MultisigConfig",
+        &[("members", List("MultisigMember")), ("threshold", U8)],
+    ),
+    (
+        "Proposal",
+        &[
+            ("wallet", PublicKey),
+            ("tx", Bytes),
+            ("approvals", List("MultisigMember")),
+        ],
+    ),
+    ("Wallet", &[("balance", U64), ("assets", List("AssetBundle"))]),
+    (
+        "Allowance",
+        &[
+            ("owner", PublicKey),
+            ("spender", PublicKey),
+            ("amount", U64),
+            ("assets", List("AssetBundle")),
+            ("expires_at", U64),
+        ],
+    ),
+    (
+        "Loan",
+        &[
+            ("lender", PublicKey),
+            ("borrower", PublicKey),
+            ("assets", List("AssetBundle")),
+            ("collateral", U64),
+            ("rental_fee", U64),
+            ("duration", U64),
+            ("deadline", U64),
+            ("state", U8),
+        ],
+    ),
+    (
+        "Vesting",
+        &[
+            ("creator", PublicKey),
+            ("beneficiary", PublicKey),
+            ("start", U64),
+            ("cliff", U64),
+            ("end", U64),
+            ("curve", U8),
+            ("granted", Struct("Wallet")),
+            ("claimed", Struct("Wallet")),
+        ],
+    ),
+    (
+        "Freeze",
+        &[("officer", PublicKey), ("height", U64), ("reason", Str)],
+    ),
+];
+
+/// `message!` transaction types with their message IDs.
+const MESSAGES: &[(&str, u16, Fields)] = &[
+    (
+        "Transfer",
+        TRANSFER_ID,
+        &[
+            ("from", PublicKey),
+            ("to", PublicKey),
+            ("amount", U64),
+            ("assets", List("AssetBundle")),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "TransferTokens",
+        TRANSFER_TOKENS_ID,
+        &[
+            ("from", PublicKey),
+            ("to", PublicKey),
+            ("amount", U64),
+            ("assets", List("AssetBundle")),
+            ("tokens", List("TokenId")),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "MultiTransfer",
+        MULTI_TRANSFER_ID,
+        &[
+            ("from", PublicKey),
+            ("outputs", List("TransferOutput")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "AddAssets",
+        ADD_ASSETS_ID,
+        &[
+            ("pub_key", PublicKey),
+            ("meta_assets", List("MetaAsset")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "DeleteAssets",
+        DELETE_ASSETS_ID,
+        &[
+            ("pub_key", PublicKey),
+            ("assets", List("AssetBundle")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "Trade",
+        TRADE_ID,
+        &[
+            ("offer", Struct("TradeOffer")),
+            ("seller_signature", Signature),
+        ],
+    ),
+    (
+        "TradeTokens",
+        TRADE_TOKENS_ID,
+        &[
+            ("offer", Struct("TradeTokensOffer")),
+            ("seller_signature", Signature),
+        ],
+    ),
+    (
+        "TradeIntermediary",
+        TRADE_INTERMEDIARY_ID,
+        &[
+            ("offer", Struct("TradeOfferIntermediary")),
+            ("seller_signature", Signature),
+            ("intermediary_signature", Signature),
+        ],
+    ),
+    (
+        "CartCheckout",
+        CART_CHECKOUT_ID,
+        &[
+            ("buyer", PublicKey),
+            ("items", List("CartItem")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "Exchange",
+        EXCHANGE_ID,
+        &[
+            ("offer", Struct("ExchangeOffer")),
+            ("sender_signature", Signature),
+        ],
+    ),
+    (
+        "ExchangeTokens",
+        EXCHANGE_TOKENS_ID,
+        &[
+            ("offer", Struct("ExchangeTokensOffer")),
+            ("sender_signature", Signature),
+        ],
+    ),
+    (
+        "ExchangeIntermediary",
+        EXCHANGE_INTERMEDIARY_ID,
+        &[
+            ("offer", Struct("ExchangeOfferIntermediary")),
+            ("sender_signature", Signature),
+            ("intermediary_signature", Signature),
+        ],
+    ),
+    (
+        "MultiExchange",
+        MULTI_EXCHANGE_ID,
+        &[
+            ("submitter", PublicKey),
+            ("offer", Struct("MultiExchangeOffer")),
+            ("signatures", List("PartySignature")),
+        ],
+    ),
+    (
+        "CreateMultisig",
+        CREATE_MULTISIG_ID,
+        &[
+            ("creator", PublicKey),
+            ("config", Struct("MultisigConfig")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "MultisigSpend",
+        MULTISIG_SPEND_ID,
+        &[
+            ("submitter", PublicKey),
+            ("wallet", PublicKey),
+            ("tx", Bytes),
+            ("signatures", List("PartySignature")),
+        ],
+    ),
+    (
+        "MultisigPropose",
+        MULTISIG_PROPOSE_ID,
+        &[
+            ("proposer", PublicKey),
+            ("wallet", PublicKey),
+            ("tx", Bytes),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "MultisigApprove",
+        MULTISIG_APPROVE_ID,
+        &[("approver", PublicKey), ("proposal", Hash), ("seed", U64)],
+    ),
+    (
+        "Approve",
+        APPROVE_ID,
+        &[
+            ("owner", PublicKey),
+            ("spender", PublicKey),
+            ("amount", U64),
+            ("assets", List("AssetBundle")),
+            ("expires_at", U64),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "RevokeApproval",
+        REVOKE_APPROVAL_ID,
+        &[("owner", PublicKey), ("spender", PublicKey), ("seed", U64)],
+    ),
+    (
+        "TransferFrom",
+        TRANSFER_FROM_ID,
+        &[
+            ("spender", PublicKey),
+            ("from", PublicKey),
+            ("to", PublicKey),
+            ("amount", U64),
+            ("assets", List("AssetBundle")),
+            ("seed", U64),
+            ("data_info", Str),
+        ],
+    ),
+    (
+        "CreateVesting",
+        CREATE_VESTING_ID,
+        &[
+            ("creator", PublicKey),
+            ("beneficiary", PublicKey),
+            ("amount", U64),
+            ("assets", List("AssetBundle")),
+            ("cliff", U64),
+            ("end", U64),
+            ("curve", U8),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "ClaimVested",
+        CLAIM_VESTED_ID,
+        &[("beneficiary", PublicKey), ("vesting", Hash), ("seed", U64)],
+    ),
+    (
+        "OfferLoan",
+        OFFER_LOAN_ID,
+        &[
+            ("lender", PublicKey),
+            ("assets", List("AssetBundle")),
+            ("collateral", U64),
+            ("rental_fee", U64),
+            ("duration", U64),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "AcceptLoan",
+        ACCEPT_LOAN_ID,
+        &[("borrower", PublicKey), ("loan", Hash), ("seed", U64)],
+    ),
+    (
+        "ReturnLoan",
+        RETURN_LOAN_ID,
+        &[("borrower", PublicKey), ("loan", Hash), ("seed", U64)],
+    ),
+    (
+        "ClaimCollateral",
+        CLAIM_COLLATERAL_ID,
+        &[("lender", PublicKey), ("loan", Hash), ("seed", U64)],
+    ),
+    (
+        "CancelLoan",
+        CANCEL_LOAN_ID,
+        &[("lender", PublicKey), ("loan", Hash), ("seed", U64)],
+    ),
+    (
+        "UpdateAssetFees",
+        UPDATE_ASSET_FEES_ID,
+        &[
+            ("creator", PublicKey),
+            ("asset_id", AssetId),
+            ("fees", Struct("Fees")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "TransferAssetCreator",
+        TRANSFER_ASSET_CREATOR_ID,
+        &[
+            ("creator", PublicKey),
+            ("asset_id", AssetId),
+            ("new_creator", PublicKey),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "SetAssetRestriction",
+        SET_ASSET_RESTRICTION_ID,
+        &[
+            ("creator", PublicKey),
+            ("asset_id", AssetId),
+            ("registry", Hash),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "SetAssetSupply",
+        SET_ASSET_SUPPLY_ID,
+        &[
+            ("creator", PublicKey),
+            ("policy", Struct("SupplyPolicy")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "SetAssetMetadata",
+        SET_ASSET_METADATA_ID,
+        &[
+            ("creator", PublicKey),
+            ("metadata", List("MetadataRef")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "UpdateAllowlist",
+        UPDATE_ALLOWLIST_ID,
+        &[
+            ("officer", PublicKey),
+            ("registry", Hash),
+            ("holder", PublicKey),
+            ("allowed", Bool),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "FreezeWallet",
+        FREEZE_WALLET_ID,
+        &[
+            ("officer", PublicKey),
+            ("wallet", PublicKey),
+            ("reason", Str),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "UnfreezeWallet",
+        UNFREEZE_WALLET_ID,
+        &[("officer", PublicKey), ("wallet", PublicKey), ("seed", U64)],
+    ),
+    (
+        "MintTokens",
+        MINT_TOKENS_ID,
+        &[
+            ("creator", PublicKey),
+            ("receiver", PublicKey),
+            ("name", Str),
+            ("fees", Struct("Fees")),
+            ("tokens", List("MetaToken")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "CreateCollection",
+        CREATE_COLLECTION_ID,
+        &[
+            ("creator", PublicKey),
+            ("parent", Hash),
+            ("name", Str),
+            ("data", Str),
+            ("fees", Struct("Fees")),
+            ("seed", U64),
+        ],
+    ),
+    (
+        "AddToCollection",
+        ADD_TO_COLLECTION_ID,
+        &[
+            ("creator", PublicKey),
+            ("members", List("CollectionMember")),
+            ("seed", U64),
+        ],
+    ),
+];
+
+/// Names of `ApiError` variants as serialized by the `/v1` routes.
+const API_ERRORS: &[&str] = &[
+    "TransactionNotFound",
+    "TransactionHashInvalid",
+    "AssetIdNotFound",
+    "AssetIdInvalid",
+    "EmptyRequestBody",
+    "IncorrectRequest",
+    "WalletHexInvalid",
+    "HeightIsMissing",
+    "BlockNotFound",
+    "MultisigNotFound",
+    "ProposalNotFound",
+    "LoanNotFound",
+    "TokenNotFound",
+    "CollectionIdInvalid",
+    "CollectionNotFound",
+    "MetadataHashInvalid",
+    "MetadataHashMismatch",
+    "MetadataTooLarge",
+    "MetadataNotFound",
+    "MetadataStoreFailed",
+    "WalletCursorInvalid",
+    "BatchTooLarge",
+    "TransactionHexInvalid",
+    "TransactionLengthInvalid",
+    "TransactionTypeUnknown",
+    "TransactionSignatureInvalid",
+    "TransactionMalformed",
+    "TransactionPending",
+    "Unauthorized",
+    "RateLimited",
+    "OriginNotAllowed",
+];
+
+/// Body of a request.
+#[derive(Clone, Copy, Debug)]
+enum Body {
+    None,
+    /// JSON of the named schema.
+    Json(&'static str),
+    /// Arbitrary bytes.
+    Bytes,
+}
+
+/// Body of a successful response.
+#[derive(Clone, Copy, Debug)]
+enum Reply {
+    /// `Result<T, ApiError>` of the named schema.
+    Api(&'static str),
+    /// `Result<Result<T, Error>, ApiError>` of the named schema.
+    Checked(&'static str),
+    /// Like `Checked`, or `TxDisabledResponse` refusing the transaction.
+    Submitted(&'static str),
+    /// `/v2` envelope of the named schema.
+    Envelope(&'static str),
+    /// Plain text of the media type.
+    Text(&'static str),
+    /// Server-Sent Events.
+    Events,
+    /// Any JSON.
+    Json,
+}
+
+/// A query parameter with its JSON type and description.
+type Query = (&'static str, &'static str, &'static str);
+
+/// A route as wired in the router, with path parameters written `:name`.
+struct Route {
+    method: &'static str,
+    path: &'static str,
+    id: &'static str,
+    tag: &'static str,
+    summary: &'static str,
+    query: &'static [Query],
+    body: Body,
+    reply: Reply,
+}
+
+const PAGINATION: &[Query] = &[
+    ("offset", "integer", "Number of items to skip, used together with `limit`."),
+    ("limit", "integer", "Largest number of items to return."),
+];
+
+const DECIMALS: &[Query] = &[(
+    "decimals",
+    "integer",
+    "Number of decimal places amounts are formatted with, 19 at most.",
+)];
+
+const META_DATA: &[Query] = &[(
+    "meta_data",
+    "boolean",
+    "Whether to add the info of each asset.",
+)];
+
+const ROUTES: &[Route] = &[
+    Route {
+        method: "post",
+        path: "/v1/transactions",
+        id: "transaction",
+        tag: "transactions",
+        summary: "Submit a signed transaction.",
+        query: &[],
+        body: Body::Json("Transaction"),
+        reply: Reply::Submitted("TransactionResponse"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/transactions/raw",
+        id: "transaction_raw",
+        tag: "transactions",
+        summary: "Submit a signed transaction as the hex of its raw message.",
+        query: &[],
+        body: Body::Json("RawTransactionRequest"),
+        reply: Reply::Submitted("TransactionResponse"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/transactions/batch",
+        id: "transaction_batch",
+        tag: "transactions",
+        summary: "Submit signed transactions, as JSON or raw hex, each answered in order.",
+        query: &[],
+        body: Body::Json("Transaction[]"),
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/transactions/simulate",
+        id: "simulate_transaction",
+        tag: "transactions",
+        summary: "Execute a transaction against the current state without committing it.",
+        query: &[],
+        body: Body::Json("Transaction"),
+        reply: Reply::Api("SimulationResponseBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/transactions/disabled",
+        id: "get_disabled_transactions",
+        tag: "transactions",
+        summary: "Transaction types switched off by governance.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("DisabledTransaction[]"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/transactions/:hash",
+        id: "get_transaction_status",
+        tag: "transactions",
+        summary: "Status of a committed transaction, or pending if it was submitted here.",
+        query: &[(
+            "wait",
+            "integer",
+            "Seconds to wait for the transaction to be committed, 10 at most.",
+        )],
+        body: Body::None,
+        reply: Reply::Api("TransactionStatus"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/wallets",
+        id: "wallets_info",
+        tag: "wallets",
+        summary: "A page of wallets.",
+        query: &[
+            ("limit", "integer", "Largest number of wallets to return."),
+            ("cursor", "string", "The `next` cursor of the previous page."),
+            ("sort", "string", "Order of the wallets, `key` or `balance`."),
+            ("min_balance", "integer", "Smallest balance of the wallets to return."),
+        ],
+        body: Body::None,
+        reply: Reply::Api("WalletsResponseBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/wallets/:pub_key",
+        id: "get_balance",
+        tag: "wallets",
+        summary: "Balance of the wallet and the number of its assets.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("WalletInfo"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/wallets/:pub_key/assets",
+        id: "assets_info",
+        tag: "wallets",
+        summary: "Assets of the wallet.",
+        query: &[
+            ("offset", "integer", "Number of assets to skip, used together with `limit`."),
+            ("limit", "integer", "Largest number of assets to return."),
+            ("meta_data", "boolean", "Whether to add the info of each asset."),
+        ],
+        body: Body::None,
+        reply: Reply::Api("WalletAssetsResponseBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/wallets/:pub_key/assets/:asset_id",
+        id: "asset_info",
+        tag: "wallets",
+        summary: "Amount of the asset in the wallet.",
+        query: META_DATA,
+        body: Body::None,
+        reply: Reply::Api("ExtendedAsset"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/wallets/:pub_key/allowances",
+        id: "allowances_info",
+        tag: "wallets",
+        summary: "Allowances granted by the wallet.",
+        query: PAGINATION,
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/wallets/:pub_key/vesting",
+        id: "vesting_info",
+        tag: "wallets",
+        summary: "Vesting schedules of the wallet.",
+        query: PAGINATION,
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/wallets/:pub_key/loans",
+        id: "wallet_loans",
+        tag: "lending",
+        summary: "Loans of the wallet.",
+        query: PAGINATION,
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/wallets/:pub_key/tokens",
+        id: "wallet_tokens",
+        tag: "tokens",
+        summary: "Tokens owned by the wallet.",
+        query: PAGINATION,
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/assets/:asset_id",
+        id: "get_owner_for_asset_id",
+        tag: "assets",
+        summary: "Info of the asset.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("AssetInfo"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/assets/:asset_id/history",
+        id: "get_asset_history",
+        tag: "assets",
+        summary: "Changes of the creator and the fees of the asset.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("AssetChange[]"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/assets/:asset_id/supply",
+        id: "get_asset_supply",
+        tag: "assets",
+        summary: "Supply limits of the asset and the amount minted so far.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("Supply"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/assets/:asset_id/metadata",
+        id: "get_asset_metadata",
+        tag: "assets",
+        summary: "Reference to the off-chain metadata of the asset.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("MetadataRef"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/assets/:asset_id/tokens",
+        id: "series_tokens",
+        tag: "tokens",
+        summary: "Tokens of the asset.",
+        query: PAGINATION,
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/tokens/:asset_id/:serial",
+        id: "token_info",
+        tag: "tokens",
+        summary: "The token with the serial number.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("Token"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/collections/:collection_id",
+        id: "collection_info",
+        tag: "collections",
+        summary: "The collection.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/collections/:collection_id/assets",
+        id: "collection_assets",
+        tag: "collections",
+        summary: "Assets of the collection.",
+        query: PAGINATION,
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/collections/:collection_id/supply",
+        id: "collection_supply",
+        tag: "collections",
+        summary: "Supply of the assets of the collection.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/search/assets",
+        id: "search_assets",
+        tag: "assets",
+        summary: "Assets matching the text, the id prefix or the creator.",
+        query: &[
+            ("q", "string", "Text to look for in the asset data."),
+            ("prefix", "string", "Prefix of the hex of the asset id."),
+            ("creator", "string", "Public key of the creator."),
+            ("offset", "integer", "Number of assets to skip, used together with `limit`."),
+            ("limit", "integer", "Largest number of assets to return."),
+        ],
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/metadata/:content_hash",
+        id: "metadata_upload",
+        tag: "assets",
+        summary: "Store off-chain metadata, which must hash to the content hash.",
+        query: &[],
+        body: Body::Bytes,
+        reply: Reply::Api("MetadataResponseBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/metadata/:content_hash",
+        id: "metadata_fetch",
+        tag: "assets",
+        summary: "Stored off-chain metadata.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Text("application/octet-stream"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/multisig/:pub_key",
+        id: "multisig_info",
+        tag: "multisig",
+        summary: "Configuration of the multisig wallet.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/multisig/:pub_key/proposals",
+        id: "multisig_proposals",
+        tag: "multisig",
+        summary: "Open proposals of the multisig wallet.",
+        query: PAGINATION,
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/proposals/:proposal_id",
+        id: "proposal_info",
+        tag: "multisig",
+        summary: "The proposal with its approvals.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/loans/:loan_id",
+        id: "loan_info",
+        tag: "lending",
+        summary: "The loan.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("Any"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/fees/transactions",
+        id: "transaction_fee",
+        tag: "fees",
+        summary: "Fees each key would pay for the signed transaction.",
+        query: &[],
+        body: Body::Json("Transaction"),
+        reply: Reply::Checked("FeesResponseBody"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/fees/quote",
+        id: "transaction_fee_quote",
+        tag: "fees",
+        summary: "Fees of a transaction from its description, without signing it.",
+        query: &[],
+        body: Body::Json("FeeQuoteRequest"),
+        reply: Reply::Checked("FeeQuote"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/hex/transactions",
+        id: "hash_transaction",
+        tag: "hex",
+        summary: "Hex of the body of the transaction, to be signed.",
+        query: &[],
+        body: Body::Json("Transaction"),
+        reply: Reply::Api("HexResponse"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/hex/transactions/offer",
+        id: "hash_offer",
+        tag: "hex",
+        summary: "Hex of the offer of a trade or an exchange, to be signed.",
+        query: &[],
+        body: Body::Json("Transaction"),
+        reply: Reply::Api("HexResponse"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/intern/assets/:pub_key/:meta_data",
+        id: "asset_id",
+        tag: "intern",
+        summary: "Id of the asset with the meta data created by the key.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("AssetIdResponseBody"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/intern/assets/:pub_key",
+        id: "assets_ids_for_key",
+        tag: "intern",
+        summary: "Ids of the assets with the meta data created by the key.",
+        query: &[],
+        body: Body::Json("AssetIdRequest"),
+        reply: Reply::Api("AssetIdResponseBody"),
+    },
+    Route {
+        method: "post",
+        path: "/v1/intern/assets",
+        id: "assets_ids_batch",
+        tag: "intern",
+        summary: "Ids of the assets with the meta data, by creator.",
+        query: &[],
+        body: Body::Json("AssetIdBatchRequest"),
+        reply: Reply::Api("AssetIdBatchResponseBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/intern/collections/:pub_key/:name",
+        id: "collection_id",
+        tag: "intern",
+        summary: "Id of the collection with the name created by the key.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("CollectionIdResponseBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/blocks",
+        id: "blocks_info",
+        tag: "blocks",
+        summary: "Headers of the latest blocks.",
+        query: &[
+            ("count", "integer", "Number of blocks, 1000 at most."),
+            ("latest", "integer", "Height of the latest block to return."),
+            ("skip_empty_blocks", "boolean", "Whether to leave out blocks without transactions."),
+        ],
+        body: Body::None,
+        reply: Reply::Api("Block[]"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/blocks/:height",
+        id: "height",
+        tag: "blocks",
+        summary: "The block at the height with its precommits and transactions.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Api("BlockInfo"),
+    },
+    Route {
+        method: "get",
+        path: "/v1/events",
+        id: "events",
+        tag: "events",
+        summary: "Events of committed blocks, reconnecting for the next ones.",
+        query: &[
+            ("blocks", "boolean", "Whether to send an event for every block."),
+            ("tx", "string", "Comma-separated hashes of transactions to follow."),
+            ("wallet", "string", "Comma-separated public keys of wallets to follow."),
+            ("asset", "string", "Comma-separated ids of assets to follow."),
+            ("from_height", "integer", "First block to tell, unless resuming after an event id."),
+            ("to_height", "integer", "Height of the last block to tell."),
+        ],
+        body: Body::None,
+        reply: Reply::Events,
+    },
+    Route {
+        method: "get",
+        path: "/v1/openapi.json",
+        id: "openapi",
+        tag: "meta",
+        summary: "This document.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Json,
+    },
+    Route {
+        method: "get",
+        path: "/metrics",
+        id: "metrics",
+        tag: "meta",
+        summary: "Metrics of the node in the Prometheus text format.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Text("text/plain"),
+    },
+    Route {
+        method: "get",
+        path: "/db_stats",
+        id: "stats",
+        tag: "meta",
+        summary: "Statistics of the database.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Text("text/plain"),
+    },
+    Route {
+        method: "post",
+        path: "/v2/transactions",
+        id: "v2_transaction",
+        tag: "v2",
+        summary: "Submit a signed transaction.",
+        query: &[],
+        body: Body::Json("Transaction"),
+        reply: Reply::Envelope("TransactionResponse"),
+    },
+    Route {
+        method: "post",
+        path: "/v2/transactions/raw",
+        id: "v2_transaction_raw",
+        tag: "v2",
+        summary: "Submit a signed transaction as the hex of its raw message.",
+        query: &[],
+        body: Body::Json("RawTransactionRequest"),
+        reply: Reply::Envelope("TransactionResponse"),
+    },
+    Route {
+        method: "get",
+        path: "/v2/transactions/:hash",
+        id: "v2_get_transaction_status",
+        tag: "v2",
+        summary: "State of a transaction, pending only if it was submitted to this node.",
+        query: &[(
+            "wait",
+            "integer",
+            "Seconds to wait for the transaction to be committed, 10 at most.",
+        )],
+        body: Body::None,
+        reply: Reply::Envelope("TxStatusBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v2/wallets",
+        id: "v2_wallets_info",
+        tag: "v2",
+        summary: "A page of wallets.",
+        query: &[
+            ("decimals", "integer", "Number of decimal places of amounts, 19 at most."),
+            ("limit", "integer", "Largest number of wallets to return."),
+            ("cursor", "string", "The `next` cursor of the previous page."),
+            ("sort", "string", "Order of the wallets, `key` or `balance`."),
+            ("min_balance", "string", "Smallest balance of the wallets to return."),
+        ],
+        body: Body::None,
+        reply: Reply::Envelope("WalletsBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v2/wallets/:pub_key",
+        id: "v2_get_balance",
+        tag: "v2",
+        summary: "Balance of the wallet and the number of its assets.",
+        query: DECIMALS,
+        body: Body::None,
+        reply: Reply::Envelope("WalletBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v2/wallets/:pub_key/assets",
+        id: "v2_assets_info",
+        tag: "v2",
+        summary: "Assets of the wallet.",
+        query: &[
+            ("decimals", "integer", "Number of decimal places of amounts, 19 at most."),
+            ("offset", "integer", "Number of assets to skip, used together with `limit`."),
+            ("limit", "integer", "Largest number of assets to return."),
+        ],
+        body: Body::None,
+        reply: Reply::Envelope("WalletAssetsBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v2/assets/:asset_id",
+        id: "v2_get_asset_info",
+        tag: "v2",
+        summary: "Info of the asset.",
+        query: DECIMALS,
+        body: Body::None,
+        reply: Reply::Envelope("AssetInfoBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v2/assets/:asset_id/supply",
+        id: "v2_get_asset_supply",
+        tag: "v2",
+        summary: "Supply limits of the asset and the amount minted so far.",
+        query: DECIMALS,
+        body: Body::None,
+        reply: Reply::Envelope("SupplyBody"),
+    },
+    Route {
+        method: "post",
+        path: "/v2/fees/transactions",
+        id: "v2_transaction_fee",
+        tag: "v2",
+        summary: "Fees each key would pay for the signed transaction.",
+        query: DECIMALS,
+        body: Body::Json("Transaction"),
+        reply: Reply::Envelope("TransactionFeesBody"),
+    },
+    Route {
+        method: "get",
+        path: "/v2/blocks",
+        id: "v2_blocks_info",
+        tag: "v2",
+        summary: "Headers of the latest blocks.",
+        query: &[
+            ("count", "integer", "Number of blocks, 1000 at most."),
+            ("latest", "integer", "Height of the latest block to return."),
+            ("skip_empty_blocks", "boolean", "Whether to leave out blocks without transactions."),
+        ],
+        body: Body::None,
+        reply: Reply::Envelope("Block[]"),
+    },
+    Route {
+        method: "get",
+        path: "/v2/blocks/:height",
+        id: "v2_height",
+        tag: "v2",
+        summary: "The block at the height with its precommits and transactions.",
+        query: &[],
+        body: Body::None,
+        reply: Reply::Envelope("BlockInfo"),
+    },
+];
+
+fn field_schema(field: Field) -> Value {
+    match field {
+        U64 => json!({ "type": "string", "format": "uint64", "pattern": "^[0-9]+$" }),
+        U8 => json!({ "type": "integer", "minimum": 0, "maximum": 255 }),
+        Bool => json!({ "type": "boolean" }),
+        Str => json!({ "type": "string" }),
+        Bytes => json!({ "type": "string", "format": "hex" }),
+        PublicKey => schema_ref("PublicKey"),
+        Hash => schema_ref("Hash"),
+        Signature => schema_ref("Signature"),
+        AssetId => schema_ref("AssetId"),
+        Fraction => json!({ "type": "string", "pattern": "^(0\\.)?[0-9]{1,19}$" }),
+        Struct(name) => schema_ref(name),
+        List(name) => json!({ "type": "array", "items": schema_ref(name) }),
+    }
+}
+
+fn object_schema(fields: Fields) -> Value {
+    let properties: Map<String, Value> = fields
+        .iter()
+        .map(|&(name, field)| (name.to_string(), field_schema(field)))
+        .collect();
+    let required: Vec<&str> = fields.iter().map(|&(name, _)| name).collect();
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+fn message_schema(message_id: u16, fields: Fields) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "protocol_version": { "type": "integer" },
+            "service_id": { "type": "integer", "enum": [SERVICE_ID] },
+            "message_id": { "type": "integer", "enum": [message_id] },
+            "body": object_schema(fields),
+            "signature": schema_ref("Signature"),
+        },
+        "required": ["service_id", "message_id", "body", "signature"],
+    })
+}
+
+/// Schema of a named type. `Name[]` stands for an array and `Any` for any
+/// JSON value.
+fn schema_ref(name: &str) -> Value {
+    if name.ends_with("[]") {
+        return json!({ "type": "array", "items": schema_ref(&name[..name.len() - 2]) });
+    }
+    match name {
+        "Any" => json!({}),
+        name => json!({ "$ref": format!("#/components/schemas/{}", name) }),
+    }
+}
+
+fn hex_schema(bytes: usize) -> Value {
+    let pattern = format!("^[0-9a-f]{{{}}}$", bytes * 2);
+    json!({ "type": "string", "format": "hex", "pattern": pattern })
+}
+
+fn map_schema(values: Value) -> Value {
+    json!({ "type": "object", "additionalProperties": values })
+}
+
+/// Schemas of the types defined with serde rather than Exonum.
+fn api_schemas() -> Vec<(&'static str, Value)> {
+    let errors: Vec<Value> = (0..256u16)
+        .filter_map(|code| Error::try_from(code as u8))
+        .map(|e| serde_json::to_value(e).unwrap())
+        .collect();
+    let u64_number = json!({ "type": "integer", "format": "uint64" });
+    let amount = json!({ "type": "string", "pattern": "^[0-9]+(\\.[0-9]+)?$" });
+
+    vec![
+        ("PublicKey", hex_schema(32)),
+        ("Hash", hex_schema(32)),
+        ("Signature", hex_schema(64)),
+        ("AssetId", hex_schema(16)),
+        ("ApiError", json!({ "type": "string", "enum": API_ERRORS })),
+        ("Error", json!({ "type": "string", "enum": errors })),
+        (
+            "ErrorBody",
+            json!({
+                "type": "object",
+                "properties": {
+                    "code": { "type": "string" },
+                    "message": { "type": "string" },
+                },
+                "required": ["code", "message"],
+            }),
+        ),
+        (
+            "Transaction",
+            json!({
+                "oneOf": MESSAGES
+                    .iter()
+                    .map(|&(name, _, _)| schema_ref(name))
+                    .collect::<Vec<_>>(),
+            }),
+        ),
+        (
+            "RawTransactionRequest",
+            json!({
+                "type": "object",
+                "properties": { "hex": { "type": "string", "format": "hex" } },
+                "required": ["hex"],
+            }),
+        ),
+        (
+            "TransactionResponse",
+            json!({
+                "type": "object",
+                "properties": { "tx_hash": schema_ref("Hash") },
+                "required": ["tx_hash"],
+            }),
+        ),
+        (
+            "TransactionStatus",
+            json!({
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": { "Ok": { "type": "null" } },
+                        "required": ["Ok"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "Err": schema_ref("Error") },
+                        "required": ["Err"],
+                    },
+                ],
+            }),
+        ),
+        (
+            "DisabledTransaction",
+            json!({
+                "type": "object",
+                "properties": {
+                    "message_id": { "type": "integer" },
+                    "reason": { "type": "string" },
+                },
+                "required": ["message_id"],
+            }),
+        ),
+        (
+            "TxDisabledResponse",
+            json!({
+                "type": "object",
+                "properties": {
+                    "error": schema_ref("Error"),
+                    "message_id": { "type": "integer" },
+                    "reason": { "type": "string" },
+                },
+                "required": ["error", "message_id", "reason"],
+            }),
+        ),
+        (
+            "SimulationResponseBody",
+            json!({
+                "type": "object",
+                "properties": {
+                    "tx_hash": schema_ref("Hash"),
+                    "verified": { "type": "boolean" },
+                    "status": schema_ref("TransactionStatus"),
+                    "fees": map_schema(u64_number.clone()),
+                    "wallets": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "pub_key": schema_ref("PublicKey"),
+                                "balance_before": u64_number,
+                                "balance_after": u64_number,
+                                "assets": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "object",
+                                        "properties": {
+                                            "id": schema_ref("AssetId"),
+                                            "before": u64_number,
+                                            "after": u64_number,
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+            }),
+        ),
+        (
+            "WalletInfo",
+            json!({
+                "type": "object",
+                "properties": {
+                    "balance": u64_number,
+                    "assets_count": u64_number,
+                },
+            }),
+        ),
+        (
+            "WalletsResponseBody",
+            json!({
+                "type": "object",
+                "properties": {
+                    "count": u64_number,
+                    "next": { "type": "string", "nullable": true },
+                    "wallets": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "pub_key": schema_ref("PublicKey"),
+                                "balance": u64_number,
+                                "assets_count": u64_number,
+                            },
+                        },
+                    },
+                },
+            }),
+        ),
+        (
+            "ExtendedAsset",
+            json!({
+                "type": "object",
+                "properties": {
+                    "id": schema_ref("AssetId"),
+                    "amount": u64_number,
+                    "meta_data": schema_ref("AssetInfo"),
+                },
+            }),
+        ),
+        (
+            "WalletAssetsResponseBody",
+            json!({
+                "type": "object",
+                "properties": {
+                    "total": u64_number,
+                    "count": u64_number,
+                    "assets": { "type": "array", "items": schema_ref("ExtendedAsset") },
+                },
+            }),
+        ),
+        (
+            "FeesResponseBody",
+            json!({
+                "type": "object",
+                "properties": { "fees": map_schema(u64_number.clone()) },
+            }),
+        ),
+        (
+            "FeeQuoteRequest",
+            json!({
+                "type": "object",
+                "properties": {
+                    "type": {
+                        "type": "string",
+                        "enum": ["transfer", "add_assets", "delete_assets", "trade", "exchange"],
+                    },
+                },
+                "required": ["type"],
+                "description": "Fields of the transaction besides the signature and the seed.",
+            }),
+        ),
+        (
+            "FeeQuote",
+            json!({
+                "type": "object",
+                "properties": {
+                    "payers": map_schema(u64_number.clone()),
+                    "third_party": map_schema(u64_number.clone()),
+                },
+            }),
+        ),
+        (
+            "HexResponse",
+            json!({
+                "type": "object",
+                "properties": { "hex": { "type": "string", "format": "hex" } },
+            }),
+        ),
+        (
+            "AssetIdRequest",
+            json!({
+                "type": "object",
+                "properties": { "assets": { "type": "array", "items": { "type": "string" } } },
+            }),
+        ),
+        (
+            "AssetIdResponseBody",
+            json!({
+                "type": "object",
+                "properties": { "assets": map_schema(schema_ref("AssetId")) },
+            }),
+        ),
+        (
+            "AssetIdBatchRequest",
+            json!({
+                "type": "object",
+                "properties": {
+                    "assets": map_schema(json!({ "type": "array", "items": { "type": "string" } })),
+                },
+            }),
+        ),
+        (
+            "AssetIdBatchResponseBody",
+            json!({
+                "type": "object",
+                "properties": { "assets": map_schema(map_schema(schema_ref("AssetId"))) },
+            }),
+        ),
+        (
+            "CollectionIdResponseBody",
+            json!({
+                "type": "object",
+                "properties": { "collections": map_schema(schema_ref("Hash")) },
+            }),
+        ),
+        (
+            "MetadataResponseBody",
+            json!({
+                "type": "object",
+                "properties": { "content_hash": schema_ref("Hash") },
+            }),
+        ),
+        (
+            "Block",
+            json!({
+                "type": "object",
+                "description": "Header of a block as serialized by Exonum.",
+            }),
+        ),
+        (
+            "BlockInfo",
+            json!({
+                "type": "object",
+                "properties": {
+                    "block": schema_ref("Block"),
+                    "precommits": { "type": "array", "items": {} },
+                    "txs": { "type": "array", "items": schema_ref("Hash") },
+                },
+            }),
+        ),
+        (
+            "TxStatusBody",
+            json!({
+                "type": "object",
+                "properties": {
+                    "tx_hash": schema_ref("Hash"),
+                    "state": { "type": "string", "enum": ["pending", "committed", "failed"] },
+                    "error": schema_ref("ErrorBody"),
+                },
+            }),
+        ),
+        (
+            "WalletBody",
+            json!({
+                "type": "object",
+                "properties": { "balance": amount, "assets_count": u64_number },
+            }),
+        ),
+        (
+            "WalletsBody",
+            json!({
+                "type": "object",
+                "properties": {
+                    "count": u64_number,
+                    "next": { "type": "string", "nullable": true },
+                    "wallets": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "pub_key": schema_ref("PublicKey"),
+                                "balance": amount,
+                                "assets_count": u64_number,
+                            },
+                        },
+                    },
+                },
+            }),
+        ),
+        (
+            "WalletAssetsBody",
+            json!({
+                "type": "object",
+                "properties": {
+                    "total": u64_number,
+                    "count": u64_number,
+                    "assets": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": { "id": schema_ref("AssetId"), "amount": amount },
+                        },
+                    },
+                },
+            }),
+        ),
+        (
+            "AssetInfoBody",
+            json!({
+                "type": "object",
+                "properties": {
+                    "creator": schema_ref("PublicKey"),
+                    "origin": schema_ref("Hash"),
+                    "amount": amount,
+                    "fees": {
+                        "type": "object",
+                        "additionalProperties": {
+                            "type": "object",
+                            "properties": {
+                                "fixed": amount,
+                                "fraction": field_schema(Fraction),
+                            },
+                        },
+                    },
+                    "data": { "type": "string" },
+                },
+            }),
+        ),
+        (
+            "SupplyBody",
+            json!({
+                "type": "object",
+                "properties": {
+                    "max_supply": amount,
+                    "sealed": { "type": "boolean" },
+                    "minted": amount,
+                },
+            }),
+        ),
+        (
+            "TransactionFeesBody",
+            json!({
+                "type": "object",
+                "properties": { "fees": map_schema(amount.clone()) },
+            }),
+        ),
+    ]
+}
+
+fn result_schema(ok: Value, err: Value) -> Value {
+    json!({
+        "oneOf": [
+            { "type": "object", "properties": { "Ok": ok }, "required": ["Ok"] },
+            { "type": "object", "properties": { "Err": err }, "required": ["Err"] },
+        ],
+    })
+}
+
+fn reply_content(reply: Reply) -> Value {
+    let (media_type, schema) = match reply {
+        Reply::Api(name) => (
+            "application/json",
+            result_schema(schema_ref(name), schema_ref("ApiError")),
+        ),
+        Reply::Checked(name) => (
+            "application/json",
+            result_schema(
+                result_schema(schema_ref(name), schema_ref("Error")),
+                schema_ref("ApiError"),
+            ),
+        ),
+        Reply::Submitted(name) => (
+            "application/json",
+            json!({
+                "oneOf": [
+                    result_schema(
+                        result_schema(schema_ref(name), schema_ref("Error")),
+                        schema_ref("ApiError"),
+                    ),
+                    schema_ref("TxDisabledResponse"),
+                ],
+            }),
+        ),
+        Reply::Envelope(name) => (
+            "application/json",
+            json!({
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": { "data": schema_ref(name) },
+                        "required": ["data"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "error": schema_ref("ErrorBody") },
+                        "required": ["error"],
+                    },
+                ],
+            }),
+        ),
+        Reply::Text(media_type) => (media_type, json!({ "type": "string" })),
+        Reply::Events => ("text/event-stream", json!({ "type": "string" })),
+        Reply::Json => ("application/json", json!({})),
+    };
+    let mut content = Map::new();
+    content.insert(media_type.to_string(), json!({ "schema": schema }));
+    Value::Object(content)
+}
+
+/// Path of the route in OpenAPI syntax, `:name` becoming `{name}`.
+pub fn openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if segment.starts_with(':') {
+                format!("{{{}}}", &segment[1..])
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn operation(route: &Route) -> Value {
+    let mut parameters: Vec<Value> = route
+        .path
+        .split('/')
+        .filter(|segment| segment.starts_with(':'))
+        .map(|segment| {
+            json!({
+                "name": &segment[1..],
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" },
+            })
+        })
+        .collect();
+    parameters.extend(route.query.iter().map(|&(name, kind, description)| {
+        json!({
+            "name": name,
+            "in": "query",
+            "required": false,
+            "description": description,
+            "schema": { "type": kind },
+        })
+    }));
+
+    let mut operation = json!({
+        "operationId": route.id,
+        "tags": [route.tag],
+        "summary": route.summary,
+        "parameters": parameters,
+        "responses": {
+            "default": {
+                "description": "Result of the request.",
+                "content": reply_content(route.reply),
+            },
+        },
+    });
+    let body = match route.body {
+        Body::None => None,
+        Body::Json(name) => Some(json!({ "application/json": { "schema": schema_ref(name) } })),
+        Body::Bytes => Some(json!({
+            "application/octet-stream": { "schema": { "type": "string", "format": "binary" } },
+        })),
+    };
+    if let Some(content) = body {
+        operation["requestBody"] = json!({ "required": true, "content": content });
+    }
+    operation
+}
+
+/// The OpenAPI document of the service API.
+pub fn document() -> Value {
+    let mut paths = Map::new();
+    for route in ROUTES {
+        let path = paths
+            .entry(openapi_path(route.path))
+            .or_insert_with(|| json!({}));
+        path[route.method] = operation(route);
+    }
+
+    let mut schemas = Map::new();
+    for (name, schema) in api_schemas() {
+        schemas.insert(name.to_string(), schema);
+    }
+    for &(name, fields) in STRUCTS {
+        schemas.insert(name.to_string(), object_schema(fields));
+    }
+    for &(name, message_id, fields) in MESSAGES {
+        schemas.insert(name.to_string(), message_schema(message_id, fields));
+    }
+
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "info": {
+            "title": "DMarket blockchain service API",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "servers": [{ "url": format!("/api/services/{}", SERVICE_NAME) }],
+        "paths": paths,
+        "components": { "schemas": schemas },
+    })
+}
+
+impl Api for OpenApiApi {
+    fn wire(&self, router: &mut Router) {
+        // Describes every route of the service API.
+        let openapi = move |_: &mut Request| -> IronResult<Response> {
+            let mut res = Response::with((
+                status::Ok,
+                serde_json::to_string_pretty(&document()).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            Ok(res)
+        };
+
+        router.get("/v1/openapi.json", openapi, "openapi");
+    }
+}
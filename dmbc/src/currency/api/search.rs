@@ -0,0 +1,143 @@
+extern crate serde_json;
+
+use exonum::api::Api;
+use exonum::blockchain::Blockchain;
+use exonum::crypto::PublicKey;
+use exonum::encoding::serialize::FromHex;
+use hyper::header::ContentType;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::api::ServiceApi;
+use currency::assets;
+use currency::assets::{AssetId, AssetInfo};
+use currency::search::SEARCH_INDEX;
+
+const PARAMETER_TEXT_KEY: &str = "q";
+const PARAMETER_PREFIX_KEY: &str = "prefix";
+const PARAMETER_CREATOR_KEY: &str = "creator";
+
+#[derive(Clone)]
+pub struct SearchApi {
+    pub blockchain: Blockchain,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AssetSearchResult {
+    pub id: AssetId,
+    pub info: AssetInfo,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AssetSearchResponseBody {
+    pub total: u64,
+    pub count: u64,
+    pub assets: Vec<AssetSearchResult>,
+}
+
+pub type AssetSearchResponse = Result<AssetSearchResponseBody, ApiError>;
+
+impl SearchApi {
+    /// Find the assets matching the query, ordered by `AssetId`.
+    fn search_assets(
+        &self,
+        text: &str,
+        prefix: &str,
+        creator: Option<PublicKey>,
+    ) -> Vec<AssetSearchResult> {
+        let view = self.blockchain.fork();
+
+        // The index is built on the first commit; build it now if no block
+        // has been committed since the node started.
+        if !SEARCH_INDEX.read().unwrap().is_synced() {
+            SEARCH_INDEX.write().unwrap().rebuild(&view);
+        }
+        let ids = SEARCH_INDEX
+            .read()
+            .unwrap()
+            .search(text, prefix, creator.as_ref());
+
+        let mut results: Vec<AssetSearchResult> = ids.into_iter()
+            .filter_map(|id| {
+                assets::Schema(&view)
+                    .fetch(&id)
+                    .map(|info| AssetSearchResult { id, info })
+            })
+            .filter(|result| result.info.amount() > 0)
+            .collect();
+        results.sort_by_key(|result| result.id.to_string());
+        results
+    }
+}
+
+lazy_static! {
+    static ref SEARCH_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_search_api_asset_search_requests_total",
+        "Asset search requests."
+    ).unwrap();
+    static ref SEARCH_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_search_api_asset_search_responses_total",
+        "Asset search responses."
+    ).unwrap();
+}
+
+impl Api for SearchApi {
+    fn wire(&self, router: &mut Router) {
+        // Searches assets by the terms of their data.
+        //
+        // `q` matches assets whose data contains all of its words, `prefix`
+        // matches assets with a word starting with it and `creator` keeps
+        // assets created by the key. At least one of them must be given.
+        let self_ = self.clone();
+        let search_assets = move |req: &mut Request| -> IronResult<Response> {
+            SEARCH_REQUESTS.inc();
+
+            let text = ServiceApi::read_parameter(req, PARAMETER_TEXT_KEY, String::new());
+            let prefix = ServiceApi::read_parameter(req, PARAMETER_PREFIX_KEY, String::new());
+            let creator = ServiceApi::read_parameter(req, PARAMETER_CREATOR_KEY, String::new());
+
+            let creator = match creator.as_str() {
+                "" => Ok(None),
+                hex => PublicKey::from_hex(hex)
+                    .map(Some)
+                    .map_err(|_| ApiError::WalletHexInvalid),
+            };
+
+            let result: AssetSearchResponse = creator.and_then(|creator| {
+                if text.is_empty() && prefix.is_empty() && creator.is_none() {
+                    return Err(ApiError::IncorrectRequest);
+                }
+
+                let assets = self_.search_assets(&text, &prefix, creator);
+                // apply pagination parameters if they exist
+                let assets_to_send = ServiceApi::apply_pagination(req, &assets);
+                Ok(AssetSearchResponseBody {
+                    total: assets.len() as u64,
+                    count: assets_to_send.len() as u64,
+                    assets: assets_to_send.to_vec(),
+                })
+            });
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            SEARCH_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        router.get("/v1/search/assets", search_assets, "search_assets");
+    }
+}
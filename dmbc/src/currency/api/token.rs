@@ -0,0 +1,208 @@
+extern crate serde_json;
+
+use exonum::api::Api;
+use exonum::blockchain::Blockchain;
+use exonum::crypto::PublicKey;
+use exonum::encoding::serialize::FromHex;
+use hyper::header::ContentType;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::api::ServiceApi;
+use currency::assets::AssetId;
+use currency::nft;
+use currency::nft::{Token, TokenId};
+
+#[derive(Clone)]
+pub struct TokenApi {
+    pub blockchain: Blockchain,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct TokensResponseBody {
+    pub total: u64,
+    pub count: u64,
+    pub tokens: Vec<Token>,
+}
+
+pub type TokenResponse = Result<Token, ApiError>;
+
+pub type TokensResponse = Result<TokensResponseBody, ApiError>;
+
+impl TokenApi {
+    fn token(&self, id: &TokenId) -> Option<Token> {
+        let view = self.blockchain.fork();
+        nft::Schema(view).fetch(id)
+    }
+
+    fn series_tokens(&self, asset_id: &AssetId) -> Vec<Token> {
+        let view = self.blockchain.fork();
+        nft::Schema(view).fetch_series_tokens(asset_id)
+    }
+
+    fn wallet_tokens(&self, pub_key: &PublicKey) -> Vec<Token> {
+        let view = self.blockchain.fork();
+        nft::Schema(view).fetch_wallet_tokens(pub_key)
+    }
+
+    fn tokens_body(req: &mut Request, tokens: Vec<Token>) -> TokensResponseBody {
+        // apply pagination parameters if they exist
+        let tokens_to_send = ServiceApi::apply_pagination(req, &tokens);
+        TokensResponseBody {
+            total: tokens.len() as u64,
+            count: tokens_to_send.len() as u64,
+            tokens: tokens_to_send.to_vec(),
+        }
+    }
+}
+
+lazy_static! {
+    static ref TOKEN_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_token_api_token_requests_total",
+        "Token requests."
+    ).unwrap();
+    static ref TOKEN_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_token_api_token_responses_total",
+        "Token responses."
+    ).unwrap();
+    static ref SERIES_TOKENS_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_token_api_series_tokens_requests_total",
+        "Token series list requests."
+    ).unwrap();
+    static ref SERIES_TOKENS_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_token_api_series_tokens_responses_total",
+        "Token series list responses."
+    ).unwrap();
+    static ref WALLET_TOKENS_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_token_api_wallet_tokens_requests_total",
+        "Wallet token list requests."
+    ).unwrap();
+    static ref WALLET_TOKENS_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_token_api_wallet_tokens_responses_total",
+        "Wallet token list responses."
+    ).unwrap();
+}
+
+impl Api for TokenApi {
+    fn wire(&self, router: &mut Router) {
+        // Gets a token by the series and serial number.
+        let self_ = self.clone();
+        let token_info = move |req: &mut Request| -> IronResult<Response> {
+            TOKEN_REQUESTS.inc();
+
+            let (asset_id_str, serial_str) = {
+                let params = req.extensions.get::<Router>().unwrap();
+                (
+                    params.find("asset_id").unwrap().to_string(),
+                    params.find("serial").unwrap().to_string(),
+                )
+            };
+            let result: TokenResponse = AssetId::from_hex(&asset_id_str)
+                .map_err(|_| ApiError::AssetIdInvalid)
+                .and_then(|asset_id| {
+                    serial_str
+                        .parse::<u64>()
+                        .map(|serial| TokenId::new(asset_id, serial))
+                        .map_err(|_| ApiError::IncorrectRequest)
+                })
+                .and_then(|id| self_.token(&id).ok_or(ApiError::TokenNotFound));
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            TOKEN_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Gets all tokens of a series.
+        let self_ = self.clone();
+        let series_tokens = move |req: &mut Request| -> IronResult<Response> {
+            SERIES_TOKENS_REQUESTS.inc();
+
+            let asset_id_result = {
+                let asset_id = req.extensions
+                    .get::<Router>()
+                    .unwrap()
+                    .find("asset_id")
+                    .unwrap();
+                AssetId::from_hex(asset_id)
+            };
+            let result: TokensResponse = match asset_id_result {
+                Ok(asset_id) => {
+                    let tokens = self_.series_tokens(&asset_id);
+                    Ok(TokenApi::tokens_body(req, tokens))
+                }
+                Err(_) => Err(ApiError::AssetIdInvalid),
+            };
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            SERIES_TOKENS_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Gets all tokens owned by a wallet.
+        let self_ = self.clone();
+        let wallet_tokens = move |req: &mut Request| -> IronResult<Response> {
+            WALLET_TOKENS_REQUESTS.inc();
+
+            let public_key_result = {
+                let wallet_key = req.extensions
+                    .get::<Router>()
+                    .unwrap()
+                    .find("pub_key")
+                    .unwrap();
+                PublicKey::from_hex(wallet_key)
+            };
+            let result: TokensResponse = match public_key_result {
+                Ok(public_key) => {
+                    let tokens = self_.wallet_tokens(&public_key);
+                    Ok(TokenApi::tokens_body(req, tokens))
+                }
+                Err(_) => Err(ApiError::WalletHexInvalid),
+            };
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            WALLET_TOKENS_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        router.get("/v1/tokens/:asset_id/:serial", token_info, "token_info");
+        router.get("/v1/assets/:asset_id/tokens", series_tokens, "series_tokens");
+        router.get("/v1/wallets/:pub_key/tokens", wallet_tokens, "wallet_tokens");
+    }
+}
@@ -5,11 +5,16 @@ extern crate router;
 extern crate serde;
 extern crate serde_json;
 
+use std::cmp;
+use std::collections::HashMap;
+use std::time::Duration;
+
 use exonum::api::Api;
 use exonum::blockchain::{Blockchain, Transaction};
-use exonum::crypto::Hash;
+use exonum::crypto::{Hash, PublicKey, SIGNATURE_LENGTH};
+use exonum::encoding;
 use exonum::encoding::serialize::FromHex;
-use exonum::messages::Message;
+use exonum::messages::{Message, RawMessage, HEADER_LENGTH};
 use exonum::node::{ApiSender, TransactionSend};
 use hyper::header::ContentType;
 use iron::headers::AccessControlAllowOrigin;
@@ -18,14 +23,46 @@ use iron::status as istatus;
 use prometheus::IntCounter;
 use router::Router;
 
+use config;
 use currency::api::error::ApiError;
+use currency::api::ServiceApi;
+use currency::assets::AssetId;
+use currency::configuration::{Configuration, DisabledTransaction};
+use currency::pending;
 use currency::status;
-use currency::transactions::{AddAssets, DeleteAssets, Exchange, ExchangeIntermediary, Trade,
-                             TradeIntermediary, Transfer};
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::transactions::{AcceptLoan, AddAssets, AddToCollection, Approve, CancelLoan,
+                             CartCheckout, ClaimCollateral, ClaimVested, CreateCollection,
+                             CreateMultisig, CreateVesting, DeleteAssets, Exchange,
+                             ExchangeIntermediary, ExchangeTokens, FreezeWallet, MintTokens,
+                             MultiExchange, MultiTransfer, MultisigApprove, MultisigPropose,
+                             MultisigSpend, OfferLoan, ReturnLoan, RevokeApproval, SetAssetMetadata,
+                             SetAssetRestriction, SetAssetSupply, Trade, TradeIntermediary,
+                             TradeTokens, Transfer, TransferAssetCreator, TransferFrom,
+                             TransferTokens, UnfreezeWallet, UpdateAllowlist, UpdateAssetFees,
+                             ACCEPT_LOAN_ID, ADD_ASSETS_ID, ADD_TO_COLLECTION_ID, APPROVE_ID,
+                             CANCEL_LOAN_ID, CART_CHECKOUT_ID, CLAIM_COLLATERAL_ID, CLAIM_VESTED_ID,
+                             CREATE_COLLECTION_ID, CREATE_MULTISIG_ID, CREATE_VESTING_ID,
+                             DELETE_ASSETS_ID, EXCHANGE_ID, EXCHANGE_INTERMEDIARY_ID,
+                             EXCHANGE_TOKENS_ID, FREEZE_WALLET_ID, MINT_TOKENS_ID,
+                             MULTISIG_APPROVE_ID, MULTISIG_PROPOSE_ID, MULTISIG_SPEND_ID,
+                             MULTI_EXCHANGE_ID, MULTI_TRANSFER_ID, OFFER_LOAN_ID, RETURN_LOAN_ID,
+                             REVOKE_APPROVAL_ID, SET_ASSET_METADATA_ID, SET_ASSET_RESTRICTION_ID,
+                             SET_ASSET_SUPPLY_ID, TRADE_ID, TRADE_INTERMEDIARY_ID, TRADE_TOKENS_ID,
+                             TRANSFER_ASSET_CREATOR_ID, TRANSFER_FROM_ID, TRANSFER_ID,
+                             TRANSFER_TOKENS_ID, UNFREEZE_WALLET_ID, UPDATE_ALLOWLIST_ID,
+                             UPDATE_ASSET_FEES_ID};
+use currency::SERVICE_ID;
 
 use currency::error::Error;
+use currency::wallet;
+use currency::wallet::Wallet;
+
+pub const MAX_TRANSACTION_LENGTH: usize = 17408;
 
-const MAX_TRANSACTION_LENGTH: usize = 17408;
+pub const PARAMETER_WAIT_KEY: &str = "wait";
+/// Longest time in seconds a status request may wait for a commit.
+pub const STATUS_WAIT_MAX_SECS: u64 = 10;
 
 #[derive(Clone)]
 pub struct TransactionApi {
@@ -34,27 +71,355 @@ pub struct TransactionApi {
 }
 
 #[serde(untagged)]
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize)]
 pub enum TransactionRequest {
     Transfer(Transfer),
+    TransferTokens(TransferTokens),
     AddAssets(AddAssets),
     DeleteAssets(DeleteAssets),
     Trade(Trade),
+    TradeTokens(TradeTokens),
     TradeIntermediary(TradeIntermediary),
     Exchange(Exchange),
+    ExchangeTokens(ExchangeTokens),
     ExchangeIntermediary(ExchangeIntermediary),
+    CartCheckout(CartCheckout),
+    MultiExchange(MultiExchange),
+    MultiTransfer(MultiTransfer),
+    CreateMultisig(CreateMultisig),
+    MultisigSpend(MultisigSpend),
+    MultisigPropose(MultisigPropose),
+    MultisigApprove(MultisigApprove),
+    Approve(Approve),
+    RevokeApproval(RevokeApproval),
+    TransferFrom(TransferFrom),
+    CreateVesting(CreateVesting),
+    ClaimVested(ClaimVested),
+    OfferLoan(OfferLoan),
+    AcceptLoan(AcceptLoan),
+    ReturnLoan(ReturnLoan),
+    ClaimCollateral(ClaimCollateral),
+    CancelLoan(CancelLoan),
+    UpdateAssetFees(UpdateAssetFees),
+    TransferAssetCreator(TransferAssetCreator),
+    SetAssetRestriction(SetAssetRestriction),
+    SetAssetSupply(SetAssetSupply),
+    SetAssetMetadata(SetAssetMetadata),
+    UpdateAllowlist(UpdateAllowlist),
+    FreezeWallet(FreezeWallet),
+    UnfreezeWallet(UnfreezeWallet),
+    MintTokens(MintTokens),
+    CreateCollection(CreateCollection),
+    AddToCollection(AddToCollection),
 }
 
 impl TransactionRequest {
-    fn len(&self) -> usize {
+    /// Decode a transaction from its JSON, picking the type by `message_id`.
+    pub fn from_json(value: serde_json::Value) -> Result<Self, ApiError> {
+        let service_id = value.get("service_id").and_then(|id| id.as_u64());
+        let message_id = value.get("message_id").and_then(|id| id.as_u64());
+        let message_id = match (service_id, message_id) {
+            (Some(service_id), Some(message_id)) if service_id == u64::from(SERVICE_ID) => {
+                message_id as u16
+            }
+            (Some(_), Some(_)) => return Err(ApiError::TransactionTypeUnknown),
+            _ => return Err(ApiError::IncorrectRequest),
+        };
+
+        let request = match message_id {
+            TRANSFER_ID => serde_json::from_value(value).map(TransactionRequest::Transfer),
+            TRANSFER_TOKENS_ID => {
+                serde_json::from_value(value).map(TransactionRequest::TransferTokens)
+            }
+            ADD_ASSETS_ID => serde_json::from_value(value).map(TransactionRequest::AddAssets),
+            DELETE_ASSETS_ID => serde_json::from_value(value).map(TransactionRequest::DeleteAssets),
+            TRADE_ID => serde_json::from_value(value).map(TransactionRequest::Trade),
+            TRADE_TOKENS_ID => serde_json::from_value(value).map(TransactionRequest::TradeTokens),
+            TRADE_INTERMEDIARY_ID => {
+                serde_json::from_value(value).map(TransactionRequest::TradeIntermediary)
+            }
+            EXCHANGE_ID => serde_json::from_value(value).map(TransactionRequest::Exchange),
+            EXCHANGE_TOKENS_ID => {
+                serde_json::from_value(value).map(TransactionRequest::ExchangeTokens)
+            }
+            EXCHANGE_INTERMEDIARY_ID => {
+                serde_json::from_value(value).map(TransactionRequest::ExchangeIntermediary)
+            }
+            CART_CHECKOUT_ID => serde_json::from_value(value).map(TransactionRequest::CartCheckout),
+            MULTI_EXCHANGE_ID => {
+                serde_json::from_value(value).map(TransactionRequest::MultiExchange)
+            }
+            MULTI_TRANSFER_ID => {
+                serde_json::from_value(value).map(TransactionRequest::MultiTransfer)
+            }
+            CREATE_MULTISIG_ID => {
+                serde_json::from_value(value).map(TransactionRequest::CreateMultisig)
+            }
+            MULTISIG_SPEND_ID => {
+                serde_json::from_value(value).map(TransactionRequest::MultisigSpend)
+            }
+            MULTISIG_PROPOSE_ID => {
+                serde_json::from_value(value).map(TransactionRequest::MultisigPropose)
+            }
+            MULTISIG_APPROVE_ID => {
+                serde_json::from_value(value).map(TransactionRequest::MultisigApprove)
+            }
+            APPROVE_ID => serde_json::from_value(value).map(TransactionRequest::Approve),
+            REVOKE_APPROVAL_ID => {
+                serde_json::from_value(value).map(TransactionRequest::RevokeApproval)
+            }
+            TRANSFER_FROM_ID => serde_json::from_value(value).map(TransactionRequest::TransferFrom),
+            CREATE_VESTING_ID => {
+                serde_json::from_value(value).map(TransactionRequest::CreateVesting)
+            }
+            CLAIM_VESTED_ID => serde_json::from_value(value).map(TransactionRequest::ClaimVested),
+            OFFER_LOAN_ID => serde_json::from_value(value).map(TransactionRequest::OfferLoan),
+            ACCEPT_LOAN_ID => serde_json::from_value(value).map(TransactionRequest::AcceptLoan),
+            RETURN_LOAN_ID => serde_json::from_value(value).map(TransactionRequest::ReturnLoan),
+            CLAIM_COLLATERAL_ID => {
+                serde_json::from_value(value).map(TransactionRequest::ClaimCollateral)
+            }
+            CANCEL_LOAN_ID => serde_json::from_value(value).map(TransactionRequest::CancelLoan),
+            UPDATE_ASSET_FEES_ID => {
+                serde_json::from_value(value).map(TransactionRequest::UpdateAssetFees)
+            }
+            TRANSFER_ASSET_CREATOR_ID => {
+                serde_json::from_value(value).map(TransactionRequest::TransferAssetCreator)
+            }
+            SET_ASSET_RESTRICTION_ID => {
+                serde_json::from_value(value).map(TransactionRequest::SetAssetRestriction)
+            }
+            SET_ASSET_SUPPLY_ID => {
+                serde_json::from_value(value).map(TransactionRequest::SetAssetSupply)
+            }
+            SET_ASSET_METADATA_ID => {
+                serde_json::from_value(value).map(TransactionRequest::SetAssetMetadata)
+            }
+            UPDATE_ALLOWLIST_ID => {
+                serde_json::from_value(value).map(TransactionRequest::UpdateAllowlist)
+            }
+            FREEZE_WALLET_ID => serde_json::from_value(value).map(TransactionRequest::FreezeWallet),
+            UNFREEZE_WALLET_ID => {
+                serde_json::from_value(value).map(TransactionRequest::UnfreezeWallet)
+            }
+            MINT_TOKENS_ID => serde_json::from_value(value).map(TransactionRequest::MintTokens),
+            CREATE_COLLECTION_ID => {
+                serde_json::from_value(value).map(TransactionRequest::CreateCollection)
+            }
+            ADD_TO_COLLECTION_ID => {
+                serde_json::from_value(value).map(TransactionRequest::AddToCollection)
+            }
+            _ => return Err(ApiError::TransactionTypeUnknown),
+        };
+        request.map_err(|_| ApiError::IncorrectRequest)
+    }
+
+    /// Decode a transaction from its raw message, picking the type by message ID.
+    pub fn from_raw(raw: RawMessage) -> Result<Self, encoding::Error> {
+        let request = match raw.message_type() {
+            TRANSFER_ID => TransactionRequest::Transfer(Transfer::from_raw(raw)?),
+            TRANSFER_TOKENS_ID => {
+                TransactionRequest::TransferTokens(TransferTokens::from_raw(raw)?)
+            }
+            ADD_ASSETS_ID => TransactionRequest::AddAssets(AddAssets::from_raw(raw)?),
+            DELETE_ASSETS_ID => TransactionRequest::DeleteAssets(DeleteAssets::from_raw(raw)?),
+            TRADE_ID => TransactionRequest::Trade(Trade::from_raw(raw)?),
+            TRADE_TOKENS_ID => TransactionRequest::TradeTokens(TradeTokens::from_raw(raw)?),
+            TRADE_INTERMEDIARY_ID => {
+                TransactionRequest::TradeIntermediary(TradeIntermediary::from_raw(raw)?)
+            }
+            EXCHANGE_ID => TransactionRequest::Exchange(Exchange::from_raw(raw)?),
+            EXCHANGE_TOKENS_ID => {
+                TransactionRequest::ExchangeTokens(ExchangeTokens::from_raw(raw)?)
+            }
+            EXCHANGE_INTERMEDIARY_ID => {
+                TransactionRequest::ExchangeIntermediary(ExchangeIntermediary::from_raw(raw)?)
+            }
+            CART_CHECKOUT_ID => TransactionRequest::CartCheckout(CartCheckout::from_raw(raw)?),
+            MULTI_EXCHANGE_ID => TransactionRequest::MultiExchange(MultiExchange::from_raw(raw)?),
+            MULTI_TRANSFER_ID => TransactionRequest::MultiTransfer(MultiTransfer::from_raw(raw)?),
+            CREATE_MULTISIG_ID => {
+                TransactionRequest::CreateMultisig(CreateMultisig::from_raw(raw)?)
+            }
+            MULTISIG_SPEND_ID => TransactionRequest::MultisigSpend(MultisigSpend::from_raw(raw)?),
+            MULTISIG_PROPOSE_ID => {
+                TransactionRequest::MultisigPropose(MultisigPropose::from_raw(raw)?)
+            }
+            MULTISIG_APPROVE_ID => {
+                TransactionRequest::MultisigApprove(MultisigApprove::from_raw(raw)?)
+            }
+            APPROVE_ID => TransactionRequest::Approve(Approve::from_raw(raw)?),
+            REVOKE_APPROVAL_ID => {
+                TransactionRequest::RevokeApproval(RevokeApproval::from_raw(raw)?)
+            }
+            TRANSFER_FROM_ID => TransactionRequest::TransferFrom(TransferFrom::from_raw(raw)?),
+            CREATE_VESTING_ID => TransactionRequest::CreateVesting(CreateVesting::from_raw(raw)?),
+            CLAIM_VESTED_ID => TransactionRequest::ClaimVested(ClaimVested::from_raw(raw)?),
+            OFFER_LOAN_ID => TransactionRequest::OfferLoan(OfferLoan::from_raw(raw)?),
+            ACCEPT_LOAN_ID => TransactionRequest::AcceptLoan(AcceptLoan::from_raw(raw)?),
+            RETURN_LOAN_ID => TransactionRequest::ReturnLoan(ReturnLoan::from_raw(raw)?),
+            CLAIM_COLLATERAL_ID => {
+                TransactionRequest::ClaimCollateral(ClaimCollateral::from_raw(raw)?)
+            }
+            CANCEL_LOAN_ID => TransactionRequest::CancelLoan(CancelLoan::from_raw(raw)?),
+            UPDATE_ASSET_FEES_ID => {
+                TransactionRequest::UpdateAssetFees(UpdateAssetFees::from_raw(raw)?)
+            }
+            TRANSFER_ASSET_CREATOR_ID => {
+                TransactionRequest::TransferAssetCreator(TransferAssetCreator::from_raw(raw)?)
+            }
+            SET_ASSET_RESTRICTION_ID => {
+                TransactionRequest::SetAssetRestriction(SetAssetRestriction::from_raw(raw)?)
+            }
+            SET_ASSET_SUPPLY_ID => {
+                TransactionRequest::SetAssetSupply(SetAssetSupply::from_raw(raw)?)
+            }
+            SET_ASSET_METADATA_ID => {
+                TransactionRequest::SetAssetMetadata(SetAssetMetadata::from_raw(raw)?)
+            }
+            UPDATE_ALLOWLIST_ID => {
+                TransactionRequest::UpdateAllowlist(UpdateAllowlist::from_raw(raw)?)
+            }
+            FREEZE_WALLET_ID => TransactionRequest::FreezeWallet(FreezeWallet::from_raw(raw)?),
+            UNFREEZE_WALLET_ID => {
+                TransactionRequest::UnfreezeWallet(UnfreezeWallet::from_raw(raw)?)
+            }
+            MINT_TOKENS_ID => TransactionRequest::MintTokens(MintTokens::from_raw(raw)?),
+            CREATE_COLLECTION_ID => {
+                TransactionRequest::CreateCollection(CreateCollection::from_raw(raw)?)
+            }
+            ADD_TO_COLLECTION_ID => {
+                TransactionRequest::AddToCollection(AddToCollection::from_raw(raw)?)
+            }
+            _ => {
+                return Err(encoding::Error::IncorrectMessageType {
+                    message_type: raw.message_type(),
+                })
+            }
+        };
+        Ok(request)
+    }
+
+    /// Key the transaction has to be signed with.
+    pub fn signer(&self) -> PublicKey {
+        match self {
+            &TransactionRequest::Transfer(ref trans) => *trans.from(),
+            &TransactionRequest::TransferTokens(ref trans) => *trans.from(),
+            &TransactionRequest::AddAssets(ref trans) => *trans.pub_key(),
+            &TransactionRequest::DeleteAssets(ref trans) => *trans.pub_key(),
+            &TransactionRequest::Trade(ref trans) => *trans.offer().buyer(),
+            &TransactionRequest::TradeTokens(ref trans) => *trans.offer().buyer(),
+            &TransactionRequest::TradeIntermediary(ref trans) => *trans.offer().buyer(),
+            &TransactionRequest::Exchange(ref trans) => *trans.offer().recipient(),
+            &TransactionRequest::ExchangeTokens(ref trans) => *trans.offer().recipient(),
+            &TransactionRequest::ExchangeIntermediary(ref trans) => *trans.offer().recipient(),
+            &TransactionRequest::CartCheckout(ref trans) => *trans.buyer(),
+            &TransactionRequest::MultiExchange(ref trans) => *trans.submitter(),
+            &TransactionRequest::MultiTransfer(ref trans) => *trans.from(),
+            &TransactionRequest::CreateMultisig(ref trans) => *trans.creator(),
+            &TransactionRequest::MultisigSpend(ref trans) => *trans.submitter(),
+            &TransactionRequest::MultisigPropose(ref trans) => *trans.proposer(),
+            &TransactionRequest::MultisigApprove(ref trans) => *trans.approver(),
+            &TransactionRequest::Approve(ref trans) => *trans.owner(),
+            &TransactionRequest::RevokeApproval(ref trans) => *trans.owner(),
+            &TransactionRequest::TransferFrom(ref trans) => *trans.spender(),
+            &TransactionRequest::CreateVesting(ref trans) => *trans.creator(),
+            &TransactionRequest::ClaimVested(ref trans) => *trans.beneficiary(),
+            &TransactionRequest::OfferLoan(ref trans) => *trans.lender(),
+            &TransactionRequest::AcceptLoan(ref trans) => *trans.borrower(),
+            &TransactionRequest::ReturnLoan(ref trans) => *trans.borrower(),
+            &TransactionRequest::ClaimCollateral(ref trans) => *trans.lender(),
+            &TransactionRequest::CancelLoan(ref trans) => *trans.lender(),
+            &TransactionRequest::UpdateAssetFees(ref trans) => *trans.creator(),
+            &TransactionRequest::TransferAssetCreator(ref trans) => *trans.creator(),
+            &TransactionRequest::SetAssetRestriction(ref trans) => *trans.creator(),
+            &TransactionRequest::SetAssetSupply(ref trans) => *trans.creator(),
+            &TransactionRequest::SetAssetMetadata(ref trans) => *trans.creator(),
+            &TransactionRequest::UpdateAllowlist(ref trans) => *trans.officer(),
+            &TransactionRequest::FreezeWallet(ref trans) => *trans.officer(),
+            &TransactionRequest::UnfreezeWallet(ref trans) => *trans.officer(),
+            &TransactionRequest::MintTokens(ref trans) => *trans.creator(),
+            &TransactionRequest::CreateCollection(ref trans) => *trans.creator(),
+            &TransactionRequest::AddToCollection(ref trans) => *trans.creator(),
+        }
+    }
+
+    /// Check the signature first, so that it can be told apart from
+    /// the other reasons for the transaction to fail `verify`.
+    pub fn into_verified(self) -> Result<Box<Transaction>, ApiError> {
+        let signer = self.signer();
+        let tx: Box<Transaction> = self.into();
+        if !tx.raw().verify_signature(&signer) {
+            return Err(ApiError::TransactionSignatureInvalid);
+        }
+        if !tx.verify() {
+            return Err(ApiError::TransactionMalformed);
+        }
+        Ok(tx)
+    }
+
+    /// Decode a signed transaction from the hex of its raw message and verify it.
+    pub fn from_hex(hex: &str) -> Result<Box<Transaction>, ApiError> {
+        let bytes = Vec::<u8>::from_hex(hex).map_err(|_| ApiError::TransactionHexInvalid)?;
+        if bytes.len() < HEADER_LENGTH + SIGNATURE_LENGTH || bytes.len() > MAX_TRANSACTION_LENGTH {
+            return Err(ApiError::TransactionLengthInvalid);
+        }
+
+        let raw = RawMessage::from_vec(bytes);
+        if raw.service_id() != SERVICE_ID {
+            return Err(ApiError::TransactionTypeUnknown);
+        }
+        let request = TransactionRequest::from_raw(raw).map_err(|e| match e {
+            encoding::Error::IncorrectMessageType { .. } => ApiError::TransactionTypeUnknown,
+            encoding::Error::UnexpectedlyShortPayload { .. }
+            | encoding::Error::UnexpectedlyShortRawMessage { .. }
+            | encoding::Error::IncorrectSizeOfRawMessage { .. }
+            | encoding::Error::Basic(_) => ApiError::TransactionLengthInvalid,
+            _ => ApiError::TransactionMalformed,
+        })?;
+        request.into_verified()
+    }
+
+    pub fn len(&self) -> usize {
         match self {
             &TransactionRequest::Transfer(ref trans) => trans.raw().len(),
+            &TransactionRequest::TransferTokens(ref trans) => trans.raw().len(),
             &TransactionRequest::AddAssets(ref trans) => trans.raw().len(),
             &TransactionRequest::DeleteAssets(ref trans) => trans.raw().len(),
             &TransactionRequest::Trade(ref trans) => trans.raw().len(),
+            &TransactionRequest::TradeTokens(ref trans) => trans.raw().len(),
             &TransactionRequest::TradeIntermediary(ref trans) => trans.raw().len(),
             &TransactionRequest::Exchange(ref trans) => trans.raw().len(),
+            &TransactionRequest::ExchangeTokens(ref trans) => trans.raw().len(),
             &TransactionRequest::ExchangeIntermediary(ref trans) => trans.raw().len(),
+            &TransactionRequest::CartCheckout(ref trans) => trans.raw().len(),
+            &TransactionRequest::MultiExchange(ref trans) => trans.raw().len(),
+            &TransactionRequest::MultiTransfer(ref trans) => trans.raw().len(),
+            &TransactionRequest::CreateMultisig(ref trans) => trans.raw().len(),
+            &TransactionRequest::MultisigSpend(ref trans) => trans.raw().len(),
+            &TransactionRequest::MultisigPropose(ref trans) => trans.raw().len(),
+            &TransactionRequest::MultisigApprove(ref trans) => trans.raw().len(),
+            &TransactionRequest::Approve(ref trans) => trans.raw().len(),
+            &TransactionRequest::RevokeApproval(ref trans) => trans.raw().len(),
+            &TransactionRequest::TransferFrom(ref trans) => trans.raw().len(),
+            &TransactionRequest::CreateVesting(ref trans) => trans.raw().len(),
+            &TransactionRequest::ClaimVested(ref trans) => trans.raw().len(),
+            &TransactionRequest::OfferLoan(ref trans) => trans.raw().len(),
+            &TransactionRequest::AcceptLoan(ref trans) => trans.raw().len(),
+            &TransactionRequest::ReturnLoan(ref trans) => trans.raw().len(),
+            &TransactionRequest::ClaimCollateral(ref trans) => trans.raw().len(),
+            &TransactionRequest::CancelLoan(ref trans) => trans.raw().len(),
+            &TransactionRequest::UpdateAssetFees(ref trans) => trans.raw().len(),
+            &TransactionRequest::TransferAssetCreator(ref trans) => trans.raw().len(),
+            &TransactionRequest::SetAssetRestriction(ref trans) => trans.raw().len(),
+            &TransactionRequest::SetAssetSupply(ref trans) => trans.raw().len(),
+            &TransactionRequest::SetAssetMetadata(ref trans) => trans.raw().len(),
+            &TransactionRequest::UpdateAllowlist(ref trans) => trans.raw().len(),
+            &TransactionRequest::FreezeWallet(ref trans) => trans.raw().len(),
+            &TransactionRequest::UnfreezeWallet(ref trans) => trans.raw().len(),
+            &TransactionRequest::MintTokens(ref trans) => trans.raw().len(),
+            &TransactionRequest::CreateCollection(ref trans) => trans.raw().len(),
+            &TransactionRequest::AddToCollection(ref trans) => trans.raw().len(),
         }
     }
 }
@@ -63,16 +428,144 @@ impl Into<Box<Transaction>> for TransactionRequest {
     fn into(self) -> Box<Transaction> {
         match self {
             TransactionRequest::Transfer(trans) => Box::new(trans),
+            TransactionRequest::TransferTokens(trans) => Box::new(trans),
+            TransactionRequest::AddAssets(trans) => Box::new(trans),
+            TransactionRequest::DeleteAssets(trans) => Box::new(trans),
+            TransactionRequest::Trade(trans) => Box::new(trans),
+            TransactionRequest::TradeTokens(trans) => Box::new(trans),
+            TransactionRequest::TradeIntermediary(trans) => Box::new(trans),
+            TransactionRequest::Exchange(trans) => Box::new(trans),
+            TransactionRequest::ExchangeTokens(trans) => Box::new(trans),
+            TransactionRequest::ExchangeIntermediary(trans) => Box::new(trans),
+            TransactionRequest::CartCheckout(trans) => Box::new(trans),
+            TransactionRequest::MultiExchange(trans) => Box::new(trans),
+            TransactionRequest::MultiTransfer(trans) => Box::new(trans),
+            TransactionRequest::CreateMultisig(trans) => Box::new(trans),
+            TransactionRequest::MultisigSpend(trans) => Box::new(trans),
+            TransactionRequest::MultisigPropose(trans) => Box::new(trans),
+            TransactionRequest::MultisigApprove(trans) => Box::new(trans),
+            TransactionRequest::Approve(trans) => Box::new(trans),
+            TransactionRequest::RevokeApproval(trans) => Box::new(trans),
+            TransactionRequest::TransferFrom(trans) => Box::new(trans),
+            TransactionRequest::CreateVesting(trans) => Box::new(trans),
+            TransactionRequest::ClaimVested(trans) => Box::new(trans),
+            TransactionRequest::OfferLoan(trans) => Box::new(trans),
+            TransactionRequest::AcceptLoan(trans) => Box::new(trans),
+            TransactionRequest::ReturnLoan(trans) => Box::new(trans),
+            TransactionRequest::ClaimCollateral(trans) => Box::new(trans),
+            TransactionRequest::CancelLoan(trans) => Box::new(trans),
+            TransactionRequest::UpdateAssetFees(trans) => Box::new(trans),
+            TransactionRequest::TransferAssetCreator(trans) => Box::new(trans),
+            TransactionRequest::SetAssetRestriction(trans) => Box::new(trans),
+            TransactionRequest::SetAssetSupply(trans) => Box::new(trans),
+            TransactionRequest::SetAssetMetadata(trans) => Box::new(trans),
+            TransactionRequest::UpdateAllowlist(trans) => Box::new(trans),
+            TransactionRequest::FreezeWallet(trans) => Box::new(trans),
+            TransactionRequest::UnfreezeWallet(trans) => Box::new(trans),
+            TransactionRequest::MintTokens(trans) => Box::new(trans),
+            TransactionRequest::CreateCollection(trans) => Box::new(trans),
+            TransactionRequest::AddToCollection(trans) => Box::new(trans),
+        }
+    }
+}
+
+impl Into<Box<FeesCalculator>> for TransactionRequest {
+    fn into(self) -> Box<FeesCalculator> {
+        match self {
+            TransactionRequest::Transfer(trans) => Box::new(trans),
+            TransactionRequest::TransferTokens(trans) => Box::new(trans),
+            TransactionRequest::AddAssets(trans) => Box::new(trans),
+            TransactionRequest::DeleteAssets(trans) => Box::new(trans),
+            TransactionRequest::Trade(trans) => Box::new(trans),
+            TransactionRequest::TradeTokens(trans) => Box::new(trans),
+            TransactionRequest::TradeIntermediary(trans) => Box::new(trans),
+            TransactionRequest::Exchange(trans) => Box::new(trans),
+            TransactionRequest::ExchangeTokens(trans) => Box::new(trans),
+            TransactionRequest::ExchangeIntermediary(trans) => Box::new(trans),
+            TransactionRequest::CartCheckout(trans) => Box::new(trans),
+            TransactionRequest::MultiExchange(trans) => Box::new(trans),
+            TransactionRequest::MultiTransfer(trans) => Box::new(trans),
+            TransactionRequest::CreateMultisig(trans) => Box::new(trans),
+            TransactionRequest::MultisigSpend(trans) => Box::new(trans),
+            TransactionRequest::MultisigPropose(trans) => Box::new(trans),
+            TransactionRequest::MultisigApprove(trans) => Box::new(trans),
+            TransactionRequest::Approve(trans) => Box::new(trans),
+            TransactionRequest::RevokeApproval(trans) => Box::new(trans),
+            TransactionRequest::TransferFrom(trans) => Box::new(trans),
+            TransactionRequest::CreateVesting(trans) => Box::new(trans),
+            TransactionRequest::ClaimVested(trans) => Box::new(trans),
+            TransactionRequest::OfferLoan(trans) => Box::new(trans),
+            TransactionRequest::AcceptLoan(trans) => Box::new(trans),
+            TransactionRequest::ReturnLoan(trans) => Box::new(trans),
+            TransactionRequest::ClaimCollateral(trans) => Box::new(trans),
+            TransactionRequest::CancelLoan(trans) => Box::new(trans),
+            TransactionRequest::UpdateAssetFees(trans) => Box::new(trans),
+            TransactionRequest::TransferAssetCreator(trans) => Box::new(trans),
+            TransactionRequest::SetAssetRestriction(trans) => Box::new(trans),
+            TransactionRequest::SetAssetSupply(trans) => Box::new(trans),
+            TransactionRequest::SetAssetMetadata(trans) => Box::new(trans),
+            TransactionRequest::UpdateAllowlist(trans) => Box::new(trans),
+            TransactionRequest::FreezeWallet(trans) => Box::new(trans),
+            TransactionRequest::UnfreezeWallet(trans) => Box::new(trans),
+            TransactionRequest::MintTokens(trans) => Box::new(trans),
+            TransactionRequest::CreateCollection(trans) => Box::new(trans),
+            TransactionRequest::AddToCollection(trans) => Box::new(trans),
+        }
+    }
+}
+
+impl Into<Box<Processable>> for TransactionRequest {
+    fn into(self) -> Box<Processable> {
+        match self {
+            TransactionRequest::Transfer(trans) => Box::new(trans),
+            TransactionRequest::TransferTokens(trans) => Box::new(trans),
             TransactionRequest::AddAssets(trans) => Box::new(trans),
             TransactionRequest::DeleteAssets(trans) => Box::new(trans),
             TransactionRequest::Trade(trans) => Box::new(trans),
+            TransactionRequest::TradeTokens(trans) => Box::new(trans),
             TransactionRequest::TradeIntermediary(trans) => Box::new(trans),
             TransactionRequest::Exchange(trans) => Box::new(trans),
+            TransactionRequest::ExchangeTokens(trans) => Box::new(trans),
             TransactionRequest::ExchangeIntermediary(trans) => Box::new(trans),
+            TransactionRequest::CartCheckout(trans) => Box::new(trans),
+            TransactionRequest::MultiExchange(trans) => Box::new(trans),
+            TransactionRequest::MultiTransfer(trans) => Box::new(trans),
+            TransactionRequest::CreateMultisig(trans) => Box::new(trans),
+            TransactionRequest::MultisigSpend(trans) => Box::new(trans),
+            TransactionRequest::MultisigPropose(trans) => Box::new(trans),
+            TransactionRequest::MultisigApprove(trans) => Box::new(trans),
+            TransactionRequest::Approve(trans) => Box::new(trans),
+            TransactionRequest::RevokeApproval(trans) => Box::new(trans),
+            TransactionRequest::TransferFrom(trans) => Box::new(trans),
+            TransactionRequest::CreateVesting(trans) => Box::new(trans),
+            TransactionRequest::ClaimVested(trans) => Box::new(trans),
+            TransactionRequest::OfferLoan(trans) => Box::new(trans),
+            TransactionRequest::AcceptLoan(trans) => Box::new(trans),
+            TransactionRequest::ReturnLoan(trans) => Box::new(trans),
+            TransactionRequest::ClaimCollateral(trans) => Box::new(trans),
+            TransactionRequest::CancelLoan(trans) => Box::new(trans),
+            TransactionRequest::UpdateAssetFees(trans) => Box::new(trans),
+            TransactionRequest::TransferAssetCreator(trans) => Box::new(trans),
+            TransactionRequest::SetAssetRestriction(trans) => Box::new(trans),
+            TransactionRequest::SetAssetSupply(trans) => Box::new(trans),
+            TransactionRequest::SetAssetMetadata(trans) => Box::new(trans),
+            TransactionRequest::UpdateAllowlist(trans) => Box::new(trans),
+            TransactionRequest::FreezeWallet(trans) => Box::new(trans),
+            TransactionRequest::UnfreezeWallet(trans) => Box::new(trans),
+            TransactionRequest::MintTokens(trans) => Box::new(trans),
+            TransactionRequest::CreateCollection(trans) => Box::new(trans),
+            TransactionRequest::AddToCollection(trans) => Box::new(trans),
         }
     }
 }
 
+/// A signed transaction as the hex of its raw message, that is the body from
+/// `/v1/hex/transactions` followed by the signature.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]
+pub struct RawTransactionRequest {
+    pub hex: String,
+}
+
 #[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug)]
 pub struct TransactionResponse {
     pub tx_hash: Hash,
@@ -80,14 +573,235 @@ pub struct TransactionResponse {
 
 pub type TxPostResponse = Result<Result<TransactionResponse, Error>, ApiError>;
 
+/// Body of the response refusing a transaction of a disabled type, in place
+/// of `TxPostResponse`.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]
+pub struct TxDisabledResponse {
+    /// Always `TransactionDisabled`.
+    pub error: Error,
+    pub message_id: u16,
+    /// Why the transaction type is disabled.
+    pub reason: String,
+}
+
+impl TxDisabledResponse {
+    fn body(s: &TxPostResponse, disabled: Option<DisabledTransaction>) -> String {
+        match (disabled, s) {
+            (Some(disabled), &Ok(Err(Error::TransactionDisabled))) => {
+                let refusal = TxDisabledResponse {
+                    error: Error::TransactionDisabled,
+                    message_id: disabled.message_id,
+                    reason: disabled.reason,
+                };
+                serde_json::to_string_pretty(&refusal).unwrap()
+            }
+            _ => serde_json::to_string_pretty(s).unwrap(),
+        }
+    }
+}
+
+/// Responses to the items of a batch, in the order of the items.
+pub type TxBatchPostResponse = Result<Vec<TxPostResponse>, ApiError>;
+
 //#[derive(Serialize, Deserialize, Debug)]
 pub type StatusResponse = Result<Result<(), Error>, ApiError>;
 
+pub type DisabledTransactionsResponse = Result<Vec<DisabledTransaction>, ApiError>;
+
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]
+pub struct AssetDiff {
+    pub id: AssetId,
+    pub before: u64,
+    pub after: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]
+pub struct WalletDiff {
+    pub pub_key: PublicKey,
+    pub balance_before: u64,
+    pub balance_after: u64,
+    /// Assets whose amount in the wallet changed.
+    pub assets: Vec<AssetDiff>,
+}
+
+impl WalletDiff {
+    pub fn from(pub_key: PublicKey, before: &Wallet, after: &Wallet) -> Self {
+        let mut amounts: HashMap<AssetId, (u64, u64)> = HashMap::new();
+        for asset in before.assets() {
+            amounts.entry(asset.id()).or_insert((0, 0)).0 += asset.amount();
+        }
+        for asset in after.assets() {
+            amounts.entry(asset.id()).or_insert((0, 0)).1 += asset.amount();
+        }
+
+        let mut assets: Vec<AssetDiff> = amounts
+            .into_iter()
+            .filter(|&(_, (before, after))| before != after)
+            .map(|(id, (before, after))| AssetDiff { id, before, after })
+            .collect();
+        assets.sort_by_key(|diff| diff.id.to_string());
+
+        WalletDiff {
+            pub_key,
+            balance_before: before.balance(),
+            balance_after: after.balance(),
+            assets,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SimulationResponseBody {
+    pub tx_hash: Hash,
+    /// Whether the transaction passes `verify`, signature included. Unsigned
+    /// transactions are still executed so they can be previewed.
+    pub verified: bool,
+    /// The status the transaction would get if it was committed now.
+    pub status: Result<(), Error>,
+    /// Fees charged from each payer, empty if they can't be calculated.
+    pub fees: HashMap<PublicKey, u64>,
+    /// Wallets changed by the transaction, ordered by public key.
+    pub wallets: Vec<WalletDiff>,
+}
+
+pub type SimulationResponse = Result<SimulationResponseBody, ApiError>;
+
 impl TransactionApi {
     fn get_status(&self, tx_hash: &Hash) -> Option<Result<(), Error>> {
         let view = &mut self.blockchain.fork();
         status::Schema(view).fetch(tx_hash)
     }
+
+    /// Wait up to `wait` for the transaction to be committed and tell its status,
+    /// or whether it is still pending.
+    ///
+    /// Only transactions submitted through this node are known to be pending,
+    /// others are not found until they are committed.
+    pub fn wait_status(&self, tx_hash: &Hash, wait: Duration) -> StatusResponse {
+        match pending::PENDING.wait_for(wait, || self.get_status(tx_hash)) {
+            Some(status) => Ok(status),
+            None if pending::PENDING.contains(tx_hash) => Err(ApiError::TransactionPending),
+            None => Err(ApiError::TransactionNotFound),
+        }
+    }
+
+    fn configuration(&self) -> Configuration {
+        Configuration::extract(&self.blockchain.snapshot())
+    }
+
+    /// The switch disabling the type of the transaction, if there is one.
+    pub fn disabled(&self, tx: &Transaction) -> Option<DisabledTransaction> {
+        let message_type = tx.raw().message_type();
+        self.configuration()
+            .disabled_transactions()
+            .iter()
+            .find(|disabled| disabled.message_id == message_type)
+            .cloned()
+    }
+
+    /// Forward the transaction to the node unless its type is disabled.
+    pub fn send(&self, tx: Box<Transaction>) -> Result<TransactionResponse, Error> {
+        let verified = tx.verify();
+        self.send_verified(tx, verified)
+    }
+
+    /// Forward a transaction whose `verify` result is known already.
+    fn send_verified(
+        &self,
+        tx: Box<Transaction>,
+        verified: bool,
+    ) -> Result<TransactionResponse, Error> {
+        let tx_hash = tx.hash();
+        // Refuse disabled types up front instead of letting them fail in a block.
+        // The reasons are listed at `/v1/transactions/disabled`.
+        if let Some(disabled) = self.disabled(&*tx) {
+            info!(
+                "Refusing tx {:?} of disabled type {}: {}",
+                tx_hash, disabled.message_id, disabled.reason
+            );
+            return Err(Error::TransactionDisabled);
+        }
+
+        match self.channel.send(tx) {
+            Ok(_) => {
+                // The node drops transactions that fail verification.
+                if verified {
+                    pending::PENDING.insert(tx_hash);
+                }
+                Ok(TransactionResponse { tx_hash })
+            }
+            Err(_) => Err(Error::UnableToVerifyTransaction),
+        }
+    }
+
+    /// Check and forward one transaction of a batch, given as JSON or as
+    /// the hex of its raw message.
+    ///
+    /// Unlike single submissions, batch items are verified here so that
+    /// invalid ones are reported next to their position in the batch.
+    fn send_batch_item(&self, item: serde_json::Value) -> TxPostResponse {
+        let tx: Box<Transaction> = match item {
+            // Raw messages are verified while decoding.
+            serde_json::Value::String(hex) => TransactionRequest::from_hex(&hex)?,
+            item => {
+                let transaction = TransactionRequest::from_json(item).map_err(|e| match e {
+                    ApiError::IncorrectRequest => ApiError::TransactionMalformed,
+                    e => e,
+                })?;
+                if transaction.len() > MAX_TRANSACTION_LENGTH {
+                    return Err(ApiError::TransactionLengthInvalid);
+                }
+
+                transaction.into_verified()?
+            }
+        };
+
+        Ok(self.send_verified(tx, true))
+    }
+
+    /// Process the transaction against a throwaway fork of the latest state.
+    /// Nothing is broadcast, the fork is dropped afterwards and the execution
+    /// metrics of the transaction are left untouched.
+    fn simulate(
+        &self,
+        tx: Box<Processable>,
+        calculator: Box<FeesCalculator>,
+    ) -> SimulationResponseBody {
+        let tx_hash = tx.hash();
+        let verified = tx.verify();
+
+        let fees = calculator
+            .calculate_fees(&mut self.blockchain.fork())
+            .unwrap_or_default();
+
+        let snapshot = self.blockchain.snapshot();
+        let mut fork = self.blockchain.fork();
+        // Transactions with atomic parts rely on the checkpoint the
+        // blockchain sets around every executed transaction.
+        fork.checkpoint();
+        let status = tx.process_enabled(&mut fork);
+        fork.commit();
+
+        let mut changed = wallet::Schema(&mut fork).changed();
+        changed.sort();
+        changed.dedup();
+        let wallets = changed
+            .into_iter()
+            .map(|pub_key| {
+                let before = wallet::Schema(&snapshot).fetch(&pub_key);
+                let after = wallet::Schema(&fork).fetch(&pub_key);
+                WalletDiff::from(pub_key, &before, &after)
+            })
+            .collect();
+
+        SimulationResponseBody {
+            tx_hash,
+            verified,
+            status,
+            fees,
+            wallets,
+        }
+    }
 }
 
 lazy_static! {
@@ -107,6 +821,38 @@ lazy_static! {
         "dmbc_transaction_api_get_status_responses_total",
         "Transaction status responses."
     ).unwrap();
+    static ref GET_DISABLED_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_transaction_api_get_disabled_requests_total",
+        "Disabled transaction types requests."
+    ).unwrap();
+    static ref GET_DISABLED_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_transaction_api_get_disabled_responses_total",
+        "Disabled transaction types responses."
+    ).unwrap();
+    static ref POST_RAW_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_transaction_api_post_raw_requests_total",
+        "Raw transaction post requests."
+    ).unwrap();
+    static ref POST_RAW_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_transaction_api_post_raw_responses_total",
+        "Raw transaction post responses."
+    ).unwrap();
+    static ref BATCH_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_transaction_api_batch_requests_total",
+        "Transaction batch post requests."
+    ).unwrap();
+    static ref BATCH_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_transaction_api_batch_responses_total",
+        "Transaction batch post responses."
+    ).unwrap();
+    static ref SIMULATE_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_transaction_api_simulate_requests_total",
+        "Transaction simulation requests."
+    ).unwrap();
+    static ref SIMULATE_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_transaction_api_simulate_responses_total",
+        "Transaction simulation responses."
+    ).unwrap();
 }
 
 impl Api for TransactionApi {
@@ -115,18 +861,16 @@ impl Api for TransactionApi {
         let transaction = move |req: &mut Request| -> IronResult<Response> {
             POST_REQUESTS.inc();
 
-            let s: TxPostResponse = match req.get::<bodyparser::Struct<TransactionRequest>>() {
-                Ok(Some(ref transaction)) if transaction.len() > MAX_TRANSACTION_LENGTH => {
-                    Ok(Err(Error::InvalidTransaction))
-                }
-                Ok(Some(transaction)) => {
-                    let tx: Box<Transaction> = transaction.into();
-                    let tx_hash = tx.hash();
-                    match self_.channel.send(tx) {
-                        Ok(_) => Ok(Ok(TransactionResponse { tx_hash })),
-                        Err(_) => Ok(Err(Error::UnableToVerifyTransaction)),
+            let mut disabled = None;
+            let s: TxPostResponse = match req.get::<bodyparser::Json>() {
+                Ok(Some(value)) => TransactionRequest::from_json(value).map(|transaction| {
+                    if transaction.len() > MAX_TRANSACTION_LENGTH {
+                        return Err(Error::InvalidTransaction);
                     }
-                }
+                    let tx: Box<Transaction> = transaction.into();
+                    disabled = self_.disabled(&*tx);
+                    self_.send(tx)
+                }),
                 Ok(None) => Err(ApiError::EmptyRequestBody),
                 Err(_) => Err(ApiError::IncorrectRequest),
             };
@@ -139,7 +883,7 @@ impl Api for TransactionApi {
                 })
                 .unwrap_or(istatus::BadRequest);
 
-            let mut res = Response::with((ss, serde_json::to_string_pretty(&s).unwrap()));
+            let mut res = Response::with((ss, TxDisabledResponse::body(&s, disabled)));
             res.headers.set(ContentType::json());
             res.headers.set(AccessControlAllowOrigin::Any);
 
@@ -149,19 +893,19 @@ impl Api for TransactionApi {
         };
         // Bind the transaction handler to a specific route.
 
+        // Status of a transaction. With `?wait=<seconds>` the request blocks
+        // until the transaction is committed or the time runs out.
         let self_ = self.clone();
         let get_status = move |request: &mut Request| -> IronResult<Response> {
             GET_STATUS_REQUESTS.inc();
 
+            let wait = ServiceApi::read_parameter(request, PARAMETER_WAIT_KEY, 0u64);
+            let wait = Duration::from_secs(cmp::min(wait, STATUS_WAIT_MAX_SECS));
             let path = request.url.path();
             let tx_hash_str = path.last().unwrap();
             let s: StatusResponse = Hash::from_hex(tx_hash_str)
                 .map_err(|_| ApiError::TransactionHashInvalid)
-                .and_then(|tx_hash| {
-                    self_
-                        .get_status(&tx_hash)
-                        .ok_or(ApiError::TransactionNotFound)
-                });
+                .and_then(|tx_hash| self_.wait_status(&tx_hash, wait));
 
             let mut res = Response::with((
                 s.clone()
@@ -178,7 +922,136 @@ impl Api for TransactionApi {
             Ok(res)
         };
 
+        let self_ = self.clone();
+        let get_disabled = move |_: &mut Request| -> IronResult<Response> {
+            GET_DISABLED_REQUESTS.inc();
+
+            let s: DisabledTransactionsResponse =
+                Ok(self_.configuration().disabled_transactions().to_vec());
+
+            let mut res = Response::with((istatus::Ok, serde_json::to_string_pretty(&s).unwrap()));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            GET_DISABLED_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Submits a signed transaction given as the hex of its raw message.
+        let self_ = self.clone();
+        let transaction_raw = move |req: &mut Request| -> IronResult<Response> {
+            POST_RAW_REQUESTS.inc();
+
+            let mut disabled = None;
+            let s: TxPostResponse = match req.get::<bodyparser::Struct<RawTransactionRequest>>() {
+                Ok(Some(request)) => TransactionRequest::from_hex(&request.hex).map(|tx| {
+                    disabled = self_.disabled(&*tx);
+                    self_.send_verified(tx, true)
+                }),
+                Ok(None) => Err(ApiError::EmptyRequestBody),
+                Err(_) => Err(ApiError::IncorrectRequest),
+            };
+            let ss = s.clone()
+                .map(|r| {
+                    r.err()
+                        .map(|_| istatus::BadRequest)
+                        .unwrap_or(istatus::Created)
+                })
+                .unwrap_or_else(|e| e.to_status());
+
+            let mut res = Response::with((ss, TxDisabledResponse::body(&s, disabled)));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            POST_RAW_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Submits an array of transactions of any types, as JSON or as the hex
+        // of their raw messages, answering with the hash or the error of each
+        // one in the same order.
+        let self_ = self.clone();
+        let batch_max_size = config::config().api().batch_max_size();
+        let batch = move |req: &mut Request| -> IronResult<Response> {
+            BATCH_REQUESTS.inc();
+
+            let body = req.get::<bodyparser::Struct<Vec<serde_json::Value>>>();
+            let s: TxBatchPostResponse = match body {
+                Ok(Some(ref items)) if items.len() > batch_max_size => {
+                    Err(ApiError::BatchTooLarge)
+                }
+                Ok(Some(items)) => Ok(items
+                    .into_iter()
+                    .map(|item| self_.send_batch_item(item))
+                    .collect()),
+                Ok(None) => Err(ApiError::EmptyRequestBody),
+                Err(_) => Err(ApiError::IncorrectRequest),
+            };
+
+            let mut res = Response::with((
+                s.clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(istatus::Created),
+                serde_json::to_string_pretty(&s).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            BATCH_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Dry-runs a transaction and reports its status, fees and the changes
+        // it would make to wallets, without broadcasting it.
+        let self_ = self.clone();
+        let simulate = move |req: &mut Request| -> IronResult<Response> {
+            SIMULATE_REQUESTS.inc();
+
+            let s: SimulationResponse = match req.get::<bodyparser::Json>() {
+                Ok(Some(value)) => TransactionRequest::from_json(value).and_then(|transaction| {
+                    if transaction.len() > MAX_TRANSACTION_LENGTH {
+                        return Err(ApiError::IncorrectRequest);
+                    }
+                    let calculator: Box<FeesCalculator> = transaction.clone().into();
+                    let tx: Box<Processable> = transaction.into();
+                    Ok(self_.simulate(tx, calculator))
+                }),
+                Ok(None) => Err(ApiError::EmptyRequestBody),
+                Err(_) => Err(ApiError::IncorrectRequest),
+            };
+
+            let mut res = Response::with((
+                s.clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(istatus::Ok),
+                serde_json::to_string_pretty(&s).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            SIMULATE_RESPONSES.inc();
+
+            Ok(res)
+        };
+
         router.post("/v1/transactions", transaction, "transaction");
+        router.post("/v1/transactions/raw", transaction_raw, "transaction_raw");
+        router.post("/v1/transactions/batch", batch, "transaction_batch");
+        router.post(
+            "/v1/transactions/simulate",
+            simulate,
+            "simulate_transaction",
+        );
+        router.get(
+            "/v1/transactions/disabled",
+            get_disabled,
+            "get_disabled_transactions",
+        );
         router.get(
             "/v1/transactions/:hash",
             get_status,
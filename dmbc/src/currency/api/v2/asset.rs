@@ -0,0 +1,164 @@
+use exonum::api::Api;
+use exonum::blockchain::Blockchain;
+use exonum::crypto::{Hash, PublicKey};
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::asset::AssetApi;
+use currency::api::error::ApiError;
+use currency::api::v2::{read_decimals, respond, V2Result};
+use currency::assets::{AssetId, AssetInfo, Fee, Supply};
+use decimal;
+
+#[derive(Clone)]
+pub struct AssetV2Api {
+    pub blockchain: Blockchain,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct FeeBody {
+    pub fixed: String,
+    pub fraction: String,
+}
+
+impl FeeBody {
+    fn from(fee: &Fee, decimals: u32) -> Self {
+        FeeBody {
+            fixed: decimal::format_units(fee.fixed(), decimals),
+            fraction: fee.fraction().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct FeesBody {
+    pub trade: FeeBody,
+    pub exchange: FeeBody,
+    pub transfer: FeeBody,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AssetInfoBody {
+    pub creator: PublicKey,
+    pub origin: Hash,
+    pub amount: String,
+    pub fees: FeesBody,
+    pub data: String,
+}
+
+impl AssetInfoBody {
+    fn from(info: &AssetInfo, decimals: u32) -> Self {
+        let fees = info.fees();
+        AssetInfoBody {
+            creator: *info.creator(),
+            origin: *info.origin(),
+            amount: decimal::format_units(info.amount(), decimals),
+            fees: FeesBody {
+                trade: FeeBody::from(&fees.trade(), decimals),
+                exchange: FeeBody::from(&fees.exchange(), decimals),
+                transfer: FeeBody::from(&fees.transfer(), decimals),
+            },
+            data: info.data().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct SupplyBody {
+    pub max_supply: String,
+    pub sealed: bool,
+    pub minted: String,
+}
+
+impl SupplyBody {
+    fn from(supply: &Supply, decimals: u32) -> Self {
+        SupplyBody {
+            max_supply: decimal::format_units(supply.max_supply(), decimals),
+            sealed: supply.sealed(),
+            minted: decimal::format_units(supply.minted(), decimals),
+        }
+    }
+}
+
+fn asset_id(req: &Request) -> V2Result<AssetId> {
+    let id_hex = req.extensions
+        .get::<Router>()
+        .unwrap()
+        .find("asset_id")
+        .unwrap();
+    AssetId::from_hex(id_hex).map_err(|_| ApiError::AssetIdInvalid.into())
+}
+
+impl AssetV2Api {
+    fn v1(&self) -> AssetApi {
+        AssetApi {
+            blockchain: self.blockchain.clone(),
+        }
+    }
+
+    fn info(&self, req: &mut Request) -> V2Result<AssetInfoBody> {
+        let decimals = read_decimals(req)?;
+        let info = self.v1()
+            .get_asset_info(&asset_id(req)?)
+            .ok_or(ApiError::AssetIdNotFound)?;
+        Ok(AssetInfoBody::from(&info, decimals))
+    }
+
+    fn supply(&self, req: &mut Request) -> V2Result<SupplyBody> {
+        let decimals = read_decimals(req)?;
+        let supply = self.v1()
+            .get_asset_supply(&asset_id(req)?)
+            .ok_or(ApiError::AssetIdNotFound)?;
+        Ok(SupplyBody::from(&supply, decimals))
+    }
+}
+
+lazy_static! {
+    static ref INFO_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_asset_api_info_requests_total",
+        "AssetInfo requests."
+    ).unwrap();
+    static ref INFO_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_asset_api_info_responses_total",
+        "AssetInfo responses."
+    ).unwrap();
+    static ref SUPPLY_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_asset_api_supply_requests_total",
+        "Asset supply requests."
+    ).unwrap();
+    static ref SUPPLY_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_asset_api_supply_responses_total",
+        "Asset supply responses."
+    ).unwrap();
+}
+
+impl Api for AssetV2Api {
+    fn wire(&self, router: &mut Router) {
+        // Gets the info of the asset, with fee fractions as decimal strings.
+        let self_ = self.clone();
+        let asset_info = move |req: &mut Request| -> IronResult<Response> {
+            INFO_REQUESTS.inc();
+            let res = respond(self_.info(req), status::Ok);
+            INFO_RESPONSES.inc();
+            Ok(res)
+        };
+
+        // Gets the supply limits of the asset and the amount minted so far.
+        let self_ = self.clone();
+        let asset_supply = move |req: &mut Request| -> IronResult<Response> {
+            SUPPLY_REQUESTS.inc();
+            let res = respond(self_.supply(req), status::Ok);
+            SUPPLY_RESPONSES.inc();
+            Ok(res)
+        };
+
+        router.get("/v2/assets/:asset_id", asset_info, "v2_get_asset_info");
+        router.get(
+            "/v2/assets/:asset_id/supply",
+            asset_supply,
+            "v2_get_asset_supply",
+        );
+    }
+}
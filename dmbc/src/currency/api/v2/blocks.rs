@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use exonum::api::Api;
+use exonum::blockchain::{Block, Blockchain};
+use exonum::explorer::BlockInfo;
+use exonum::helpers::Height;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::blocks::{BlocksApi, MAX_BLOCKS_PER_REQUEST};
+use currency::api::error::ApiError;
+use currency::api::v2::{respond, V2Result};
+use currency::api::ServiceApi;
+
+#[derive(Clone)]
+pub struct BlocksV2Api {
+    pub blockchain: Blockchain,
+}
+
+/// Parse an optional query parameter, refusing malformed values instead of
+/// falling back to the default.
+fn parse_parameter<T: FromStr>(req: &mut Request, key: &str) -> V2Result<Option<T>> {
+    match ServiceApi::read_parameter(req, key, String::new()).as_str() {
+        "" => Ok(None),
+        value => value
+            .parse()
+            .map(Some)
+            .map_err(|_| ApiError::IncorrectRequest.into()),
+    }
+}
+
+impl BlocksV2Api {
+    fn v1(&self) -> BlocksApi {
+        BlocksApi {
+            blockchain: self.blockchain.clone(),
+        }
+    }
+
+    fn blocks(&self, req: &mut Request) -> V2Result<Vec<Block>> {
+        let count = parse_parameter(req, "count")?.unwrap_or(MAX_BLOCKS_PER_REQUEST);
+        let latest = parse_parameter(req, "latest")?;
+        let skip_empty_blocks = parse_parameter(req, "skip_empty_blocks")?.unwrap_or(false);
+        Ok(self.v1().get_blocks(count, latest, skip_empty_blocks)?)
+    }
+
+    fn block(&self, req: &mut Request) -> V2Result<BlockInfo> {
+        let height = req.extensions
+            .get::<Router>()
+            .unwrap()
+            .find("height")
+            .unwrap()
+            .parse::<u64>()
+            .map_err(|_| ApiError::IncorrectRequest)?;
+        self.v1()
+            .get_block(Height(height))?
+            .ok_or_else(|| ApiError::BlockNotFound.into())
+    }
+}
+
+lazy_static! {
+    static ref LIST_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_blocks_api_list_requests_total",
+        "Block list requests."
+    ).unwrap();
+    static ref LIST_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_blocks_api_list_responses_total",
+        "Block list responses."
+    ).unwrap();
+    static ref INFO_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_blocks_api_info_requests_total",
+        "Block info requests."
+    ).unwrap();
+    static ref INFO_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_blocks_api_info_responses_total",
+        "Block info responses."
+    ).unwrap();
+}
+
+impl Api for BlocksV2Api {
+    fn wire(&self, router: &mut Router) {
+        // Gets up to `count` blocks down from `latest`, or from the last block.
+        let self_ = self.clone();
+        let blocks = move |req: &mut Request| -> IronResult<Response> {
+            LIST_REQUESTS.inc();
+            let res = respond(self_.blocks(req), status::Ok);
+            LIST_RESPONSES.inc();
+            Ok(res)
+        };
+
+        // Gets the block at the height with the hashes of its transactions.
+        let self_ = self.clone();
+        let block = move |req: &mut Request| -> IronResult<Response> {
+            INFO_REQUESTS.inc();
+            let res = respond(self_.block(req), status::Ok);
+            INFO_RESPONSES.inc();
+            Ok(res)
+        };
+
+        router.get("/v2/blocks", blocks, "v2_blocks_info");
+        router.get("/v2/blocks/:height", block, "v2_height");
+    }
+}
@@ -0,0 +1,72 @@
+extern crate bodyparser;
+
+use std::collections::HashMap;
+
+use exonum::api::Api;
+use exonum::blockchain::Blockchain;
+use exonum::crypto::PublicKey;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::api::transaction::TransactionRequest;
+use currency::api::v2::{read_decimals, respond, V2Result};
+use currency::transactions::components::FeesCalculator;
+use decimal;
+
+#[derive(Clone)]
+pub struct FeesV2Api {
+    pub blockchain: Blockchain,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FeesBody {
+    pub fees: HashMap<PublicKey, String>,
+}
+
+impl FeesV2Api {
+    fn fees(&self, req: &mut Request) -> V2Result<FeesBody> {
+        let decimals = read_decimals(req)?;
+        let value = match req.get::<bodyparser::Json>() {
+            Ok(Some(value)) => value,
+            Ok(None) => return Err(ApiError::EmptyRequestBody.into()),
+            Err(_) => return Err(ApiError::IncorrectRequest.into()),
+        };
+        let calculator: Box<FeesCalculator> = TransactionRequest::from_json(value)?.into();
+        let view = &mut self.blockchain.fork();
+        let fees = calculator.calculate_fees(view)?;
+        Ok(FeesBody {
+            fees: fees.into_iter()
+                .map(|(key, fee)| (key, decimal::format_units(fee, decimals)))
+                .collect(),
+        })
+    }
+}
+
+lazy_static! {
+    static ref FEES_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_fees_api_transaction_requests_total",
+        "Transaction fee requests."
+    ).unwrap();
+    static ref FEES_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_fees_api_transaction_responses_total",
+        "Transaction fee responses."
+    ).unwrap();
+}
+
+impl Api for FeesV2Api {
+    fn wire(&self, router: &mut Router) {
+        // Calculates the fees each key would pay for the signed transaction.
+        let self_ = self.clone();
+        let fees = move |req: &mut Request| -> IronResult<Response> {
+            FEES_REQUESTS.inc();
+            let res = respond(self_.fees(req), status::Ok);
+            FEES_RESPONSES.inc();
+            Ok(res)
+        };
+
+        router.post("/v2/fees/transactions", fees, "v2_transaction_fee");
+    }
+}
@@ -0,0 +1,187 @@
+//! Version 2 of the public API.
+//!
+//! Every response is an `Envelope`, either `{"data": ...}` or
+//! `{"error": {"code": ..., "message": ...}}`, with the HTTP status agreeing
+//! with it. Errors of the service and of the API share one set of stable
+//! codes. Amounts of coins and assets are strings, so they survive JSON
+//! parsers that read numbers as doubles, and are formatted with the number
+//! of decimal places given in the `decimals` parameter.
+
+pub mod asset;
+pub mod blocks;
+pub mod fees;
+pub mod transaction;
+pub mod wallet;
+
+extern crate serde_json;
+
+use exonum::api::Api;
+use exonum::blockchain::Blockchain;
+use exonum::node::ApiSender;
+use hyper::header::ContentType;
+use hyper::status::StatusCode;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use router::Router;
+use serde::Serialize;
+
+use currency::api::error::ApiError;
+use currency::api::ServiceApi;
+use currency::configuration::DisabledTransaction;
+use currency::error::Error;
+use decimal;
+
+use self::asset::AssetV2Api;
+use self::blocks::BlocksV2Api;
+use self::fees::FeesV2Api;
+use self::transaction::TransactionV2Api;
+use self::wallet::WalletV2Api;
+
+pub const PARAMETER_DECIMALS_KEY: &str = "decimals";
+
+/// Body of every response.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Envelope<T> {
+    Data(T),
+    Error(ErrorBody),
+}
+
+/// Description of an error.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ErrorBody {
+    pub code: String,
+    pub message: String,
+}
+
+/// An error of either the API or the service.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum V2Error {
+    Api(ApiError),
+    Service(Error),
+    /// `TransactionDisabled` refusing a submission, with the switch.
+    Disabled(DisabledTransaction),
+}
+
+impl From<ApiError> for V2Error {
+    fn from(e: ApiError) -> Self {
+        V2Error::Api(e)
+    }
+}
+
+impl From<Error> for V2Error {
+    fn from(e: Error) -> Self {
+        V2Error::Service(e)
+    }
+}
+
+impl V2Error {
+    /// Stable code of the error.
+    pub fn code(&self) -> &'static str {
+        match *self {
+            V2Error::Api(ref e) => e.code(),
+            V2Error::Service(ref e) => e.code(),
+            V2Error::Disabled(_) => Error::TransactionDisabled.code(),
+        }
+    }
+
+    /// HTTP status of the response with the error.
+    pub fn status(&self) -> StatusCode {
+        match *self {
+            V2Error::Api(ref e) => e.to_status(),
+            V2Error::Service(Error::AssetNotFound)
+            | V2Error::Service(Error::TransactionNotFound)
+            | V2Error::Service(Error::MultisigNotFound)
+            | V2Error::Service(Error::ProposalNotFound)
+            | V2Error::Service(Error::AllowanceNotFound)
+            | V2Error::Service(Error::VestingNotFound)
+            | V2Error::Service(Error::LoanNotFound)
+            | V2Error::Service(Error::TokenNotFound)
+            | V2Error::Service(Error::CollectionNotFound) => StatusCode::NotFound,
+            V2Error::Service(Error::TransactionDisabled) | V2Error::Disabled(_) => {
+                StatusCode::Forbidden
+            }
+            V2Error::Service(Error::UnableToVerifyTransaction) => StatusCode::ServiceUnavailable,
+            V2Error::Service(_) => StatusCode::BadRequest,
+        }
+    }
+
+    /// Body of the response with the error.
+    pub fn body(&self) -> ErrorBody {
+        let message = match *self {
+            V2Error::Api(ref e) => e.code().replace('_', " "),
+            V2Error::Service(ref e) => e.to_string(),
+            V2Error::Disabled(ref disabled) => {
+                format!("{}: {}", Error::TransactionDisabled, disabled.reason)
+            }
+        };
+        ErrorBody {
+            code: self.code().to_string(),
+            message,
+        }
+    }
+}
+
+pub type V2Result<T> = Result<T, V2Error>;
+
+/// Make the response with the envelope of `result`, with the `success`
+/// status if there is no error.
+pub fn respond<T: Serialize>(result: V2Result<T>, success: StatusCode) -> Response {
+    let (status, body) = match result {
+        Ok(data) => (success, serde_json::to_string_pretty(&Envelope::Data(data))),
+        Err(e) => (
+            e.status(),
+            serde_json::to_string_pretty(&Envelope::<()>::Error(e.body())),
+        ),
+    };
+
+    let mut res = Response::with((status, body.unwrap()));
+    res.headers.set(ContentType::json());
+    res.headers.set(AccessControlAllowOrigin::Any);
+    res
+}
+
+/// Number of decimal places to format amounts with.
+pub fn read_decimals(req: &mut Request) -> V2Result<u32> {
+    let decimals = ServiceApi::read_parameter(req, PARAMETER_DECIMALS_KEY, 0u64);
+    if decimals > u64::from(decimal::MAX_PLACES) {
+        return Err(ApiError::IncorrectRequest.into());
+    }
+    Ok(decimals as u32)
+}
+
+#[derive(Clone)]
+pub struct V2Api {
+    pub channel: ApiSender,
+    pub blockchain: Blockchain,
+}
+
+impl Api for V2Api {
+    fn wire(&self, router: &mut Router) {
+        let api = TransactionV2Api {
+            channel: self.channel.clone(),
+            blockchain: self.blockchain.clone(),
+        };
+        api.wire(router);
+
+        let api = WalletV2Api {
+            blockchain: self.blockchain.clone(),
+        };
+        api.wire(router);
+
+        let api = AssetV2Api {
+            blockchain: self.blockchain.clone(),
+        };
+        api.wire(router);
+
+        let api = FeesV2Api {
+            blockchain: self.blockchain.clone(),
+        };
+        api.wire(router);
+
+        let api = BlocksV2Api {
+            blockchain: self.blockchain.clone(),
+        };
+        api.wire(router);
+    }
+}
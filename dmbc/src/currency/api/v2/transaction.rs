@@ -0,0 +1,181 @@
+extern crate bodyparser;
+
+use std::cmp;
+use std::time::Duration;
+
+use exonum::api::Api;
+use exonum::blockchain::{Blockchain, Transaction};
+use exonum::crypto::Hash;
+use exonum::encoding::serialize::FromHex;
+use exonum::node::ApiSender;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::api::transaction::{RawTransactionRequest, TransactionApi, TransactionRequest,
+                                 TransactionResponse, MAX_TRANSACTION_LENGTH,
+                                 PARAMETER_WAIT_KEY, STATUS_WAIT_MAX_SECS};
+use currency::api::v2::{respond, ErrorBody, V2Error, V2Result};
+use currency::api::ServiceApi;
+
+#[derive(Clone)]
+pub struct TransactionV2Api {
+    pub channel: ApiSender,
+    pub blockchain: Blockchain,
+}
+
+/// Where a transaction is on its way into the blockchain.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum TxState {
+    /// Accepted by the node and waiting for a block.
+    Pending,
+    /// Committed and executed successfully.
+    Committed,
+    /// Committed, but failed to execute.
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]
+pub struct TxStatusBody {
+    pub tx_hash: Hash,
+    pub state: TxState,
+    /// Why the transaction failed, for the `failed` state.
+    pub error: Option<ErrorBody>,
+}
+
+impl TransactionV2Api {
+    fn v1(&self) -> TransactionApi {
+        TransactionApi {
+            channel: self.channel.clone(),
+            blockchain: self.blockchain.clone(),
+        }
+    }
+
+    fn post(&self, req: &mut Request) -> V2Result<TransactionResponse> {
+        let value = match req.get::<bodyparser::Json>() {
+            Ok(Some(value)) => value,
+            Ok(None) => return Err(ApiError::EmptyRequestBody.into()),
+            Err(_) => return Err(ApiError::IncorrectRequest.into()),
+        };
+        let transaction = TransactionRequest::from_json(value)?;
+        if transaction.len() > MAX_TRANSACTION_LENGTH {
+            return Err(ApiError::TransactionLengthInvalid.into());
+        }
+        let tx: Box<Transaction> = transaction.into();
+        self.send(tx)
+    }
+
+    fn post_raw(&self, req: &mut Request) -> V2Result<TransactionResponse> {
+        let request = match req.get::<bodyparser::Struct<RawTransactionRequest>>() {
+            Ok(Some(request)) => request,
+            Ok(None) => return Err(ApiError::EmptyRequestBody.into()),
+            Err(_) => return Err(ApiError::IncorrectRequest.into()),
+        };
+        let tx = TransactionRequest::from_hex(&request.hex)?;
+        self.send(tx)
+    }
+
+    fn send(&self, tx: Box<Transaction>) -> V2Result<TransactionResponse> {
+        let v1 = self.v1();
+        if let Some(disabled) = v1.disabled(&*tx) {
+            return Err(V2Error::Disabled(disabled));
+        }
+        Ok(v1.send(tx)?)
+    }
+
+    fn status(&self, req: &mut Request) -> V2Result<TxStatusBody> {
+        let wait = ServiceApi::read_parameter(req, PARAMETER_WAIT_KEY, 0u64);
+        let wait = Duration::from_secs(cmp::min(wait, STATUS_WAIT_MAX_SECS));
+        let tx_hash = {
+            let hash_hex = req.extensions.get::<Router>().unwrap().find("hash").unwrap();
+            Hash::from_hex(hash_hex).map_err(|_| ApiError::TransactionHashInvalid)?
+        };
+
+        let (state, error) = match self.v1().wait_status(&tx_hash, wait) {
+            Ok(Ok(())) => (TxState::Committed, None),
+            Ok(Err(e)) => (TxState::Failed, Some(V2Error::from(e).body())),
+            Err(ApiError::TransactionPending) => (TxState::Pending, None),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(TxStatusBody {
+            tx_hash,
+            state,
+            error,
+        })
+    }
+}
+
+lazy_static! {
+    static ref POST_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_transaction_api_post_requests_total",
+        "Transaction post requests."
+    ).unwrap();
+    static ref POST_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_transaction_api_post_responses_total",
+        "Transaction post responses."
+    ).unwrap();
+    static ref POST_RAW_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_transaction_api_post_raw_requests_total",
+        "Raw transaction post requests."
+    ).unwrap();
+    static ref POST_RAW_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_transaction_api_post_raw_responses_total",
+        "Raw transaction post responses."
+    ).unwrap();
+    static ref GET_STATUS_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_transaction_api_get_status_requests_total",
+        "Transaction status requests."
+    ).unwrap();
+    static ref GET_STATUS_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_transaction_api_get_status_responses_total",
+        "Transaction status responses."
+    ).unwrap();
+}
+
+impl Api for TransactionV2Api {
+    fn wire(&self, router: &mut Router) {
+        // Submits a signed transaction given as JSON. The transaction is only
+        // accepted for processing, its outcome is told by the status route.
+        let self_ = self.clone();
+        let transaction = move |req: &mut Request| -> IronResult<Response> {
+            POST_REQUESTS.inc();
+            let res = respond(self_.post(req), status::Accepted);
+            POST_RESPONSES.inc();
+            Ok(res)
+        };
+
+        // Submits a signed transaction given as the hex of its raw message.
+        let self_ = self.clone();
+        let transaction_raw = move |req: &mut Request| -> IronResult<Response> {
+            POST_RAW_REQUESTS.inc();
+            let res = respond(self_.post_raw(req), status::Accepted);
+            POST_RAW_RESPONSES.inc();
+            Ok(res)
+        };
+
+        // State of a transaction. With `?wait=<seconds>` the request blocks
+        // until the transaction is committed or the time runs out.
+        let self_ = self.clone();
+        let get_status = move |req: &mut Request| -> IronResult<Response> {
+            GET_STATUS_REQUESTS.inc();
+            let res = respond(self_.status(req), status::Ok);
+            GET_STATUS_RESPONSES.inc();
+            Ok(res)
+        };
+
+        router.post("/v2/transactions", transaction, "v2_transaction");
+        router.post(
+            "/v2/transactions/raw",
+            transaction_raw,
+            "v2_transaction_raw",
+        );
+        router.get(
+            "/v2/transactions/:hash",
+            get_status,
+            "v2_get_transaction_status",
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::cmp;
+
+use exonum::api::Api;
+use exonum::blockchain::Blockchain;
+use exonum::crypto::PublicKey;
+use exonum::encoding::serialize::FromHex;
+use iron::prelude::*;
+use iron::status;
+use prometheus::IntCounter;
+use router::Router;
+
+use currency::api::error::ApiError;
+use currency::api::v2::{read_decimals, respond, V2Result};
+use currency::api::wallet::{WalletApi, WalletEntry, WalletsOrder, PARAMETER_CURSOR_KEY,
+                            PARAMETER_MIN_BALANCE_KEY, PARAMETER_SORT_KEY};
+use currency::api::{ServiceApi, PARAMETER_LIMIT_KEY};
+use currency::assets::AssetId;
+use decimal;
+
+const WALLETS_LIMIT_DEFAULT: u64 = 1000;
+
+#[derive(Clone)]
+pub struct WalletV2Api {
+    pub blockchain: Blockchain,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct WalletBody {
+    pub balance: String,
+    pub assets_count: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct WalletEntryBody {
+    pub pub_key: PublicKey,
+    pub balance: String,
+    pub assets_count: u64,
+}
+
+impl WalletEntryBody {
+    fn from(entry: WalletEntry, decimals: u32) -> Self {
+        WalletEntryBody {
+            pub_key: entry.pub_key,
+            balance: decimal::format_units(entry.balance, decimals),
+            assets_count: entry.assets_count,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct WalletsBody {
+    pub count: u64,
+    /// Cursor of the next page, `None` on the last page.
+    pub next: Option<String>,
+    pub wallets: Vec<WalletEntryBody>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AssetAmountBody {
+    pub id: AssetId,
+    pub amount: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct WalletAssetsBody {
+    pub total: u64,
+    pub count: u64,
+    pub assets: Vec<AssetAmountBody>,
+}
+
+fn pub_key(req: &Request) -> V2Result<PublicKey> {
+    let wallet_key = req.extensions
+        .get::<Router>()
+        .unwrap()
+        .find("pub_key")
+        .unwrap();
+    PublicKey::from_hex(wallet_key).map_err(|_| ApiError::WalletHexInvalid.into())
+}
+
+impl WalletV2Api {
+    fn v1(&self) -> WalletApi {
+        WalletApi {
+            blockchain: self.blockchain.clone(),
+        }
+    }
+
+    fn wallet(&self, req: &mut Request) -> V2Result<WalletBody> {
+        let decimals = read_decimals(req)?;
+        let wallet = self.v1().wallet(&pub_key(req)?);
+        Ok(WalletBody {
+            balance: decimal::format_units(wallet.balance(), decimals),
+            assets_count: wallet.assets().len() as u64,
+        })
+    }
+
+    fn wallets(&self, req: &mut Request) -> V2Result<WalletsBody> {
+        let decimals = read_decimals(req)?;
+        let limit = ServiceApi::read_parameter(req, PARAMETER_LIMIT_KEY, WALLETS_LIMIT_DEFAULT);
+        let limit = cmp::max(limit, 1);
+        let cursor = ServiceApi::read_parameter(req, PARAMETER_CURSOR_KEY, String::new());
+        let sort = ServiceApi::read_parameter(req, PARAMETER_SORT_KEY, "key".to_string());
+        let min_balance =
+            ServiceApi::read_parameter(req, PARAMETER_MIN_BALANCE_KEY, "0".to_string());
+
+        let order = match sort.as_str() {
+            "key" => WalletsOrder::Key,
+            "balance" => WalletsOrder::Balance,
+            _ => return Err(ApiError::IncorrectRequest.into()),
+        };
+        let min_balance =
+            decimal::parse_units(&min_balance, decimals).ok_or(ApiError::IncorrectRequest)?;
+        let cursor = if cursor.is_empty() {
+            None
+        } else {
+            Some(cursor.as_str())
+        };
+
+        let (wallets, next) = self.v1().wallets(order, cursor, min_balance, limit)?;
+        Ok(WalletsBody {
+            count: wallets.len() as u64,
+            next,
+            wallets: wallets
+                .into_iter()
+                .map(|entry| WalletEntryBody::from(entry, decimals))
+                .collect(),
+        })
+    }
+
+    fn assets(&self, req: &mut Request) -> V2Result<WalletAssetsBody> {
+        let decimals = read_decimals(req)?;
+        let assets = self.v1().wallet(&pub_key(req)?).assets();
+        let page = ServiceApi::apply_pagination(req, &assets);
+        Ok(WalletAssetsBody {
+            total: assets.len() as u64,
+            count: page.len() as u64,
+            assets: page
+                .iter()
+                .map(|asset| AssetAmountBody {
+                    id: asset.id(),
+                    amount: decimal::format_units(asset.amount(), decimals),
+                })
+                .collect(),
+        })
+    }
+}
+
+lazy_static! {
+    static ref LIST_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_wallet_api_list_requests_total",
+        "Wallet list requests."
+    ).unwrap();
+    static ref LIST_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_wallet_api_list_responses_total",
+        "Wallet list responses."
+    ).unwrap();
+    static ref BALANCE_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_wallet_api_balance_requests_total",
+        "Balance requests."
+    ).unwrap();
+    static ref BALANCE_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_wallet_api_balance_responses_total",
+        "Balance responses."
+    ).unwrap();
+    static ref ASSETS_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_v2_wallet_api_assets_requests_total",
+        "Wallet asset list requests."
+    ).unwrap();
+    static ref ASSETS_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_v2_wallet_api_assets_responses_total",
+        "Wallet asset list responses."
+    ).unwrap();
+}
+
+impl Api for WalletV2Api {
+    fn wire(&self, router: &mut Router) {
+        // Gets the balance of the wallet and the number of its assets.
+        let self_ = self.clone();
+        let wallet_info = move |req: &mut Request| -> IronResult<Response> {
+            BALANCE_REQUESTS.inc();
+            let res = respond(self_.wallet(req), status::Ok);
+            BALANCE_RESPONSES.inc();
+            Ok(res)
+        };
+
+        // Gets a page of wallets, see `/v1/wallets` for the ordering. The
+        // `min_balance` is a decimal with at most `decimals` places.
+        let self_ = self.clone();
+        let wallets_info = move |req: &mut Request| -> IronResult<Response> {
+            LIST_REQUESTS.inc();
+            let res = respond(self_.wallets(req), status::Ok);
+            LIST_RESPONSES.inc();
+            Ok(res)
+        };
+
+        // Gets the assets of the wallet, paginated with `offset` and `limit`.
+        let self_ = self.clone();
+        let wallet_assets_info = move |req: &mut Request| -> IronResult<Response> {
+            ASSETS_REQUESTS.inc();
+            let res = respond(self_.assets(req), status::Ok);
+            ASSETS_RESPONSES.inc();
+            Ok(res)
+        };
+
+        router.get("/v2/wallets", wallets_info, "v2_wallets_info");
+        router.get("/v2/wallets/:pub_key", wallet_info, "v2_get_balance");
+        router.get(
+            "/v2/wallets/:pub_key/assets",
+            wallet_assets_info,
+            "v2_assets_info",
+        );
+    }
+}
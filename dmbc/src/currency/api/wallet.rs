@@ -1,11 +1,13 @@
 extern crate serde_json;
 
-use std::collections::HashMap;
+use std::cmp;
 
 use exonum::api::Api;
+use exonum::blockchain;
 use exonum::blockchain::Blockchain;
-use exonum::crypto::PublicKey;
-use exonum::encoding::serialize::FromHex;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::encoding::serialize::{encode_hex, FromHex};
+use exonum::storage::Fork;
 use hyper::header::ContentType;
 use iron::headers::AccessControlAllowOrigin;
 use iron::prelude::*;
@@ -14,13 +16,22 @@ use prometheus::IntCounter;
 use router::Router;
 
 use currency::api::error::ApiError;
-use currency::api::ServiceApi;
+use currency::allowance;
+use currency::allowance::Allowance;
+use currency::api::{ServiceApi, PARAMETER_LIMIT_KEY};
 use currency::assets;
 use currency::assets::{AssetBundle, AssetId, AssetInfo};
+use currency::vesting;
+use currency::vesting::Vesting;
 use currency::wallet;
 use currency::wallet::Wallet;
 
 pub const PARAMETER_META_DATA_KEY: &str = "meta_data";
+pub const PARAMETER_CURSOR_KEY: &str = "cursor";
+pub const PARAMETER_SORT_KEY: &str = "sort";
+pub const PARAMETER_MIN_BALANCE_KEY: &str = "min_balance";
+
+const WALLETS_LIMIT_DEFAULT: u64 = 1000;
 
 #[derive(Clone)]
 pub struct WalletApi {
@@ -41,6 +52,33 @@ impl WalletInfo {
     }
 }
 
+/// Order of the wallet list.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum WalletsOrder {
+    /// Ascending by public key, the cursor is a public key.
+    Key,
+    /// Descending by balance, the cursor is a key of the balance index.
+    Balance,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct WalletEntry {
+    pub pub_key: PublicKey,
+    pub balance: u64,
+    pub assets_count: u64,
+}
+
+impl WalletEntry {
+    pub fn from(pub_key: PublicKey, wallet: Wallet) -> Self {
+        WalletEntry {
+            pub_key,
+            balance: wallet.balance(),
+            assets_count: wallet.assets().len() as u64,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ExtendedAsset {
     pub id: AssetId,
@@ -62,11 +100,75 @@ impl ExtendedAsset {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AllowanceInfo {
+    pub spender: PublicKey,
+    pub amount: u64,
+    pub assets: Vec<AssetBundle>,
+    pub expires_at: u64,
+}
+
+impl AllowanceInfo {
+    pub fn from(allowance: Allowance) -> Self {
+        AllowanceInfo {
+            spender: *allowance.spender(),
+            amount: allowance.amount(),
+            assets: allowance.assets(),
+            expires_at: allowance.expires_at(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct VestedValue {
+    pub amount: u64,
+    pub assets: Vec<AssetBundle>,
+}
+
+impl VestedValue {
+    pub fn from(value: Wallet) -> Self {
+        VestedValue {
+            amount: value.balance(),
+            assets: value.assets(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct VestingInfo {
+    pub id: Hash,
+    pub creator: PublicKey,
+    pub start: u64,
+    pub cliff: u64,
+    pub end: u64,
+    pub curve: u8,
+    pub locked: VestedValue,
+    pub claimable: VestedValue,
+    pub claimed: VestedValue,
+}
+
+impl VestingInfo {
+    pub fn from(id: Hash, vesting: Vesting, height: u64) -> Self {
+        VestingInfo {
+            id,
+            creator: *vesting.creator(),
+            start: vesting.start(),
+            cliff: vesting.cliff(),
+            end: vesting.end(),
+            curve: vesting.curve(),
+            locked: VestedValue::from(vesting.locked(height)),
+            claimable: VestedValue::from(vesting.claimable(height)),
+            claimed: VestedValue::from(vesting.claimed()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct WalletsResponseBody {
-    pub total: u64,
     pub count: u64,
-    pub wallets: HashMap<PublicKey, WalletInfo>,
+    /// Cursor of the next page, `None` on the last page.
+    pub next: Option<String>,
+    pub wallets: Vec<WalletEntry>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
@@ -84,46 +186,104 @@ pub type WalletAssetsResponse = Result<WalletAssetsResponseBody, ApiError>;
 
 pub type WalletAssetResponse = Result<ExtendedAsset, ApiError>;
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct WalletAllowancesResponseBody {
+    pub total: u64,
+    pub count: u64,
+    pub allowances: Vec<AllowanceInfo>,
+}
+
+pub type WalletAllowancesResponse = Result<WalletAllowancesResponseBody, ApiError>;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct WalletVestingResponseBody {
+    pub total: u64,
+    pub count: u64,
+    pub vesting: Vec<VestingInfo>,
+}
+
+pub type WalletVestingResponse = Result<WalletVestingResponseBody, ApiError>;
+
+/// Wallets with at least `min_balance` coins for the keys of the balance
+/// index, see `wallet::balance_key`.
+fn balance_page<I>(view: &Fork, keys: I, min_balance: u64, take: usize) -> Vec<WalletEntry>
+where
+    I: Iterator<Item = Vec<u8>>,
+{
+    keys.filter_map(|key| wallet::parse_balance_key(&key))
+        .take_while(|&(_, balance)| balance >= min_balance)
+        .take(take)
+        .map(|(key, _)| WalletEntry::from(key, wallet::Schema(view).fetch(&key)))
+        .collect()
+}
+
 impl WalletApi {
-    fn wallet(&self, pub_key: &PublicKey) -> Wallet {
+    pub fn wallet(&self, pub_key: &PublicKey) -> Wallet {
         let view = &mut self.blockchain.fork();
         wallet::Schema(view).fetch(pub_key)
     }
 
-    fn wallets(&self) -> HashMap<PublicKey, WalletInfo> {
-        let view = &mut self.blockchain.fork();
-        let index = wallet::Schema(view).index();
-        let mut result: HashMap<PublicKey, WalletInfo> = HashMap::new();
-        for v in index.iter() {
-            let wi = WalletInfo::from(v.1);
-            result.insert(v.0, wi);
-        }
-
-        result
-    }
-
-    fn pagination_wallets(
+    /// A page of wallets with at least `min_balance` coins, starting at the
+    /// `cursor` and followed by the cursor of the next page.
+    pub fn wallets(
         &self,
-        offset: u64,
+        order: WalletsOrder,
+        cursor: Option<&str>,
+        min_balance: u64,
         limit: u64,
-    ) -> (HashMap<PublicKey, WalletInfo>, u64, u64) {
-        let view = &mut self.blockchain.fork();
-        let idx = wallet::Schema(view).index();
-        let mut total: u64 = 0;
-        let mut count: u64 = 0;
-        let mut result: HashMap<PublicKey, WalletInfo> = HashMap::new();
-        for v in idx.iter() {
-            if total < offset || total >= offset + limit {
-                total += 1;
-                continue;
+    ) -> Result<(Vec<WalletEntry>, Option<String>), ApiError> {
+        let view = self.blockchain.fork();
+        // Read one wallet past the page to learn where the next one starts.
+        let take = limit as usize + 1;
+
+        let mut wallets: Vec<WalletEntry> = match order {
+            WalletsOrder::Key => {
+                let from = match cursor {
+                    Some(hex) => {
+                        Some(PublicKey::from_hex(hex).map_err(|_| ApiError::WalletCursorInvalid)?)
+                    }
+                    None => None,
+                };
+                let index = wallet::Schema(&view).index();
+                let iter = match from {
+                    Some(ref key) => index.iter_from(key),
+                    None => index.iter(),
+                };
+                iter.filter(|&(_, ref wallet)| wallet.balance() >= min_balance)
+                    .take(take)
+                    .map(|(key, wallet)| WalletEntry::from(key, wallet))
+                    .collect()
             }
-            let wi = WalletInfo::from(v.1);
-            result.insert(v.0, wi);
-            count += 1;
-            total += 1;
-        }
+            WalletsOrder::Balance => {
+                let from = match cursor {
+                    Some(hex) => Some(
+                        Vec::<u8>::from_hex(hex)
+                            .ok()
+                            .filter(|key| wallet::parse_balance_key(key).is_some())
+                            .ok_or(ApiError::WalletCursorInvalid)?,
+                    ),
+                    None => None,
+                };
+                let index = wallet::Schema(&view).balances_index();
+                match from {
+                    Some(ref key) => balance_page(&view, index.iter_from(key), min_balance, take),
+                    None => balance_page(&view, index.iter(), min_balance, take),
+                }
+            }
+        };
 
-        (result, total, count)
+        let next = if wallets.len() > limit as usize {
+            wallets.pop().map(|entry| match order {
+                WalletsOrder::Key => entry.pub_key.to_hex(),
+                WalletsOrder::Balance => {
+                    encode_hex(wallet::balance_key(&entry.pub_key, entry.balance))
+                }
+            })
+        } else {
+            None
+        };
+
+        Ok((wallets, next))
     }
 
     fn wallets_balance(&self) -> Vec<PublicKey> {
@@ -141,6 +301,27 @@ impl WalletApi {
         let view = self.blockchain.fork();
         assets::Schema(view).fetch(asset_id)
     }
+
+    fn allowances(&self, pub_key: &PublicKey) -> Vec<AllowanceInfo> {
+        let view = self.blockchain.fork();
+        allowance::Schema(view)
+            .fetch_wallet_allowances(pub_key)
+            .into_iter()
+            .map(AllowanceInfo::from)
+            .collect()
+    }
+
+    /// Vesting schedules of the beneficiary, with amounts as they would be
+    /// claimed in the next block.
+    fn vesting(&self, pub_key: &PublicKey) -> Vec<VestingInfo> {
+        let view = self.blockchain.fork();
+        let height = blockchain::Schema::new(&view).height().next();
+        vesting::Schema(&view)
+            .fetch_wallet_vestings(pub_key)
+            .into_iter()
+            .map(|(id, vesting)| VestingInfo::from(id, vesting, height.0))
+            .collect()
+    }
 }
 
 lazy_static! {
@@ -176,6 +357,22 @@ lazy_static! {
         "dmbc_wallet_api_asset_responses_total",
         "Wallet asset counter responses."
     ).unwrap();
+    static ref ALLOWANCES_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_wallet_api_allowances_requests_total",
+        "Wallet allowance list requests."
+    ).unwrap();
+    static ref ALLOWANCES_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_wallet_api_allowances_responses_total",
+        "Wallet allowance list responses."
+    ).unwrap();
+    static ref VESTING_REQUESTS: IntCounter = register_int_counter!(
+        "dmbc_wallet_api_vesting_requests_total",
+        "Wallet vesting schedule list requests."
+    ).unwrap();
+    static ref VESTING_RESPONSES: IntCounter = register_int_counter!(
+        "dmbc_wallet_api_vesting_responses_total",
+        "Wallet vesting schedule list responses."
+    ).unwrap();
 }
 
 impl Api for WalletApi {
@@ -211,22 +408,49 @@ impl Api for WalletApi {
             Ok(res)
         };
 
-        // Gets status of all wallets.
+        // Gets status of all wallets, a page at a time.
+        //
+        // Pages are ordered by public key, or by balance from the largest
+        // down with `sort=balance`, and continue from the `next` cursor of
+        // the previous page. `min_balance` leaves out poorer wallets.
         let self_ = self.clone();
         let wallets_info = move |req: &mut Request| -> IronResult<Response> {
             LIST_REQUESTS.inc();
 
-            let (offset, limit) = ServiceApi::pagination_params(req);
-            let (wallets, total, count) = self_.pagination_wallets(offset, limit);
+            let limit = ServiceApi::read_parameter(req, PARAMETER_LIMIT_KEY, WALLETS_LIMIT_DEFAULT);
+            let limit = cmp::max(limit, 1);
+            let cursor = ServiceApi::read_parameter(req, PARAMETER_CURSOR_KEY, String::new());
+            let sort = ServiceApi::read_parameter(req, PARAMETER_SORT_KEY, "key".to_string());
+            let min_balance = ServiceApi::read_parameter(req, PARAMETER_MIN_BALANCE_KEY, 0u64);
 
-            let result: WalletsResponse = Ok(WalletsResponseBody {
-                total,
-                count,
-                wallets,
+            let order = match sort.as_str() {
+                "key" => Ok(WalletsOrder::Key),
+                "balance" => Ok(WalletsOrder::Balance),
+                _ => Err(ApiError::IncorrectRequest),
+            };
+
+            let result: WalletsResponse = order.and_then(|order| {
+                let cursor = if cursor.is_empty() {
+                    None
+                } else {
+                    Some(cursor.as_str())
+                };
+                let (wallets, next) = self_.wallets(order, cursor, min_balance, limit)?;
+                Ok(WalletsResponseBody {
+                    count: wallets.len() as u64,
+                    next,
+                    wallets,
+                })
             });
 
-            let mut res =
-                Response::with((status::Ok, serde_json::to_string_pretty(&result).unwrap()));
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
             res.headers.set(ContentType::json());
             res.headers.set(AccessControlAllowOrigin::Any);
 
@@ -349,6 +573,92 @@ impl Api for WalletApi {
             Ok(res)
         };
 
+        // Gets allowances granted by the wallet to other keys.
+        let self_ = self.clone();
+        let wallet_allowances_info = move |req: &mut Request| -> IronResult<Response> {
+            ALLOWANCES_REQUESTS.inc();
+
+            let public_key_result = {
+                let wallet_key = req.extensions
+                    .get::<Router>()
+                    .unwrap()
+                    .find("pub_key")
+                    .unwrap();
+                PublicKey::from_hex(wallet_key)
+            };
+            let result: WalletAllowancesResponse = match public_key_result {
+                Ok(public_key) => {
+                    let allowances = self_.allowances(&public_key);
+                    // apply pagination parameters if they exist
+                    let allowances_to_send = ServiceApi::apply_pagination(req, &allowances);
+                    Ok(WalletAllowancesResponseBody {
+                        total: allowances.len() as u64,
+                        count: allowances_to_send.len() as u64,
+                        allowances: allowances_to_send.to_vec(),
+                    })
+                }
+                Err(_) => Err(ApiError::WalletHexInvalid),
+            };
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            ALLOWANCES_RESPONSES.inc();
+
+            Ok(res)
+        };
+
+        // Gets vesting schedules of the wallet with locked, claimable and claimed value.
+        let self_ = self.clone();
+        let wallet_vesting_info = move |req: &mut Request| -> IronResult<Response> {
+            VESTING_REQUESTS.inc();
+
+            let public_key_result = {
+                let wallet_key = req.extensions
+                    .get::<Router>()
+                    .unwrap()
+                    .find("pub_key")
+                    .unwrap();
+                PublicKey::from_hex(wallet_key)
+            };
+            let result: WalletVestingResponse = match public_key_result {
+                Ok(public_key) => {
+                    let vesting = self_.vesting(&public_key);
+                    // apply pagination parameters if they exist
+                    let vesting_to_send = ServiceApi::apply_pagination(req, &vesting);
+                    Ok(WalletVestingResponseBody {
+                        total: vesting.len() as u64,
+                        count: vesting_to_send.len() as u64,
+                        vesting: vesting_to_send.to_vec(),
+                    })
+                }
+                Err(_) => Err(ApiError::WalletHexInvalid),
+            };
+
+            let mut res = Response::with((
+                result
+                    .clone()
+                    .err()
+                    .map(|e| e.to_status())
+                    .unwrap_or(status::Ok),
+                serde_json::to_string_pretty(&result).unwrap(),
+            ));
+            res.headers.set(ContentType::json());
+            res.headers.set(AccessControlAllowOrigin::Any);
+
+            VESTING_RESPONSES.inc();
+
+            Ok(res)
+        };
+
         router.get("/v1/wallets", wallets_info, "wallets_info");
         router.get("/v1/wallets/:pub_key", wallet_info, "get_balance");
         router.get(
@@ -361,5 +671,15 @@ impl Api for WalletApi {
             wallet_asset_info,
             "asset_info"
         );
+        router.get(
+            "/v1/wallets/:pub_key/allowances",
+            wallet_allowances_info,
+            "allowances_info",
+        );
+        router.get(
+            "/v1/wallets/:pub_key/vesting",
+            wallet_vesting_info,
+            "vesting_info",
+        );
     }
 }
@@ -0,0 +1,15 @@
+use exonum::crypto::{Hash, PublicKey};
+
+use currency::assets::Fees;
+
+encoding_struct! {
+    /// Record of an administrative change made to an `AssetInfo`.
+    struct AssetChange {
+        tx_hash:     &Hash,
+        height:      u64,
+        old_creator: &PublicKey,
+        new_creator: &PublicKey,
+        old_fees:    Fees,
+        new_fees:    Fees,
+    }
+}
@@ -17,6 +17,9 @@ use uuid::Uuid;
 
 pub const ASSET_ID_LEN: usize = 16;
 
+/// Prefix of the hashed names of token series.
+const SERIES_PREFIX: &str = "series:";
+
 /// An identifier for an asset.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct AssetId(pub [u8; ASSET_ID_LEN]);
@@ -55,6 +58,18 @@ impl AssetId {
         AssetId::from_slice(uuid.as_bytes()).unwrap()
     }
 
+    /// Create an `AssetId` of a series of non-fungible tokens.
+    ///
+    /// The name is hashed after a prefix that the hex of a public key can't
+    /// start with, so series never get the ID of an asset made by
+    /// `from_data`.
+    pub fn from_series(name: &str, pub_key: &PublicKey) -> AssetId {
+        let ful_s = format!("{}{}{}", SERIES_PREFIX, pub_key.to_hex(), name);
+
+        let uuid = Uuid::new_v5(&uuid::NAMESPACE_DNS, &ful_s);
+        AssetId::from_slice(uuid.as_bytes()).unwrap()
+    }
+
     /// Create an `AssetId` from a slice of bytes.
     pub fn from_slice(b: &[u8]) -> Result<AssetId, ParseError> {
         let len = b.len();
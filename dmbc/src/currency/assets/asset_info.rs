@@ -57,4 +57,26 @@ impl AssetInfo {
             self.data(),
         ))
     }
+
+    /// Replaces the third party fees of the asset.
+    pub fn update_fees(self, fees: Fees) -> Self {
+        AssetInfo::new(
+            self.creator(),
+            self.origin(),
+            self.amount(),
+            fees,
+            self.data(),
+        )
+    }
+
+    /// Hands the creator role of the asset over to another key.
+    pub fn transfer_creator(self, creator: &PublicKey) -> Self {
+        AssetInfo::new(
+            creator,
+            self.origin(),
+            self.amount(),
+            self.fees(),
+            self.data(),
+        )
+    }
 }
@@ -0,0 +1,61 @@
+use exonum::crypto;
+use exonum::crypto::{Hash, PublicKey};
+
+use currency::assets::{AssetId, Fees};
+
+pub const COLLECTION_DATA_MAX_LENGTH: usize = 10 * 1024;
+
+encoding_struct! {
+    /// Named group of assets registered by a creator.
+    ///
+    /// Collections can be nested, e.g. a game, then a season, then a case.
+    /// Top level collections have a zero `parent`.
+    struct Collection {
+        creator: &PublicKey,
+        parent:  &Hash,
+        name:    &str,
+        data:    &str,
+        fees:    Fees,
+    }
+}
+
+impl Collection {
+    /// Create the ID of a collection from its name and creator.
+    pub fn id_from(name: &str, creator: &PublicKey) -> Hash {
+        let mut bytes = creator.as_ref().to_vec();
+        bytes.extend_from_slice(name.as_bytes());
+        crypto::hash(&bytes)
+    }
+
+    /// ID of the collection.
+    pub fn id(&self) -> Hash {
+        Collection::id_from(self.name(), self.creator())
+    }
+
+    /// Verify validity of the collection metadata.
+    pub fn verify(&self) -> bool {
+        self.data().len() <= COLLECTION_DATA_MAX_LENGTH
+    }
+}
+
+encoding_struct! {
+    /// Places an asset into a collection with the `add_to_collection`
+    /// transaction.
+    ///
+    /// Unless `own_fees` is set, the fees of the asset are replaced with the
+    /// default fees of the collection.
+    struct CollectionMember {
+        id:         AssetId,
+        collection: &Hash,
+        own_fees:   bool,
+    }
+}
+
+/// Amount of assets held in a collection and its nested collections.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CollectionSupply {
+    /// Number of distinct assets.
+    pub assets: u64,
+    /// Total amount of units of all assets.
+    pub amount: u64,
+}
@@ -1,6 +1,6 @@
 use exonum::crypto::{Hash, PublicKey};
 
-use currency::assets::{AssetBundle, AssetId, AssetInfo, Fees};
+use currency::assets::{AssetBundle, AssetId, AssetInfo, Fees, MetadataRef};
 
 pub const ASSET_DATA_MAX_LENGTH: usize = 10 * 1024;
 
@@ -15,6 +15,16 @@ encoding_struct! {
 }
 
 impl MetaAsset {
+    /// Create a `MetaAsset` whose metadata is kept off-chain.
+    pub fn from_metadata(
+        receiver: &PublicKey,
+        metadata: &MetadataRef,
+        amount: u64,
+        fees: Fees,
+    ) -> MetaAsset {
+        MetaAsset::new(receiver, &metadata.asset_data(), amount, fees)
+    }
+
     /// Verify valididty of the committed assets.
     pub fn verify(&self) -> bool {
         self.data().len() <= ASSET_DATA_MAX_LENGTH
@@ -0,0 +1,30 @@
+use exonum::crypto::Hash;
+
+pub const MEDIA_TYPE_MAX_LENGTH: usize = 255;
+pub const URI_MAX_LENGTH: usize = 2048;
+
+encoding_struct! {
+    /// Commitment to asset metadata kept off-chain.
+    ///
+    /// Instead of the metadata itself the asset stores the hex encoded
+    /// `content_hash` as its data, so the `AssetId` is derived from the
+    /// hash. The content can be fetched from `uri` or from the metadata
+    /// store of a node and checked against the hash.
+    struct MetadataRef {
+        content_hash: &Hash,
+        media_type:   &str,
+        uri:          &str,
+    }
+}
+
+impl MetadataRef {
+    /// Verify validity of the metadata reference.
+    pub fn verify(&self) -> bool {
+        self.media_type().len() <= MEDIA_TYPE_MAX_LENGTH && self.uri().len() <= URI_MAX_LENGTH
+    }
+
+    /// Data of the asset committing to the metadata.
+    pub fn asset_data(&self) -> String {
+        self.content_hash().to_hex()
+    }
+}
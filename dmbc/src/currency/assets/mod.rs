@@ -1,17 +1,25 @@
 //! Asset representations.
 
 mod asset_bundle;
+mod asset_change;
 mod asset_id;
 mod asset_info;
+mod collection;
 mod fees;
 mod meta_asset;
+mod metadata;
 mod schema;
+mod supply;
 mod trade_asset;
 
 pub use currency::assets::asset_bundle::AssetBundle;
+pub use currency::assets::asset_change::AssetChange;
 pub use currency::assets::asset_id::AssetId;
 pub use currency::assets::asset_info::AssetInfo;
+pub use currency::assets::collection::{Collection, CollectionMember, CollectionSupply};
 pub use currency::assets::fees::{Fee, Fees};
 pub use currency::assets::meta_asset::MetaAsset;
+pub use currency::assets::metadata::MetadataRef;
 pub use currency::assets::schema::Schema;
+pub use currency::assets::supply::{Supply, SupplyPolicy};
 pub use currency::assets::trade_asset::TradeAsset;
@@ -1,6 +1,7 @@
-use exonum::storage::{Fork, MapIndex, Snapshot};
+use exonum::crypto::{Hash, PublicKey};
+use exonum::storage::{Fork, ListIndex, MapIndex, Snapshot};
 
-use currency::assets::{AssetId, AssetInfo};
+use currency::assets::{AssetChange, AssetId, AssetInfo, Collection, MetadataRef, Supply};
 use currency::SERVICE_NAME;
 
 /// Schema for accessing global asset information.
@@ -22,6 +23,98 @@ where
     pub fn fetch(self, id: &AssetId) -> Option<AssetInfo> {
         self.index().get(id)
     }
+
+    /// Internal `MapIndex` from the ID an asset would have if its current
+    /// creator had created it to its actual ID, for assets whose creator
+    /// role was handed over.
+    pub fn handovers_index(self) -> MapIndex<S, AssetId, AssetId> {
+        let key = SERVICE_NAME.to_string() + ".asset_handovers";
+        MapIndex::new(key, self.0)
+    }
+
+    /// ID of the asset with the given data whose creator role `creator` holds.
+    ///
+    /// This is the ID derived from the data and the creator, unless the
+    /// role of an asset with the same data was handed over to `creator`.
+    pub fn resolve_id(self, data: &str, creator: &PublicKey) -> AssetId {
+        let id = AssetId::from_data(data, creator);
+        self.handovers_index().get(&id).unwrap_or(id)
+    }
+
+    /// Internal `ListIndex` with the change history of an asset.
+    pub fn history_index(self, id: &AssetId) -> ListIndex<S, AssetChange> {
+        let key = SERVICE_NAME.to_string() + ".asset_history";
+        ListIndex::with_prefix(key, id.0.to_vec(), self.0)
+    }
+
+    /// Fetch the change history of an asset, oldest change first.
+    pub fn fetch_history(self, id: &AssetId) -> Vec<AssetChange> {
+        let index = self.history_index(id);
+        let history = index.iter().collect();
+        history
+    }
+
+    /// Internal `MapIndex` with the supply limits of assets.
+    pub fn supply_index(self) -> MapIndex<S, AssetId, Supply> {
+        let key = SERVICE_NAME.to_string() + ".asset_supply";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Fetch the supply limits of an asset, if it has any.
+    pub fn fetch_supply(self, id: &AssetId) -> Option<Supply> {
+        self.supply_index().get(id)
+    }
+
+    /// Internal `MapIndex` with the registered collections.
+    pub fn collections_index(self) -> MapIndex<S, Hash, Collection> {
+        let key = SERVICE_NAME.to_string() + ".asset_collections";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Fetch a collection from the database.
+    pub fn fetch_collection(self, id: &Hash) -> Option<Collection> {
+        self.collections_index().get(id)
+    }
+
+    /// Fetch the IDs of the collections nested directly in a collection.
+    pub fn fetch_subcollections(self, id: &Hash) -> Vec<Hash> {
+        self.collections_index()
+            .values()
+            .filter(|collection| collection.parent() == id)
+            .map(|collection| collection.id())
+            .collect()
+    }
+
+    /// Internal `MapIndex` with the collection each asset belongs to.
+    pub fn members_index(self) -> MapIndex<S, AssetId, Hash> {
+        let key = SERVICE_NAME.to_string() + ".asset_collection_members";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Fetch the ID of the collection an asset belongs to, if any.
+    pub fn fetch_asset_collection(self, id: &AssetId) -> Option<Hash> {
+        self.members_index().get(id)
+    }
+
+    /// Fetch the IDs of the assets placed directly in a collection.
+    pub fn fetch_collection_assets(self, id: &Hash) -> Vec<AssetId> {
+        self.members_index()
+            .iter()
+            .filter(|&(_, ref collection)| collection == id)
+            .map(|(asset_id, _)| asset_id)
+            .collect()
+    }
+
+    /// Internal `MapIndex` with the off-chain metadata references of assets.
+    pub fn metadata_index(self) -> MapIndex<S, AssetId, MetadataRef> {
+        let key = SERVICE_NAME.to_string() + ".asset_metadata";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Fetch the off-chain metadata reference of an asset, if it has one.
+    pub fn fetch_metadata(self, id: &AssetId) -> Option<MetadataRef> {
+        self.metadata_index().get(id)
+    }
 }
 
 impl<'a> Schema<&'a mut Fork> {
@@ -32,15 +125,102 @@ impl<'a> Schema<&'a mut Fork> {
     }
 
     /// Store asset info in the database.
+    ///
+    /// The info is kept when all units are deleted, so that the creator
+    /// role of the asset can't be claimed by creating it anew.
     pub fn store(&mut self, id: &AssetId, asset: AssetInfo) {
-        match asset.amount() {
-            0 => self.remove(id),
-            _ => self.index_mut().put(&*id, asset),
-        };
+        self.index_mut().put(&*id, asset);
     }
 
     /// Remove asset info from the database.
     pub fn remove(&mut self, id: &AssetId) {
         self.index_mut().remove(id)
     }
+
+    /// Internal `MapIndex` of handed over assets, with mutable access.
+    pub fn handovers_index_mut(&mut self) -> MapIndex<&mut Fork, AssetId, AssetId> {
+        let key = SERVICE_NAME.to_string() + ".asset_handovers";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Let `resolve_id` find the asset under its new creator once the role
+    /// moves from `creator` to `new_creator`.
+    pub fn store_handover(
+        &mut self,
+        id: &AssetId,
+        data: &str,
+        creator: &PublicKey,
+        new_creator: &PublicKey,
+    ) {
+        self.handovers_index_mut()
+            .remove(&AssetId::from_data(data, creator));
+        let handover = AssetId::from_data(data, new_creator);
+        if handover != *id {
+            self.handovers_index_mut().put(&handover, *id);
+        }
+    }
+
+    /// Internal `ListIndex` with the change history of an asset, with
+    /// mutable access.
+    pub fn history_index_mut(&mut self, id: &AssetId) -> ListIndex<&mut Fork, AssetChange> {
+        let key = SERVICE_NAME.to_string() + ".asset_history";
+        ListIndex::with_prefix(key, id.0.to_vec(), &mut *self.0)
+    }
+
+    /// Append a change to the history of an asset.
+    pub fn store_change(&mut self, id: &AssetId, change: AssetChange) {
+        self.history_index_mut(id).push(change);
+    }
+
+    /// Internal `MapIndex` with the supply limits of assets, with mutable
+    /// access.
+    pub fn supply_index_mut(&mut self) -> MapIndex<&mut Fork, AssetId, Supply> {
+        let key = SERVICE_NAME.to_string() + ".asset_supply";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Store the supply limits of an asset.
+    ///
+    /// Unlike asset info, the supply is kept when all units are deleted.
+    pub fn store_supply(&mut self, id: &AssetId, supply: Supply) {
+        self.supply_index_mut().put(id, supply);
+    }
+
+    /// Internal `MapIndex` with the registered collections, with mutable
+    /// access.
+    pub fn collections_index_mut(&mut self) -> MapIndex<&mut Fork, Hash, Collection> {
+        let key = SERVICE_NAME.to_string() + ".asset_collections";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Store a collection in the database.
+    pub fn store_collection(&mut self, collection: Collection) {
+        self.collections_index_mut().put(&collection.id(), collection);
+    }
+
+    /// Internal `MapIndex` with the collection each asset belongs to, with
+    /// mutable access.
+    pub fn members_index_mut(&mut self) -> MapIndex<&mut Fork, AssetId, Hash> {
+        let key = SERVICE_NAME.to_string() + ".asset_collection_members";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Place an asset into a collection.
+    ///
+    /// Like the supply, membership is kept when all units are deleted.
+    pub fn store_member(&mut self, id: &AssetId, collection: &Hash) {
+        self.members_index_mut().put(id, *collection);
+    }
+
+    /// Internal `MapIndex` with the off-chain metadata references of
+    /// assets, with mutable access.
+    pub fn metadata_index_mut(&mut self) -> MapIndex<&mut Fork, AssetId, MetadataRef> {
+        let key = SERVICE_NAME.to_string() + ".asset_metadata";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Store the off-chain metadata reference of an asset.
+    pub fn store_metadata(&mut self, id: &AssetId, metadata: MetadataRef) {
+        self.metadata_index_mut().put(id, metadata);
+    }
 }
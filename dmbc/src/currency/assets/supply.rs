@@ -0,0 +1,67 @@
+use currency::assets::AssetId;
+use currency::error::Error;
+
+encoding_struct! {
+    /// Supply limits set by the `set_asset_supply` transaction.
+    ///
+    /// A `max_supply` of zero leaves the amount uncapped.
+    struct SupplyPolicy {
+        id:         AssetId,
+        max_supply: u64,
+        sealed:     bool,
+    }
+}
+
+impl SupplyPolicy {
+    /// Verify that the policy actually limits the supply.
+    pub fn verify(&self) -> bool {
+        self.max_supply() > 0 || self.sealed()
+    }
+
+    /// Start tracking the supply of an asset with `amount` units minted.
+    ///
+    /// # Errors
+    /// Returns a `SupplyCapExceeded` error if `amount` is over the cap.
+    pub fn to_supply(&self, amount: u64) -> Result<Supply, Error> {
+        if self.max_supply() > 0 && amount > self.max_supply() {
+            return Err(Error::SupplyCapExceeded);
+        }
+
+        Ok(Supply::new(self.max_supply(), self.sealed(), amount))
+    }
+}
+
+encoding_struct! {
+    /// Supply limits of an asset along with the amount minted so far.
+    struct Supply {
+        max_supply: u64,
+        sealed:     bool,
+        minted:     u64,
+    }
+}
+
+impl Supply {
+    /// Account for `amount` newly minted units.
+    ///
+    /// Deleted units are not subtracted, so a capped asset can't be
+    /// burned and minted again.
+    ///
+    /// # Errors
+    /// Returns an `AssetSealed` error if the asset is sealed and a
+    /// `SupplyCapExceeded` error if the cap would be exceeded.
+    pub fn mint(self, amount: u64) -> Result<Self, Error> {
+        if self.sealed() {
+            return Err(Error::AssetSealed);
+        }
+
+        let minted = self.minted()
+            .checked_add(amount)
+            .ok_or(Error::SupplyCapExceeded)?;
+
+        if self.max_supply() > 0 && minted > self.max_supply() {
+            return Err(Error::SupplyCapExceeded);
+        }
+
+        Ok(Supply::new(self.max_supply(), self.sealed(), minted))
+    }
+}
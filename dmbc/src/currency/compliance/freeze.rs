@@ -0,0 +1,10 @@
+use exonum::crypto::PublicKey;
+
+encoding_struct! {
+    /// Record of a wallet freeze placed by a compliance officer.
+    struct Freeze {
+        officer: &PublicKey,
+        height:  u64,
+        reason:  &str,
+    }
+}
@@ -0,0 +1,80 @@
+//! Compliance controls: frozen wallets and asset holder allowlists.
+//!
+//! Wallets are frozen by the compliance officers listed in the currency
+//! `Configuration`. Asset creators may require the holders of an asset to
+//! be on an allowlist registry, which is maintained by the compliance
+//! officers as well. Transactions that move value check both rules before
+//! touching any wallet. Fees paid to the genesis wallet and to asset
+//! creators are not subject to these checks.
+
+mod freeze;
+mod schema;
+
+use exonum::crypto::PublicKey;
+use exonum::storage::Snapshot;
+
+use currency::assets::AssetBundle;
+use currency::error::Error;
+
+pub use currency::compliance::freeze::Freeze;
+pub use currency::compliance::schema::Schema;
+
+/// Check that none of the wallets is frozen.
+///
+/// # Errors
+///
+/// Returns `WalletFrozen` if any of the wallets is frozen.
+pub fn check_wallets<'a, S, I>(view: S, pub_keys: I) -> Result<(), Error>
+where
+    S: AsRef<Snapshot>,
+    I: IntoIterator<Item = &'a PublicKey>,
+{
+    let view = view.as_ref();
+    for pub_key in pub_keys {
+        if Schema(view).fetch_freeze(pub_key).is_some() {
+            return Err(Error::WalletFrozen);
+        }
+    }
+    Ok(())
+}
+
+/// Check that the holder may receive the assets.
+///
+/// # Errors
+///
+/// Returns `HolderNotAllowed` if any of the assets is restricted to an
+/// allowlist registry the holder is not on.
+pub fn check_holder<S>(view: S, holder: &PublicKey, assets: &[AssetBundle]) -> Result<(), Error>
+where
+    S: AsRef<Snapshot>,
+{
+    let view = view.as_ref();
+    for asset in assets {
+        if let Some(registry) = Schema(view).fetch_restriction(&asset.id()) {
+            if !Schema(view).is_allowed(&registry, holder) {
+                return Err(Error::HolderNotAllowed);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check that value, including the assets, may move between the wallets.
+///
+/// # Errors
+///
+/// Returns `WalletFrozen` if either wallet is frozen and `HolderNotAllowed`
+/// if the recipient may not hold some of the assets.
+pub fn check_transfer<S>(
+    view: S,
+    from: &PublicKey,
+    to: &PublicKey,
+    assets: &[AssetBundle],
+) -> Result<(), Error>
+where
+    S: AsRef<Snapshot>,
+{
+    let view = view.as_ref();
+    check_wallets(view, vec![from, to])?;
+    check_holder(view, to, assets)
+}
@@ -0,0 +1,109 @@
+use exonum::crypto;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::storage::{Fork, MapIndex, Snapshot};
+
+use currency::assets::AssetId;
+use currency::compliance::Freeze;
+use currency::SERVICE_NAME;
+
+/// Schema for accessing frozen wallets, asset holder restrictions and
+/// allowlist registries.
+pub struct Schema<S>(pub S)
+where
+    S: AsRef<Snapshot>;
+
+fn allowlist_key(registry: &Hash, holder: &PublicKey) -> Hash {
+    let mut bytes = registry.as_ref().to_vec();
+    bytes.extend_from_slice(holder.as_ref());
+    crypto::hash(&bytes)
+}
+
+impl<S> Schema<S>
+where
+    S: AsRef<Snapshot>,
+{
+    /// Internal `MapIndex` of frozen wallets.
+    pub fn frozen_wallets(self) -> MapIndex<S, PublicKey, Freeze> {
+        let key = SERVICE_NAME.to_string() + ".frozen_wallets";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Internal `MapIndex` of asset holder restrictions, mapping an asset
+    /// to the allowlist registry its holders must be on.
+    pub fn restrictions(self) -> MapIndex<S, AssetId, Hash> {
+        let key = SERVICE_NAME.to_string() + ".asset_restrictions";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Internal `MapIndex` of allowlist entries, keyed by the registry and
+    /// holder pair.
+    pub fn allowlists(self) -> MapIndex<S, Hash, PublicKey> {
+        let key = SERVICE_NAME.to_string() + ".allowlists";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Fetch the freeze placed on a wallet.
+    pub fn fetch_freeze(self, pub_key: &PublicKey) -> Option<Freeze> {
+        self.frozen_wallets().get(pub_key)
+    }
+
+    /// Fetch the allowlist registry required to hold an asset.
+    pub fn fetch_restriction(self, id: &AssetId) -> Option<Hash> {
+        self.restrictions().get(id)
+    }
+
+    /// Check if the holder is on the allowlist registry.
+    pub fn is_allowed(self, registry: &Hash, holder: &PublicKey) -> bool {
+        self.allowlists().contains(&allowlist_key(registry, holder))
+    }
+}
+
+impl<'a> Schema<&'a mut Fork> {
+    /// Internal `MapIndex` of frozen wallets, with mutable access.
+    pub fn frozen_wallets_mut(&mut self) -> MapIndex<&mut Fork, PublicKey, Freeze> {
+        let key = SERVICE_NAME.to_string() + ".frozen_wallets";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Internal `MapIndex` of asset holder restrictions, with mutable access.
+    pub fn restrictions_mut(&mut self) -> MapIndex<&mut Fork, AssetId, Hash> {
+        let key = SERVICE_NAME.to_string() + ".asset_restrictions";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Internal `MapIndex` of allowlist entries, with mutable access.
+    pub fn allowlists_mut(&mut self) -> MapIndex<&mut Fork, Hash, PublicKey> {
+        let key = SERVICE_NAME.to_string() + ".allowlists";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Freeze a wallet.
+    pub fn freeze(&mut self, pub_key: &PublicKey, freeze: Freeze) {
+        self.frozen_wallets_mut().put(pub_key, freeze);
+    }
+
+    /// Lift the freeze from a wallet.
+    pub fn unfreeze(&mut self, pub_key: &PublicKey) {
+        self.frozen_wallets_mut().remove(pub_key);
+    }
+
+    /// Restrict the holders of an asset to an allowlist registry. A zero
+    /// hash lifts the restriction.
+    pub fn store_restriction(&mut self, id: &AssetId, registry: &Hash) {
+        if *registry == Hash::zero() {
+            self.restrictions_mut().remove(id);
+        } else {
+            self.restrictions_mut().put(id, *registry);
+        }
+    }
+
+    /// Add the holder to the allowlist registry, or remove them from it.
+    pub fn store_allowed(&mut self, registry: &Hash, holder: &PublicKey, allowed: bool) {
+        let key = allowlist_key(registry, holder);
+        if allowed {
+            self.allowlists_mut().put(&key, *holder);
+        } else {
+            self.allowlists_mut().remove(&key);
+        }
+    }
+}
@@ -8,6 +8,7 @@ use exonum::encoding::serialize::FromHex;
 use exonum::storage::Snapshot;
 
 use currency;
+use currency::error::Error;
 
 encoding_struct! {
     /// Fixed fees to be paid to the genesis wallet when transaction is executed.
@@ -58,12 +59,24 @@ impl Default for TransactionFees {
     }
 }
 
-encoding_struct! {
-    /// Currency service configuration.
-    #[derive(Eq, PartialOrd, Ord)]
-    struct Configuration {
-        fees: TransactionFees,
-    }
+/// Transaction type switched off by governance.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DisabledTransaction {
+    /// Message ID of the transaction type.
+    pub message_id: u16,
+    /// Why the transaction type is disabled.
+    #[serde(default)]
+    pub reason: String,
+}
+
+/// Currency service configuration.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Configuration {
+    fees: TransactionFees,
+    #[serde(default)]
+    compliance_officers: Vec<PublicKey>,
+    #[serde(default)]
+    disabled_transactions: Vec<DisabledTransaction>,
 }
 
 /// Hexadecimal representation of the public key for genesis wallet.
@@ -77,6 +90,77 @@ impl Default for Configuration {
 }
 
 impl Configuration {
+    /// Create a configuration with the given fees, no compliance officers
+    /// and all transaction types enabled.
+    pub fn new(fees: TransactionFees) -> Self {
+        Configuration {
+            fees,
+            compliance_officers: Vec::new(),
+            disabled_transactions: Vec::new(),
+        }
+    }
+
+    /// Set the keys allowed to freeze wallets and manage holder allowlists.
+    pub fn with_compliance_officers(self, compliance_officers: Vec<PublicKey>) -> Self {
+        Configuration {
+            compliance_officers,
+            ..self
+        }
+    }
+
+    /// Disable the transaction type with the given message ID.
+    pub fn disable_transaction(mut self, message_id: u16, reason: &str) -> Self {
+        self.disabled_transactions
+            .retain(|disabled| disabled.message_id != message_id);
+        self.disabled_transactions.push(DisabledTransaction {
+            message_id,
+            reason: reason.to_string(),
+        });
+        self
+    }
+
+    /// Fixed transaction fees.
+    pub fn fees(&self) -> TransactionFees {
+        self.fees.clone()
+    }
+
+    /// Keys of the compliance officers.
+    pub fn compliance_officers(&self) -> &[PublicKey] {
+        &self.compliance_officers
+    }
+
+    /// Check if the key belongs to a compliance officer.
+    pub fn is_compliance_officer(&self, pub_key: &PublicKey) -> bool {
+        self.compliance_officers.contains(pub_key)
+    }
+
+    /// Transaction types that are currently disabled.
+    pub fn disabled_transactions(&self) -> &[DisabledTransaction] {
+        &self.disabled_transactions
+    }
+
+    /// Reason the transaction type is disabled, if it is.
+    pub fn disabled_reason(&self, message_id: u16) -> Option<&str> {
+        self.disabled_transactions
+            .iter()
+            .find(|disabled| disabled.message_id == message_id)
+            .map(|disabled| disabled.reason.as_str())
+    }
+
+    /// Check if the transaction type with the given message ID is enabled.
+    pub fn is_enabled(&self, message_id: u16) -> bool {
+        self.disabled_reason(message_id).is_none()
+    }
+
+    /// Fail with `TransactionDisabled` if the transaction type is disabled.
+    pub fn check_enabled(&self, message_id: u16) -> Result<(), Error> {
+        if self.is_enabled(message_id) {
+            Ok(())
+        } else {
+            Err(Error::TransactionDisabled)
+        }
+    }
+
     /// Extract the `Configuration`.
     ///
     /// # Panics
@@ -29,12 +29,150 @@ pub enum Error {
     /// Unable to verify transaction
     UnableToVerifyTransaction = 7,
 
+    /// Multisig wallet is not found in the network.
+    MultisigNotFound = 8,
+
+    /// Multisig wallet with the same key set and threshold already exists.
+    MultisigExists = 9,
+
+    /// Key is not a member of the multisig wallet.
+    NotMultisigMember = 10,
+
+    /// Multisig proposal is not found in the network.
+    ProposalNotFound = 11,
+
+    /// Multisig proposal has already been approved by the key.
+    AlreadyApproved = 12,
+
+    /// Not enough multisig members have signed the transaction.
+    InsufficientSignatures = 13,
+
+    /// Transaction has already been executed.
+    AlreadyExecuted = 14,
+
+    /// Spender has no allowance from the wallet.
+    AllowanceNotFound = 15,
+
+    /// Allowance has expired.
+    AllowanceExpired = 16,
+
+    /// Operation exceeds the coins or assets left in the allowance.
+    AllowanceExceeded = 17,
+
+    /// Vesting schedule is not found in the network.
+    VestingNotFound = 18,
+
+    /// Key is not the beneficiary of the vesting schedule.
+    NotVestingBeneficiary = 19,
+
+    /// Vesting schedule has nothing unlocked left to claim.
+    NothingToClaim = 20,
+
+    /// Loan is not found in the network.
+    LoanNotFound = 21,
+
+    /// Loan is not in a state that allows the operation.
+    LoanNotAvailable = 22,
+
+    /// Key is not the lender or the borrower required by the operation.
+    NotLoanParticipant = 23,
+
+    /// Loan deadline has not passed yet.
+    LoanNotOverdue = 24,
+
+    /// Key is not the creator of the asset.
+    NotAssetCreator = 25,
+
+    /// Key is not a compliance officer of the network.
+    NotComplianceOfficer = 26,
+
+    /// Wallet is frozen and can neither send nor receive value.
+    WalletFrozen = 27,
+
+    /// Wallet is not on the allowlist required to hold the asset.
+    HolderNotAllowed = 28,
+
+    /// Transaction type is disabled by the service configuration.
+    TransactionDisabled = 29,
+
+    /// Minting would take the asset over its maximum supply.
+    SupplyCapExceeded = 30,
+
+    /// Asset is sealed and can't be minted anymore.
+    AssetSealed = 31,
+
+    /// Token is not found in the network.
+    TokenNotFound = 32,
+
+    /// Token belongs to another wallet.
+    NotTokenOwner = 33,
+
+    /// Token with the same series and serial number already exists.
+    TokenExists = 34,
+
+    /// Collection is not found in the network.
+    CollectionNotFound = 35,
+
+    /// Collection with the same name and creator already exists.
+    CollectionExists = 36,
+
+    /// Operation is reserved to the creator of the collection.
+    NotCollectionCreator = 37,
+
+    /// Signed offer has already been executed.
+    OfferExecuted = 38,
+
     /// Requested operation is not implemented. Must not happen in production
     /// setting.
     NotImplemented = 255,
 }
 
 impl Error {
+    /// Stable code of the error for API clients.
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Error::AssetNotFound => "asset_not_found",
+            Error::TransactionNotFound => "transaction_not_found",
+            Error::InvalidAssetInfo => "invalid_asset_info",
+            Error::InsufficientFunds => "insufficient_funds",
+            Error::InsufficientAssets => "insufficient_assets",
+            Error::InvalidTransaction => "invalid_transaction",
+            Error::UnableToVerifyTransaction => "unable_to_verify_transaction",
+            Error::MultisigNotFound => "multisig_not_found",
+            Error::MultisigExists => "multisig_exists",
+            Error::NotMultisigMember => "not_multisig_member",
+            Error::ProposalNotFound => "proposal_not_found",
+            Error::AlreadyApproved => "already_approved",
+            Error::InsufficientSignatures => "insufficient_signatures",
+            Error::AlreadyExecuted => "already_executed",
+            Error::AllowanceNotFound => "allowance_not_found",
+            Error::AllowanceExpired => "allowance_expired",
+            Error::AllowanceExceeded => "allowance_exceeded",
+            Error::VestingNotFound => "vesting_not_found",
+            Error::NotVestingBeneficiary => "not_vesting_beneficiary",
+            Error::NothingToClaim => "nothing_to_claim",
+            Error::LoanNotFound => "loan_not_found",
+            Error::LoanNotAvailable => "loan_not_available",
+            Error::NotLoanParticipant => "not_loan_participant",
+            Error::LoanNotOverdue => "loan_not_overdue",
+            Error::NotAssetCreator => "not_asset_creator",
+            Error::NotComplianceOfficer => "not_compliance_officer",
+            Error::WalletFrozen => "wallet_frozen",
+            Error::HolderNotAllowed => "holder_not_allowed",
+            Error::TransactionDisabled => "transaction_disabled",
+            Error::SupplyCapExceeded => "supply_cap_exceeded",
+            Error::AssetSealed => "asset_sealed",
+            Error::TokenNotFound => "token_not_found",
+            Error::NotTokenOwner => "not_token_owner",
+            Error::TokenExists => "token_exists",
+            Error::CollectionNotFound => "collection_not_found",
+            Error::CollectionExists => "collection_exists",
+            Error::NotCollectionCreator => "not_collection_creator",
+            Error::OfferExecuted => "offer_executed",
+            Error::NotImplemented => "not_implemented",
+        }
+    }
+
     /// Try creating an error from its u8 representation.
     pub fn try_from(value: u8) -> Option<Self> {
         match value {
@@ -45,6 +183,37 @@ impl Error {
             5 => Some(Error::InsufficientAssets),
             6 => Some(Error::InvalidTransaction),
             7 => Some(Error::UnableToVerifyTransaction),
+            8 => Some(Error::MultisigNotFound),
+            9 => Some(Error::MultisigExists),
+            10 => Some(Error::NotMultisigMember),
+            11 => Some(Error::ProposalNotFound),
+            12 => Some(Error::AlreadyApproved),
+            13 => Some(Error::InsufficientSignatures),
+            14 => Some(Error::AlreadyExecuted),
+            15 => Some(Error::AllowanceNotFound),
+            16 => Some(Error::AllowanceExpired),
+            17 => Some(Error::AllowanceExceeded),
+            18 => Some(Error::VestingNotFound),
+            19 => Some(Error::NotVestingBeneficiary),
+            20 => Some(Error::NothingToClaim),
+            21 => Some(Error::LoanNotFound),
+            22 => Some(Error::LoanNotAvailable),
+            23 => Some(Error::NotLoanParticipant),
+            24 => Some(Error::LoanNotOverdue),
+            25 => Some(Error::NotAssetCreator),
+            26 => Some(Error::NotComplianceOfficer),
+            27 => Some(Error::WalletFrozen),
+            28 => Some(Error::HolderNotAllowed),
+            29 => Some(Error::TransactionDisabled),
+            30 => Some(Error::SupplyCapExceeded),
+            31 => Some(Error::AssetSealed),
+            32 => Some(Error::TokenNotFound),
+            33 => Some(Error::NotTokenOwner),
+            34 => Some(Error::TokenExists),
+            35 => Some(Error::CollectionNotFound),
+            36 => Some(Error::CollectionExists),
+            37 => Some(Error::NotCollectionCreator),
+            38 => Some(Error::OfferExecuted),
             255 => Some(Error::NotImplemented),
             _ => None,
         }
@@ -62,6 +231,37 @@ impl error::Error for Error {
             &Error::NotImplemented => "not implemented",
             &Error::InvalidTransaction => "invalid transaction",
             &Error::UnableToVerifyTransaction => "unable to verify transaction",
+            &Error::MultisigNotFound => "multisig wallet not found",
+            &Error::MultisigExists => "multisig wallet already exists",
+            &Error::NotMultisigMember => "not a multisig member",
+            &Error::ProposalNotFound => "proposal not found",
+            &Error::AlreadyApproved => "proposal already approved",
+            &Error::InsufficientSignatures => "insufficient signatures",
+            &Error::AlreadyExecuted => "transaction already executed",
+            &Error::AllowanceNotFound => "allowance not found",
+            &Error::AllowanceExpired => "allowance expired",
+            &Error::AllowanceExceeded => "allowance exceeded",
+            &Error::VestingNotFound => "vesting schedule not found",
+            &Error::NotVestingBeneficiary => "not a vesting beneficiary",
+            &Error::NothingToClaim => "nothing to claim",
+            &Error::LoanNotFound => "loan not found",
+            &Error::LoanNotAvailable => "loan not available",
+            &Error::NotLoanParticipant => "not a loan participant",
+            &Error::LoanNotOverdue => "loan not overdue",
+            &Error::NotAssetCreator => "not an asset creator",
+            &Error::NotComplianceOfficer => "not a compliance officer",
+            &Error::WalletFrozen => "wallet frozen",
+            &Error::HolderNotAllowed => "holder not allowed",
+            &Error::TransactionDisabled => "transaction type disabled",
+            &Error::SupplyCapExceeded => "supply cap exceeded",
+            &Error::AssetSealed => "asset sealed",
+            &Error::TokenNotFound => "token not found",
+            &Error::NotTokenOwner => "not a token owner",
+            &Error::TokenExists => "token already exists",
+            &Error::CollectionNotFound => "collection not found",
+            &Error::CollectionExists => "collection already exists",
+            &Error::NotCollectionCreator => "not a collection creator",
+            &Error::OfferExecuted => "offer already executed",
         }
     }
 }
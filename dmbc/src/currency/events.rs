@@ -0,0 +1,499 @@
+//! Events of committed blocks for the subscribers of the event stream.
+//!
+//! The history of wallet and asset events is node-local and kept in memory.
+//! It is built from the transactions of every block the node commits, so
+//! the events of recent blocks can be told again to a subscriber resuming
+//! after a reconnect. A block tells a wallet or an asset as changed when
+//! one of its transactions names it, whether the transaction succeeded or
+//! not. The wallet receiving the fees and the creators of the named assets
+//! count as named too.
+//!
+//! Only the last `HISTORY_BLOCKS` blocks are kept. Older blocks, and the
+//! blocks committed before the node started, tell their block and
+//! transaction events alone.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::RwLock;
+
+use exonum::blockchain;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::helpers::Height;
+use exonum::messages::{Message, RawMessage, HEADER_LENGTH};
+use exonum::storage::Snapshot;
+
+use currency::assets;
+use currency::assets::{AssetBundle, AssetId};
+use currency::error::Error;
+use currency::lending;
+use currency::multisig;
+use currency::nft::TokenId;
+use currency::status;
+use currency::transactions::{AcceptLoan, AddAssets, AddToCollection, Approve, CancelLoan,
+                             CartCheckout, ClaimCollateral, ClaimVested, CreateCollection,
+                             CreateMultisig, CreateVesting, DeleteAssets, Exchange,
+                             ExchangeIntermediary, ExchangeTokens, FreezeWallet, MintTokens,
+                             MultiExchange, MultiTransfer, MultisigApprove, MultisigPropose,
+                             MultisigSpend, OfferLoan, ReturnLoan, RevokeApproval, SetAssetMetadata,
+                             SetAssetRestriction, SetAssetSupply, Trade, TradeIntermediary,
+                             TradeTokens, Transfer, TransferAssetCreator, TransferFrom,
+                             TransferTokens, UnfreezeWallet, UpdateAllowlist, UpdateAssetFees,
+                             ACCEPT_LOAN_ID, ADD_ASSETS_ID, ADD_TO_COLLECTION_ID, APPROVE_ID,
+                             CANCEL_LOAN_ID, CART_CHECKOUT_ID, CLAIM_COLLATERAL_ID, CLAIM_VESTED_ID,
+                             CREATE_COLLECTION_ID, CREATE_MULTISIG_ID, CREATE_VESTING_ID,
+                             DELETE_ASSETS_ID, EXCHANGE_ID, EXCHANGE_INTERMEDIARY_ID,
+                             EXCHANGE_TOKENS_ID, FREEZE_WALLET_ID, MINT_TOKENS_ID,
+                             MULTISIG_APPROVE_ID, MULTISIG_PROPOSE_ID, MULTISIG_SPEND_ID,
+                             MULTI_EXCHANGE_ID, MULTI_TRANSFER_ID, OFFER_LOAN_ID, RETURN_LOAN_ID,
+                             REVOKE_APPROVAL_ID, SET_ASSET_METADATA_ID, SET_ASSET_RESTRICTION_ID,
+                             SET_ASSET_SUPPLY_ID, TRADE_ID, TRADE_INTERMEDIARY_ID, TRADE_TOKENS_ID,
+                             TRANSFER_ASSET_CREATOR_ID, TRANSFER_FROM_ID, TRANSFER_ID,
+                             TRANSFER_TOKENS_ID, UNFREEZE_WALLET_ID, UPDATE_ALLOWLIST_ID,
+                             UPDATE_ASSET_FEES_ID};
+use currency::vesting;
+use currency::{Service, SERVICE_ID};
+
+/// Number of blocks whose wallet and asset events are kept.
+pub const HISTORY_BLOCKS: u64 = 10_000;
+
+lazy_static! {
+    /// Event history of the current node.
+    pub static ref HISTORY: RwLock<History> = RwLock::new(History::new());
+}
+
+/// An event of a committed block.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    /// A block was committed.
+    Block { height: u64, hash: Hash, tx_count: u32 },
+    /// A transaction was committed with the given status.
+    Transaction {
+        height: u64,
+        tx_hash: Hash,
+        status: Result<(), Error>,
+    },
+    /// The balance or the assets of a wallet changed.
+    Wallet { height: u64, pub_key: PublicKey },
+    /// An asset changed hands or its info changed.
+    Asset { height: u64, id: AssetId },
+}
+
+impl Event {
+    /// Name of the event kind.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Event::Block { .. } => "block",
+            Event::Transaction { .. } => "transaction",
+            Event::Wallet { .. } => "wallet",
+            Event::Asset { .. } => "asset",
+        }
+    }
+}
+
+/// Events a subscriber is interested in.
+#[derive(Clone, Debug, Default)]
+pub struct Subscription {
+    pub blocks: bool,
+    pub transactions: HashSet<Hash>,
+    pub wallets: HashSet<PublicKey>,
+    pub assets: HashSet<AssetId>,
+}
+
+impl Subscription {
+    /// Check if the subscription matches no events at all.
+    pub fn is_empty(&self) -> bool {
+        !self.blocks && self.transactions.is_empty() && self.wallets.is_empty()
+            && self.assets.is_empty()
+    }
+
+    /// Events of the block at `height` matching the subscription.
+    pub fn events(&self, view: &Snapshot, height: u64) -> Vec<Event> {
+        let schema = blockchain::Schema::new(view);
+        let block = match schema.block_hash_by_height(Height(height)) {
+            Some(hash) => schema.blocks().get(&hash).map(|block| (hash, block)),
+            None => None,
+        };
+        let (hash, block) = match block {
+            Some(block) => block,
+            None => return Vec::new(),
+        };
+
+        let mut events = Vec::new();
+        if self.blocks {
+            events.push(Event::Block {
+                height,
+                hash,
+                tx_count: block.tx_count(),
+            });
+        }
+        if !self.transactions.is_empty() {
+            for tx_hash in schema.block_txs(Height(height)).iter() {
+                if !self.transactions.contains(&tx_hash) {
+                    continue;
+                }
+                if let Some(status) = status::Schema(view).fetch(&tx_hash) {
+                    events.push(Event::Transaction {
+                        height,
+                        tx_hash,
+                        status,
+                    });
+                }
+            }
+        }
+        let history = HISTORY.read().unwrap();
+        for pub_key in history.wallets(height) {
+            if self.wallets.contains(&pub_key) {
+                events.push(Event::Wallet { height, pub_key });
+            }
+        }
+        for id in history.assets(height) {
+            if self.assets.contains(&id) {
+                events.push(Event::Asset { height, id });
+            }
+        }
+        events
+    }
+}
+
+/// Wallets and assets named by the transactions of a block.
+#[derive(Clone, Debug, Default)]
+struct Changes {
+    wallets: Vec<PublicKey>,
+    assets: Vec<AssetId>,
+}
+
+impl Changes {
+    fn wallet(&mut self, pub_key: &PublicKey) {
+        if !self.wallets.contains(pub_key) {
+            self.wallets.push(*pub_key);
+        }
+    }
+
+    fn asset(&mut self, id: AssetId) {
+        if !self.assets.contains(&id) {
+            self.assets.push(id);
+        }
+    }
+
+    fn bundles(&mut self, bundles: Vec<AssetBundle>) {
+        for bundle in bundles {
+            self.asset(bundle.id());
+        }
+    }
+
+    fn tokens(&mut self, tokens: Vec<TokenId>) {
+        for token in tokens {
+            self.asset(token.asset_id());
+        }
+    }
+
+    /// Add the wallets and assets named by a transaction of the service.
+    ///
+    /// Loans, vestings and multisig proposals are looked up to name the
+    /// wallets and assets they hold.
+    fn add_tx(&mut self, view: &Snapshot, raw: RawMessage) {
+        if raw.service_id() != SERVICE_ID {
+            return;
+        }
+        match raw.message_type() {
+            ADD_ASSETS_ID => if let Ok(tx) = AddAssets::from_raw(raw) {
+                self.wallet(tx.pub_key());
+                for meta in tx.meta_assets() {
+                    self.wallet(meta.receiver());
+                    self.asset(assets::Schema(view).resolve_id(meta.data(), tx.pub_key()));
+                }
+            },
+            DELETE_ASSETS_ID => if let Ok(tx) = DeleteAssets::from_raw(raw) {
+                self.wallet(tx.pub_key());
+                self.bundles(tx.assets());
+            },
+            TRANSFER_ID => if let Ok(tx) = Transfer::from_raw(raw) {
+                self.wallet(tx.from());
+                self.wallet(tx.to());
+                self.bundles(tx.assets());
+            },
+            TRANSFER_TOKENS_ID => if let Ok(tx) = TransferTokens::from_raw(raw) {
+                self.wallet(tx.from());
+                self.wallet(tx.to());
+                self.bundles(tx.assets());
+                self.tokens(tx.tokens());
+            },
+            TRANSFER_FROM_ID => if let Ok(tx) = TransferFrom::from_raw(raw) {
+                self.wallet(tx.spender());
+                self.wallet(tx.from());
+                self.wallet(tx.to());
+                self.bundles(tx.assets());
+            },
+            MULTI_TRANSFER_ID => if let Ok(tx) = MultiTransfer::from_raw(raw) {
+                self.wallet(tx.from());
+                for output in tx.outputs() {
+                    self.wallet(output.to());
+                    self.bundles(output.assets());
+                }
+            },
+            EXCHANGE_ID => if let Ok(tx) = Exchange::from_raw(raw) {
+                let offer = tx.offer();
+                self.wallet(offer.sender());
+                self.wallet(offer.recipient());
+                self.bundles(offer.sender_assets());
+                self.bundles(offer.recipient_assets());
+            },
+            EXCHANGE_INTERMEDIARY_ID => if let Ok(tx) = ExchangeIntermediary::from_raw(raw) {
+                let offer = tx.offer();
+                self.wallet(offer.intermediary().wallet());
+                self.wallet(offer.sender());
+                self.wallet(offer.recipient());
+                self.bundles(offer.sender_assets());
+                self.bundles(offer.recipient_assets());
+            },
+            EXCHANGE_TOKENS_ID => if let Ok(tx) = ExchangeTokens::from_raw(raw) {
+                let offer = tx.offer();
+                self.wallet(offer.sender());
+                self.wallet(offer.recipient());
+                self.bundles(offer.sender_assets());
+                self.bundles(offer.recipient_assets());
+                self.tokens(offer.sender_tokens());
+                self.tokens(offer.recipient_tokens());
+            },
+            TRADE_ID => if let Ok(tx) = Trade::from_raw(raw) {
+                let offer = tx.offer();
+                self.wallet(offer.buyer());
+                self.wallet(offer.seller());
+                for asset in offer.assets() {
+                    self.asset(asset.id());
+                }
+            },
+            TRADE_INTERMEDIARY_ID => if let Ok(tx) = TradeIntermediary::from_raw(raw) {
+                let offer = tx.offer();
+                self.wallet(offer.intermediary().wallet());
+                self.wallet(offer.buyer());
+                self.wallet(offer.seller());
+                for asset in offer.assets() {
+                    self.asset(asset.id());
+                }
+            },
+            TRADE_TOKENS_ID => if let Ok(tx) = TradeTokens::from_raw(raw) {
+                let offer = tx.offer();
+                self.wallet(offer.buyer());
+                self.wallet(offer.seller());
+                for asset in offer.assets() {
+                    self.asset(asset.id());
+                }
+                for token in offer.tokens() {
+                    self.asset(token.id().asset_id());
+                }
+            },
+            CART_CHECKOUT_ID => if let Ok(tx) = CartCheckout::from_raw(raw) {
+                self.wallet(tx.buyer());
+                for item in tx.items() {
+                    let offer = item.offer();
+                    self.wallet(offer.seller());
+                    for asset in offer.assets() {
+                        self.asset(asset.id());
+                    }
+                }
+            },
+            MULTI_EXCHANGE_ID => if let Ok(tx) = MultiExchange::from_raw(raw) {
+                self.wallet(tx.submitter());
+                for leg in tx.offer().legs() {
+                    self.wallet(leg.from());
+                    self.wallet(leg.to());
+                    self.bundles(leg.assets());
+                }
+            },
+            CREATE_MULTISIG_ID => if let Ok(tx) = CreateMultisig::from_raw(raw) {
+                self.wallet(tx.creator());
+                self.wallet(&tx.config().address());
+            },
+            MULTISIG_SPEND_ID => if let Ok(tx) = MultisigSpend::from_raw(raw) {
+                self.wallet(tx.submitter());
+                self.wallet(tx.wallet());
+                self.add_proposed_tx(view, tx.tx());
+            },
+            MULTISIG_PROPOSE_ID => if let Ok(tx) = MultisigPropose::from_raw(raw) {
+                self.wallet(tx.proposer());
+            },
+            MULTISIG_APPROVE_ID => if let Ok(tx) = MultisigApprove::from_raw(raw) {
+                self.wallet(tx.approver());
+                // An executed proposal is removed, its transaction is found
+                // in the proposing one.
+                if multisig::Schema(view).fetch_proposal(tx.proposal()).is_none() {
+                    let schema = blockchain::Schema::new(view);
+                    let propose = schema
+                        .transactions()
+                        .get(tx.proposal())
+                        .and_then(|raw| MultisigPropose::from_raw(raw).ok());
+                    if let Some(propose) = propose {
+                        self.add_proposed_tx(view, propose.tx());
+                    }
+                }
+            },
+            APPROVE_ID => if let Ok(tx) = Approve::from_raw(raw) {
+                self.wallet(tx.owner());
+                self.wallet(tx.spender());
+            },
+            REVOKE_APPROVAL_ID => if let Ok(tx) = RevokeApproval::from_raw(raw) {
+                self.wallet(tx.owner());
+                self.wallet(tx.spender());
+            },
+            CREATE_VESTING_ID => if let Ok(tx) = CreateVesting::from_raw(raw) {
+                self.wallet(tx.creator());
+                self.wallet(tx.beneficiary());
+                self.bundles(tx.assets());
+            },
+            CLAIM_VESTED_ID => if let Ok(tx) = ClaimVested::from_raw(raw) {
+                self.wallet(tx.beneficiary());
+                if let Some(vesting) = vesting::Schema(view).fetch(tx.vesting()) {
+                    self.bundles(vesting.granted().assets());
+                }
+            },
+            OFFER_LOAN_ID => if let Ok(tx) = OfferLoan::from_raw(raw) {
+                self.wallet(tx.lender());
+                self.bundles(tx.assets());
+            },
+            ACCEPT_LOAN_ID => if let Ok(tx) = AcceptLoan::from_raw(raw) {
+                self.wallet(tx.borrower());
+                self.add_loan(view, tx.loan());
+            },
+            RETURN_LOAN_ID => if let Ok(tx) = ReturnLoan::from_raw(raw) {
+                self.wallet(tx.borrower());
+                self.add_loan(view, tx.loan());
+            },
+            CLAIM_COLLATERAL_ID => if let Ok(tx) = ClaimCollateral::from_raw(raw) {
+                self.wallet(tx.lender());
+                self.add_loan(view, tx.loan());
+            },
+            CANCEL_LOAN_ID => if let Ok(tx) = CancelLoan::from_raw(raw) {
+                self.wallet(tx.lender());
+                self.add_loan(view, tx.loan());
+            },
+            UPDATE_ASSET_FEES_ID => if let Ok(tx) = UpdateAssetFees::from_raw(raw) {
+                self.wallet(tx.creator());
+                self.asset(tx.asset_id());
+            },
+            TRANSFER_ASSET_CREATOR_ID => if let Ok(tx) = TransferAssetCreator::from_raw(raw) {
+                self.wallet(tx.creator());
+                self.wallet(tx.new_creator());
+                self.asset(tx.asset_id());
+            },
+            SET_ASSET_RESTRICTION_ID => if let Ok(tx) = SetAssetRestriction::from_raw(raw) {
+                self.wallet(tx.creator());
+                self.asset(tx.asset_id());
+            },
+            SET_ASSET_SUPPLY_ID => if let Ok(tx) = SetAssetSupply::from_raw(raw) {
+                self.wallet(tx.creator());
+                self.asset(tx.policy().id());
+            },
+            SET_ASSET_METADATA_ID => if let Ok(tx) = SetAssetMetadata::from_raw(raw) {
+                self.wallet(tx.creator());
+            },
+            MINT_TOKENS_ID => if let Ok(tx) = MintTokens::from_raw(raw) {
+                self.wallet(tx.creator());
+                self.wallet(tx.receiver());
+                self.asset(tx.asset_id());
+            },
+            CREATE_COLLECTION_ID => if let Ok(tx) = CreateCollection::from_raw(raw) {
+                self.wallet(tx.creator());
+            },
+            ADD_TO_COLLECTION_ID => if let Ok(tx) = AddToCollection::from_raw(raw) {
+                self.wallet(tx.creator());
+                for member in tx.members() {
+                    self.asset(member.id());
+                }
+            },
+            FREEZE_WALLET_ID => if let Ok(tx) = FreezeWallet::from_raw(raw) {
+                self.wallet(tx.officer());
+                self.wallet(tx.wallet());
+            },
+            UNFREEZE_WALLET_ID => if let Ok(tx) = UnfreezeWallet::from_raw(raw) {
+                self.wallet(tx.officer());
+                self.wallet(tx.wallet());
+            },
+            UPDATE_ALLOWLIST_ID => if let Ok(tx) = UpdateAllowlist::from_raw(raw) {
+                self.wallet(tx.officer());
+                self.wallet(tx.holder());
+            },
+            _ => (),
+        }
+    }
+
+    /// Add the wallets and assets named by a transaction spending from a
+    /// multisig wallet.
+    fn add_proposed_tx(&mut self, view: &Snapshot, bytes: &[u8]) {
+        if bytes.len() >= HEADER_LENGTH {
+            self.add_tx(view, RawMessage::from_vec(bytes.to_vec()));
+        }
+    }
+
+    /// Add the wallets and assets of a loan.
+    fn add_loan(&mut self, view: &Snapshot, loan_id: &Hash) {
+        if let Some(loan) = lending::Schema(view).fetch(loan_id) {
+            self.wallet(loan.lender());
+            self.wallet(loan.borrower());
+            self.bundles(loan.assets());
+        }
+    }
+}
+
+/// Wallet and asset events of the recent blocks committed by the node.
+#[derive(Debug, Default)]
+pub struct History {
+    blocks: BTreeMap<u64, Changes>,
+}
+
+impl History {
+    /// Create an empty history.
+    pub fn new() -> Self {
+        History::default()
+    }
+
+    /// Record the wallets and assets named by the last committed block and
+    /// forget the blocks that fell out of the history.
+    pub fn handle_commit(&mut self, view: &Snapshot) {
+        let schema = blockchain::Schema::new(view);
+        let height = schema.last_block().height();
+
+        let mut changes = self.blocks.remove(&height.0).unwrap_or_default();
+        for tx_hash in schema.block_txs(height).iter() {
+            if let Some(raw) = schema.transactions().get(&tx_hash) {
+                changes.add_tx(view, raw);
+            }
+        }
+        let creators: Vec<PublicKey> = changes
+            .assets
+            .iter()
+            .filter_map(|id| assets::Schema(view).fetch(id))
+            .map(|info| *info.creator())
+            .collect();
+        for creator in creators {
+            changes.wallet(&creator);
+        }
+        if !changes.wallets.is_empty() {
+            changes.wallet(&Service::genesis_wallet(view));
+        }
+        self.blocks.insert(height.0, changes);
+
+        while let Some(&oldest) = self.blocks.keys().next() {
+            if oldest + HISTORY_BLOCKS > height.0 {
+                break;
+            }
+            self.blocks.remove(&oldest);
+        }
+    }
+
+    /// Wallets changed by the block at `height`.
+    pub fn wallets(&self, height: u64) -> Vec<PublicKey> {
+        self.blocks
+            .get(&height)
+            .map(|changes| changes.wallets.clone())
+            .unwrap_or_default()
+    }
+
+    /// Assets changed by the block at `height`.
+    pub fn assets(&self, height: u64) -> Vec<AssetId> {
+        self.blocks
+            .get(&height)
+            .map(|changes| changes.assets.clone())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,87 @@
+use exonum::crypto::PublicKey;
+
+use currency::assets::AssetBundle;
+
+/// Stage of the loan lifecycle.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Debug)]
+pub enum LoanState {
+    /// Offered by the lender and waiting for a borrower.
+    Offered = 1,
+    /// Assets are with the borrower and the collateral is locked.
+    Active = 2,
+    /// Assets have been returned to the lender.
+    Returned = 3,
+    /// Lender has taken the collateral after the deadline.
+    Defaulted = 4,
+    /// Offer withdrawn by the lender before anyone accepted it.
+    Cancelled = 5,
+}
+
+impl LoanState {
+    /// Try converting from an u8. To be replaced when the `TryFrom` trait
+    /// is stabilised.
+    pub fn try_from(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(LoanState::Offered),
+            2 => Some(LoanState::Active),
+            3 => Some(LoanState::Returned),
+            4 => Some(LoanState::Defaulted),
+            5 => Some(LoanState::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+encoding_struct! {
+    /// Loan of assets against coin collateral.
+    ///
+    /// The borrower key is zero and the deadline is unset until the
+    /// offer is accepted. Offered assets and locked collateral are held
+    /// by the loan itself.
+    struct Loan {
+        lender:     &PublicKey,
+        borrower:   &PublicKey,
+        assets:     Vec<AssetBundle>,
+        collateral: u64,
+        rental_fee: u64,
+        duration:   u64,
+        deadline:   u64,
+        state:      u8,
+    }
+}
+
+impl Loan {
+    /// Current stage of the loan.
+    pub fn loan_state(&self) -> LoanState {
+        LoanState::try_from(self.state()).expect("loan state must be valid")
+    }
+
+    /// Same loan accepted by the borrower, due at the deadline height.
+    pub fn accept(self, borrower: &PublicKey, deadline: u64) -> Self {
+        Loan::new(
+            self.lender(),
+            borrower,
+            self.assets(),
+            self.collateral(),
+            self.rental_fee(),
+            self.duration(),
+            deadline,
+            LoanState::Active as u8,
+        )
+    }
+
+    /// Same loan moved to a closing state.
+    pub fn close(self, state: LoanState) -> Self {
+        Loan::new(
+            self.lender(),
+            self.borrower(),
+            self.assets(),
+            self.collateral(),
+            self.rental_fee(),
+            self.duration(),
+            self.deadline(),
+            state as u8,
+        )
+    }
+}
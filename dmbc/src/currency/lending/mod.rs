@@ -0,0 +1,7 @@
+//! Lending of assets against coin collateral.
+
+mod loan;
+mod schema;
+
+pub use currency::lending::loan::{Loan, LoanState};
+pub use currency::lending::schema::Schema;
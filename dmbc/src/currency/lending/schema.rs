@@ -0,0 +1,50 @@
+use exonum::crypto::{Hash, PublicKey};
+use exonum::storage::{Fork, MapIndex, Snapshot};
+
+use currency::lending::Loan;
+use currency::SERVICE_NAME;
+
+/// Schema for accessing loans.
+pub struct Schema<S>(pub S)
+where
+    S: AsRef<Snapshot>;
+
+impl<S> Schema<S>
+where
+    S: AsRef<Snapshot>,
+{
+    /// Internal `MapIndex` of loans, keyed by the hash of the `offer_loan`
+    /// transaction.
+    pub fn index(self) -> MapIndex<S, Hash, Loan> {
+        let key = SERVICE_NAME.to_string() + ".loans";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Fetch a loan.
+    pub fn fetch(self, loan_id: &Hash) -> Option<Loan> {
+        self.index().get(loan_id)
+    }
+
+    /// Fetch all loans where the key is the lender or the borrower.
+    pub fn fetch_wallet_loans(self, pub_key: &PublicKey) -> Vec<(Hash, Loan)> {
+        let index = self.index();
+        let loans = index
+            .iter()
+            .filter(|&(_, ref loan)| loan.lender() == pub_key || loan.borrower() == pub_key)
+            .collect();
+        loans
+    }
+}
+
+impl<'a> Schema<&'a mut Fork> {
+    /// Internal `MapIndex` of loans, with mutable access.
+    pub fn index_mut(&mut self) -> MapIndex<&mut Fork, Hash, Loan> {
+        let key = SERVICE_NAME.to_string() + ".loans";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Store a loan.
+    pub fn store(&mut self, loan_id: &Hash, loan: Loan) {
+        self.index_mut().put(loan_id, loan);
+    }
+}
@@ -0,0 +1,107 @@
+//! Node-local store of off-chain asset metadata.
+//!
+//! Content is addressed by its hash, which is what assets commit to in
+//! their `MetadataRef`. The store is not part of the blockchain state and
+//! is not replicated between nodes.
+
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::path::PathBuf;
+
+use exonum::crypto;
+use exonum::crypto::Hash;
+
+use config;
+
+/// Maximum size of a single metadata document.
+pub const CONTENT_MAX_LENGTH: usize = 1024 * 1024;
+
+/// Errors of the metadata store.
+#[derive(Debug)]
+pub enum StoreError {
+    /// Content does not match the hash it is stored under.
+    HashMismatch,
+    /// Content is larger than `CONTENT_MAX_LENGTH`.
+    TooLarge,
+    /// Reading or writing the content failed.
+    Io(io::Error),
+}
+
+impl From<io::Error> for StoreError {
+    fn from(e: io::Error) -> Self {
+        StoreError::Io(e)
+    }
+}
+
+/// Content-addressed metadata store in a directory on disk.
+#[derive(Clone, Debug)]
+pub struct Store {
+    root: PathBuf,
+}
+
+impl Store {
+    /// Create a store in the `root` directory.
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Store { root: root.into() }
+    }
+
+    /// Create a store in the directory set in the node configuration.
+    pub fn from_config() -> Self {
+        Store::new(config::config().metadata().path())
+    }
+
+    fn path(&self, content_hash: &Hash) -> PathBuf {
+        self.root.join(content_hash.to_hex())
+    }
+
+    /// Store `content` under its hash.
+    ///
+    /// # Errors
+    /// Returns a `HashMismatch` error if `content` does not hash to
+    /// `content_hash`.
+    pub fn put(&self, content_hash: &Hash, content: &[u8]) -> Result<(), StoreError> {
+        if content.len() > CONTENT_MAX_LENGTH {
+            return Err(StoreError::TooLarge);
+        }
+        if crypto::hash(content) != *content_hash {
+            return Err(StoreError::HashMismatch);
+        }
+
+        fs::create_dir_all(&self.root)?;
+
+        // Write to a temporary file first, so readers never see partial content.
+        let path = self.path(content_hash);
+        let tmp_path = path.with_extension("tmp");
+        {
+            let mut file = File::create(&tmp_path)?;
+            file.write_all(content)?;
+            file.sync_all()?;
+        }
+        fs::rename(&tmp_path, &path)?;
+
+        Ok(())
+    }
+
+    /// Fetch the content stored under `content_hash`.
+    ///
+    /// # Errors
+    /// Returns a `HashMismatch` error if the stored content got corrupted.
+    pub fn get(&self, content_hash: &Hash) -> Result<Option<Vec<u8>>, StoreError> {
+        let mut file = match File::open(self.path(content_hash)) {
+            Ok(file) => file,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut content = Vec::new();
+        file.read_to_end(&mut content)?;
+
+        if crypto::hash(&content) != *content_hash {
+            return Err(StoreError::HashMismatch);
+        }
+
+        Ok(Some(content))
+    }
+}
@@ -1,11 +1,22 @@
 //! The currency service.
 
+pub mod allowance;
 pub mod api;
 pub mod assets;
+pub mod compliance;
 pub mod configuration;
 pub mod error;
+pub mod events;
+pub mod lending;
+pub mod metadata;
+pub mod multisig;
+pub mod nft;
+pub mod offers;
+pub mod pending;
+pub mod search;
 pub mod status;
 pub mod transactions;
+pub mod vesting;
 pub mod wallet;
 
 mod nats;
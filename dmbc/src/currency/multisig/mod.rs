@@ -0,0 +1,9 @@
+//! Multisignature wallets and their pending proposals.
+
+mod multisig;
+mod proposal;
+mod schema;
+
+pub use currency::multisig::multisig::{MultisigConfig, MultisigMember, MAX_MULTISIG_MEMBERS};
+pub use currency::multisig::proposal::Proposal;
+pub use currency::multisig::schema::Schema;
@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use exonum::crypto;
+use exonum::crypto::PublicKey;
+
+/// Maximum number of keys in a multisig wallet.
+pub const MAX_MULTISIG_MEMBERS: usize = 16;
+
+encoding_struct! {
+    /// Key allowed to sign on behalf of a multisig wallet.
+    struct MultisigMember {
+        pub_key: &PublicKey,
+    }
+}
+
+encoding_struct! {
+    /// Key set and threshold of a multisig wallet.
+    struct MultisigConfig {
+        members:   Vec<MultisigMember>,
+        threshold: u8,
+    }
+}
+
+impl MultisigConfig {
+    /// Address of the wallet governed by this configuration.
+    ///
+    /// The address is derived from the configuration itself, so nobody
+    /// holds a secret key for it and funds can only be spent on chain.
+    pub fn address(&self) -> PublicKey {
+        let hash = crypto::hash(&self.raw);
+        PublicKey::from_slice(hash.as_ref()).expect("hash must be a valid key length")
+    }
+
+    /// Check if the key belongs to the key set.
+    pub fn is_member(&self, pub_key: &PublicKey) -> bool {
+        self.members().iter().any(|member| member.pub_key() == pub_key)
+    }
+
+    /// Check that the key set is non-empty, bounded and has no duplicates,
+    /// and that the threshold can be reached.
+    pub fn verify(&self) -> bool {
+        let members = self.members();
+
+        let mut keys = HashSet::new();
+        let unique_ok = members.iter().all(|member| keys.insert(*member.pub_key()));
+        let count_ok = !members.is_empty() && members.len() <= MAX_MULTISIG_MEMBERS;
+        let threshold_ok = self.threshold() > 0 && self.threshold() as usize <= members.len();
+
+        unique_ok && count_ok && threshold_ok
+    }
+}
@@ -0,0 +1,28 @@
+use exonum::crypto::PublicKey;
+
+use currency::multisig::MultisigMember;
+
+encoding_struct! {
+    /// Spending transaction from a multisig wallet waiting for approvals.
+    struct Proposal {
+        wallet:    &PublicKey,
+        tx:        &[u8],
+        approvals: Vec<MultisigMember>,
+    }
+}
+
+impl Proposal {
+    /// Check if the key has already approved the proposal.
+    pub fn is_approved_by(&self, pub_key: &PublicKey) -> bool {
+        self.approvals()
+            .iter()
+            .any(|member| member.pub_key() == pub_key)
+    }
+
+    /// Add an approval from the key.
+    pub fn approve(self, pub_key: &PublicKey) -> Self {
+        let mut approvals = self.approvals();
+        approvals.push(MultisigMember::new(pub_key));
+        Proposal::new(self.wallet(), self.tx(), approvals)
+    }
+}
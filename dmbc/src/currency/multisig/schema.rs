@@ -0,0 +1,104 @@
+use exonum::crypto::{Hash, PublicKey};
+use exonum::storage::{Fork, KeySetIndex, MapIndex, Snapshot};
+
+use currency::multisig::{MultisigConfig, Proposal};
+use currency::SERVICE_NAME;
+
+/// Schema for accessing multisig wallets and pending proposals.
+pub struct Schema<S>(pub S)
+where
+    S: AsRef<Snapshot>;
+
+/// Key of a proposal in the index of proposals by wallet.
+fn wallet_proposal_key(address: &PublicKey, proposal_id: &Hash) -> Vec<u8> {
+    let mut key = address.as_ref().to_vec();
+    key.extend_from_slice(proposal_id.as_ref());
+    key
+}
+
+impl<S> Schema<S>
+where
+    S: AsRef<Snapshot>,
+{
+    /// Internal `MapIndex` of multisig wallet configurations.
+    pub fn configs(self) -> MapIndex<S, PublicKey, MultisigConfig> {
+        let key = SERVICE_NAME.to_string() + ".multisig_wallets";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Internal `MapIndex` of pending proposals.
+    pub fn proposals(self) -> MapIndex<S, Hash, Proposal> {
+        let key = SERVICE_NAME.to_string() + ".multisig_proposals";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Internal `KeySetIndex` of pending proposals by wallet, see `wallet_proposal_key`.
+    pub fn wallet_proposals(self) -> KeySetIndex<S, Vec<u8>> {
+        let key = SERVICE_NAME.to_string() + ".multisig_wallet_proposals";
+        KeySetIndex::new(key, self.0)
+    }
+
+    /// Fetch configuration of a multisig wallet.
+    pub fn fetch(self, address: &PublicKey) -> Option<MultisigConfig> {
+        self.configs().get(address)
+    }
+
+    /// Fetch a pending proposal.
+    pub fn fetch_proposal(self, proposal_id: &Hash) -> Option<Proposal> {
+        self.proposals().get(proposal_id)
+    }
+
+    /// Fetch all pending proposals for a multisig wallet.
+    pub fn fetch_wallet_proposals(self, address: &PublicKey) -> Vec<(Hash, Proposal)> {
+        let view = self.0.as_ref();
+        let prefix = address.as_ref().to_vec();
+        Schema(view)
+            .wallet_proposals()
+            .iter_from(&prefix)
+            .take_while(|key| key.starts_with(&prefix))
+            .filter_map(|key| Hash::from_slice(&key[prefix.len()..]))
+            .filter_map(|id| Schema(view).fetch_proposal(&id).map(|proposal| (id, proposal)))
+            .collect()
+    }
+}
+
+impl<'a> Schema<&'a mut Fork> {
+    /// Internal `MapIndex` of multisig wallet configurations, with mutable access.
+    pub fn configs_mut(&mut self) -> MapIndex<&mut Fork, PublicKey, MultisigConfig> {
+        let key = SERVICE_NAME.to_string() + ".multisig_wallets";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Internal `MapIndex` of pending proposals, with mutable access.
+    pub fn proposals_mut(&mut self) -> MapIndex<&mut Fork, Hash, Proposal> {
+        let key = SERVICE_NAME.to_string() + ".multisig_proposals";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Internal `KeySetIndex` of pending proposals by wallet, with mutable access.
+    pub fn wallet_proposals_mut(&mut self) -> KeySetIndex<&mut Fork, Vec<u8>> {
+        let key = SERVICE_NAME.to_string() + ".multisig_wallet_proposals";
+        KeySetIndex::new(key, &mut *self.0)
+    }
+
+    /// Store configuration of a multisig wallet under its address.
+    pub fn store(&mut self, config: MultisigConfig) {
+        self.configs_mut().put(&config.address(), config);
+    }
+
+    /// Store a pending proposal.
+    pub fn store_proposal(&mut self, proposal_id: &Hash, proposal: Proposal) {
+        let key = wallet_proposal_key(proposal.wallet(), proposal_id);
+        self.wallet_proposals_mut().insert(key);
+        self.proposals_mut().put(proposal_id, proposal);
+    }
+
+    /// Remove a proposal once it has been executed.
+    pub fn remove_proposal(&mut self, proposal_id: &Hash) {
+        if let Some(proposal) = self.proposals_mut().get(proposal_id) {
+            let key = wallet_proposal_key(proposal.wallet(), proposal_id);
+            self.wallet_proposals_mut().remove(&key);
+        }
+        self.proposals_mut().remove(proposal_id);
+    }
+}
@@ -0,0 +1,66 @@
+//! Non-fungible tokens.
+//!
+//! Unlike fungible assets, which are held as amounts of an `AssetId`, every
+//! token is a single item with its own serial number, owner and immutable
+//! metadata. Tokens minted together share the `AssetId` of their series,
+//! so rules set for an asset, like holder restrictions, apply to them too.
+
+mod schema;
+mod token;
+
+use std::collections::HashSet;
+
+use exonum::crypto::PublicKey;
+use exonum::storage::{Fork, Snapshot};
+
+use currency::assets::AssetBundle;
+use currency::error::Error;
+
+pub use currency::nft::schema::Schema;
+pub use currency::nft::token::{MetaToken, Token, TokenId, TradeToken, TOKEN_DATA_MAX_LENGTH};
+
+/// Check that no token is listed twice.
+pub fn unique<'a, I>(ids: I) -> bool
+where
+    I: IntoIterator<Item = &'a TokenId>,
+{
+    let mut keys = HashSet::new();
+    ids.into_iter().all(|id| keys.insert(id.key()))
+}
+
+/// Bundles of the token series, used for the compliance checks.
+pub fn to_bundles(ids: &[TokenId]) -> Vec<AssetBundle> {
+    ids.iter().map(|id| id.to_bundle()).collect()
+}
+
+/// Check that the wallet owns all of the tokens.
+///
+/// # Errors
+///
+/// Returns `TokenNotFound` if a token does not exist and `NotTokenOwner`
+/// if it belongs to another wallet.
+pub fn check_owner<S>(view: S, owner: &PublicKey, ids: &[TokenId]) -> Result<(), Error>
+where
+    S: AsRef<Snapshot>,
+{
+    let view = view.as_ref();
+    for id in ids {
+        let token = Schema(view).fetch(id).ok_or(Error::TokenNotFound)?;
+        if token.owner() != owner {
+            return Err(Error::NotTokenOwner);
+        }
+    }
+    Ok(())
+}
+
+/// Hand the tokens over to the wallet.
+///
+/// Tokens must be checked with `check_owner` beforehand, missing ones are
+/// skipped.
+pub fn move_tokens(view: &mut Fork, to: &PublicKey, ids: &[TokenId]) {
+    for id in ids {
+        if let Some(token) = Schema(&*view).fetch(id) {
+            Schema(&mut *view).store(token.transfer(to));
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use exonum::crypto::{Hash, PublicKey};
+use exonum::storage::{Fork, KeySetIndex, MapIndex, Snapshot};
+
+use currency::assets::AssetId;
+use currency::nft::{Token, TokenId};
+use currency::SERVICE_NAME;
+
+/// Schema for accessing non-fungible tokens.
+pub struct Schema<S>(pub S)
+where
+    S: AsRef<Snapshot>;
+
+impl<S> Schema<S>
+where
+    S: AsRef<Snapshot>,
+{
+    /// Internal `MapIndex` of tokens, keyed by the token ID.
+    pub fn tokens(self) -> MapIndex<S, Hash, Token> {
+        let key = SERVICE_NAME.to_string() + ".tokens";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Internal `KeySetIndex` with the keys of the tokens owned by a wallet.
+    pub fn wallet_index(self, owner: &PublicKey) -> KeySetIndex<S, Hash> {
+        let key = SERVICE_NAME.to_string() + ".wallet_tokens";
+        KeySetIndex::with_prefix(key, owner.as_ref().to_vec(), self.0)
+    }
+
+    /// Internal `KeySetIndex` with the serial numbers of the tokens of a
+    /// series.
+    pub fn series_index(self, asset_id: &AssetId) -> KeySetIndex<S, u64> {
+        let key = SERVICE_NAME.to_string() + ".series_tokens";
+        KeySetIndex::with_prefix(key, asset_id.0.to_vec(), self.0)
+    }
+
+    /// Fetch a token from the database.
+    pub fn fetch(self, id: &TokenId) -> Option<Token> {
+        self.tokens().get(&id.key())
+    }
+
+    /// Fetch all tokens owned by the wallet.
+    pub fn fetch_wallet_tokens(self, owner: &PublicKey) -> Vec<Token> {
+        let view = self.0.as_ref();
+        let index = Schema(view).wallet_index(owner);
+        let tokens = Schema(view).tokens();
+        let wallet_tokens = index.iter().filter_map(|key| tokens.get(&key)).collect();
+        wallet_tokens
+    }
+
+    /// Fetch all tokens of the series, ordered by serial number.
+    pub fn fetch_series_tokens(self, asset_id: &AssetId) -> Vec<Token> {
+        let view = self.0.as_ref();
+        let index = Schema(view).series_index(asset_id);
+        let series_tokens = index
+            .iter()
+            .filter_map(|serial| Schema(view).fetch(&TokenId::new(*asset_id, serial)))
+            .collect();
+        series_tokens
+    }
+
+    /// Check if any token of the series has been minted.
+    pub fn is_series(self, asset_id: &AssetId) -> bool {
+        let index = self.series_index(asset_id);
+        let minted = index.iter().next().is_some();
+        minted
+    }
+}
+
+impl<'a> Schema<&'a mut Fork> {
+    /// Internal `MapIndex` of tokens, with mutable access.
+    pub fn tokens_mut(&mut self) -> MapIndex<&mut Fork, Hash, Token> {
+        let key = SERVICE_NAME.to_string() + ".tokens";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Internal `KeySetIndex` with the keys of the tokens owned by a
+    /// wallet, with mutable access.
+    pub fn wallet_index_mut(&mut self, owner: &PublicKey) -> KeySetIndex<&mut Fork, Hash> {
+        let key = SERVICE_NAME.to_string() + ".wallet_tokens";
+        KeySetIndex::with_prefix(key, owner.as_ref().to_vec(), &mut *self.0)
+    }
+
+    /// Internal `KeySetIndex` with the serial numbers of the tokens of a
+    /// series, with mutable access.
+    pub fn series_index_mut(&mut self, asset_id: &AssetId) -> KeySetIndex<&mut Fork, u64> {
+        let key = SERVICE_NAME.to_string() + ".series_tokens";
+        KeySetIndex::with_prefix(key, asset_id.0.to_vec(), &mut *self.0)
+    }
+
+    /// Store a token, replacing the previous state of the token.
+    ///
+    /// The indexes of the wallet and the series are updated along.
+    pub fn store(&mut self, token: Token) {
+        let id = token.id();
+        let key = id.key();
+        let previous = Schema(&*self.0).tokens().get(&key);
+        if let Some(previous) = previous {
+            self.wallet_index_mut(previous.owner()).remove(&key);
+        }
+        self.wallet_index_mut(token.owner()).insert(key);
+        self.series_index_mut(&id.asset_id()).insert(id.serial());
+        self.tokens_mut().put(&key, token);
+    }
+}
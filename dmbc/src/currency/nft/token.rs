@@ -0,0 +1,92 @@
+use exonum::crypto::{Hash, PublicKey};
+use exonum::storage::StorageValue;
+
+use currency::assets::{AssetBundle, AssetId, TradeAsset};
+
+/// Maximum length of the metadata of a single token.
+pub const TOKEN_DATA_MAX_LENGTH: usize = 10 * 1024;
+
+encoding_struct! {
+    /// Identifier of a single non-fungible token.
+    ///
+    /// Tokens are grouped into series identified by an `AssetId` and told
+    /// apart by their serial numbers.
+    struct TokenId {
+        asset_id: AssetId,
+        serial:   u64,
+    }
+}
+
+impl TokenId {
+    /// Key of the token in the database.
+    pub fn key(&self) -> Hash {
+        self.hash()
+    }
+
+    /// Bundle of a single unit of the token series, used to apply the
+    /// rules set for the series as a whole.
+    pub fn to_bundle(&self) -> AssetBundle {
+        AssetBundle::new(self.asset_id(), 1)
+    }
+}
+
+encoding_struct! {
+    /// Non-fungible token in the network.
+    ///
+    /// The metadata is set when the token is minted and never changes.
+    struct Token {
+        id:      TokenId,
+        creator: &PublicKey,
+        owner:   &PublicKey,
+        origin:  &Hash,
+        data:    &str,
+    }
+}
+
+impl Token {
+    /// Hands the token over to another wallet.
+    pub fn transfer(self, owner: &PublicKey) -> Self {
+        Token::new(
+            self.id(),
+            self.creator(),
+            owner,
+            self.origin(),
+            self.data(),
+        )
+    }
+}
+
+encoding_struct! {
+    /// Token to be minted in the `mint_tokens` transaction.
+    struct MetaToken {
+        serial: u64,
+        data:   &str,
+    }
+}
+
+impl MetaToken {
+    /// Verify validity of the minted token.
+    pub fn verify(&self) -> bool {
+        self.data().len() <= TOKEN_DATA_MAX_LENGTH
+    }
+
+    /// Create the `TokenId` of this token within the series.
+    pub fn to_id(&self, asset_id: AssetId) -> TokenId {
+        TokenId::new(asset_id, self.serial())
+    }
+}
+
+encoding_struct! {
+    /// Token sold in a trade, along with its price.
+    struct TradeToken {
+        id:    TokenId,
+        price: u64,
+    }
+}
+
+impl TradeToken {
+    /// Single unit of the token series sold at the price of the token.
+    pub fn to_trade_asset(&self) -> TradeAsset {
+        TradeAsset::from_bundle(self.id().to_bundle(), self.price())
+    }
+}
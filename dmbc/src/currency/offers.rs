@@ -0,0 +1,45 @@
+//! Signed offers that have been executed.
+//!
+//! Offers that travel inside transactions with a hash of their own, like the
+//! items of a cart or the offer of a multi exchange submitted by any of its
+//! participants, could otherwise be executed again in a new transaction.
+//! The hashes of executed offers are kept here to refuse that.
+
+use exonum::crypto::Hash;
+use exonum::storage::{Fork, KeySetIndex, Snapshot};
+
+use currency::SERVICE_NAME;
+
+/// Schema for accessing executed offers.
+pub struct Schema<S>(pub S)
+where
+    S: AsRef<Snapshot>;
+
+impl<S> Schema<S>
+where
+    S: AsRef<Snapshot>,
+{
+    /// Internal `KeySetIndex` of the hashes of executed offers.
+    pub fn index(self) -> KeySetIndex<S, Hash> {
+        let key = SERVICE_NAME.to_string() + ".executed_offers";
+        KeySetIndex::new(key, self.0)
+    }
+
+    /// Check if the offer with the hash has been executed.
+    pub fn is_executed(self, offer_hash: &Hash) -> bool {
+        self.index().contains(offer_hash)
+    }
+}
+
+impl<'a> Schema<&'a mut Fork> {
+    /// Internal `KeySetIndex` of the hashes of executed offers, with mutable access.
+    pub fn index_mut(&mut self) -> KeySetIndex<&mut Fork, Hash> {
+        let key = SERVICE_NAME.to_string() + ".executed_offers";
+        KeySetIndex::new(key, &mut *self.0)
+    }
+
+    /// Record the offer with the hash as executed.
+    pub fn store(&mut self, offer_hash: &Hash) {
+        self.index_mut().insert(*offer_hash);
+    }
+}
@@ -0,0 +1,127 @@
+//! Transactions waiting in the pool of the current node.
+//!
+//! The node does not expose its transaction pool to the services, so the
+//! registry only knows about the transactions submitted through the API of
+//! the current node. They are added once forwarded to the node and removed
+//! when a block containing them is committed. Transactions the node drops
+//! without committing expire after `PENDING_TTL_SECS`.
+//!
+//! Waiting for a commit holds a worker thread of the API, so at most
+//! `WAITERS_MAX` requests wait at once. The rest are answered right away.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use exonum::blockchain;
+use exonum::crypto::Hash;
+use exonum::storage::Snapshot;
+
+/// Seconds after which an uncommitted transaction is no longer considered pending.
+pub const PENDING_TTL_SECS: u64 = 600;
+
+/// Largest number of requests waiting for a commit at once.
+pub const WAITERS_MAX: usize = 4;
+
+lazy_static! {
+    /// Pending transactions of the current node.
+    pub static ref PENDING: Pending = Pending::new();
+}
+
+/// Registry of pending transactions with notification of commits.
+#[derive(Debug, Default)]
+pub struct Pending {
+    submitted: Mutex<HashMap<Hash, Instant>>,
+    committed: Condvar,
+    waiters: AtomicUsize,
+}
+
+/// Place of a request among the waiters, given back when dropped.
+struct Waiter<'a>(&'a AtomicUsize);
+
+impl<'a> Waiter<'a> {
+    fn enter(waiters: &'a AtomicUsize) -> Option<Self> {
+        if waiters.fetch_add(1, Ordering::SeqCst) < WAITERS_MAX {
+            Some(Waiter(waiters))
+        } else {
+            waiters.fetch_sub(1, Ordering::SeqCst);
+            None
+        }
+    }
+}
+
+impl<'a> Drop for Waiter<'a> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Pending {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Pending::default()
+    }
+
+    /// Mark a transaction as pending.
+    pub fn insert(&self, tx_hash: Hash) {
+        self.submitted.lock().unwrap().insert(tx_hash, Instant::now());
+    }
+
+    /// Check if a transaction is pending.
+    pub fn contains(&self, tx_hash: &Hash) -> bool {
+        match self.submitted.lock().unwrap().get(tx_hash) {
+            Some(submitted) => submitted.elapsed() < Duration::from_secs(PENDING_TTL_SECS),
+            None => false,
+        }
+    }
+
+    /// Remove the transactions of the last committed block and wake up
+    /// everyone waiting for a commit.
+    pub fn handle_commit(&self, view: &Snapshot) {
+        let schema = blockchain::Schema::new(view);
+        let txs = schema.block_txs(schema.last_block().height());
+
+        let mut submitted = self.submitted.lock().unwrap();
+        for tx_hash in txs.iter() {
+            submitted.remove(&tx_hash);
+        }
+        submitted.retain(|_, time| time.elapsed() < Duration::from_secs(PENDING_TTL_SECS));
+
+        self.committed.notify_all();
+    }
+
+    /// Call `check` after every commit until it returns a value or `timeout`
+    /// runs out, in which case `None` is returned. When `WAITERS_MAX`
+    /// requests are waiting already, `check` is called only once.
+    ///
+    /// `check` runs under the registry lock, so a commit cannot slip in
+    /// between a check and the wait for the next one.
+    pub fn wait_for<T, F>(&self, timeout: Duration, mut check: F) -> Option<T>
+    where
+        F: FnMut() -> Option<T>,
+    {
+        let waiter = Waiter::enter(&self.waiters);
+        let timeout = if waiter.is_some() {
+            timeout
+        } else {
+            Duration::from_secs(0)
+        };
+
+        let start = Instant::now();
+        let mut submitted = self.submitted.lock().unwrap();
+        loop {
+            if let Some(value) = check() {
+                return Some(value);
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return None;
+            }
+            submitted = self.committed
+                .wait_timeout(submitted, timeout - elapsed)
+                .unwrap()
+                .0;
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! Search index over asset data.
+//!
+//! The index is node-local and kept in memory. It is rebuilt from the
+//! database on the first commit after the node starts and then updated
+//! with the assets created or changed in every committed block.
+//!
+//! Asset data never changes once an asset is created, but the creator
+//! role can be handed over, so the index entry of every asset touched by
+//! a block is refreshed from its current `AssetInfo`. Assets that were
+//! deleted since are sorted out by looking up the `AssetInfo` of every hit.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::RwLock;
+
+use exonum::blockchain;
+use exonum::crypto::PublicKey;
+use exonum::messages::{Message, RawMessage};
+use exonum::storage::Snapshot;
+
+use currency::assets;
+use currency::assets::{AssetId, AssetInfo};
+use currency::status;
+use currency::transactions::{AddAssets, AddToCollection, MintTokens, TransferAssetCreator,
+                             UpdateAssetFees, ADD_ASSETS_ID, ADD_TO_COLLECTION_ID,
+                             MINT_TOKENS_ID, TRANSFER_ASSET_CREATOR_ID, UPDATE_ASSET_FEES_ID};
+use currency::SERVICE_ID;
+
+lazy_static! {
+    /// Search index of the current node.
+    pub static ref SEARCH_INDEX: RwLock<SearchIndex> = RwLock::new(SearchIndex::new());
+}
+
+/// Split text into lowercase alphanumeric terms.
+pub fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+/// IDs of the assets a transaction may have created or changed.
+fn changed_assets(raw: RawMessage) -> Vec<AssetId> {
+    match raw.message_type() {
+        ADD_ASSETS_ID => AddAssets::from_raw(raw)
+            .map(|tx| {
+                tx.meta_assets()
+                    .iter()
+                    .map(|meta| AssetId::from_data(meta.data(), tx.pub_key()))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        MINT_TOKENS_ID => MintTokens::from_raw(raw)
+            .map(|tx| vec![tx.asset_id()])
+            .unwrap_or_default(),
+        UPDATE_ASSET_FEES_ID => UpdateAssetFees::from_raw(raw)
+            .map(|tx| vec![tx.asset_id()])
+            .unwrap_or_default(),
+        TRANSFER_ASSET_CREATOR_ID => TransferAssetCreator::from_raw(raw)
+            .map(|tx| vec![tx.asset_id()])
+            .unwrap_or_default(),
+        ADD_TO_COLLECTION_ID => AddToCollection::from_raw(raw)
+            .map(|tx| tx.members().iter().map(|member| member.id()).collect())
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+/// Inverted index from the terms of asset data and from creators to
+/// asset IDs.
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    synced: bool,
+    assets: HashMap<AssetId, PublicKey>,
+    terms: BTreeMap<String, HashSet<AssetId>>,
+    creators: HashMap<PublicKey, HashSet<AssetId>>,
+}
+
+impl SearchIndex {
+    /// Create an empty index.
+    pub fn new() -> Self {
+        SearchIndex::default()
+    }
+
+    /// Check if the index has been built from the database.
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    /// Add an asset to the index or refresh its creator.
+    pub fn insert(&mut self, id: AssetId, info: &AssetInfo) {
+        let creator = *info.creator();
+        match self.assets.insert(id, creator) {
+            Some(old) if old == creator => return,
+            Some(old) => {
+                if let Some(ids) = self.creators.get_mut(&old) {
+                    ids.remove(&id);
+                }
+            }
+            None => {
+                for term in terms(info.data()) {
+                    self.terms.entry(term).or_insert_with(HashSet::new).insert(id);
+                }
+            }
+        }
+        self.creators
+            .entry(creator)
+            .or_insert_with(HashSet::new)
+            .insert(id);
+    }
+
+    /// Rebuild the index from all assets in the database.
+    pub fn rebuild(&mut self, view: &Snapshot) {
+        *self = SearchIndex::new();
+        for (id, info) in assets::Schema(view).index().iter() {
+            self.insert(id, &info);
+        }
+        self.synced = true;
+    }
+
+    /// Index the assets created or changed in the last committed block.
+    pub fn handle_commit(&mut self, view: &Snapshot) {
+        if !self.synced {
+            self.rebuild(view);
+            return;
+        }
+
+        let schema = blockchain::Schema::new(view);
+        let txs = schema.block_txs(schema.last_block().height());
+        for hash in txs.iter() {
+            let raw = match schema.transactions().get(&hash) {
+                Some(raw) => raw,
+                None => continue,
+            };
+            if raw.service_id() != SERVICE_ID {
+                continue;
+            }
+            if status::Schema(view).fetch(&hash) != Some(Ok(())) {
+                continue;
+            }
+            for id in changed_assets(raw) {
+                if let Some(info) = assets::Schema(view).fetch(&id) {
+                    self.insert(id, &info);
+                }
+            }
+        }
+    }
+
+    /// Find the assets whose data contains all terms of `text` and a term
+    /// starting with `prefix`, created by `creator`. Empty arguments match
+    /// every asset.
+    pub fn search(&self, text: &str, prefix: &str, creator: Option<&PublicKey>) -> Vec<AssetId> {
+        let prefix = prefix.to_lowercase();
+        let prefixed = if prefix.is_empty() {
+            None
+        } else {
+            let mut ids = HashSet::new();
+            for (_, matches) in self.terms
+                .range(prefix.clone()..)
+                .take_while(|&(term, _)| term.starts_with(&prefix))
+            {
+                ids.extend(matches.iter().cloned());
+            }
+            Some(ids)
+        };
+
+        // Sets of IDs every match must belong to.
+        let mut sets: Vec<&HashSet<AssetId>> = Vec::new();
+        for term in terms(text) {
+            match self.terms.get(&term) {
+                Some(ids) => sets.push(ids),
+                None => return Vec::new(),
+            }
+        }
+        if let Some(creator) = creator {
+            match self.creators.get(creator) {
+                Some(ids) => sets.push(ids),
+                None => return Vec::new(),
+            }
+        }
+        if let Some(ref ids) = prefixed {
+            sets.push(ids);
+        }
+
+        // Walk the smallest set and check the others.
+        sets.sort_by_key(|ids| ids.len());
+        match sets.split_first() {
+            Some((first, rest)) => first
+                .iter()
+                .filter(|id| rest.iter().all(|ids| ids.contains(*id)))
+                .cloned()
+                .collect(),
+            None => self.assets.keys().cloned().collect(),
+        }
+    }
+}
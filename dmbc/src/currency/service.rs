@@ -8,21 +8,42 @@ use exonum::messages::RawTransaction;
 use exonum::messages::Message;
 use exonum::storage::Fork;
 use exonum::storage::Snapshot;
-use iron::Handler;
+use iron::{Chain, Handler};
 use router::Router;
 use prometheus::IntGauge;
 use std::sync::RwLock;
 
 use super::nats;
 use config;
+use currency::api::access::AccessControl;
 use currency::api::ServiceApi;
 use currency::configuration;
 use currency::configuration::Configuration;
+use currency::events;
+use currency::pending;
+use currency::search;
 use currency::status;
-use currency::transactions::{AddAssets, DeleteAssets, Exchange, ExchangeIntermediary, Trade,
-                             TradeIntermediary, Transfer, ADD_ASSETS_ID, DELETE_ASSETS_ID,
-                             EXCHANGE_ID, EXCHANGE_INTERMEDIARY_ID, TRADE_ID,
-                             TRADE_INTERMEDIARY_ID, TRANSFER_ID};
+use currency::transactions::{AcceptLoan, AddAssets, AddToCollection, Approve, CancelLoan,
+                             CartCheckout, ClaimCollateral, ClaimVested, CreateCollection,
+                             CreateMultisig, CreateVesting, DeleteAssets, Exchange,
+                             ExchangeIntermediary, ExchangeTokens, FreezeWallet, MintTokens,
+                             MultiExchange, MultiTransfer, MultisigApprove, MultisigPropose,
+                             MultisigSpend, OfferLoan, ReturnLoan, RevokeApproval, SetAssetMetadata,
+                             SetAssetRestriction, SetAssetSupply, Trade, TradeIntermediary,
+                             TradeTokens, Transfer, TransferAssetCreator, TransferFrom,
+                             TransferTokens, UnfreezeWallet, UpdateAllowlist, UpdateAssetFees,
+                             ACCEPT_LOAN_ID, ADD_ASSETS_ID, ADD_TO_COLLECTION_ID, APPROVE_ID,
+                             CANCEL_LOAN_ID, CART_CHECKOUT_ID, CLAIM_COLLATERAL_ID, CLAIM_VESTED_ID,
+                             CREATE_COLLECTION_ID, CREATE_MULTISIG_ID, CREATE_VESTING_ID,
+                             DELETE_ASSETS_ID, EXCHANGE_ID, EXCHANGE_INTERMEDIARY_ID,
+                             EXCHANGE_TOKENS_ID, FREEZE_WALLET_ID, MINT_TOKENS_ID,
+                             MULTISIG_APPROVE_ID, MULTISIG_PROPOSE_ID, MULTISIG_SPEND_ID,
+                             MULTI_EXCHANGE_ID, MULTI_TRANSFER_ID, OFFER_LOAN_ID, RETURN_LOAN_ID,
+                             REVOKE_APPROVAL_ID, SET_ASSET_METADATA_ID, SET_ASSET_RESTRICTION_ID,
+                             SET_ASSET_SUPPLY_ID, TRADE_ID, TRADE_INTERMEDIARY_ID, TRADE_TOKENS_ID,
+                             TRANSFER_ASSET_CREATOR_ID, TRANSFER_FROM_ID, TRANSFER_ID,
+                             TRANSFER_TOKENS_ID, UNFREEZE_WALLET_ID, UPDATE_ALLOWLIST_ID,
+                             UPDATE_ASSET_FEES_ID};
 use currency::wallet;
 use currency::wallet::Wallet;
 use serde_json;
@@ -42,6 +63,14 @@ impl Service {
         Service()
     }
 
+    /// Build the indices a database created by an older version lacks.
+    ///
+    /// Run once on the database before the node starts, indices that are
+    /// already built are left as they are.
+    pub fn upgrade(fork: &mut Fork) {
+        wallet::Schema(fork).build_balances();
+    }
+
     /// Genesis wallet public key.
     pub fn genesis_wallet<S: AsRef<Snapshot>>(view: S) -> PublicKey {
         let config = Configuration::extract(view.as_ref());
@@ -76,10 +105,41 @@ impl blockchain::Service for Service {
             ADD_ASSETS_ID => Box::new(AddAssets::from_raw(raw)?),
             DELETE_ASSETS_ID => Box::new(DeleteAssets::from_raw(raw)?),
             EXCHANGE_ID => Box::new(Exchange::from_raw(raw)?),
+            EXCHANGE_TOKENS_ID => Box::new(ExchangeTokens::from_raw(raw)?),
             EXCHANGE_INTERMEDIARY_ID => Box::new(ExchangeIntermediary::from_raw(raw)?),
             TRADE_ID => Box::new(Trade::from_raw(raw)?),
+            TRADE_TOKENS_ID => Box::new(TradeTokens::from_raw(raw)?),
             TRADE_INTERMEDIARY_ID => Box::new(TradeIntermediary::from_raw(raw)?),
             TRANSFER_ID => Box::new(Transfer::from_raw(raw)?),
+            TRANSFER_TOKENS_ID => Box::new(TransferTokens::from_raw(raw)?),
+            CART_CHECKOUT_ID => Box::new(CartCheckout::from_raw(raw)?),
+            MULTI_EXCHANGE_ID => Box::new(MultiExchange::from_raw(raw)?),
+            MULTI_TRANSFER_ID => Box::new(MultiTransfer::from_raw(raw)?),
+            CREATE_MULTISIG_ID => Box::new(CreateMultisig::from_raw(raw)?),
+            MULTISIG_SPEND_ID => Box::new(MultisigSpend::from_raw(raw)?),
+            MULTISIG_PROPOSE_ID => Box::new(MultisigPropose::from_raw(raw)?),
+            MULTISIG_APPROVE_ID => Box::new(MultisigApprove::from_raw(raw)?),
+            APPROVE_ID => Box::new(Approve::from_raw(raw)?),
+            REVOKE_APPROVAL_ID => Box::new(RevokeApproval::from_raw(raw)?),
+            TRANSFER_FROM_ID => Box::new(TransferFrom::from_raw(raw)?),
+            CREATE_VESTING_ID => Box::new(CreateVesting::from_raw(raw)?),
+            CLAIM_VESTED_ID => Box::new(ClaimVested::from_raw(raw)?),
+            OFFER_LOAN_ID => Box::new(OfferLoan::from_raw(raw)?),
+            ACCEPT_LOAN_ID => Box::new(AcceptLoan::from_raw(raw)?),
+            RETURN_LOAN_ID => Box::new(ReturnLoan::from_raw(raw)?),
+            CLAIM_COLLATERAL_ID => Box::new(ClaimCollateral::from_raw(raw)?),
+            CANCEL_LOAN_ID => Box::new(CancelLoan::from_raw(raw)?),
+            UPDATE_ASSET_FEES_ID => Box::new(UpdateAssetFees::from_raw(raw)?),
+            TRANSFER_ASSET_CREATOR_ID => Box::new(TransferAssetCreator::from_raw(raw)?),
+            SET_ASSET_RESTRICTION_ID => Box::new(SetAssetRestriction::from_raw(raw)?),
+            SET_ASSET_SUPPLY_ID => Box::new(SetAssetSupply::from_raw(raw)?),
+            SET_ASSET_METADATA_ID => Box::new(SetAssetMetadata::from_raw(raw)?),
+            UPDATE_ALLOWLIST_ID => Box::new(UpdateAllowlist::from_raw(raw)?),
+            FREEZE_WALLET_ID => Box::new(FreezeWallet::from_raw(raw)?),
+            UNFREEZE_WALLET_ID => Box::new(UnfreezeWallet::from_raw(raw)?),
+            MINT_TOKENS_ID => Box::new(MintTokens::from_raw(raw)?),
+            CREATE_COLLECTION_ID => Box::new(CreateCollection::from_raw(raw)?),
+            ADD_TO_COLLECTION_ID => Box::new(AddToCollection::from_raw(raw)?),
             _ => {
                 return Err(encoding::Error::IncorrectMessageType {
                     message_type: raw.message_type(),
@@ -96,7 +156,11 @@ impl blockchain::Service for Service {
             blockchain: ctx.blockchain().clone(),
         };
         api.wire(&mut router);
-        Some(Box::new(router))
+
+        let mut chain = Chain::new(router);
+        let access = AccessControl::from_config().unwrap_or_else(|e| panic!("{}", e));
+        chain.link_around(access);
+        Some(Box::new(chain))
     }
 
     fn handle_commit(&self, ctx: &ServiceContext) {
@@ -107,6 +171,9 @@ impl blockchain::Service for Service {
 
         BLOCKCHAIN_HEIGHT.set(last_block.height().0 as i64);
         *CONFIGURATION.write().unwrap() = Configuration::extract(ctx.snapshot());
+        search::SEARCH_INDEX.write().unwrap().handle_commit(ctx.snapshot());
+        pending::PENDING.handle_commit(ctx.snapshot());
+        events::HISTORY.write().unwrap().handle_commit(ctx.snapshot());
 
         let txs = schema.block_txs(last_block.height());
         for hash in txs.iter() {
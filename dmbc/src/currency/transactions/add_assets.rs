@@ -8,10 +8,13 @@ use exonum::storage::Fork;
 use prometheus::{IntCounter, Histogram};
 
 use currency::assets;
-use currency::assets::{AssetId, AssetInfo, MetaAsset};
+use currency::assets::{AssetId, AssetInfo, MetaAsset, Supply};
+use currency::compliance;
+use currency::configuration::Configuration;
 use currency::error::Error;
+use currency::nft;
 use currency::status;
-use currency::transactions::components::{FeesCalculator, ThirdPartyFees};
+use currency::transactions::components::{FeesCalculator, Processable, ThirdPartyFees};
 use currency::wallet;
 use currency::SERVICE_ID;
 use currency::service::CONFIGURATION;
@@ -51,8 +54,71 @@ impl FeesCalculator for AddAssets {
 }
 
 impl AddAssets {
+    /// Check that the signer holds the creator role of the assets that
+    /// exist already.
+    ///
+    /// The role is stored with the asset and may have been handed over
+    /// to a key other than the one the asset ID is derived from, see
+    /// `assets::Schema::resolve_id`.
+    fn check_creator(&self, view: &Fork) -> Result<(), Error> {
+        let key = self.pub_key();
+        for meta in self.meta_assets() {
+            let id = assets::Schema(view).resolve_id(meta.data(), key);
+            if let Some(info) = assets::Schema(view).fetch(&id) {
+                if info.creator() != key {
+                    return Err(Error::NotAssetCreator);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that none of the assets is a series of non-fungible tokens.
+    fn check_series(&self, view: &Fork) -> Result<(), Error> {
+        for meta in self.meta_assets() {
+            let id = assets::Schema(view).resolve_id(meta.data(), self.pub_key());
+            if nft::Schema(view).is_series(&id) {
+                return Err(Error::InvalidAssetInfo);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the minted amounts against the supply limits of the assets.
+    ///
+    /// Limits are set by the `set_asset_supply` transaction.
+    fn check_supply(&self, view: &Fork) -> Result<HashMap<AssetId, Supply>, Error> {
+        let key = self.pub_key();
+        let mut amounts: HashMap<AssetId, u64> = HashMap::new();
+        for meta in self.meta_assets() {
+            let id = assets::Schema(view).resolve_id(meta.data(), key);
+            *amounts.entry(id).or_insert(0) += meta.amount();
+        }
+
+        let mut supplies = HashMap::new();
+        for (id, amount) in amounts {
+            if let Some(supply) = assets::Schema(view).fetch_supply(&id) {
+                supplies.insert(id, supply.mint(amount)?);
+            }
+        }
+
+        Ok(supplies)
+    }
+
     fn process(&self, view: &mut Fork) -> Result<(), Error> {
         info!("Processing tx: {:?}", self);
+        // Compliance rules are checked before any value is moved.
+        compliance::check_wallets(&*view, Some(self.pub_key()))?;
+        for meta in self.meta_assets() {
+            let id = assets::Schema(&*view).resolve_id(meta.data(), self.pub_key());
+            compliance::check_wallets(&*view, Some(meta.receiver()))?;
+            compliance::check_holder(&*view, meta.receiver(), &[meta.to_bundle(id)])?;
+        }
+
+        self.check_creator(&*view)?;
+        self.check_series(&*view)?;
+        let supplies = self.check_supply(&*view)?;
+
         let genesis_fees = CONFIGURATION.read().unwrap().fees();
         let tx_fee = genesis_fees.add_assets();
 
@@ -75,7 +141,7 @@ impl AddAssets {
         let key = self.pub_key();
 
         for meta in self.meta_assets() {
-            let id = AssetId::from_data(meta.data(), key);
+            let id = assets::Schema(&*view).resolve_id(meta.data(), key);
 
             let wallet = wallets
                 .entry(*meta.receiver())
@@ -90,7 +156,9 @@ impl AddAssets {
                 Entry::Vacant(entry) => {
                     let origin = self.hash();
                     let new_info = meta.to_info(key, &origin);
+                    // Assets with no units left are created anew.
                     let info = match assets::Schema(&*view).fetch(&id) {
+                        Some(ref info) if info.amount() == 0 => new_info,
                         Some(info) => info.merge(new_info)?,
                         None => new_info,
                     };
@@ -107,10 +175,21 @@ impl AddAssets {
             assets::Schema(&mut *view).store(&id, info);
         }
 
+        for (id, supply) in supplies {
+            assets::Schema(&mut *view).store_supply(&id, supply);
+        }
+
         Ok(())
     }
 }
 
+impl Processable for AddAssets {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
 lazy_static! {
     static ref VERIFY_COUNT: IntCounter = register_int_counter!(
         "dmbc_transaction_add_assets_verify_count",
@@ -165,7 +244,7 @@ impl Transaction for AddAssets {
         EXECUTE_COUNT.inc();
         let timer = EXECUTE_DURATION.start_timer();
 
-        let result = self.process(view);
+        let result = self.process_enabled(view);
 
         if let &Ok(_) = &result {
             EXECUTE_SUCCESS_COUNT.inc();
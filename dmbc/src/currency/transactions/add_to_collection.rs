@@ -0,0 +1,207 @@
+use std::collections::{HashMap, HashSet};
+
+use exonum::blockchain;
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets;
+use currency::assets::{AssetChange, AssetInfo, CollectionMember, Fees};
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const ADD_TO_COLLECTION_ID: u16 = 1402;
+
+message! {
+    /// `add_to_collection` transaction.
+    ///
+    /// Places existing assets into collections. Both the assets and the
+    /// collections must belong to the signer. Members that don't keep
+    /// their own fees take the default fees of their collection.
+    struct AddToCollection {
+        const TYPE = SERVICE_ID;
+        const ID = ADD_TO_COLLECTION_ID;
+
+        creator: &PublicKey,
+        members: Vec<CollectionMember>,
+        seed:    u64,
+    }
+}
+
+impl FeesCalculator for AddToCollection {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.creator() {
+            fees_table.insert(*self.creator(), genesis_fees.add_assets());
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl AddToCollection {
+    /// Check the members against the assets and collections they name.
+    ///
+    /// Returns the members along with the assets they place and, unless
+    /// they keep their own fees, the fees of their collection.
+    fn check_members(
+        &self,
+        view: &Fork,
+    ) -> Result<Vec<(CollectionMember, AssetInfo, Option<Fees>)>, Error> {
+        let mut checked = Vec::new();
+        for member in self.members() {
+            let id = member.id();
+            let info = assets::Schema(view)
+                .fetch(&id)
+                .ok_or(Error::AssetNotFound)?;
+            if info.creator() != self.creator() {
+                return Err(Error::NotAssetCreator);
+            }
+            if assets::Schema(view).fetch_asset_collection(&id).is_some() {
+                return Err(Error::InvalidAssetInfo);
+            }
+
+            let collection = assets::Schema(view)
+                .fetch_collection(member.collection())
+                .ok_or(Error::CollectionNotFound)?;
+            if collection.creator() != self.creator() {
+                return Err(Error::NotCollectionCreator);
+            }
+
+            let fees = if member.own_fees() {
+                None
+            } else {
+                Some(collection.fees())
+            };
+            checked.push((member, info, fees));
+        }
+        Ok(checked)
+    }
+
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        compliance::check_wallets(&*view, Some(self.creator()))?;
+
+        let members = self.check_members(&*view)?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut creator = wallet::Schema(&*view).fetch(self.creator());
+        wallet::move_coins(&mut creator, &mut genesis, genesis_fees.add_assets())?;
+
+        wallet::Schema(&mut *view).store(self.creator(), creator);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        let height = blockchain::Schema::new(&*view).height().next();
+        for (member, info, fees) in members {
+            let id = member.id();
+            assets::Schema(&mut *view).store_member(&id, member.collection());
+
+            let fees = match fees {
+                Some(ref fees) if *fees != info.fees() => fees.clone(),
+                _ => continue,
+            };
+            let change = AssetChange::new(
+                &self.hash(),
+                height.0,
+                info.creator(),
+                info.creator(),
+                info.fees(),
+                fees.clone(),
+            );
+            assets::Schema(&mut *view).store(&id, info.update_fees(fees));
+            assets::Schema(&mut *view).store_change(&id, change);
+        }
+
+        Ok(())
+    }
+}
+
+impl Processable for AddToCollection {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_add_to_collection_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_add_to_collection_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_add_to_collection_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_add_to_collection_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_add_to_collection_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_add_to_collection_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for AddToCollection {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        // Every asset is placed into a single collection.
+        let members = self.members();
+        let mut ids = HashSet::new();
+        let members_ok = !members.is_empty() && members.iter().all(|m| ids.insert(m.id()));
+
+        if cfg!(fuzzing) {
+            return members_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.creator());
+
+        if members_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -1,20 +1,56 @@
 #![allow(missing_docs)]
 
 use exonum::crypto;
-use exonum::crypto::{PublicKey, SecretKey};
+use exonum::crypto::{Hash, PublicKey, SecretKey};
+use exonum::messages::Message;
 use exonum::storage::StorageValue;
 
 use currency;
-use currency::assets::{AssetBundle, AssetId, Fees, MetaAsset, TradeAsset};
+use currency::assets::{AssetBundle, AssetId, CollectionMember, Fees, MetaAsset, MetadataRef,
+                       SupplyPolicy, TradeAsset};
+use currency::multisig::{MultisigConfig, MultisigMember};
+use currency::nft::{MetaToken, TokenId, TradeToken};
+use currency::transactions::accept_loan::AcceptLoan;
 use currency::transactions::add_assets::AddAssets;
-use currency::transactions::components::{FeeStrategy, Intermediary};
+use currency::transactions::add_to_collection::AddToCollection;
+use currency::transactions::approve::Approve;
+use currency::transactions::cancel_loan::CancelLoan;
+use currency::transactions::cart_checkout::{CartCheckout, CartItem};
+use currency::transactions::claim_collateral::ClaimCollateral;
+use currency::transactions::claim_vested::ClaimVested;
+use currency::transactions::components::{FeeStrategy, Intermediary, PartySignature};
+use currency::transactions::create_collection::CreateCollection;
+use currency::transactions::create_multisig::CreateMultisig;
+use currency::transactions::create_vesting::CreateVesting;
 use currency::transactions::delete_assets::DeleteAssets;
 use currency::transactions::exchange::{Exchange, ExchangeOffer};
 use currency::transactions::exchange_intermediary::{ExchangeIntermediary,
                                                     ExchangeOfferIntermediary};
+use currency::transactions::exchange_tokens::{ExchangeTokens, ExchangeTokensOffer};
+use currency::transactions::freeze_wallet::FreezeWallet;
+use currency::transactions::mint_tokens::MintTokens;
+use currency::transactions::multi_exchange::{ExchangeLeg, MultiExchange, MultiExchangeOffer};
+use currency::transactions::multi_transfer::{MultiTransfer, TransferOutput};
+use currency::transactions::multisig_approve::MultisigApprove;
+use currency::transactions::multisig_propose::MultisigPropose;
+use currency::transactions::multisig_spend::MultisigSpend;
+use currency::transactions::offer_loan::OfferLoan;
+use currency::transactions::return_loan::ReturnLoan;
+use currency::transactions::revoke_approval::RevokeApproval;
+use currency::transactions::set_asset_metadata::SetAssetMetadata;
+use currency::transactions::set_asset_restriction::SetAssetRestriction;
+use currency::transactions::set_asset_supply::SetAssetSupply;
 use currency::transactions::trade::{Trade, TradeOffer};
 use currency::transactions::trade_intermediary::{TradeIntermediary, TradeOfferIntermediary};
+use currency::transactions::trade_tokens::{TradeTokens, TradeTokensOffer};
 use currency::transactions::transfer::Transfer;
+use currency::transactions::transfer_asset_creator::TransferAssetCreator;
+use currency::transactions::transfer_from::TransferFrom;
+use currency::transactions::transfer_tokens::TransferTokens;
+use currency::transactions::unfreeze_wallet::UnfreezeWallet;
+use currency::transactions::update_allowlist::UpdateAllowlist;
+use currency::transactions::update_asset_fees::UpdateAssetFees;
+use currency::vesting::ReleaseCurve;
 
 pub struct Builder {
     public_key: Option<PublicKey>,
@@ -107,6 +143,11 @@ impl Builder {
         ExchangeIntermediaryBuilder::new(self.into())
     }
 
+    pub fn tx_multi_exchange(self) -> MultiExchangeBuilder {
+        self.validate();
+        MultiExchangeBuilder::new(self.into())
+    }
+
     pub fn tx_trade_assets(self) -> TradeBuilder {
         self.validate();
         TradeBuilder::new(self.into())
@@ -117,11 +158,161 @@ impl Builder {
         TradeIntermediaryBuilder::new(self.into())
     }
 
+    pub fn tx_cart_checkout(self) -> CartCheckoutBuilder {
+        self.validate();
+        CartCheckoutBuilder::new(self.into())
+    }
+
     pub fn tx_transfer(self) -> TransferBuilder {
         self.validate();
         TransferBuilder::new(self.into())
     }
 
+    pub fn tx_multi_transfer(self) -> MultiTransferBuilder {
+        self.validate();
+        MultiTransferBuilder::new(self.into())
+    }
+
+    pub fn tx_create_multisig(self) -> CreateMultisigBuilder {
+        self.validate();
+        CreateMultisigBuilder::new(self.into())
+    }
+
+    pub fn tx_multisig_spend(self) -> MultisigSpendBuilder {
+        self.validate();
+        MultisigSpendBuilder::new(self.into())
+    }
+
+    pub fn tx_multisig_propose(self) -> MultisigProposeBuilder {
+        self.validate();
+        MultisigProposeBuilder::new(self.into())
+    }
+
+    pub fn tx_multisig_approve(self) -> MultisigApproveBuilder {
+        self.validate();
+        MultisigApproveBuilder::new(self.into())
+    }
+
+    pub fn tx_approve(self) -> ApproveBuilder {
+        self.validate();
+        ApproveBuilder::new(self.into())
+    }
+
+    pub fn tx_revoke_approval(self) -> RevokeApprovalBuilder {
+        self.validate();
+        RevokeApprovalBuilder::new(self.into())
+    }
+
+    pub fn tx_transfer_from(self) -> TransferFromBuilder {
+        self.validate();
+        TransferFromBuilder::new(self.into())
+    }
+
+    pub fn tx_create_vesting(self) -> CreateVestingBuilder {
+        self.validate();
+        CreateVestingBuilder::new(self.into())
+    }
+
+    pub fn tx_claim_vested(self) -> ClaimVestedBuilder {
+        self.validate();
+        ClaimVestedBuilder::new(self.into())
+    }
+
+    pub fn tx_offer_loan(self) -> OfferLoanBuilder {
+        self.validate();
+        OfferLoanBuilder::new(self.into())
+    }
+
+    pub fn tx_accept_loan(self) -> AcceptLoanBuilder {
+        self.validate();
+        AcceptLoanBuilder::new(self.into())
+    }
+
+    pub fn tx_return_loan(self) -> ReturnLoanBuilder {
+        self.validate();
+        ReturnLoanBuilder::new(self.into())
+    }
+
+    pub fn tx_claim_collateral(self) -> ClaimCollateralBuilder {
+        self.validate();
+        ClaimCollateralBuilder::new(self.into())
+    }
+
+    pub fn tx_cancel_loan(self) -> CancelLoanBuilder {
+        self.validate();
+        CancelLoanBuilder::new(self.into())
+    }
+
+    pub fn tx_update_asset_fees(self) -> UpdateAssetFeesBuilder {
+        self.validate();
+        UpdateAssetFeesBuilder::new(self.into())
+    }
+
+    pub fn tx_transfer_asset_creator(self) -> TransferAssetCreatorBuilder {
+        self.validate();
+        TransferAssetCreatorBuilder::new(self.into())
+    }
+
+    pub fn tx_set_asset_restriction(self) -> SetAssetRestrictionBuilder {
+        self.validate();
+        SetAssetRestrictionBuilder::new(self.into())
+    }
+
+    pub fn tx_set_asset_metadata(self) -> SetAssetMetadataBuilder {
+        self.validate();
+        SetAssetMetadataBuilder::new(self.into())
+    }
+
+    pub fn tx_set_asset_supply(self) -> SetAssetSupplyBuilder {
+        self.validate();
+        SetAssetSupplyBuilder::new(self.into())
+    }
+
+    pub fn tx_update_allowlist(self) -> UpdateAllowlistBuilder {
+        self.validate();
+        UpdateAllowlistBuilder::new(self.into())
+    }
+
+    pub fn tx_freeze_wallet(self) -> FreezeWalletBuilder {
+        self.validate();
+        FreezeWalletBuilder::new(self.into())
+    }
+
+    pub fn tx_unfreeze_wallet(self) -> UnfreezeWalletBuilder {
+        self.validate();
+        UnfreezeWalletBuilder::new(self.into())
+    }
+
+    pub fn tx_mint_tokens(self) -> MintTokensBuilder {
+        self.validate();
+        MintTokensBuilder::new(self.into())
+    }
+
+    pub fn tx_create_collection(self) -> CreateCollectionBuilder {
+        self.validate();
+        CreateCollectionBuilder::new(self.into())
+    }
+
+    pub fn tx_add_to_collection(self) -> AddToCollectionBuilder {
+        self.validate();
+        AddToCollectionBuilder::new(self.into())
+    }
+
+    pub fn tx_exchange_tokens(self) -> ExchangeTokensBuilder {
+        self.validate();
+        ExchangeTokensBuilder::new(self.into())
+    }
+
+    pub fn tx_trade_tokens(self) -> TradeTokensBuilder {
+        self.validate();
+        TradeTokensBuilder::new(self.into())
+    }
+
+    pub fn tx_transfer_tokens(self) -> TransferTokensBuilder {
+        self.validate();
+        TransferTokensBuilder::new(self.into())
+    }
+
     fn validate(&self) {
         match (&self.public_key, &self.secret_key) {
             (&Some(_), &Some(_)) => (),
@@ -166,6 +357,11 @@ impl AddAssetBuilder {
         self.add_asset_value(asset)
     }
 
+    pub fn add_asset_metadata(self, metadata: MetadataRef, count: u64, fees: Fees) -> Self {
+        let asset = MetaAsset::from_metadata(&self.meta.public_key, &metadata, count, fees);
+        self.add_asset_value(asset)
+    }
+
     pub fn seed(self, seed: u64) -> Self {
         AddAssetBuilder { seed, ..self }
     }
@@ -715,6 +911,154 @@ impl TradeIntermediaryBuilder {
     }
 }
 
+pub struct MultiExchangeBuilder {
+    meta: TransactionMetadata,
+    legs: Vec<ExchangeLeg>,
+    signers: Vec<(PublicKey, SecretKey)>,
+    fee_strategy: FeeStrategy,
+    seed: u64,
+    data_info: Option<String>,
+}
+
+impl MultiExchangeBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        MultiExchangeBuilder {
+            meta,
+            legs: Vec::new(),
+            signers: Vec::new(),
+            fee_strategy: FeeStrategy::Recipient,
+            seed: 0,
+            data_info: None,
+        }
+    }
+
+    pub fn add_leg(
+        self,
+        from: PublicKey,
+        to: PublicKey,
+        value: u64,
+        assets: Vec<AssetBundle>,
+    ) -> Self {
+        let leg = ExchangeLeg::new(&from, &to, value, assets);
+        self.add_leg_value(leg)
+    }
+
+    pub fn add_leg_value(mut self, leg: ExchangeLeg) -> Self {
+        self.legs.push(leg);
+        self
+    }
+
+    pub fn signer(mut self, public_key: PublicKey, secret_key: SecretKey) -> Self {
+        self.signers.push((public_key, secret_key));
+        self
+    }
+
+    pub fn fee_strategy(self, fee_strategy: FeeStrategy) -> Self {
+        MultiExchangeBuilder {
+            fee_strategy,
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        MultiExchangeBuilder { seed, ..self }
+    }
+
+    pub fn data_info(self, data_info: &str) -> Self {
+        MultiExchangeBuilder {
+            data_info: Some(data_info.to_string()),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> MultiExchange {
+        self.verify();
+        let offer = MultiExchangeOffer::new(
+            self.legs,
+            self.fee_strategy as u8,
+            self.seed,
+            &self.data_info.unwrap_or_default(),
+        );
+        let signatures = self.signers
+            .iter()
+            .map(|&(ref public_key, ref secret_key)| {
+                let signature = crypto::sign(&offer.clone().into_bytes(), secret_key);
+                PartySignature::new(public_key, &signature)
+            })
+            .collect();
+        MultiExchange::new(
+            &self.meta.public_key,
+            offer,
+            signatures,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(!self.legs.is_empty());
+        assert!(!self.signers.is_empty());
+    }
+}
+
+pub struct CartCheckoutBuilder {
+    meta: TransactionMetadata,
+    items: Vec<CartItem>,
+    seed: u64,
+}
+
+impl CartCheckoutBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        CartCheckoutBuilder {
+            meta,
+            items: Vec::new(),
+            seed: 0,
+        }
+    }
+
+    pub fn add_offer(
+        self,
+        seller_public: PublicKey,
+        seller_secret: SecretKey,
+        assets: Vec<TradeAsset>,
+        fee_strategy: FeeStrategy,
+    ) -> Self {
+        let offer = TradeOffer::new(
+            &self.meta.public_key,
+            &seller_public,
+            assets,
+            fee_strategy as u8,
+            self.items.len() as u64,
+            "",
+        );
+        let signature = crypto::sign(&offer.clone().into_bytes(), &seller_secret);
+        self.add_item_value(CartItem::new(offer, &signature))
+    }
+
+    pub fn add_item_value(mut self, item: CartItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        CartCheckoutBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> CartCheckout {
+        self.verify();
+
+        CartCheckout::new(
+            &self.meta.public_key,
+            self.items,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(!self.items.is_empty());
+    }
+}
+
 pub struct TransferBuilder {
     meta: TransactionMetadata,
     recipient: Option<PublicKey>,
@@ -787,291 +1131,2613 @@ impl TransferBuilder {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use exonum::crypto;
-    use exonum::storage::StorageValue;
-
-    use currency::assets::{AssetBundle, MetaAsset, TradeAsset};
-
-    use currency::transactions::add_assets::AddAssets;
-    use currency::transactions::components::{FeeStrategy, Intermediary};
-    use currency::transactions::delete_assets::DeleteAssets;
-    use currency::transactions::exchange::{Exchange, ExchangeOffer};
-    use currency::transactions::exchange_intermediary::{ExchangeIntermediary,
-                                                        ExchangeOfferIntermediary};
-    use currency::transactions::trade::{Trade, TradeOffer};
-    use currency::transactions::trade_intermediary::{TradeIntermediary, TradeOfferIntermediary};
-    use currency::transactions::transfer::Transfer;
-
-    use currency::transactions::builders::fee;
-    use currency::transactions::builders::transaction;
+pub struct MultiTransferBuilder {
+    meta: TransactionMetadata,
+    outputs: Vec<TransferOutput>,
+    seed: u64,
+}
 
-    #[test]
-    #[should_panic]
-    fn meta_underspecified() {
-        transaction::Builder::new().tx_add_assets();
+impl MultiTransferBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        MultiTransferBuilder {
+            meta,
+            outputs: Vec::new(),
+            seed: 0,
+        }
     }
 
-    #[test]
-    fn not_equal() {
-        let (public_key, secret_key) = crypto::gen_keypair();
-        let (receiver_key, _) = crypto::gen_keypair();
-
-        let fees_foobar = fee::Builder::new()
-            .trade(10, "0.1".parse().unwrap())
-            .exchange(10, "0.1".parse().unwrap())
-            .transfer(10, "0.1".parse().unwrap())
-            .build();
+    pub fn add_output(
+        self,
+        recipient: PublicKey,
+        amount: u64,
+        assets: Vec<AssetBundle>,
+        data_info: &str,
+    ) -> Self {
+        let output = TransferOutput::new(&recipient, amount, assets, data_info);
+        self.add_output_value(output)
+    }
 
-        let fees_bazqux = fee::Builder::new()
-            .trade(11, "0.1".parse().unwrap())
-            .exchange(11, "0.1".parse().unwrap())
-            .transfer(11, "0.1".parse().unwrap())
-            .build();
+    pub fn add_output_value(mut self, output: TransferOutput) -> Self {
+        self.outputs.push(output);
+        self
+    }
 
-        let asset_foobar = MetaAsset::new(&receiver_key, "foobar", 9, fees_foobar);
-        let asset_bazqux = MetaAsset::new(&receiver_key, "bazqux", 18, fees_bazqux);
+    pub fn seed(self, seed: u64) -> Self {
+        MultiTransferBuilder { seed, ..self }
+    }
 
-        let transaction = transaction::Builder::new()
-            .keypair(public_key, secret_key.clone())
-            .tx_add_assets()
-            .add_asset_value(asset_foobar.clone())
-            .build();
+    pub fn build(self) -> MultiTransfer {
+        self.verify();
 
-        let assets = vec![asset_bazqux];
-        let equivalent = AddAssets::new(&public_key, assets, 0, &secret_key);
+        MultiTransfer::new(
+            &self.meta.public_key,
+            self.outputs,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
 
-        assert_ne!(transaction, equivalent);
+    fn verify(&self) {
+        assert!(!self.outputs.is_empty());
     }
+}
 
-    #[test]
-    fn add_assets() {
+pub struct CreateMultisigBuilder {
+    meta: TransactionMetadata,
+    members: Vec<MultisigMember>,
+    threshold: u8,
+    seed: u64,
+}
+
+impl CreateMultisigBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        CreateMultisigBuilder {
+            meta,
+            members: Vec::new(),
+            threshold: 0,
+            seed: 0,
+        }
+    }
+
+    pub fn add_member(mut self, pub_key: PublicKey) -> Self {
+        self.members.push(MultisigMember::new(&pub_key));
+        self
+    }
+
+    pub fn threshold(self, threshold: u8) -> Self {
+        CreateMultisigBuilder { threshold, ..self }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        CreateMultisigBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> CreateMultisig {
+        self.verify();
+
+        CreateMultisig::new(
+            &self.meta.public_key,
+            MultisigConfig::new(self.members, self.threshold),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(!self.members.is_empty());
+        assert!(self.threshold > 0);
+    }
+}
+
+pub struct MultisigSpendBuilder {
+    meta: TransactionMetadata,
+    wallet: Option<PublicKey>,
+    tx: Option<Vec<u8>>,
+    signers: Vec<(PublicKey, SecretKey)>,
+}
+
+impl MultisigSpendBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        MultisigSpendBuilder {
+            meta,
+            wallet: None,
+            tx: None,
+            signers: Vec::new(),
+        }
+    }
+
+    pub fn wallet(self, wallet: PublicKey) -> Self {
+        MultisigSpendBuilder {
+            wallet: Some(wallet),
+            ..self
+        }
+    }
+
+    pub fn tx_raw(self, tx: Vec<u8>) -> Self {
+        MultisigSpendBuilder {
+            tx: Some(tx),
+            ..self
+        }
+    }
+
+    pub fn tx_value<T: Message>(self, tx: &T) -> Self {
+        self.tx_raw(tx.raw().as_ref().to_vec())
+    }
+
+    pub fn signer(mut self, public_key: PublicKey, secret_key: SecretKey) -> Self {
+        self.signers.push((public_key, secret_key));
+        self
+    }
+
+    pub fn build(self) -> MultisigSpend {
+        self.verify();
+
+        let tx = self.tx.unwrap();
+        let signatures = self.signers
+            .iter()
+            .map(|&(ref public_key, ref secret_key)| {
+                PartySignature::new(public_key, &crypto::sign(&tx, secret_key))
+            })
+            .collect();
+        MultisigSpend::new(
+            &self.meta.public_key,
+            self.wallet.as_ref().unwrap(),
+            &tx,
+            signatures,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.wallet.is_some());
+        assert!(self.tx.is_some());
+        assert!(!self.signers.is_empty());
+    }
+}
+
+pub struct MultisigProposeBuilder {
+    meta: TransactionMetadata,
+    wallet: Option<PublicKey>,
+    tx: Option<Vec<u8>>,
+    seed: u64,
+}
+
+impl MultisigProposeBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        MultisigProposeBuilder {
+            meta,
+            wallet: None,
+            tx: None,
+            seed: 0,
+        }
+    }
+
+    pub fn wallet(self, wallet: PublicKey) -> Self {
+        MultisigProposeBuilder {
+            wallet: Some(wallet),
+            ..self
+        }
+    }
+
+    pub fn tx_raw(self, tx: Vec<u8>) -> Self {
+        MultisigProposeBuilder {
+            tx: Some(tx),
+            ..self
+        }
+    }
+
+    pub fn tx_value<T: Message>(self, tx: &T) -> Self {
+        self.tx_raw(tx.raw().as_ref().to_vec())
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        MultisigProposeBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> MultisigPropose {
+        self.verify();
+
+        MultisigPropose::new(
+            &self.meta.public_key,
+            self.wallet.as_ref().unwrap(),
+            self.tx.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.wallet.is_some());
+        assert!(self.tx.is_some());
+    }
+}
+
+pub struct MultisigApproveBuilder {
+    meta: TransactionMetadata,
+    proposal: Option<Hash>,
+    seed: u64,
+}
+
+impl MultisigApproveBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        MultisigApproveBuilder {
+            meta,
+            proposal: None,
+            seed: 0,
+        }
+    }
+
+    pub fn proposal(self, proposal: Hash) -> Self {
+        MultisigApproveBuilder {
+            proposal: Some(proposal),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        MultisigApproveBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> MultisigApprove {
+        self.verify();
+
+        MultisigApprove::new(
+            &self.meta.public_key,
+            self.proposal.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.proposal.is_some());
+    }
+}
+
+pub struct ApproveBuilder {
+    meta: TransactionMetadata,
+    spender: Option<PublicKey>,
+    amount: u64,
+    assets: Vec<AssetBundle>,
+    expires_at: u64,
+    seed: u64,
+}
+
+impl ApproveBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        ApproveBuilder {
+            meta,
+            spender: None,
+            amount: 0,
+            assets: Vec::new(),
+            expires_at: 0,
+            seed: 0,
+        }
+    }
+
+    pub fn spender(self, pub_key: PublicKey) -> Self {
+        ApproveBuilder {
+            spender: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn amount(self, amount: u64) -> Self {
+        ApproveBuilder { amount, ..self }
+    }
+
+    pub fn add_asset_value(mut self, asset: AssetBundle) -> Self {
+        self.assets.push(asset);
+        self
+    }
+
+    pub fn expires_at(self, expires_at: u64) -> Self {
+        ApproveBuilder { expires_at, ..self }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        ApproveBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> Approve {
+        self.verify();
+
+        Approve::new(
+            &self.meta.public_key,
+            self.spender.as_ref().unwrap(),
+            self.amount,
+            self.assets,
+            self.expires_at,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.spender.is_some());
+    }
+}
+
+pub struct RevokeApprovalBuilder {
+    meta: TransactionMetadata,
+    spender: Option<PublicKey>,
+    seed: u64,
+}
+
+impl RevokeApprovalBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        RevokeApprovalBuilder {
+            meta,
+            spender: None,
+            seed: 0,
+        }
+    }
+
+    pub fn spender(self, pub_key: PublicKey) -> Self {
+        RevokeApprovalBuilder {
+            spender: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        RevokeApprovalBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> RevokeApproval {
+        self.verify();
+
+        RevokeApproval::new(
+            &self.meta.public_key,
+            self.spender.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.spender.is_some());
+    }
+}
+
+pub struct TransferFromBuilder {
+    meta: TransactionMetadata,
+    owner: Option<PublicKey>,
+    recipient: Option<PublicKey>,
+    amount: u64,
+    assets: Vec<AssetBundle>,
+    seed: u64,
+    data_info: Option<String>,
+}
+
+impl TransferFromBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        TransferFromBuilder {
+            meta,
+            owner: None,
+            recipient: None,
+            amount: 0,
+            assets: Vec::new(),
+            seed: 0,
+            data_info: None,
+        }
+    }
+
+    pub fn owner(self, pub_key: PublicKey) -> Self {
+        TransferFromBuilder {
+            owner: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn recipient(self, pub_key: PublicKey) -> Self {
+        TransferFromBuilder {
+            recipient: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn amount(self, amount: u64) -> Self {
+        TransferFromBuilder { amount, ..self }
+    }
+
+    pub fn add_asset_value(mut self, asset: AssetBundle) -> Self {
+        self.assets.push(asset);
+        self
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        TransferFromBuilder { seed, ..self }
+    }
+
+    pub fn data_info(self, data_info: &str) -> Self {
+        TransferFromBuilder {
+            data_info: Some(data_info.to_string()),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> TransferFrom {
+        self.verify();
+
+        TransferFrom::new(
+            &self.meta.public_key,
+            self.owner.as_ref().unwrap(),
+            self.recipient.as_ref().unwrap(),
+            self.amount,
+            self.assets,
+            self.seed,
+            &self.data_info.unwrap_or_default(),
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.owner.is_some());
+        assert!(self.recipient.is_some());
+    }
+}
+
+pub struct CreateVestingBuilder {
+    meta: TransactionMetadata,
+    beneficiary: Option<PublicKey>,
+    amount: u64,
+    assets: Vec<AssetBundle>,
+    cliff: u64,
+    end: u64,
+    curve: Option<ReleaseCurve>,
+    seed: u64,
+}
+
+impl CreateVestingBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        CreateVestingBuilder {
+            meta,
+            beneficiary: None,
+            amount: 0,
+            assets: Vec::new(),
+            cliff: 0,
+            end: 0,
+            curve: None,
+            seed: 0,
+        }
+    }
+
+    pub fn beneficiary(self, pub_key: PublicKey) -> Self {
+        CreateVestingBuilder {
+            beneficiary: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn amount(self, amount: u64) -> Self {
+        CreateVestingBuilder { amount, ..self }
+    }
+
+    pub fn add_asset_value(mut self, asset: AssetBundle) -> Self {
+        self.assets.push(asset);
+        self
+    }
+
+    pub fn cliff(self, cliff: u64) -> Self {
+        CreateVestingBuilder { cliff, ..self }
+    }
+
+    pub fn end(self, end: u64) -> Self {
+        CreateVestingBuilder { end, ..self }
+    }
+
+    pub fn curve(self, curve: ReleaseCurve) -> Self {
+        CreateVestingBuilder {
+            curve: Some(curve),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        CreateVestingBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> CreateVesting {
+        self.verify();
+
+        CreateVesting::new(
+            &self.meta.public_key,
+            self.beneficiary.as_ref().unwrap(),
+            self.amount,
+            self.assets,
+            self.cliff,
+            self.end,
+            self.curve.unwrap() as u8,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.beneficiary.is_some());
+        assert!(self.curve.is_some());
+    }
+}
+
+pub struct ClaimVestedBuilder {
+    meta: TransactionMetadata,
+    vesting: Option<Hash>,
+    seed: u64,
+}
+
+impl ClaimVestedBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        ClaimVestedBuilder {
+            meta,
+            vesting: None,
+            seed: 0,
+        }
+    }
+
+    pub fn vesting(self, vesting: Hash) -> Self {
+        ClaimVestedBuilder {
+            vesting: Some(vesting),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        ClaimVestedBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> ClaimVested {
+        self.verify();
+
+        ClaimVested::new(
+            &self.meta.public_key,
+            self.vesting.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.vesting.is_some());
+    }
+}
+
+pub struct OfferLoanBuilder {
+    meta: TransactionMetadata,
+    assets: Vec<AssetBundle>,
+    collateral: u64,
+    rental_fee: u64,
+    duration: u64,
+    seed: u64,
+}
+
+impl OfferLoanBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        OfferLoanBuilder {
+            meta,
+            assets: Vec::new(),
+            collateral: 0,
+            rental_fee: 0,
+            duration: 0,
+            seed: 0,
+        }
+    }
+
+    pub fn add_asset_value(mut self, asset: AssetBundle) -> Self {
+        self.assets.push(asset);
+        self
+    }
+
+    pub fn collateral(self, collateral: u64) -> Self {
+        OfferLoanBuilder { collateral, ..self }
+    }
+
+    pub fn rental_fee(self, rental_fee: u64) -> Self {
+        OfferLoanBuilder { rental_fee, ..self }
+    }
+
+    pub fn duration(self, duration: u64) -> Self {
+        OfferLoanBuilder { duration, ..self }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        OfferLoanBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> OfferLoan {
+        self.verify();
+
+        OfferLoan::new(
+            &self.meta.public_key,
+            self.assets,
+            self.collateral,
+            self.rental_fee,
+            self.duration,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(!self.assets.is_empty());
+    }
+}
+
+pub struct AcceptLoanBuilder {
+    meta: TransactionMetadata,
+    loan: Option<Hash>,
+    seed: u64,
+}
+
+impl AcceptLoanBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        AcceptLoanBuilder {
+            meta,
+            loan: None,
+            seed: 0,
+        }
+    }
+
+    pub fn loan(self, loan: Hash) -> Self {
+        AcceptLoanBuilder {
+            loan: Some(loan),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        AcceptLoanBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> AcceptLoan {
+        self.verify();
+
+        AcceptLoan::new(
+            &self.meta.public_key,
+            self.loan.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.loan.is_some());
+    }
+}
+
+pub struct ReturnLoanBuilder {
+    meta: TransactionMetadata,
+    loan: Option<Hash>,
+    seed: u64,
+}
+
+impl ReturnLoanBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        ReturnLoanBuilder {
+            meta,
+            loan: None,
+            seed: 0,
+        }
+    }
+
+    pub fn loan(self, loan: Hash) -> Self {
+        ReturnLoanBuilder {
+            loan: Some(loan),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        ReturnLoanBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> ReturnLoan {
+        self.verify();
+
+        ReturnLoan::new(
+            &self.meta.public_key,
+            self.loan.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.loan.is_some());
+    }
+}
+
+pub struct ClaimCollateralBuilder {
+    meta: TransactionMetadata,
+    loan: Option<Hash>,
+    seed: u64,
+}
+
+impl ClaimCollateralBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        ClaimCollateralBuilder {
+            meta,
+            loan: None,
+            seed: 0,
+        }
+    }
+
+    pub fn loan(self, loan: Hash) -> Self {
+        ClaimCollateralBuilder {
+            loan: Some(loan),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        ClaimCollateralBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> ClaimCollateral {
+        self.verify();
+
+        ClaimCollateral::new(
+            &self.meta.public_key,
+            self.loan.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.loan.is_some());
+    }
+}
+
+pub struct CancelLoanBuilder {
+    meta: TransactionMetadata,
+    loan: Option<Hash>,
+    seed: u64,
+}
+
+impl CancelLoanBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        CancelLoanBuilder {
+            meta,
+            loan: None,
+            seed: 0,
+        }
+    }
+
+    pub fn loan(self, loan: Hash) -> Self {
+        CancelLoanBuilder {
+            loan: Some(loan),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        CancelLoanBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> CancelLoan {
+        self.verify();
+
+        CancelLoan::new(
+            &self.meta.public_key,
+            self.loan.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.loan.is_some());
+    }
+}
+
+pub struct UpdateAssetFeesBuilder {
+    meta: TransactionMetadata,
+    asset_id: Option<AssetId>,
+    fees: Option<Fees>,
+    seed: u64,
+}
+
+impl UpdateAssetFeesBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        UpdateAssetFeesBuilder {
+            meta,
+            asset_id: None,
+            fees: None,
+            seed: 0,
+        }
+    }
+
+    pub fn asset_id(self, asset_id: AssetId) -> Self {
+        UpdateAssetFeesBuilder {
+            asset_id: Some(asset_id),
+            ..self
+        }
+    }
+
+    pub fn fees(self, fees: Fees) -> Self {
+        UpdateAssetFeesBuilder {
+            fees: Some(fees),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        UpdateAssetFeesBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> UpdateAssetFees {
+        self.verify();
+
+        UpdateAssetFees::new(
+            &self.meta.public_key,
+            self.asset_id.unwrap(),
+            self.fees.unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.asset_id.is_some());
+        assert!(self.fees.is_some());
+    }
+}
+
+pub struct TransferAssetCreatorBuilder {
+    meta: TransactionMetadata,
+    asset_id: Option<AssetId>,
+    new_creator: Option<PublicKey>,
+    seed: u64,
+}
+
+impl TransferAssetCreatorBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        TransferAssetCreatorBuilder {
+            meta,
+            asset_id: None,
+            new_creator: None,
+            seed: 0,
+        }
+    }
+
+    pub fn asset_id(self, asset_id: AssetId) -> Self {
+        TransferAssetCreatorBuilder {
+            asset_id: Some(asset_id),
+            ..self
+        }
+    }
+
+    pub fn new_creator(self, pub_key: PublicKey) -> Self {
+        TransferAssetCreatorBuilder {
+            new_creator: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        TransferAssetCreatorBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> TransferAssetCreator {
+        self.verify();
+
+        TransferAssetCreator::new(
+            &self.meta.public_key,
+            self.asset_id.unwrap(),
+            self.new_creator.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.asset_id.is_some());
+        assert!(self.new_creator.is_some());
+    }
+}
+
+pub struct SetAssetRestrictionBuilder {
+    meta: TransactionMetadata,
+    asset_id: Option<AssetId>,
+    registry: Hash,
+    seed: u64,
+}
+
+impl SetAssetRestrictionBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        SetAssetRestrictionBuilder {
+            meta,
+            asset_id: None,
+            registry: Hash::zero(),
+            seed: 0,
+        }
+    }
+
+    pub fn asset_id(self, asset_id: AssetId) -> Self {
+        SetAssetRestrictionBuilder {
+            asset_id: Some(asset_id),
+            ..self
+        }
+    }
+
+    pub fn registry(self, registry: Hash) -> Self {
+        SetAssetRestrictionBuilder { registry, ..self }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        SetAssetRestrictionBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> SetAssetRestriction {
+        self.verify();
+
+        SetAssetRestriction::new(
+            &self.meta.public_key,
+            self.asset_id.unwrap(),
+            &self.registry,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.asset_id.is_some());
+    }
+}
+
+pub struct SetAssetMetadataBuilder {
+    meta: TransactionMetadata,
+    metadata: Vec<MetadataRef>,
+    seed: u64,
+}
+
+impl SetAssetMetadataBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        SetAssetMetadataBuilder {
+            meta,
+            metadata: Vec::new(),
+            seed: 0,
+        }
+    }
+
+    pub fn add_metadata(mut self, metadata: MetadataRef) -> Self {
+        self.metadata.push(metadata);
+        self
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        SetAssetMetadataBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> SetAssetMetadata {
+        self.verify();
+
+        SetAssetMetadata::new(
+            &self.meta.public_key,
+            self.metadata,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(!self.metadata.is_empty());
+    }
+}
+
+pub struct SetAssetSupplyBuilder {
+    meta: TransactionMetadata,
+    asset_id: Option<AssetId>,
+    max_supply: u64,
+    sealed: bool,
+    seed: u64,
+}
+
+impl SetAssetSupplyBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        SetAssetSupplyBuilder {
+            meta,
+            asset_id: None,
+            max_supply: 0,
+            sealed: false,
+            seed: 0,
+        }
+    }
+
+    pub fn asset_id(self, asset_id: AssetId) -> Self {
+        SetAssetSupplyBuilder {
+            asset_id: Some(asset_id),
+            ..self
+        }
+    }
+
+    pub fn max_supply(self, max_supply: u64) -> Self {
+        SetAssetSupplyBuilder { max_supply, ..self }
+    }
+
+    pub fn sealed(self, sealed: bool) -> Self {
+        SetAssetSupplyBuilder { sealed, ..self }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        SetAssetSupplyBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> SetAssetSupply {
+        self.verify();
+
+        let policy = SupplyPolicy::new(self.asset_id.unwrap(), self.max_supply, self.sealed);
+        SetAssetSupply::new(
+            &self.meta.public_key,
+            policy,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.asset_id.is_some());
+    }
+}
+
+pub struct UpdateAllowlistBuilder {
+    meta: TransactionMetadata,
+    registry: Option<Hash>,
+    holder: Option<PublicKey>,
+    allowed: bool,
+    seed: u64,
+}
+
+impl UpdateAllowlistBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        UpdateAllowlistBuilder {
+            meta,
+            registry: None,
+            holder: None,
+            allowed: true,
+            seed: 0,
+        }
+    }
+
+    pub fn registry(self, registry: Hash) -> Self {
+        UpdateAllowlistBuilder {
+            registry: Some(registry),
+            ..self
+        }
+    }
+
+    pub fn holder(self, pub_key: PublicKey) -> Self {
+        UpdateAllowlistBuilder {
+            holder: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn allowed(self, allowed: bool) -> Self {
+        UpdateAllowlistBuilder { allowed, ..self }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        UpdateAllowlistBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> UpdateAllowlist {
+        self.verify();
+
+        UpdateAllowlist::new(
+            &self.meta.public_key,
+            self.registry.as_ref().unwrap(),
+            self.holder.as_ref().unwrap(),
+            self.allowed,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.registry.is_some());
+        assert!(self.holder.is_some());
+    }
+}
+
+pub struct FreezeWalletBuilder {
+    meta: TransactionMetadata,
+    wallet: Option<PublicKey>,
+    reason: Option<String>,
+    seed: u64,
+}
+
+impl FreezeWalletBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        FreezeWalletBuilder {
+            meta,
+            wallet: None,
+            reason: None,
+            seed: 0,
+        }
+    }
+
+    pub fn wallet(self, pub_key: PublicKey) -> Self {
+        FreezeWalletBuilder {
+            wallet: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn reason(self, reason: &str) -> Self {
+        FreezeWalletBuilder {
+            reason: Some(reason.to_string()),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        FreezeWalletBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> FreezeWallet {
+        self.verify();
+
+        FreezeWallet::new(
+            &self.meta.public_key,
+            self.wallet.as_ref().unwrap(),
+            &self.reason.unwrap_or_default(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.wallet.is_some());
+    }
+}
+
+pub struct UnfreezeWalletBuilder {
+    meta: TransactionMetadata,
+    wallet: Option<PublicKey>,
+    seed: u64,
+}
+
+impl UnfreezeWalletBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        UnfreezeWalletBuilder {
+            meta,
+            wallet: None,
+            seed: 0,
+        }
+    }
+
+    pub fn wallet(self, pub_key: PublicKey) -> Self {
+        UnfreezeWalletBuilder {
+            wallet: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        UnfreezeWalletBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> UnfreezeWallet {
+        self.verify();
+
+        UnfreezeWallet::new(
+            &self.meta.public_key,
+            self.wallet.as_ref().unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.wallet.is_some());
+    }
+}
+
+pub struct MintTokensBuilder {
+    meta: TransactionMetadata,
+    receiver: Option<PublicKey>,
+    name: Option<String>,
+    fees: Option<Fees>,
+    tokens: Vec<MetaToken>,
+    seed: u64,
+}
+
+impl MintTokensBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        MintTokensBuilder {
+            meta,
+            receiver: None,
+            name: None,
+            fees: None,
+            tokens: Vec::new(),
+            seed: 0,
+        }
+    }
+
+    pub fn receiver(self, pub_key: PublicKey) -> Self {
+        MintTokensBuilder {
+            receiver: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn name(self, name: &str) -> Self {
+        MintTokensBuilder {
+            name: Some(name.to_string()),
+            ..self
+        }
+    }
+
+    pub fn fees(self, fees: Fees) -> Self {
+        MintTokensBuilder {
+            fees: Some(fees),
+            ..self
+        }
+    }
+
+    pub fn add_token(self, serial: u64, data: &str) -> Self {
+        self.add_token_value(MetaToken::new(serial, data))
+    }
+
+    pub fn add_token_value(mut self, token: MetaToken) -> Self {
+        self.tokens.push(token);
+        self
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        MintTokensBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> MintTokens {
+        self.verify();
+
+        MintTokens::new(
+            &self.meta.public_key,
+            &self.receiver.unwrap_or(self.meta.public_key),
+            &self.name.unwrap(),
+            self.fees.unwrap(),
+            self.tokens,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.name.is_some());
+        assert!(self.fees.is_some());
+        assert!(!self.tokens.is_empty());
+    }
+}
+
+pub struct CreateCollectionBuilder {
+    meta: TransactionMetadata,
+    parent: Hash,
+    name: Option<String>,
+    data: Option<String>,
+    fees: Option<Fees>,
+    seed: u64,
+}
+
+impl CreateCollectionBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        CreateCollectionBuilder {
+            meta,
+            parent: Hash::zero(),
+            name: None,
+            data: None,
+            fees: None,
+            seed: 0,
+        }
+    }
+
+    pub fn parent(self, parent: Hash) -> Self {
+        CreateCollectionBuilder { parent, ..self }
+    }
+
+    pub fn name(self, name: &str) -> Self {
+        CreateCollectionBuilder {
+            name: Some(name.to_string()),
+            ..self
+        }
+    }
+
+    pub fn data(self, data: &str) -> Self {
+        CreateCollectionBuilder {
+            data: Some(data.to_string()),
+            ..self
+        }
+    }
+
+    pub fn fees(self, fees: Fees) -> Self {
+        CreateCollectionBuilder {
+            fees: Some(fees),
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        CreateCollectionBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> CreateCollection {
+        self.verify();
+
+        CreateCollection::new(
+            &self.meta.public_key,
+            &self.parent,
+            &self.name.unwrap(),
+            &self.data.unwrap_or_default(),
+            self.fees.unwrap(),
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.name.is_some());
+        assert!(self.fees.is_some());
+    }
+}
+
+pub struct AddToCollectionBuilder {
+    meta: TransactionMetadata,
+    members: Vec<CollectionMember>,
+    seed: u64,
+}
+
+impl AddToCollectionBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        AddToCollectionBuilder {
+            meta,
+            members: Vec::new(),
+            seed: 0,
+        }
+    }
+
+    pub fn add_asset(self, name: &str, collection: Hash, own_fees: bool) -> Self {
+        let id = AssetId::from_data(name, &self.meta.public_key);
+        let member = CollectionMember::new(id, &collection, own_fees);
+        self.add_member_value(member)
+    }
+
+    pub fn add_member_value(mut self, member: CollectionMember) -> Self {
+        self.members.push(member);
+        self
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        AddToCollectionBuilder { seed, ..self }
+    }
+
+    pub fn build(self) -> AddToCollection {
+        self.verify();
+
+        AddToCollection::new(
+            &self.meta.public_key,
+            self.members,
+            self.seed,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(!self.members.is_empty());
+    }
+}
+
+pub struct ExchangeTokensBuilder {
+    meta: TransactionMetadata,
+
+    sender: Option<PublicKey>,
+    sender_secret: Option<SecretKey>,
+
+    sender_assets: Vec<AssetBundle>,
+    sender_tokens: Vec<TokenId>,
+    sender_value: u64,
+
+    recipient_assets: Vec<AssetBundle>,
+    recipient_tokens: Vec<TokenId>,
+
+    fee_strategy: FeeStrategy,
+
+    seed: u64,
+
+    data_info: Option<String>,
+}
+
+impl ExchangeTokensBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        ExchangeTokensBuilder {
+            meta,
+
+            sender: None,
+            sender_secret: None,
+
+            sender_assets: Vec::new(),
+            sender_tokens: Vec::new(),
+            sender_value: 0,
+
+            recipient_assets: Vec::new(),
+            recipient_tokens: Vec::new(),
+
+            fee_strategy: FeeStrategy::Recipient,
+
+            seed: 0,
+
+            data_info: None,
+        }
+    }
+    pub fn sender(self, pub_key: PublicKey) -> Self {
+        ExchangeTokensBuilder {
+            sender: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn sender_secret(self, secret_key: SecretKey) -> Self {
+        ExchangeTokensBuilder {
+            sender_secret: Some(secret_key),
+            ..self
+        }
+    }
+
+    pub fn sender_add_asset(self, name: &str, count: u64) -> Self {
+        let asset = AssetBundle::from_data(name, count, &self.sender.unwrap());
+        self.sender_add_asset_value(asset)
+    }
+
+    pub fn sender_add_asset_value(mut self, asset: AssetBundle) -> Self {
+        self.sender_assets.push(asset);
+        self
+    }
+
+    pub fn sender_add_token(mut self, token: TokenId) -> Self {
+        self.sender_tokens.push(token);
+        self
+    }
+
+    pub fn sender_value(self, sender_value: u64) -> Self {
+        ExchangeTokensBuilder {
+            sender_value,
+            ..self
+        }
+    }
+
+    pub fn recipient_add_asset(self, name: &str, count: u64) -> Self {
+        let asset = AssetBundle::from_data(name, count, &self.meta.public_key);
+        self.recipient_add_asset_value(asset)
+    }
+
+    pub fn recipient_add_asset_value(mut self, asset: AssetBundle) -> Self {
+        self.recipient_assets.push(asset);
+        self
+    }
+
+    pub fn recipient_add_token(mut self, token: TokenId) -> Self {
+        self.recipient_tokens.push(token);
+        self
+    }
+
+    pub fn fee_strategy(self, fee_strategy: FeeStrategy) -> Self {
+        ExchangeTokensBuilder {
+            fee_strategy,
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        ExchangeTokensBuilder { seed, ..self }
+    }
+
+    pub fn data_info(self, data_info: &str) -> Self {
+        ExchangeTokensBuilder {
+            data_info: Some(data_info.to_string()),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> ExchangeTokens {
+        self.verify();
+        let offer = ExchangeTokensOffer::new(
+            self.sender.as_ref().unwrap(),
+            self.sender_assets,
+            self.sender_tokens,
+            self.sender_value,
+            &self.meta.public_key,
+            self.recipient_assets,
+            self.recipient_tokens,
+            self.fee_strategy as u8,
+            self.seed,
+            &self.data_info.unwrap_or_default(),
+        );
+        let sender_signature =
+            crypto::sign(&offer.clone().into_bytes(), &self.sender_secret.unwrap());
+        ExchangeTokens::new(
+            offer,
+            &sender_signature,
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.sender.is_some());
+        assert!(self.sender_secret.is_some());
+    }
+}
+
+pub struct TradeTokensBuilder {
+    meta: TransactionMetadata,
+    seller_public: Option<PublicKey>,
+    seller_secret: Option<SecretKey>,
+    assets: Vec<TradeAsset>,
+    data_for_assets: Vec<(String, u64, u64)>,
+    tokens: Vec<TradeToken>,
+    fee_strategy: FeeStrategy,
+    seed: u64,
+    data_info: Option<String>,
+
+}
+
+impl TradeTokensBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        TradeTokensBuilder {
+            meta,
+            seller_public: None,
+            seller_secret: None,
+            assets: Vec::new(),
+            data_for_assets: Vec::new(),
+            tokens: Vec::new(),
+            fee_strategy: FeeStrategy::Recipient,
+            seed: 0,
+            data_info: None,
+        }
+    }
+
+    pub fn seller(self, pub_key: PublicKey, sec_key: SecretKey) -> Self {
+        TradeTokensBuilder {
+            seller_public: Some(pub_key),
+            seller_secret: Some(sec_key),
+            ..self
+        }
+    }
+
+    pub fn add_asset(mut self, name: &str, count: u64, price: u64) -> Self {
+        self.data_for_assets.push((name.to_string(), count, price));
+        self
+    }
+
+    pub fn add_asset_value(mut self, asset: TradeAsset) -> Self {
+        self.assets.push(asset);
+        self
+    }
+
+    pub fn add_token(self, token: TokenId, price: u64) -> Self {
+        self.add_token_value(TradeToken::new(token, price))
+    }
+
+    pub fn add_token_value(mut self, token: TradeToken) -> Self {
+        self.tokens.push(token);
+        self
+    }
+
+    pub fn fee_strategy(self, fee_strategy: FeeStrategy) -> Self {
+        TradeTokensBuilder {
+            fee_strategy,
+            ..self
+        }
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        TradeTokensBuilder { seed, ..self }
+    }
+
+    pub fn data_info(self, data_info: &str) -> Self {
+        TradeTokensBuilder {
+            data_info: Some(data_info.to_string()),
+            ..self
+        }
+    }
+
+    pub fn build(mut self) -> TradeTokens {
+        self.verify();
+
+        for (name, count, price) in self.data_for_assets {
+            let id = AssetId::from_data(&name, &self.seller_public.unwrap());
+            let asset = TradeAsset::new(id, count, price);
+            self.assets.push(asset);
+        }
+
+        let offer = TradeTokensOffer::new(
+            &self.meta.public_key,
+            &self.seller_public.unwrap(),
+            self.assets,
+            self.tokens,
+            self.fee_strategy as u8,
+            self.seed,
+            &self.data_info.unwrap_or_default(),
+        );
+        let signature = crypto::sign(&offer.clone().into_bytes(), &self.seller_secret.unwrap());
+        TradeTokens::new(offer, &signature, &self.meta.secret_key)
+    }
+
+    fn verify(&self) {
+        assert!(self.seller_public.is_some());
+        assert!(self.seller_secret.is_some());
+    }
+}
+
+pub struct TransferTokensBuilder {
+    meta: TransactionMetadata,
+    recipient: Option<PublicKey>,
+    amount: u64,
+    assets: Vec<AssetBundle>,
+    tokens: Vec<TokenId>,
+    seed: u64,
+    data_info: Option<String>,
+}
+
+impl TransferTokensBuilder {
+    fn new(meta: TransactionMetadata) -> Self {
+        TransferTokensBuilder {
+            meta,
+            recipient: None,
+            amount: 0,
+            assets: Vec::new(),
+            tokens: Vec::new(),
+            seed: 0,
+            data_info: None,
+        }
+    }
+
+    pub fn recipient(self, pub_key: PublicKey) -> Self {
+        TransferTokensBuilder {
+            recipient: Some(pub_key),
+            ..self
+        }
+    }
+
+    pub fn amount(self, amount: u64) -> Self {
+        TransferTokensBuilder { amount, ..self }
+    }
+
+    pub fn add_asset(self, name: &str, count: u64) -> Self {
+        let asset = AssetBundle::from_data(name, count, &self.meta.public_key);
+        self.add_asset_value(asset)
+    }
+
+    pub fn add_asset_value(mut self, asset: AssetBundle) -> Self {
+        self.assets.push(asset);
+        self
+    }
+
+    pub fn add_token(mut self, token: TokenId) -> Self {
+        self.tokens.push(token);
+        self
+    }
+
+    pub fn seed(self, seed: u64) -> Self {
+        TransferTokensBuilder { seed, ..self }
+    }
+
+    pub fn data_info(self, data_info: &str) -> Self {
+        TransferTokensBuilder {
+            data_info: Some(data_info.to_string()),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> TransferTokens {
+        self.verify();
+
+        TransferTokens::new(
+            &self.meta.public_key,
+            self.recipient.as_ref().unwrap(),
+            self.amount,
+            self.assets,
+            self.tokens,
+            self.seed,
+            &self.data_info.unwrap_or_default(),
+            &self.meta.secret_key,
+        )
+    }
+
+    fn verify(&self) {
+        assert!(self.recipient.is_some());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use exonum::crypto;
+    use exonum::messages::Message;
+    use exonum::storage::StorageValue;
+
+    use currency::assets::{AssetBundle, AssetId, CollectionMember, MetaAsset, MetadataRef,
+                           SupplyPolicy, TradeAsset};
+    use currency::multisig::{MultisigConfig, MultisigMember};
+    use currency::nft::{MetaToken, TokenId, TradeToken};
+    use currency::vesting::ReleaseCurve;
+
+    use currency::transactions::add_assets::AddAssets;
+    use currency::transactions::add_to_collection::AddToCollection;
+    use currency::transactions::approve::Approve;
+    use currency::transactions::cart_checkout::{CartCheckout, CartItem};
+    use currency::transactions::components::{FeeStrategy, Intermediary, PartySignature};
+    use currency::transactions::create_collection::CreateCollection;
+    use currency::transactions::create_multisig::CreateMultisig;
+    use currency::transactions::create_vesting::CreateVesting;
+    use currency::transactions::offer_loan::OfferLoan;
+    use currency::transactions::delete_assets::DeleteAssets;
+    use currency::transactions::exchange::{Exchange, ExchangeOffer};
+    use currency::transactions::exchange_intermediary::{ExchangeIntermediary,
+                                                        ExchangeOfferIntermediary};
+    use currency::transactions::exchange_tokens::{ExchangeTokens, ExchangeTokensOffer};
+    use currency::transactions::freeze_wallet::FreezeWallet;
+    use currency::transactions::mint_tokens::MintTokens;
+    use currency::transactions::multi_exchange::{ExchangeLeg, MultiExchange,
+                                                 MultiExchangeOffer};
+    use currency::transactions::multi_transfer::{MultiTransfer, TransferOutput};
+    use currency::transactions::multisig_propose::MultisigPropose;
+    use currency::transactions::trade::{Trade, TradeOffer};
+    use currency::transactions::trade_intermediary::{TradeIntermediary, TradeOfferIntermediary};
+    use currency::transactions::trade_tokens::{TradeTokens, TradeTokensOffer};
+    use currency::transactions::set_asset_metadata::SetAssetMetadata;
+    use currency::transactions::set_asset_supply::SetAssetSupply;
+    use currency::transactions::transfer::Transfer;
+    use currency::transactions::transfer_from::TransferFrom;
+    use currency::transactions::transfer_tokens::TransferTokens;
+    use currency::transactions::update_asset_fees::UpdateAssetFees;
+
+    use currency::transactions::builders::fee;
+    use currency::transactions::builders::transaction;
+
+    #[test]
+    #[should_panic]
+    fn meta_underspecified() {
+        transaction::Builder::new().tx_add_assets();
+    }
+
+    #[test]
+    fn not_equal() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let (receiver_key, _) = crypto::gen_keypair();
+
+        let fees_foobar = fee::Builder::new()
+            .trade(10, "0.1".parse().unwrap())
+            .exchange(10, "0.1".parse().unwrap())
+            .transfer(10, "0.1".parse().unwrap())
+            .build();
+
+        let fees_bazqux = fee::Builder::new()
+            .trade(11, "0.1".parse().unwrap())
+            .exchange(11, "0.1".parse().unwrap())
+            .transfer(11, "0.1".parse().unwrap())
+            .build();
+
+        let asset_foobar = MetaAsset::new(&receiver_key, "foobar", 9, fees_foobar);
+        let asset_bazqux = MetaAsset::new(&receiver_key, "bazqux", 18, fees_bazqux);
+
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_add_assets()
+            .add_asset_value(asset_foobar.clone())
+            .build();
+
+        let assets = vec![asset_bazqux];
+        let equivalent = AddAssets::new(
+            &public_key,
+            assets,
+            0,
+            &secret_key,
+        );
+
+        assert_ne!(transaction, equivalent);
+    }
+
+    #[test]
+    fn add_assets() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let (receiver_key, _) = crypto::gen_keypair();
+
+        let fees_foobar = fee::Builder::new()
+            .trade(10, "0.1".parse().unwrap())
+            .exchange(10, "0.1".parse().unwrap())
+            .transfer(10, "0.1".parse().unwrap())
+            .build();
+
+        let fees_bazqux = fee::Builder::new()
+            .trade(11, "0.1".parse().unwrap())
+            .exchange(11, "0.1".parse().unwrap())
+            .transfer(11, "0.1".parse().unwrap())
+            .build();
+
+        let asset_foobar = MetaAsset::new(&receiver_key, "foobar", 9, fees_foobar);
+        let asset_bazqux = MetaAsset::new(&receiver_key, "bazqux", 18, fees_bazqux);
+
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_add_assets()
+            .add_asset_value(asset_foobar.clone())
+            .add_asset_value(asset_bazqux.clone())
+            .build();
+
+        let assets = vec![asset_foobar, asset_bazqux];
+        let equivalent = AddAssets::new(
+            &public_key,
+            assets,
+            0,
+            &secret_key,
+        );
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn add_assets_metadata() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+
+        let fees = fee::Builder::new()
+            .trade(10, "0.1".parse().unwrap())
+            .exchange(10, "0.1".parse().unwrap())
+            .transfer(10, "0.1".parse().unwrap())
+            .build();
+
+        let content_hash = crypto::hash(b"{\"name\": \"foobar\"}");
+        let metadata = MetadataRef::new(&content_hash, "application/json", "ipfs://foobar");
+
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_add_assets()
+            .add_asset_metadata(metadata, 9, fees.clone())
+            .build();
+
+        let asset = MetaAsset::new(&public_key, &content_hash.to_hex(), 9, fees);
+        let equivalent = AddAssets::new(&public_key, vec![asset], 0, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn del_assets() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let asset = AssetBundle::from_data("foobar", 9, &public_key);
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_del_assets()
+            .add_asset_value(asset.clone())
+            .seed(6)
+            .build();
+
+        let assets = vec![asset];
+        let equivalent = DeleteAssets::new(&public_key, assets, 6, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn exchange() {
+        let (recipient_pk, recipient_sk) = crypto::gen_keypair();
+
+        let (sender_pk, sender_sk) = crypto::gen_keypair();
+        let sender_asset = AssetBundle::from_data("foobar", 9, &sender_pk);
+        let recipient_asset = AssetBundle::from_data("bazqux", 13, &recipient_pk);
+
+        let transaction = transaction::Builder::new()
+            .keypair(recipient_pk, recipient_sk.clone())
+            .tx_exchange()
+            .sender(sender_pk)
+            .sender_secret(sender_sk.clone())
+            .sender_add_asset_value(sender_asset.clone())
+            .sender_value(9)
+            .recipient_add_asset_value(recipient_asset.clone())
+            .fee_strategy(FeeStrategy::Recipient)
+            .seed(1)
+            .data_info("test_exchange")
+            .build();
+
+        let offer = ExchangeOffer::new(
+            &sender_pk,
+            vec![sender_asset.clone()],
+            9,
+            &recipient_pk,
+            vec![recipient_asset.clone()],
+            1,
+            1,
+            "test_exchange",
+        );
+        let sender_signature = crypto::sign(&offer.clone().into_bytes(), &sender_sk.clone());
+        let equivalent = Exchange::new(offer, &sender_signature, &recipient_sk);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn exchange_with_intermediary() {
+        let (sender_pk, sender_sk) = crypto::gen_keypair();
+        let (intermediary_pk, intermediary_sk) = crypto::gen_keypair();
+        let (recipient_pk, recipient_sk) = crypto::gen_keypair();
+        let (creator_pk, _) = crypto::gen_keypair();
+
+        let sender_asset = AssetBundle::from_data("foobar", 9, &creator_pk);
+        let recipient_asset = AssetBundle::from_data("bazqux", 13, &creator_pk);
+        let transaction = transaction::Builder::new()
+            .keypair(recipient_pk, recipient_sk.clone())
+            .tx_exchange_with_intermediary()
+            .intermediary_key_pair(intermediary_pk, intermediary_sk.clone())
+            .commission(10)
+            .sender_key_pair(sender_pk, sender_sk.clone())
+            .sender_add_asset_value(sender_asset.clone())
+            .sender_value(9)
+            .recipient_add_asset_value(recipient_asset.clone())
+            .fee_strategy(FeeStrategy::Recipient)
+            .seed(1)
+            .data_info("test_exchange")
+            .build();
+
+        let intermediary = Intermediary::new(&intermediary_pk, 10);
+
+        let offer = ExchangeOfferIntermediary::new(
+            intermediary,
+            &sender_pk,
+            vec![sender_asset.clone()],
+            9,
+            &recipient_pk,
+            vec![recipient_asset.clone()],
+            1,
+            1,
+            "test_exchange",
+        );
+        let sender_signature = crypto::sign(&offer.clone().into_bytes(), &sender_sk);
+        let intermediary_signature = crypto::sign(&offer.clone().into_bytes(), &intermediary_sk);
+        let equivalent = ExchangeIntermediary::new(
+            offer,
+            &sender_signature,
+            &intermediary_signature,
+            &recipient_sk,
+        );
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn trade_assets() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let (seller_public, seller_secret) = crypto::gen_keypair();
+        let asset = AssetBundle::from_data("foobar", 9, &public_key);
+        let trade_asset = TradeAsset::from_bundle(asset, 10);
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_trade_assets()
+            .add_asset_value(trade_asset.clone())
+            .seller(seller_public, seller_secret.clone())
+            .fee_strategy(FeeStrategy::Recipient)
+            .seed(1)
+            .data_info("test_trade")
+            .build();
+
+        let offer = TradeOffer::new(
+            &public_key,
+            &seller_public,
+            vec![trade_asset],
+            FeeStrategy::Recipient as u8,
+            1,
+            "test_trade",
+        );
+        let signature = crypto::sign(&offer.clone().into_bytes(), &seller_secret);
+        let equivalent = Trade::new(offer, &signature, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn trade_assets_with_intermediary() {
+        let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+        let (intermediary_public_key, intermediary_secret_key) = crypto::gen_keypair();
+        let (seller_public_key, seller_secret_key) = crypto::gen_keypair();
+        let asset = AssetBundle::from_data("foobar", 9, &seller_public_key);
+        let trade_asset = TradeAsset::from_bundle(asset, 10);
+        let transaction = transaction::Builder::new()
+            .keypair(buyer_public_key, buyer_secret_key.clone())
+            .tx_trade_assets_with_intermediary()
+            .intermediary_key_pair(intermediary_public_key, intermediary_secret_key.clone())
+            .commission(40)
+            .add_asset_value(trade_asset.clone())
+            .seller(seller_public_key, seller_secret_key.clone())
+            .fee_strategy(FeeStrategy::Recipient)
+            .seed(1)
+            .data_info("trade_test")
+            .build();
+
+        let intermediary = Intermediary::new(&intermediary_public_key, 40);
+        let offer = TradeOfferIntermediary::new(
+            intermediary,
+            &buyer_public_key,
+            &seller_public_key,
+            vec![trade_asset],
+            FeeStrategy::Recipient as u8,
+            1,
+            "trade_test",
+        );
+        let seller_signature = crypto::sign(&offer.clone().into_bytes(), &seller_secret_key);
+        let intermediary_signature =
+            crypto::sign(&offer.clone().into_bytes(), &intermediary_secret_key);
+        let equivalent = TradeIntermediary::new(
+            offer,
+            &seller_signature,
+            &intermediary_signature,
+            &buyer_secret_key,
+        );
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn multi_exchange() {
+        let (alice_public_key, alice_secret_key) = crypto::gen_keypair();
+        let (bob_public_key, bob_secret_key) = crypto::gen_keypair();
+        let (carol_public_key, carol_secret_key) = crypto::gen_keypair();
+        let alice_asset = AssetBundle::from_data("foobar", 9, &alice_public_key);
+        let bob_asset = AssetBundle::from_data("bazqux", 3, &bob_public_key);
+        let transaction = transaction::Builder::new()
+            .keypair(carol_public_key, carol_secret_key.clone())
+            .tx_multi_exchange()
+            .add_leg(alice_public_key, bob_public_key, 0, vec![alice_asset.clone()])
+            .add_leg(bob_public_key, carol_public_key, 0, vec![bob_asset.clone()])
+            .add_leg(carol_public_key, alice_public_key, 100, vec![])
+            .signer(alice_public_key, alice_secret_key.clone())
+            .signer(bob_public_key, bob_secret_key.clone())
+            .signer(carol_public_key, carol_secret_key.clone())
+            .fee_strategy(FeeStrategy::Sender)
+            .seed(1)
+            .data_info("multi_exchange")
+            .build();
+
+        let legs = vec![
+            ExchangeLeg::new(&alice_public_key, &bob_public_key, 0, vec![alice_asset]),
+            ExchangeLeg::new(&bob_public_key, &carol_public_key, 0, vec![bob_asset]),
+            ExchangeLeg::new(&carol_public_key, &alice_public_key, 100, vec![]),
+        ];
+        let offer = MultiExchangeOffer::new(legs, FeeStrategy::Sender as u8, 1, "multi_exchange");
+        let signatures = vec![
+            PartySignature::new(
+                &alice_public_key,
+                &crypto::sign(&offer.clone().into_bytes(), &alice_secret_key),
+            ),
+            PartySignature::new(
+                &bob_public_key,
+                &crypto::sign(&offer.clone().into_bytes(), &bob_secret_key),
+            ),
+            PartySignature::new(
+                &carol_public_key,
+                &crypto::sign(&offer.clone().into_bytes(), &carol_secret_key),
+            ),
+        ];
+        let equivalent =
+            MultiExchange::new(&carol_public_key, offer, signatures, &carol_secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn cart_checkout() {
+        let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+        let (seller1_public_key, seller1_secret_key) = crypto::gen_keypair();
+        let (seller2_public_key, seller2_secret_key) = crypto::gen_keypair();
+        let asset1 = AssetBundle::from_data("foobar", 9, &seller1_public_key);
+        let asset2 = AssetBundle::from_data("bazqux", 3, &seller2_public_key);
+        let trade_asset1 = TradeAsset::from_bundle(asset1, 10);
+        let trade_asset2 = TradeAsset::from_bundle(asset2, 20);
+        let transaction = transaction::Builder::new()
+            .keypair(buyer_public_key, buyer_secret_key.clone())
+            .tx_cart_checkout()
+            .add_offer(
+                seller1_public_key,
+                seller1_secret_key.clone(),
+                vec![trade_asset1.clone()],
+                FeeStrategy::Recipient,
+            )
+            .add_offer(
+                seller2_public_key,
+                seller2_secret_key.clone(),
+                vec![trade_asset2.clone()],
+                FeeStrategy::Sender,
+            )
+            .seed(1)
+            .build();
+
+        let offer1 = TradeOffer::new(
+            &buyer_public_key,
+            &seller1_public_key,
+            vec![trade_asset1],
+            FeeStrategy::Recipient as u8,
+            0,
+            "",
+        );
+        let signature1 = crypto::sign(&offer1.clone().into_bytes(), &seller1_secret_key);
+        let offer2 = TradeOffer::new(
+            &buyer_public_key,
+            &seller2_public_key,
+            vec![trade_asset2],
+            FeeStrategy::Sender as u8,
+            1,
+            "",
+        );
+        let signature2 = crypto::sign(&offer2.clone().into_bytes(), &seller2_secret_key);
+        let items = vec![
+            CartItem::new(offer1, &signature1),
+            CartItem::new(offer2, &signature2),
+        ];
+        let equivalent = CartCheckout::new(&buyer_public_key, items, 1, &buyer_secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn transfer() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let (recipient, _) = crypto::gen_keypair();
+        let asset = AssetBundle::from_data("foobar", 9, &public_key);
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_transfer()
+            .recipient(recipient)
+            .amount(9)
+            .add_asset_value(asset.clone())
+            .seed(1)
+            .data_info("info")
+            .build();
+
+        let equivalent = Transfer::new(
+            &public_key,
+            &recipient,
+            9,
+            vec![asset],
+            1,
+            "info",
+            &secret_key,
+        );
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn multi_transfer() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let (recipient1, _) = crypto::gen_keypair();
+        let (recipient2, _) = crypto::gen_keypair();
+        let asset = AssetBundle::from_data("foobar", 9, &public_key);
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_multi_transfer()
+            .add_output(recipient1, 100, vec![asset.clone()], "prize")
+            .add_output(recipient2, 50, vec![], "")
+            .seed(7)
+            .build();
+
+        let outputs = vec![
+            TransferOutput::new(&recipient1, 100, vec![asset], "prize"),
+            TransferOutput::new(&recipient2, 50, vec![], ""),
+        ];
+        let equivalent = MultiTransfer::new(&public_key, outputs, 7, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn create_multisig() {
+        let (public_key1, secret_key1) = crypto::gen_keypair();
+        let (public_key2, _) = crypto::gen_keypair();
+        let transaction = transaction::Builder::new()
+            .keypair(public_key1, secret_key1.clone())
+            .tx_create_multisig()
+            .add_member(public_key1)
+            .add_member(public_key2)
+            .threshold(2)
+            .seed(3)
+            .build();
+
+        let config = MultisigConfig::new(
+            vec![
+                MultisigMember::new(&public_key1),
+                MultisigMember::new(&public_key2),
+            ],
+            2,
+        );
+        let equivalent = CreateMultisig::new(&public_key1, config, 3, &secret_key1);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn multisig_propose() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let (wallet, _) = crypto::gen_keypair();
+        let (recipient, _) = crypto::gen_keypair();
+        let transfer = transaction::Builder::new()
+            .keypair(wallet, secret_key.clone())
+            .tx_transfer()
+            .recipient(recipient)
+            .amount(10)
+            .build();
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_multisig_propose()
+            .wallet(wallet)
+            .tx_value(&transfer)
+            .seed(5)
+            .build();
+
+        let equivalent =
+            MultisigPropose::new(&public_key, &wallet, transfer.raw().as_ref(), 5, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn approve() {
         let (public_key, secret_key) = crypto::gen_keypair();
-        let (receiver_key, _) = crypto::gen_keypair();
+        let (spender, _) = crypto::gen_keypair();
+        let asset = AssetBundle::from_data("foobar", 5, &public_key);
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_approve()
+            .spender(spender)
+            .amount(100)
+            .add_asset_value(asset.clone())
+            .expires_at(50)
+            .seed(1)
+            .build();
 
-        let fees_foobar = fee::Builder::new()
+        let equivalent =
+            Approve::new(&public_key, &spender, 100, vec![asset], 50, 1, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn transfer_from() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let (owner, _) = crypto::gen_keypair();
+        let (recipient, _) = crypto::gen_keypair();
+        let asset = AssetBundle::from_data("foobar", 5, &owner);
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_transfer_from()
+            .owner(owner)
+            .recipient(recipient)
+            .amount(30)
+            .add_asset_value(asset.clone())
+            .seed(2)
+            .data_info("info")
+            .build();
+
+        let equivalent = TransferFrom::new(
+            &public_key,
+            &owner,
+            &recipient,
+            30,
+            vec![asset],
+            2,
+            "info",
+            &secret_key,
+        );
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn create_vesting() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let (beneficiary, _) = crypto::gen_keypair();
+        let asset = AssetBundle::from_data("foobar", 10, &public_key);
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_create_vesting()
+            .beneficiary(beneficiary)
+            .amount(1000)
+            .add_asset_value(asset.clone())
+            .cliff(10)
+            .end(100)
+            .curve(ReleaseCurve::Linear)
+            .seed(4)
+            .build();
+
+        let equivalent = CreateVesting::new(
+            &public_key,
+            &beneficiary,
+            1000,
+            vec![asset],
+            10,
+            100,
+            ReleaseCurve::Linear as u8,
+            4,
+            &secret_key,
+        );
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn offer_loan() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let asset = AssetBundle::from_data("foobar", 1, &public_key);
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_offer_loan()
+            .add_asset_value(asset.clone())
+            .collateral(500)
+            .rental_fee(50)
+            .duration(10)
+            .seed(6)
+            .build();
+
+        let equivalent = OfferLoan::new(&public_key, vec![asset], 500, 50, 10, 6, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn update_asset_fees() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let asset_id = AssetId::from_data("foobar", &public_key);
+        let fees = fee::Builder::new()
             .trade(10, "0.1".parse().unwrap())
             .exchange(10, "0.1".parse().unwrap())
             .transfer(10, "0.1".parse().unwrap())
             .build();
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_update_asset_fees()
+            .asset_id(asset_id)
+            .fees(fees.clone())
+            .seed(7)
+            .build();
 
-        let fees_bazqux = fee::Builder::new()
-            .trade(11, "0.1".parse().unwrap())
-            .exchange(11, "0.1".parse().unwrap())
-            .transfer(11, "0.1".parse().unwrap())
+        let equivalent = UpdateAssetFees::new(&public_key, asset_id, fees, 7, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn set_asset_supply() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let asset_id = AssetId::from_data("foobar", &public_key);
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_set_asset_supply()
+            .asset_id(asset_id)
+            .max_supply(100)
+            .sealed(true)
+            .seed(7)
             .build();
 
-        let asset_foobar = MetaAsset::new(&receiver_key, "foobar", 9, fees_foobar);
-        let asset_bazqux = MetaAsset::new(&receiver_key, "bazqux", 18, fees_bazqux);
+        let policy = SupplyPolicy::new(asset_id, 100, true);
+        let equivalent = SetAssetSupply::new(&public_key, policy, 7, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
 
+    #[test]
+    fn set_asset_metadata() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let content_hash = crypto::hash(b"{\"name\": \"foobar\"}");
+        let metadata = MetadataRef::new(&content_hash, "application/json", "ipfs://foobar");
         let transaction = transaction::Builder::new()
             .keypair(public_key, secret_key.clone())
-            .tx_add_assets()
-            .add_asset_value(asset_foobar.clone())
-            .add_asset_value(asset_bazqux.clone())
+            .tx_set_asset_metadata()
+            .add_metadata(metadata.clone())
+            .seed(8)
             .build();
 
-        let assets = vec![asset_foobar, asset_bazqux];
-        let equivalent = AddAssets::new(&public_key, assets, 0, &secret_key);
+        let equivalent = SetAssetMetadata::new(&public_key, vec![metadata], 8, &secret_key);
 
         assert_eq!(transaction, equivalent);
     }
 
     #[test]
-    fn del_assets() {
+    fn freeze_wallet() {
         let (public_key, secret_key) = crypto::gen_keypair();
-        let asset = AssetBundle::from_data("foobar", 9, &public_key);
+        let (wallet_key, _) = crypto::gen_keypair();
         let transaction = transaction::Builder::new()
             .keypair(public_key, secret_key.clone())
-            .tx_del_assets()
-            .add_asset_value(asset.clone())
-            .seed(6)
+            .tx_freeze_wallet()
+            .wallet(wallet_key)
+            .reason("court order")
+            .seed(8)
             .build();
 
-        let assets = vec![asset];
-        let equivalent = DeleteAssets::new(&public_key, assets, 6, &secret_key);
+        let equivalent = FreezeWallet::new(&public_key, &wallet_key, "court order", 8, &secret_key);
 
         assert_eq!(transaction, equivalent);
     }
 
     #[test]
-    fn exchange() {
-        let (recipient_pk, recipient_sk) = crypto::gen_keypair();
+    fn mint_tokens() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let (receiver_key, _) = crypto::gen_keypair();
 
-        let (sender_pk, sender_sk) = crypto::gen_keypair();
-        let sender_asset = AssetBundle::from_data("foobar", 9, &sender_pk);
-        let recipient_asset = AssetBundle::from_data("bazqux", 13, &recipient_pk);
+        let fees = fee::Builder::new()
+            .trade(10, "0.1".parse().unwrap())
+            .exchange(10, "0.1".parse().unwrap())
+            .transfer(10, "0.1".parse().unwrap())
+            .build();
 
         let transaction = transaction::Builder::new()
-            .keypair(recipient_pk, recipient_sk.clone())
-            .tx_exchange()
-            .sender(sender_pk)
-            .sender_secret(sender_sk.clone())
-            .sender_add_asset_value(sender_asset.clone())
-            .sender_value(9)
-            .recipient_add_asset_value(recipient_asset.clone())
-            .fee_strategy(FeeStrategy::Recipient)
-            .seed(1)
-            .data_info("test_exchange")
+            .keypair(public_key, secret_key.clone())
+            .tx_mint_tokens()
+            .receiver(receiver_key)
+            .name("knife")
+            .fees(fees.clone())
+            .add_token(1, "factory new")
+            .add_token(2, "battle scarred")
+            .seed(9)
             .build();
 
-        let offer = ExchangeOffer::new(
-            &sender_pk,
-            vec![sender_asset.clone()],
+        let tokens = vec![
+            MetaToken::new(1, "factory new"),
+            MetaToken::new(2, "battle scarred"),
+        ];
+        let equivalent = MintTokens::new(
+            &public_key,
+            &receiver_key,
+            "knife",
+            fees,
+            tokens,
             9,
-            &recipient_pk,
-            vec![recipient_asset.clone()],
-            1,
-            1,
-            "test_exchange",
+            &secret_key,
         );
-        let sender_signature = crypto::sign(&offer.clone().into_bytes(), &sender_sk.clone());
-        let equivalent = Exchange::new(offer, &sender_signature, &recipient_sk);
 
         assert_eq!(transaction, equivalent);
     }
 
     #[test]
-    fn exchange_with_intermediary() {
-        let (sender_pk, sender_sk) = crypto::gen_keypair();
-        let (intermediary_pk, intermediary_sk) = crypto::gen_keypair();
+    fn add_to_collection() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+        let collection = crypto::hash(b"case");
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_add_to_collection()
+            .add_asset("knife", collection, false)
+            .seed(4)
+            .build();
+
+        let member = CollectionMember::new(
+            AssetId::from_data("knife", &public_key),
+            &collection,
+            false,
+        );
+        let equivalent = AddToCollection::new(&public_key, vec![member], 4, &secret_key);
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn create_collection() {
+        let (public_key, secret_key) = crypto::gen_keypair();
+
+        let fees = fee::Builder::new()
+            .trade(10, "0.1".parse().unwrap())
+            .exchange(10, "0.1".parse().unwrap())
+            .transfer(10, "0.1".parse().unwrap())
+            .build();
+
+        let parent = crypto::hash(b"season");
+        let transaction = transaction::Builder::new()
+            .keypair(public_key, secret_key.clone())
+            .tx_create_collection()
+            .parent(parent)
+            .name("case")
+            .data("weapon case")
+            .fees(fees.clone())
+            .seed(3)
+            .build();
+
+        let equivalent = CreateCollection::new(
+            &public_key,
+            &parent,
+            "case",
+            "weapon case",
+            fees,
+            3,
+            &secret_key,
+        );
+
+        assert_eq!(transaction, equivalent);
+    }
+
+    #[test]
+    fn exchange_tokens() {
         let (recipient_pk, recipient_sk) = crypto::gen_keypair();
-        let (creator_pk, _) = crypto::gen_keypair();
 
-        let sender_asset = AssetBundle::from_data("foobar", 9, &creator_pk);
-        let recipient_asset = AssetBundle::from_data("bazqux", 13, &creator_pk);
+        let (sender_pk, sender_sk) = crypto::gen_keypair();
+        let sender_asset = AssetBundle::from_data("foobar", 9, &sender_pk);
+        let sender_token = TokenId::new(AssetId::from_series("knife", &sender_pk), 1);
+        let recipient_token = TokenId::new(AssetId::from_series("gloves", &recipient_pk), 2);
+
         let transaction = transaction::Builder::new()
             .keypair(recipient_pk, recipient_sk.clone())
-            .tx_exchange_with_intermediary()
-            .intermediary_key_pair(intermediary_pk, intermediary_sk.clone())
-            .commission(10)
-            .sender_key_pair(sender_pk, sender_sk.clone())
+            .tx_exchange_tokens()
+            .sender(sender_pk)
+            .sender_secret(sender_sk.clone())
             .sender_add_asset_value(sender_asset.clone())
+            .sender_add_token(sender_token.clone())
             .sender_value(9)
-            .recipient_add_asset_value(recipient_asset.clone())
+            .recipient_add_token(recipient_token.clone())
             .fee_strategy(FeeStrategy::Recipient)
             .seed(1)
             .data_info("test_exchange")
             .build();
 
-        let intermediary = Intermediary::new(&intermediary_pk, 10);
-
-        let offer = ExchangeOfferIntermediary::new(
-            intermediary,
+        let offer = ExchangeTokensOffer::new(
             &sender_pk,
-            vec![sender_asset.clone()],
+            vec![sender_asset],
+            vec![sender_token],
             9,
             &recipient_pk,
-            vec![recipient_asset.clone()],
+            Vec::new(),
+            vec![recipient_token],
             1,
             1,
             "test_exchange",
         );
         let sender_signature = crypto::sign(&offer.clone().into_bytes(), &sender_sk);
-        let intermediary_signature = crypto::sign(&offer.clone().into_bytes(), &intermediary_sk);
-        let equivalent = ExchangeIntermediary::new(
-            offer,
-            &sender_signature,
-            &intermediary_signature,
-            &recipient_sk,
-        );
+        let equivalent = ExchangeTokens::new(offer, &sender_signature, &recipient_sk);
 
         assert_eq!(transaction, equivalent);
     }
 
     #[test]
-    fn trade_assets() {
+    fn trade_tokens() {
         let (public_key, secret_key) = crypto::gen_keypair();
         let (seller_public, seller_secret) = crypto::gen_keypair();
-        let asset = AssetBundle::from_data("foobar", 9, &public_key);
-        let trade_asset = TradeAsset::from_bundle(asset, 10);
+        let token = TokenId::new(AssetId::from_series("knife", &seller_public), 1);
         let transaction = transaction::Builder::new()
             .keypair(public_key, secret_key.clone())
-            .tx_trade_assets()
-            .add_asset_value(trade_asset.clone())
+            .tx_trade_tokens()
+            .add_token(token.clone(), 10)
             .seller(seller_public, seller_secret.clone())
             .fee_strategy(FeeStrategy::Recipient)
             .seed(1)
             .data_info("test_trade")
             .build();
 
-        let offer = TradeOffer::new(
+        let offer = TradeTokensOffer::new(
             &public_key,
             &seller_public,
-            vec![trade_asset],
+            Vec::new(),
+            vec![TradeToken::new(token, 10)],
             FeeStrategy::Recipient as u8,
             1,
             "test_trade",
         );
         let signature = crypto::sign(&offer.clone().into_bytes(), &seller_secret);
-        let equivalent = Trade::new(offer, &signature, &secret_key);
-
-        assert_eq!(transaction, equivalent);
-    }
-
-    #[test]
-    fn trade_assets_with_intermediary() {
-        let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
-        let (intermediary_public_key, intermediary_secret_key) = crypto::gen_keypair();
-        let (seller_public_key, seller_secret_key) = crypto::gen_keypair();
-        let asset = AssetBundle::from_data("foobar", 9, &seller_public_key);
-        let trade_asset = TradeAsset::from_bundle(asset, 10);
-        let transaction = transaction::Builder::new()
-            .keypair(buyer_public_key, buyer_secret_key.clone())
-            .tx_trade_assets_with_intermediary()
-            .intermediary_key_pair(intermediary_public_key, intermediary_secret_key.clone())
-            .commission(40)
-            .add_asset_value(trade_asset.clone())
-            .seller(seller_public_key, seller_secret_key.clone())
-            .fee_strategy(FeeStrategy::Recipient)
-            .seed(1)
-            .data_info("trade_test")
-            .build();
-
-        let intermediary = Intermediary::new(&intermediary_public_key, 40);
-        let offer = TradeOfferIntermediary::new(
-            intermediary,
-            &buyer_public_key,
-            &seller_public_key,
-            vec![trade_asset],
-            FeeStrategy::Recipient as u8,
-            1,
-            "trade_test",
-        );
-        let seller_signature = crypto::sign(&offer.clone().into_bytes(), &seller_secret_key);
-        let intermediary_signature =
-            crypto::sign(&offer.clone().into_bytes(), &intermediary_secret_key);
-        let equivalent = TradeIntermediary::new(
-            offer,
-            &seller_signature,
-            &intermediary_signature,
-            &buyer_secret_key,
-        );
+        let equivalent = TradeTokens::new(offer, &signature, &secret_key);
 
         assert_eq!(transaction, equivalent);
     }
 
     #[test]
-    fn transfer() {
+    fn transfer_tokens() {
         let (public_key, secret_key) = crypto::gen_keypair();
         let (recipient, _) = crypto::gen_keypair();
-        let asset = AssetBundle::from_data("foobar", 9, &public_key);
+        let token = TokenId::new(AssetId::from_series("knife", &public_key), 1);
         let transaction = transaction::Builder::new()
             .keypair(public_key, secret_key.clone())
-            .tx_transfer()
+            .tx_transfer_tokens()
             .recipient(recipient)
-            .amount(9)
-            .add_asset_value(asset.clone())
+            .add_token(token.clone())
             .seed(1)
             .data_info("info")
             .build();
 
-        let equivalent = Transfer::new(
+        let equivalent = TransferTokens::new(
             &public_key,
             &recipient,
-            9,
-            vec![asset],
+            0,
+            Vec::new(),
+            vec![token],
             1,
             "info",
             &secret_key,
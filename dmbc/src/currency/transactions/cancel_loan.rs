@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::lending;
+use currency::lending::LoanState;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::wallet;
+use currency::wallet::Wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const CANCEL_LOAN_ID: u16 = 1005;
+
+message! {
+    /// `cancel_loan` transaction.
+    ///
+    /// Withdraws a loan offer nobody has accepted yet. The escrowed assets
+    /// go back to the lender.
+    struct CancelLoan {
+        const TYPE = SERVICE_ID;
+        const ID = CANCEL_LOAN_ID;
+
+        lender: &PublicKey,
+        loan:   &Hash,
+        seed:   u64,
+    }
+}
+
+impl FeesCalculator for CancelLoan {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.lender() {
+            fees_table.insert(*self.lender(), genesis_fees.transfer());
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl CancelLoan {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let loan = lending::Schema(&*view)
+            .fetch(self.loan())
+            .ok_or_else(|| Error::LoanNotFound)?;
+
+        if loan.loan_state() != LoanState::Offered {
+            return Err(Error::LoanNotAvailable);
+        }
+
+        if loan.lender() != self.lender() {
+            return Err(Error::NotLoanParticipant);
+        }
+
+        // Compliance rules are checked before any value is moved.
+        compliance::check_wallets(&*view, Some(self.lender()))?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut lender = wallet::Schema(&*view).fetch(self.lender());
+        wallet::move_coins(&mut lender, &mut genesis, genesis_fees.transfer())?;
+
+        wallet::Schema(&mut *view).store(self.lender(), lender);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        // Release the escrowed assets.
+        let mut lender = wallet::Schema(&*view).fetch(self.lender());
+        let mut escrow = Wallet::new(0, loan.assets());
+
+        wallet::move_assets(&mut escrow, &mut lender, &loan.assets())?;
+
+        wallet::Schema(&mut *view).store(self.lender(), lender);
+        lending::Schema(&mut *view).store(self.loan(), loan.close(LoanState::Cancelled));
+
+        Ok(())
+    }
+}
+
+impl Processable for CancelLoan {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cancel_loan_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cancel_loan_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cancel_loan_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cancel_loan_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cancel_loan_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_cancel_loan_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for CancelLoan {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        if cfg!(fuzzing) {
+            return true;
+        }
+
+        let verify_ok = self.verify_signature(self.lender());
+
+        if verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
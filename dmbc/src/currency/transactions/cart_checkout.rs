@@ -0,0 +1,194 @@
+use std::collections::{HashMap, HashSet};
+
+use exonum::blockchain::Transaction;
+use exonum::crypto;
+use exonum::crypto::{Hash, PublicKey, Signature};
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::offers;
+use currency::status;
+use currency::transactions::components::{atomically, FeesCalculator, Processable};
+use currency::transactions::trade::TradeOffer;
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const CART_CHECKOUT_ID: u16 = 503;
+
+/// Maximum number of offers in a single cart.
+pub const MAX_CART_ITEMS: usize = 32;
+
+encoding_struct! {
+    /// Trade offer signed by its seller, part of a `cart_checkout` transaction.
+    struct CartItem {
+        offer:            TradeOffer,
+        seller_signature: &Signature,
+    }
+}
+
+message! {
+    /// `cart_checkout` transaction.
+    struct CartCheckout {
+        const TYPE = SERVICE_ID;
+        const ID = CART_CHECKOUT_ID;
+
+        buyer: &PublicKey,
+        items: Vec<CartItem>,
+        seed:  u64,
+    }
+}
+
+impl CartItem {
+    fn verify(&self, buyer: &PublicKey) -> bool {
+        let offer = self.offer();
+
+        if offer.buyer() != buyer || !offer.verify() {
+            return false;
+        }
+
+        if cfg!(fuzzing) {
+            return true;
+        }
+
+        crypto::verify(self.seller_signature(), &offer.raw, offer.seller())
+    }
+
+    /// Hash of the signed offer, the same in every cart it is put into.
+    fn offer_hash(&self) -> Hash {
+        crypto::hash(&self.offer().raw)
+    }
+}
+
+impl FeesCalculator for CartCheckout {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let mut fees_table = HashMap::new();
+
+        for item in self.items() {
+            for (payer_key, fee) in item.offer().calculate_fees(view)? {
+                *fees_table.entry(payer_key).or_insert(0) += fee;
+            }
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl CartCheckout {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let items = self.items();
+
+        // A seller signs an offer once, so it can't be executed again in another cart.
+        for item in &items {
+            if offers::Schema(&*view).is_executed(&item.offer_hash()) {
+                return Err(Error::OfferExecuted);
+            }
+        }
+
+        // Compliance rules are checked before any value is moved.
+        for item in &items {
+            item.offer().check_compliance(view)?;
+        }
+
+        // Collect the blockchain fee for every offer. Execution shall not
+        // continue if this fails.
+        for item in &items {
+            item.offer().collect_transaction_fee(view)?;
+        }
+
+        // Offers must either all be processed, or none of them.
+        atomically(view, |view| {
+            for item in &items {
+                item.offer().process(view)?;
+            }
+            Ok(())
+        })?;
+
+        for item in &items {
+            offers::Schema(&mut *view).store(&item.offer_hash());
+        }
+        Ok(())
+    }
+}
+
+impl Processable for CartCheckout {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cart_checkout_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cart_checkout_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cart_checkout_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cart_checkout_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_cart_checkout_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_cart_checkout_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for CartCheckout {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let items = self.items();
+
+        let count_ok = !items.is_empty() && items.len() <= MAX_CART_ITEMS;
+
+        let mut offers = HashSet::new();
+        let unique_ok = items.iter().all(|item| offers.insert(item.offer().raw));
+
+        let items_ok = items.iter().all(|item| item.verify(self.buyer()));
+
+        if cfg!(fuzzing) {
+            return count_ok && unique_ok && items_ok;
+        }
+
+        let buyer_verify_ok = self.verify_signature(self.buyer());
+
+        if count_ok && unique_ok && items_ok && buyer_verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use exonum::blockchain;
+use exonum::blockchain::Transaction;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::lending;
+use currency::lending::LoanState;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::wallet;
+use currency::wallet::Wallet;
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const CLAIM_COLLATERAL_ID: u16 = 1004;
+
+message! {
+    /// `claim_collateral` transaction.
+    ///
+    /// Gives the whole collateral to the lender once the loan is past its
+    /// deadline. The borrower keeps the assets.
+    struct ClaimCollateral {
+        const TYPE = SERVICE_ID;
+        const ID = CLAIM_COLLATERAL_ID;
+
+        lender: &PublicKey,
+        loan:   &Hash,
+        seed:   u64,
+    }
+}
+
+impl FeesCalculator for ClaimCollateral {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        Ok(HashMap::new())
+    }
+}
+
+impl ClaimCollateral {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let loan = lending::Schema(&*view)
+            .fetch(self.loan())
+            .ok_or_else(|| Error::LoanNotFound)?;
+
+        if loan.loan_state() != LoanState::Active {
+            return Err(Error::LoanNotAvailable);
+        }
+
+        if loan.lender() != self.lender() {
+            return Err(Error::NotLoanParticipant);
+        }
+
+        let height = blockchain::Schema::new(&*view).height().next();
+        if height.0 <= loan.deadline() {
+            return Err(Error::LoanNotOverdue);
+        }
+
+        // Compliance rules are checked before any value is moved.
+        compliance::check_wallets(&*view, Some(self.lender()))?;
+
+        let mut lender = wallet::Schema(&*view).fetch(self.lender());
+        let mut collateral = Wallet::new(loan.collateral(), Vec::new());
+
+        wallet::move_coins(&mut collateral, &mut lender, loan.collateral())?;
+
+        wallet::Schema(&mut *view).store(self.lender(), lender);
+        lending::Schema(&mut *view).store(self.loan(), loan.close(LoanState::Defaulted));
+
+        Ok(())
+    }
+}
+
+impl Processable for ClaimCollateral {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_collateral_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_collateral_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_collateral_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_collateral_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_collateral_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_claim_collateral_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for ClaimCollateral {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        if cfg!(fuzzing) {
+            return true;
+        }
+
+        let verify_ok = self.verify_signature(self.lender());
+
+        if verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use exonum::blockchain;
+use exonum::blockchain::Transaction;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::vesting;
+use currency::wallet;
+use currency::wallet::Wallet;
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const CLAIM_VESTED_ID: u16 = 902;
+
+message! {
+    /// `claim_vested` transaction.
+    struct ClaimVested {
+        const TYPE = SERVICE_ID;
+        const ID = CLAIM_VESTED_ID;
+
+        beneficiary: &PublicKey,
+        vesting:     &Hash,
+        seed:        u64,
+    }
+}
+
+impl FeesCalculator for ClaimVested {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        Ok(HashMap::new())
+    }
+}
+
+impl ClaimVested {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let vesting = vesting::Schema(&*view)
+            .fetch(self.vesting())
+            .ok_or_else(|| Error::VestingNotFound)?;
+
+        if vesting.beneficiary() != self.beneficiary() {
+            return Err(Error::NotVestingBeneficiary);
+        }
+
+        let height = blockchain::Schema::new(&*view).height().next();
+        let claimable = vesting.claimable(height.0);
+        if claimable.balance() == 0 && claimable.assets().is_empty() {
+            return Err(Error::NothingToClaim);
+        }
+
+        // Compliance rules are checked before any value is moved.
+        compliance::check_wallets(&*view, Some(self.beneficiary()))?;
+        compliance::check_holder(&*view, self.beneficiary(), &claimable.assets())?;
+
+        let mut beneficiary = wallet::Schema(&*view).fetch(self.beneficiary());
+        beneficiary.add_assets(claimable.assets());
+        let beneficiary = Wallet::new(
+            beneficiary.balance() + claimable.balance(),
+            beneficiary.assets(),
+        );
+
+        wallet::Schema(&mut *view).store(self.beneficiary(), beneficiary);
+        vesting::Schema(&mut *view).store(self.vesting(), vesting.claim(&claimable));
+
+        Ok(())
+    }
+}
+
+impl Processable for ClaimVested {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_vested_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_vested_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_vested_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_vested_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_claim_vested_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_claim_vested_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for ClaimVested {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        if cfg!(fuzzing) {
+            return true;
+        }
+
+        let verify_ok = self.verify_signature(self.beneficiary());
+
+        if verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -0,0 +1,28 @@
+//! All-or-nothing execution of transaction parts.
+
+use exonum::storage::Fork;
+
+use currency::error::Error;
+
+/// Run `f` so that either all of its changes to `view` persist, or none do.
+///
+/// Changes made to `view` before the call are finalized and survive
+/// regardless of the outcome of `f`. This relies on the checkpoint that the
+/// blockchain creates for every executed transaction; calling this on a
+/// `Fork` without an active checkpoint panics.
+pub fn atomically<F>(view: &mut Fork, f: F) -> Result<(), Error>
+where
+    F: FnOnce(&mut Fork) -> Result<(), Error>,
+{
+    view.commit();
+    view.checkpoint();
+
+    let result = f(view);
+
+    if result.is_err() {
+        view.rollback();
+        view.checkpoint();
+    }
+
+    result
+}
@@ -256,6 +256,42 @@ impl ThirdPartyFees {
 
         Ok(updated_wallets)
     }
+
+    /// Split fees to third party wallets evenly between any number of payers.
+    ///
+    /// A payer does not pay its share of the fee owed to itself. With one or
+    /// two payers this behaves like `collect` and `collect2` respectively.
+    pub fn collect_split(
+        &self,
+        view: &Fork,
+        payer_keys: &[PublicKey],
+    ) -> Result<HashMap<PublicKey, Wallet>, Error> {
+        let mut updated_wallets = HashMap::new();
+
+        if payer_keys.is_empty() {
+            return Ok(updated_wallets);
+        }
+
+        let payers_count = payer_keys.len() as u64;
+
+        for (key, fee) in &self.0 {
+            for payer_key in payer_keys.iter().filter(|payer_key| *payer_key != key) {
+                let mut payer = updated_wallets
+                    .remove(payer_key)
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(payer_key));
+                let mut wallet = updated_wallets
+                    .remove(key)
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(key));
+
+                wallet::move_coins(&mut payer, &mut wallet, fee / payers_count)?;
+
+                updated_wallets.insert(*payer_key, payer);
+                updated_wallets.insert(*key, wallet);
+            }
+        }
+
+        Ok(updated_wallets)
+    }
 }
 
 pub trait FeesCalculator {
@@ -1,7 +1,16 @@
 //! Common transaction components.
 
+mod atomic;
 mod fees;
 mod intermediary;
+mod multisig;
+mod party_signature;
+mod processable;
 
+pub use currency::transactions::components::atomic::atomically;
 pub use currency::transactions::components::fees::{FeeStrategy, FeesCalculator, ThirdPartyFees};
 pub use currency::transactions::components::intermediary::Intermediary;
+pub use currency::transactions::components::multisig::{execute_authorized, multisig_tx_from_raw,
+                                                       MultisigSpendable};
+pub use currency::transactions::components::party_signature::PartySignature;
+pub use currency::transactions::components::processable::Processable;
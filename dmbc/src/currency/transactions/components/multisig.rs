@@ -0,0 +1,84 @@
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::{Message, RawMessage, HEADER_LENGTH};
+use exonum::storage::Fork;
+
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{atomically, FeesCalculator};
+use currency::transactions::{DeleteAssets, Exchange, ExchangeTokens, Trade, TradeTokens, Transfer,
+                             TransferTokens, DELETE_ASSETS_ID, EXCHANGE_ID, EXCHANGE_TOKENS_ID,
+                             TRADE_ID, TRADE_TOKENS_ID, TRANSFER_ID, TRANSFER_TOKENS_ID};
+
+/// Transaction that can spend from a multisig wallet.
+///
+/// Multisig wallets have no secret key, so the signature of the spending
+/// side is replaced by approvals of the wallet members checked on chain.
+pub trait MultisigSpendable: Transaction + FeesCalculator {
+    /// Wallet the transaction spends from.
+    fn spender(&self) -> PublicKey;
+
+    /// Verify the transaction, except for the signature of the spender.
+    fn verify_unsigned(&self) -> bool;
+
+    /// Process the transaction once the spender has authorized it.
+    fn process_authorized(&self, view: &mut Fork) -> Result<(), Error>;
+}
+
+/// Parse a transaction that spends from a multisig wallet.
+///
+/// # Errors
+/// Returns `InvalidTransaction` if the bytes are not a `transfer`, `trade`,
+/// `exchange` or `delete_assets` transaction, or one of their token
+/// carrying variants.
+pub fn multisig_tx_from_raw(bytes: &[u8]) -> Result<Box<MultisigSpendable>, Error> {
+    if bytes.len() < HEADER_LENGTH {
+        return Err(Error::InvalidTransaction);
+    }
+
+    let raw = RawMessage::from_vec(bytes.to_vec());
+    let tx: Box<MultisigSpendable> = match raw.message_type() {
+        TRANSFER_ID => Box::new(Transfer::from_raw(raw).map_err(|_| Error::InvalidTransaction)?),
+        TRADE_ID => Box::new(Trade::from_raw(raw).map_err(|_| Error::InvalidTransaction)?),
+        EXCHANGE_ID => Box::new(Exchange::from_raw(raw).map_err(|_| Error::InvalidTransaction)?),
+        TRANSFER_TOKENS_ID => {
+            Box::new(TransferTokens::from_raw(raw).map_err(|_| Error::InvalidTransaction)?)
+        }
+        TRADE_TOKENS_ID => {
+            Box::new(TradeTokens::from_raw(raw).map_err(|_| Error::InvalidTransaction)?)
+        }
+        EXCHANGE_TOKENS_ID => {
+            Box::new(ExchangeTokens::from_raw(raw).map_err(|_| Error::InvalidTransaction)?)
+        }
+        DELETE_ASSETS_ID => {
+            Box::new(DeleteAssets::from_raw(raw).map_err(|_| Error::InvalidTransaction)?)
+        }
+        _ => return Err(Error::InvalidTransaction),
+    };
+    Ok(tx)
+}
+
+/// Execute a spending transaction authorized by a multisig wallet.
+///
+/// The transaction status is stored under its own hash, so it can be
+/// tracked like any other transaction and is never executed twice.
+///
+/// # Errors
+/// Returns `AlreadyExecuted` if the transaction already has a status and
+/// `TransactionDisabled` if its type is switched off by the configuration,
+/// otherwise the result of processing the transaction.
+pub fn execute_authorized(view: &mut Fork, tx: &MultisigSpendable) -> Result<(), Error> {
+    if status::Schema(&*view).fetch(&tx.hash()).is_some() {
+        return Err(Error::AlreadyExecuted);
+    }
+
+    // A disabled transaction keeps no status, so it can still run once enabled again.
+    Configuration::extract(&*view).check_enabled(tx.raw().message_type())?;
+
+    let result = atomically(view, |view| tx.process_authorized(view));
+
+    status::Schema(&mut *view).store(tx.hash(), result);
+
+    result
+}
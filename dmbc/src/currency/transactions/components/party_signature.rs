@@ -0,0 +1,17 @@
+use exonum::crypto;
+use exonum::crypto::{PublicKey, Signature};
+
+encoding_struct! {
+    /// Signature of a transaction participant over a shared offer.
+    struct PartySignature {
+        pub_key:   &PublicKey,
+        signature: &Signature,
+    }
+}
+
+impl PartySignature {
+    /// Verify the signature against raw bytes of the offer.
+    pub fn verify(&self, data: &[u8]) -> bool {
+        crypto::verify(self.signature(), data, self.pub_key())
+    }
+}
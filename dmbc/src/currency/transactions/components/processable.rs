@@ -0,0 +1,13 @@
+use exonum::blockchain::Transaction;
+use exonum::storage::Fork;
+
+use currency::error::Error;
+
+/// Transaction that can be processed without touching the execution metrics.
+///
+/// `execute` wraps this with the metrics, while the simulation API calls it
+/// directly so that dry runs are not counted as executed transactions.
+pub trait Processable: Transaction {
+    /// Check the transaction type is enabled and process it against `view`.
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error>;
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets;
+use currency::assets::{Collection, Fees};
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const CREATE_COLLECTION_ID: u16 = 1401;
+
+message! {
+    /// `create_collection` transaction.
+    ///
+    /// Registers a collection of assets with its metadata and the default
+    /// fees of the assets placed in it. A nested collection names the
+    /// collection it belongs to in `parent`, which must have the same
+    /// creator.
+    struct CreateCollection {
+        const TYPE = SERVICE_ID;
+        const ID = CREATE_COLLECTION_ID;
+
+        creator: &PublicKey,
+        parent:  &Hash,
+        name:    &str,
+        data:    &str,
+        fees:    Fees,
+        seed:    u64,
+    }
+}
+
+impl FeesCalculator for CreateCollection {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.creator() {
+            fees_table.insert(*self.creator(), genesis_fees.add_assets());
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl CreateCollection {
+    /// The collection registered by this transaction.
+    pub fn collection(&self) -> Collection {
+        Collection::new(
+            self.creator(),
+            self.parent(),
+            self.name(),
+            self.data(),
+            self.fees(),
+        )
+    }
+
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        compliance::check_wallets(&*view, Some(self.creator()))?;
+
+        let collection = self.collection();
+        if assets::Schema(&*view).fetch_collection(&collection.id()).is_some() {
+            return Err(Error::CollectionExists);
+        }
+
+        if *self.parent() != Hash::zero() {
+            let parent = assets::Schema(&*view)
+                .fetch_collection(self.parent())
+                .ok_or(Error::CollectionNotFound)?;
+            if parent.creator() != self.creator() {
+                return Err(Error::NotCollectionCreator);
+            }
+        }
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut creator = wallet::Schema(&*view).fetch(self.creator());
+        wallet::move_coins(&mut creator, &mut genesis, genesis_fees.add_assets())?;
+
+        wallet::Schema(&mut *view).store(self.creator(), creator);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        assets::Schema(&mut *view).store_collection(collection);
+
+        Ok(())
+    }
+}
+
+impl Processable for CreateCollection {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_collection_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_collection_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_collection_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_collection_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_collection_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_create_collection_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for CreateCollection {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let collection_ok = self.collection().verify();
+
+        if cfg!(fuzzing) {
+            return collection_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.creator());
+
+        if collection_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::multisig;
+use currency::multisig::MultisigConfig;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const CREATE_MULTISIG_ID: u16 = 701;
+
+message! {
+    /// `create_multisig` transaction.
+    struct CreateMultisig {
+        const TYPE = SERVICE_ID;
+        const ID = CREATE_MULTISIG_ID;
+
+        creator: &PublicKey,
+        config:  MultisigConfig,
+        seed:    u64,
+    }
+}
+
+impl FeesCalculator for CreateMultisig {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.creator() {
+            fees_table.insert(*self.creator(), genesis_fees.transfer());
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl CreateMultisig {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut creator = wallet::Schema(&*view).fetch(self.creator());
+        wallet::move_coins(&mut creator, &mut genesis, genesis_fees.transfer())?;
+
+        wallet::Schema(&mut *view).store(self.creator(), creator);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        let config = self.config();
+
+        if multisig::Schema(&*view).fetch(&config.address()).is_some() {
+            return Err(Error::MultisigExists);
+        }
+
+        multisig::Schema(&mut *view).store(config);
+
+        Ok(())
+    }
+}
+
+impl Processable for CreateMultisig {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_multisig_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_multisig_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_multisig_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_multisig_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_multisig_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_create_multisig_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for CreateMultisig {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let config = self.config();
+        let config_ok = config.verify() && config.is_member(self.creator());
+
+        if cfg!(fuzzing) {
+            return config_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.creator());
+
+        if config_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
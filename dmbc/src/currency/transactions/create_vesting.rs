@@ -0,0 +1,205 @@
+use std::collections::{HashMap, HashSet};
+
+use exonum::blockchain;
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable, ThirdPartyFees};
+use currency::vesting;
+use currency::vesting::{ReleaseCurve, Vesting};
+use currency::wallet;
+use currency::wallet::Wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const CREATE_VESTING_ID: u16 = 901;
+
+/// Highest end height a vesting schedule may have.
+pub const MAX_VESTING_END: u64 = ::std::u32::MAX as u64;
+
+message! {
+    /// `create_vesting` transaction.
+    ///
+    /// Locks coins and assets of the creator for the beneficiary. The
+    /// schedule starts at the height the transaction is committed at.
+    struct CreateVesting {
+        const TYPE = SERVICE_ID;
+        const ID = CREATE_VESTING_ID;
+
+        creator:     &PublicKey,
+        beneficiary: &PublicKey,
+        amount:      u64,
+        assets:      Vec<AssetBundle>,
+        cliff:       u64,
+        end:         u64,
+        curve:       u8,
+        seed:        u64,
+    }
+}
+
+impl FeesCalculator for CreateVesting {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+        let fees = ThirdPartyFees::new_transfer(&*view, self.assets())?;
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.creator() {
+            fees_table.insert(*self.creator(), genesis_fees.transfer());
+        }
+
+        for (pub_key, fee) in fees.0 {
+            if pub_key != *self.creator() {
+                *fees_table.entry(*self.creator()).or_insert(0) += fee;
+            }
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl CreateVesting {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        // Compliance rules are checked before any value is moved.
+        compliance::check_transfer(&*view, self.creator(), self.beneficiary(), &self.assets())?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut creator = wallet::Schema(&*view).fetch(self.creator());
+        wallet::move_coins(&mut creator, &mut genesis, genesis_fees.transfer())?;
+
+        wallet::Schema(&mut *view).store(self.creator(), creator);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        let fees = ThirdPartyFees::new_transfer(&*view, self.assets())?;
+
+        // Operations bellow must either all succeed, or return an error without
+        // saving anything to the database.
+
+        // Process third party fees.
+        let mut updated_wallets = fees.collect(view, self.creator())?;
+
+        // Lock the granted value.
+        let mut creator = updated_wallets
+            .remove(self.creator())
+            .unwrap_or_else(|| wallet::Schema(&*view).fetch(self.creator()));
+        let mut granted = Wallet::new_empty();
+
+        wallet::move_coins(&mut creator, &mut granted, self.amount())?;
+        wallet::move_assets(&mut creator, &mut granted, &self.assets())?;
+
+        updated_wallets.insert(*self.creator(), creator);
+
+        // Save changes to the database.
+        for (key, wallet) in updated_wallets {
+            wallet::Schema(&mut *view).store(&key, wallet);
+        }
+
+        let start = blockchain::Schema::new(&*view).height().next();
+        let vesting = Vesting::new(
+            self.creator(),
+            self.beneficiary(),
+            start.0,
+            self.cliff(),
+            self.end(),
+            self.curve(),
+            granted,
+            Wallet::new_empty(),
+        );
+        vesting::Schema(&mut *view).store(&self.hash(), vesting);
+
+        Ok(())
+    }
+}
+
+impl Processable for CreateVesting {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_vesting_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_vesting_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_vesting_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_vesting_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_create_vesting_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_create_vesting_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for CreateVesting {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let assets = self.assets();
+        let mut ids = HashSet::new();
+
+        let schedule_ok = self.cliff() <= self.end() && self.end() <= MAX_VESTING_END
+            && ReleaseCurve::try_from(self.curve()).is_some();
+        let assets_ok = assets
+            .iter()
+            .all(|asset| asset.amount() > 0 && ids.insert(asset.id()));
+        let value_ok = self.amount() > 0 || !assets.is_empty();
+
+        if cfg!(fuzzing) {
+            return schedule_ok && assets_ok && value_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.creator());
+
+        if schedule_ok && assets_ok && value_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
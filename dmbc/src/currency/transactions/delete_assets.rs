@@ -8,9 +8,11 @@ use prometheus::{IntCounter, Histogram};
 
 use currency::assets;
 use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
 use currency::error::Error;
 use currency::status;
-use currency::transactions::components::FeesCalculator;
+use currency::transactions::components::{FeesCalculator, MultisigSpendable, Processable};
 use currency::wallet;
 use currency::SERVICE_ID;
 use currency::service::CONFIGURATION;
@@ -47,6 +49,9 @@ impl DeleteAssets {
     fn process(&self, view: &mut Fork) -> Result<(), Error> {
         info!("Processing tx: {:?}", self);
 
+        // Compliance rules are checked before any value is moved.
+        compliance::check_wallets(&*view, Some(self.pub_key()))?;
+
         let genesis_fees = CONFIGURATION.read().unwrap().fees();
 
         let genesis_pub = genesis_fees.recipient();
@@ -84,6 +89,27 @@ impl DeleteAssets {
     }
 }
 
+impl MultisigSpendable for DeleteAssets {
+    fn spender(&self) -> PublicKey {
+        *self.pub_key()
+    }
+
+    fn verify_unsigned(&self) -> bool {
+        true
+    }
+
+    fn process_authorized(&self, view: &mut Fork) -> Result<(), Error> {
+        self.process(view)
+    }
+}
+
+impl Processable for DeleteAssets {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
 lazy_static! {
     static ref VERIFY_COUNT: IntCounter = register_int_counter!(
         "dmbc_transaction_delete_assets_verify_count",
@@ -131,7 +157,7 @@ impl Transaction for DeleteAssets {
         EXECUTE_COUNT.inc();
         let timer = EXECUTE_DURATION.start_timer();
 
-        let result = self.process(view);
+        let result = self.process_enabled(view);
 
         if let &Ok(_) = &result {
             EXECUTE_SUCCESS_COUNT.inc();
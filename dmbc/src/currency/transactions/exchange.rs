@@ -8,9 +8,12 @@ use exonum::storage::Fork;
 use prometheus::{IntCounter, Histogram};
 
 use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
 use currency::error::Error;
 use currency::status;
-use currency::transactions::components::{FeeStrategy, FeesCalculator, ThirdPartyFees};
+use currency::transactions::components::{FeeStrategy, FeesCalculator, MultisigSpendable,
+                                         Processable, ThirdPartyFees};
 use currency::wallet;
 use currency::SERVICE_ID;
 use currency::service::CONFIGURATION;
@@ -103,10 +106,19 @@ impl Exchange {
     fn process(&self, view: &mut Fork) -> Result<(), Error> {
         info!("Processing tx: {:?}", self);
 
-        let genesis_fees = CONFIGURATION.read().unwrap().fees();
-
         let offer = self.offer();
 
+        // Compliance rules are checked before any value is moved.
+        compliance::check_transfer(
+            &*view,
+            offer.sender(),
+            offer.recipient(),
+            &offer.sender_assets(),
+        )?;
+        compliance::check_holder(&*view, offer.sender(), &offer.recipient_assets())?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
         let fee_strategy =
             FeeStrategy::try_from(offer.fee_strategy()).expect("fee strategy must be valid");
 
@@ -188,6 +200,41 @@ impl Exchange {
     }
 }
 
+impl MultisigSpendable for Exchange {
+    fn spender(&self) -> PublicKey {
+        *self.offer().sender()
+    }
+
+    fn verify_unsigned(&self) -> bool {
+        let offer = self.offer();
+
+        let wallets_ok = offer.sender() != offer.recipient();
+        let fee_strategy_ok = match FeeStrategy::try_from(offer.fee_strategy()) {
+            Some(FeeStrategy::Recipient)
+            | Some(FeeStrategy::Sender)
+            | Some(FeeStrategy::RecipientAndSender) => true,
+            _ => false,
+        };
+
+        if cfg!(fuzzing) {
+            return wallets_ok && fee_strategy_ok;
+        }
+
+        wallets_ok && fee_strategy_ok && self.verify_signature(offer.recipient())
+    }
+
+    fn process_authorized(&self, view: &mut Fork) -> Result<(), Error> {
+        self.process(view)
+    }
+}
+
+impl Processable for Exchange {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
 lazy_static! {
     static ref VERIFY_COUNT: IntCounter = register_int_counter!(
         "dmbc_transaction_exchange_verify_count",
@@ -246,7 +293,7 @@ impl Transaction for Exchange {
         EXECUTE_COUNT.inc();
         let timer = EXECUTE_DURATION.start_timer();
 
-        let result = self.process(view);
+        let result = self.process_enabled(view);
 
         if let &Ok(_) = &result {
             EXECUTE_SUCCESS_COUNT.inc();
@@ -8,9 +8,12 @@ use exonum::storage::Fork;
 use prometheus::{IntCounter, Histogram};
 
 use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
 use currency::error::Error;
 use currency::status;
-use currency::transactions::components::{FeeStrategy, FeesCalculator, Intermediary, ThirdPartyFees};
+use currency::transactions::components::{FeeStrategy, FeesCalculator, Intermediary, Processable,
+                                         ThirdPartyFees};
 use currency::wallet;
 use currency::{Service, SERVICE_ID};
 use currency::service::CONFIGURATION;
@@ -110,10 +113,20 @@ impl ExchangeIntermediary {
     fn process(&self, view: &mut Fork) -> Result<(), Error> {
         info!("Processing tx: {:?}", self);
 
-        let genesis_fees = CONFIGURATION.read().unwrap().fees();
-
         let offer = self.offer();
 
+        // Compliance rules are checked before any value is moved.
+        compliance::check_transfer(
+            &*view,
+            offer.sender(),
+            offer.recipient(),
+            &offer.sender_assets(),
+        )?;
+        compliance::check_holder(&*view, offer.sender(), &offer.recipient_assets())?;
+        compliance::check_wallets(&*view, Some(offer.intermediary().wallet()))?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
         let fee_strategy =
             FeeStrategy::try_from(offer.fee_strategy()).expect("fee strategy must be valid");
 
@@ -207,6 +220,13 @@ impl ExchangeIntermediary {
     }
 }
 
+impl Processable for ExchangeIntermediary {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
 lazy_static! {
     static ref VERIFY_COUNT: IntCounter = register_int_counter!(
         "dmbc_transaction_exchange_intermediary_verify_count",
@@ -269,7 +289,7 @@ impl Transaction for ExchangeIntermediary {
         EXECUTE_COUNT.inc();
         let timer = EXECUTE_DURATION.start_timer();
 
-        let result = self.process(view);
+        let result = self.process_enabled(view);
 
         if let &Ok(_) = &result {
             EXECUTE_SUCCESS_COUNT.inc();
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use exonum::blockchain;
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::compliance;
+use currency::compliance::Freeze;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const FREEZE_WALLET_ID: u16 = 1203;
+
+message! {
+    /// `freeze_wallet` transaction.
+    ///
+    /// Freezes a wallet so that it can neither send nor receive value. Only
+    /// a compliance officer may sign it.
+    struct FreezeWallet {
+        const TYPE = SERVICE_ID;
+        const ID = FREEZE_WALLET_ID;
+
+        officer: &PublicKey,
+        wallet:  &PublicKey,
+        reason:  &str,
+        seed:    u64,
+    }
+}
+
+impl FeesCalculator for FreezeWallet {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        Ok(HashMap::new())
+    }
+}
+
+impl FreezeWallet {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        if !Configuration::extract(&*view).is_compliance_officer(self.officer()) {
+            return Err(Error::NotComplianceOfficer);
+        }
+
+        let height = blockchain::Schema::new(&*view).height().next();
+        let freeze = Freeze::new(self.officer(), height.0, self.reason());
+        compliance::Schema(&mut *view).freeze(self.wallet(), freeze);
+
+        Ok(())
+    }
+}
+
+impl Processable for FreezeWallet {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_freeze_wallet_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_freeze_wallet_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_freeze_wallet_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_freeze_wallet_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_freeze_wallet_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_freeze_wallet_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for FreezeWallet {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let wallets_ok = self.officer() != self.wallet();
+
+        if cfg!(fuzzing) {
+            return wallets_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.officer());
+
+        if wallets_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
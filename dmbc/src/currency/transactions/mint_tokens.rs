@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets;
+use currency::assets::{AssetId, AssetInfo, Fees};
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::nft;
+use currency::nft::{MetaToken, Token, TokenId};
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const MINT_TOKENS_ID: u16 = 1301;
+
+message! {
+    /// `mint_tokens` transaction.
+    ///
+    /// Mints non-fungible tokens of the series named `name` to the
+    /// receiver. The series is registered as an asset with the given fees,
+    /// which are charged for every token moved, and counts the tokens
+    /// minted so far as its amount.
+    struct MintTokens {
+        const TYPE = SERVICE_ID;
+        const ID = MINT_TOKENS_ID;
+
+        creator:  &PublicKey,
+        receiver: &PublicKey,
+        name:     &str,
+        fees:     Fees,
+        tokens:   Vec<MetaToken>,
+        seed:     u64,
+    }
+}
+
+impl FeesCalculator for MintTokens {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.creator() {
+            fees_table.insert(*self.creator(), genesis_fees.add_assets());
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl MintTokens {
+    /// `AssetId` of the token series.
+    pub fn asset_id(&self) -> AssetId {
+        AssetId::from_series(self.name(), self.creator())
+    }
+
+    fn token_ids(&self) -> Vec<TokenId> {
+        let asset_id = self.asset_id();
+        self.tokens()
+            .iter()
+            .map(|token| token.to_id(asset_id))
+            .collect()
+    }
+
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let ids = self.token_ids();
+
+        // Compliance rules are checked before any value is moved.
+        compliance::check_wallets(&*view, vec![self.creator(), self.receiver()])?;
+        compliance::check_holder(&*view, self.receiver(), &nft::to_bundles(&ids))?;
+
+        for id in &ids {
+            if nft::Schema(&*view).fetch(id).is_some() {
+                return Err(Error::TokenExists);
+            }
+        }
+
+        let asset_id = self.asset_id();
+        let amount = ids.len() as u64;
+        let new_info = AssetInfo::new(
+            self.creator(),
+            &self.hash(),
+            amount,
+            self.fees(),
+            self.name(),
+        );
+        let info = match assets::Schema(&*view).fetch(&asset_id) {
+            // Fungible assets can't be minted as tokens.
+            Some(_) if !nft::Schema(&*view).is_series(&asset_id) => {
+                return Err(Error::InvalidAssetInfo)
+            }
+            Some(ref info) if info.creator() != self.creator() => {
+                return Err(Error::NotAssetCreator)
+            }
+            Some(info) => info.merge(new_info)?,
+            None => new_info,
+        };
+
+        let supply = match assets::Schema(&*view).fetch_supply(&asset_id) {
+            Some(supply) => Some(supply.mint(amount)?),
+            None => None,
+        };
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut creator = wallet::Schema(&*view).fetch(self.creator());
+        wallet::move_coins(&mut creator, &mut genesis, genesis_fees.add_assets())?;
+
+        wallet::Schema(&mut *view).store(self.creator(), creator);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        assets::Schema(&mut *view).store(&asset_id, info);
+        if let Some(supply) = supply {
+            assets::Schema(&mut *view).store_supply(&asset_id, supply);
+        }
+
+        let origin = self.hash();
+        for (id, meta) in ids.into_iter().zip(self.tokens()) {
+            let token = Token::new(id, self.creator(), self.receiver(), &origin, meta.data());
+            nft::Schema(&mut *view).store(token);
+        }
+
+        Ok(())
+    }
+}
+
+impl Processable for MintTokens {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_mint_tokens_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_mint_tokens_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_mint_tokens_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_mint_tokens_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_mint_tokens_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_mint_tokens_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for MintTokens {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let tokens = self.tokens();
+        let tokens_ok = !tokens.is_empty()
+            && tokens.iter().all(|token| token.verify())
+            && nft::unique(&self.token_ids());
+
+        if cfg!(fuzzing) {
+            return tokens_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.creator());
+
+        if tokens_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -2,20 +2,90 @@
 
 pub mod builders;
 pub mod components;
+pub mod quote;
 
+mod accept_loan;
 mod add_assets;
+mod add_to_collection;
+mod approve;
+mod cancel_loan;
+mod cart_checkout;
+mod claim_collateral;
+mod claim_vested;
+mod create_collection;
+mod create_multisig;
+mod create_vesting;
 mod delete_assets;
 mod exchange;
 mod exchange_intermediary;
+mod exchange_tokens;
+mod freeze_wallet;
+mod mint_tokens;
+mod multi_exchange;
+mod multi_transfer;
+mod multisig_approve;
+mod multisig_propose;
+mod multisig_spend;
+mod offer_loan;
+mod return_loan;
+mod revoke_approval;
+mod set_asset_metadata;
+mod set_asset_restriction;
+mod set_asset_supply;
 mod trade;
 mod trade_intermediary;
+mod trade_tokens;
 mod transfer;
+mod transfer_asset_creator;
+mod transfer_from;
+mod transfer_tokens;
+mod unfreeze_wallet;
+mod update_allowlist;
+mod update_asset_fees;
 
+pub use currency::transactions::accept_loan::{AcceptLoan, ACCEPT_LOAN_ID};
 pub use currency::transactions::add_assets::{AddAssets, ADD_ASSETS_ID};
+pub use currency::transactions::add_to_collection::{AddToCollection, ADD_TO_COLLECTION_ID};
+pub use currency::transactions::approve::{Approve, APPROVE_ID};
+pub use currency::transactions::cancel_loan::{CancelLoan, CANCEL_LOAN_ID};
+pub use currency::transactions::cart_checkout::{CartCheckout, CartItem, CART_CHECKOUT_ID,
+                                                MAX_CART_ITEMS};
+pub use currency::transactions::claim_collateral::{ClaimCollateral, CLAIM_COLLATERAL_ID};
+pub use currency::transactions::claim_vested::{ClaimVested, CLAIM_VESTED_ID};
+pub use currency::transactions::create_collection::{CreateCollection, CREATE_COLLECTION_ID};
+pub use currency::transactions::create_multisig::{CreateMultisig, CREATE_MULTISIG_ID};
+pub use currency::transactions::create_vesting::{CreateVesting, CREATE_VESTING_ID,
+                                                 MAX_VESTING_END};
 pub use currency::transactions::delete_assets::{DeleteAssets, DELETE_ASSETS_ID};
 pub use currency::transactions::exchange::{Exchange, EXCHANGE_ID};
 pub use currency::transactions::exchange_intermediary::{ExchangeIntermediary,
                                                         EXCHANGE_INTERMEDIARY_ID};
+pub use currency::transactions::exchange_tokens::{ExchangeTokens, ExchangeTokensOffer,
+                                                   EXCHANGE_TOKENS_ID};
+pub use currency::transactions::freeze_wallet::{FreezeWallet, FREEZE_WALLET_ID};
+pub use currency::transactions::mint_tokens::{MintTokens, MINT_TOKENS_ID};
+pub use currency::transactions::multi_exchange::{ExchangeLeg, MultiExchange, MultiExchangeOffer,
+                                                 MAX_EXCHANGE_LEGS, MULTI_EXCHANGE_ID};
+pub use currency::transactions::multi_transfer::{MultiTransfer, TransferOutput,
+                                                 MAX_TRANSFER_OUTPUTS, MULTI_TRANSFER_ID};
+pub use currency::transactions::multisig_approve::{MultisigApprove, MULTISIG_APPROVE_ID};
+pub use currency::transactions::multisig_propose::{MultisigPropose, MULTISIG_PROPOSE_ID};
+pub use currency::transactions::multisig_spend::{MultisigSpend, MULTISIG_SPEND_ID};
+pub use currency::transactions::offer_loan::{OfferLoan, OFFER_LOAN_ID};
+pub use currency::transactions::return_loan::{ReturnLoan, RETURN_LOAN_ID};
+pub use currency::transactions::revoke_approval::{RevokeApproval, REVOKE_APPROVAL_ID};
+pub use currency::transactions::set_asset_metadata::{SetAssetMetadata, SET_ASSET_METADATA_ID};
+pub use currency::transactions::set_asset_restriction::{SetAssetRestriction,
+                                                        SET_ASSET_RESTRICTION_ID};
+pub use currency::transactions::set_asset_supply::{SetAssetSupply, SET_ASSET_SUPPLY_ID};
 pub use currency::transactions::trade::{Trade, TRADE_ID};
 pub use currency::transactions::trade_intermediary::{TradeIntermediary, TRADE_INTERMEDIARY_ID};
+pub use currency::transactions::trade_tokens::{TradeTokens, TradeTokensOffer, TRADE_TOKENS_ID};
 pub use currency::transactions::transfer::{Transfer, TRANSFER_ID};
+pub use currency::transactions::transfer_asset_creator::{TransferAssetCreator,
+                                                         TRANSFER_ASSET_CREATOR_ID};
+pub use currency::transactions::transfer_from::{TransferFrom, TRANSFER_FROM_ID};
+pub use currency::transactions::transfer_tokens::{TransferTokens, TRANSFER_TOKENS_ID};
+pub use currency::transactions::unfreeze_wallet::{UnfreezeWallet, UNFREEZE_WALLET_ID};
+pub use currency::transactions::update_allowlist::{UpdateAllowlist, UPDATE_ALLOWLIST_ID};
+pub use currency::transactions::update_asset_fees::{UpdateAssetFees, UPDATE_ASSET_FEES_ID};
@@ -0,0 +1,308 @@
+use std::collections::{HashMap, HashSet};
+
+use exonum::blockchain::Transaction;
+use exonum::crypto;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::offers;
+use currency::status;
+use currency::transactions::components::{atomically, FeeStrategy, FeesCalculator, PartySignature,
+                                         Processable, ThirdPartyFees};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const MULTI_EXCHANGE_ID: u16 = 603;
+
+/// Maximum number of legs in a single exchange.
+pub const MAX_EXCHANGE_LEGS: usize = 16;
+
+encoding_struct! {
+    /// Transfer of coins and assets from one participant of a
+    /// `multi_exchange` transaction to another.
+    struct ExchangeLeg {
+        from:   &PublicKey,
+        to:     &PublicKey,
+        value:  u64,
+        assets: Vec<AssetBundle>,
+    }
+}
+
+encoding_struct! {
+    struct MultiExchangeOffer {
+        legs:         Vec<ExchangeLeg>,
+
+        fee_strategy: u8,
+        seed:         u64,
+        data_info:    &str,
+    }
+}
+
+message! {
+    /// `multi_exchange` transaction.
+    struct MultiExchange {
+        const TYPE = SERVICE_ID;
+        const ID = MULTI_EXCHANGE_ID;
+
+        submitter:  &PublicKey,
+        offer:      MultiExchangeOffer,
+        signatures: Vec<PartySignature>,
+    }
+}
+
+impl ExchangeLeg {
+    fn payers(&self, fee_strategy: &FeeStrategy) -> Result<Vec<PublicKey>, Error> {
+        let payers = match *fee_strategy {
+            FeeStrategy::Recipient => vec![*self.to()],
+            FeeStrategy::Sender => vec![*self.from()],
+            FeeStrategy::RecipientAndSender => vec![*self.from(), *self.to()],
+            FeeStrategy::Intermediary => return Err(Error::InvalidTransaction),
+        };
+        Ok(payers)
+    }
+}
+
+impl MultiExchangeOffer {
+    /// Distinct participants that give something in the exchange.
+    pub fn givers(&self) -> Vec<PublicKey> {
+        let mut givers = Vec::new();
+        for leg in self.legs() {
+            if !givers.contains(leg.from()) {
+                givers.push(*leg.from());
+            }
+        }
+        givers
+    }
+
+    /// Distinct participants of the exchange.
+    pub fn participants(&self) -> Vec<PublicKey> {
+        let mut participants = Vec::new();
+        for leg in self.legs() {
+            for key in &[*leg.from(), *leg.to()] {
+                if !participants.contains(key) {
+                    participants.push(*key);
+                }
+            }
+        }
+        participants
+    }
+
+    /// Distinct participants that share the blockchain fee.
+    fn payers(&self, fee_strategy: &FeeStrategy) -> Result<Vec<PublicKey>, Error> {
+        let mut payers = Vec::new();
+        for leg in self.legs() {
+            for key in leg.payers(fee_strategy)? {
+                if !payers.contains(&key) {
+                    payers.push(key);
+                }
+            }
+        }
+        Ok(payers)
+    }
+}
+
+impl FeesCalculator for MultiExchange {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let offer = self.offer();
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+        let fee_strategy =
+            FeeStrategy::try_from(offer.fee_strategy()).expect("fee strategy must be valid");
+
+        let mut fees_table = HashMap::new();
+
+        let payers = offer.payers(&fee_strategy)?;
+        for payer_key in &payers {
+            if genesis_fees.recipient() != payer_key {
+                *fees_table.entry(*payer_key).or_insert(0) +=
+                    genesis_fees.exchange() / payers.len() as u64;
+            }
+        }
+
+        for leg in offer.legs() {
+            let fees = ThirdPartyFees::new_exchange(&*view, leg.assets())?;
+            let payers = leg.payers(&fee_strategy)?;
+
+            for (receiver_key, fee) in fees.0 {
+                for payer_key in &payers {
+                    if *payer_key != receiver_key {
+                        *fees_table.entry(*payer_key).or_insert(0) += fee / payers.len() as u64;
+                    }
+                }
+            }
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl MultiExchange {
+    /// Get raw bytes of the offer.
+    pub fn offer_raw(&self) -> Vec<u8> {
+        self.offer().raw
+    }
+
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let offer = self.offer();
+
+        // Every participant may submit the offer, but it is executed only once.
+        let offer_hash = crypto::hash(&offer.raw);
+        if offers::Schema(&*view).is_executed(&offer_hash) {
+            return Err(Error::OfferExecuted);
+        }
+
+        // Compliance rules are checked before any value is moved.
+        for leg in offer.legs() {
+            compliance::check_transfer(&*view, leg.from(), leg.to(), &leg.assets())?;
+        }
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let fee_strategy =
+            FeeStrategy::try_from(offer.fee_strategy()).expect("fee strategy must be valid");
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut genesis_fee = ThirdPartyFees(HashMap::new());
+        genesis_fee.add_fee(genesis_fees.recipient(), genesis_fees.exchange());
+
+        let updated_wallets = genesis_fee.collect_split(view, &offer.payers(&fee_strategy)?)?;
+        for (key, wallet) in updated_wallets {
+            wallet::Schema(&mut *view).store(&key, wallet);
+        }
+
+        // Legs must either all succeed, or none of them.
+        atomically(view, |view| {
+            for leg in offer.legs() {
+                // Process third party fees.
+                let fees = ThirdPartyFees::new_exchange(&*view, leg.assets())?;
+                let mut updated_wallets = fees.collect_split(view, &leg.payers(&fee_strategy)?)?;
+
+                // Process the leg itself.
+                let mut from = updated_wallets
+                    .remove(leg.from())
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(leg.from()));
+                let mut to = updated_wallets
+                    .remove(leg.to())
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(leg.to()));
+
+                wallet::move_coins(&mut from, &mut to, leg.value())?;
+                wallet::move_assets(&mut from, &mut to, &leg.assets())?;
+
+                updated_wallets.insert(*leg.from(), from);
+                updated_wallets.insert(*leg.to(), to);
+
+                for (key, wallet) in updated_wallets {
+                    wallet::Schema(&mut *view).store(&key, wallet);
+                }
+            }
+
+            Ok(())
+        })?;
+
+        offers::Schema(&mut *view).store(&offer_hash);
+        Ok(())
+    }
+}
+
+impl Processable for MultiExchange {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_exchange_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_exchange_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_exchange_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_exchange_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_exchange_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_multi_exchange_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for MultiExchange {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let offer = self.offer();
+        let legs = offer.legs();
+
+        let legs_ok = !legs.is_empty()
+            && legs.len() <= MAX_EXCHANGE_LEGS
+            && legs.iter().all(|leg| leg.from() != leg.to());
+        let fee_strategy_ok = match FeeStrategy::try_from(offer.fee_strategy()) {
+            Some(FeeStrategy::Recipient)
+            | Some(FeeStrategy::Sender)
+            | Some(FeeStrategy::RecipientAndSender) => true,
+            _ => false,
+        };
+        let submitter_ok = offer.participants().contains(self.submitter());
+
+        if cfg!(fuzzing) {
+            return legs_ok && fee_strategy_ok && submitter_ok;
+        }
+
+        // Every giving party must sign the offer exactly once, and no one else.
+        let givers = offer.givers();
+        let signatures = self.signatures();
+        let mut signers = HashSet::new();
+        let signatures_ok = signatures.len() == givers.len()
+            && signatures.iter().all(|signature| {
+                givers.contains(signature.pub_key())
+                    && signers.insert(*signature.pub_key())
+                    && signature.verify(&offer.raw)
+            });
+
+        let submitter_verify_ok = self.verify_signature(self.submitter());
+
+        if legs_ok && fee_strategy_ok && submitter_ok && signatures_ok && submitter_verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{atomically, FeesCalculator, Processable, ThirdPartyFees};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const MULTI_TRANSFER_ID: u16 = 201;
+
+/// Maximum number of outputs in a single transfer.
+pub const MAX_TRANSFER_OUTPUTS: usize = 64;
+
+encoding_struct! {
+    /// Single recipient of a `multi_transfer` transaction.
+    struct TransferOutput {
+        to:        &PublicKey,
+        amount:    u64,
+        assets:    Vec<AssetBundle>,
+        data_info: &str,
+    }
+}
+
+message! {
+    /// `multi_transfer` transaction.
+    struct MultiTransfer {
+        const TYPE = SERVICE_ID;
+        const ID = MULTI_TRANSFER_ID;
+
+        from:    &PublicKey,
+        outputs: Vec<TransferOutput>,
+        seed:    u64,
+    }
+}
+
+impl FeesCalculator for MultiTransfer {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.from() {
+            fees_table.insert(*self.from(), genesis_fees.transfer());
+        }
+
+        for output in self.outputs() {
+            let fees = ThirdPartyFees::new_transfer(&*view, output.assets())?;
+
+            for (pub_key, fee) in fees.0 {
+                if pub_key != *self.from() {
+                    *fees_table.entry(*self.from()).or_insert(0) += fee;
+                }
+            }
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl MultiTransfer {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        // Compliance rules are checked before any value is moved.
+        for output in self.outputs() {
+            compliance::check_transfer(&*view, self.from(), output.to(), &output.assets())?;
+        }
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut wallet_from = wallet::Schema(&*view).fetch(self.from());
+        wallet::move_coins(&mut wallet_from, &mut genesis, genesis_fees.transfer())?;
+
+        wallet::Schema(&mut *view).store(self.from(), wallet_from);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        // Outputs must either all be paid out, or none of them.
+        atomically(view, |view| {
+            for output in self.outputs() {
+                // Process third party fees.
+                let fees = ThirdPartyFees::new_transfer(&*view, output.assets())?;
+                let mut updated_wallets = fees.collect(view, self.from())?;
+
+                // Process the output itself.
+                let mut wallet_from = updated_wallets
+                    .remove(self.from())
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(self.from()));
+                let mut wallet_to = updated_wallets
+                    .remove(output.to())
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(output.to()));
+
+                wallet::move_coins(&mut wallet_from, &mut wallet_to, output.amount())?;
+                wallet::move_assets(&mut wallet_from, &mut wallet_to, &output.assets())?;
+
+                updated_wallets.insert(*self.from(), wallet_from);
+                updated_wallets.insert(*output.to(), wallet_to);
+
+                for (key, wallet) in updated_wallets {
+                    wallet::Schema(&mut *view).store(&key, wallet);
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl Processable for MultiTransfer {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_transfer_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_transfer_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_transfer_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_transfer_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multi_transfer_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_multi_transfer_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for MultiTransfer {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let outputs = self.outputs();
+
+        let count_ok = !outputs.is_empty() && outputs.len() <= MAX_TRANSFER_OUTPUTS;
+        let wallets_ok = outputs.iter().all(|output| output.to() != self.from());
+
+        if cfg!(fuzzing) {
+            return count_ok && wallets_ok;
+        }
+
+        let verify_ok = self.verify_signature(&self.from());
+
+        if count_ok && wallets_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::multisig;
+use currency::status;
+use currency::transactions::components::{execute_authorized, multisig_tx_from_raw, FeesCalculator,
+                                         Processable};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const MULTISIG_APPROVE_ID: u16 = 704;
+
+message! {
+    /// `multisig_approve` transaction.
+    struct MultisigApprove {
+        const TYPE = SERVICE_ID;
+        const ID = MULTISIG_APPROVE_ID;
+
+        approver: &PublicKey,
+        proposal: &Hash,
+        seed:     u64,
+    }
+}
+
+impl FeesCalculator for MultisigApprove {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let proposal = multisig::Schema(&*view)
+            .fetch_proposal(self.proposal())
+            .ok_or_else(|| Error::ProposalNotFound)?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+        let mut fees_table = multisig_tx_from_raw(proposal.tx())?.calculate_fees(view)?;
+
+        if genesis_fees.recipient() != self.approver() {
+            *fees_table.entry(*self.approver()).or_insert(0) += genesis_fees.transfer();
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl MultisigApprove {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut approver = wallet::Schema(&*view).fetch(self.approver());
+        wallet::move_coins(&mut approver, &mut genesis, genesis_fees.transfer())?;
+
+        wallet::Schema(&mut *view).store(self.approver(), approver);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        let proposal = multisig::Schema(&*view)
+            .fetch_proposal(self.proposal())
+            .ok_or_else(|| Error::ProposalNotFound)?;
+
+        let config = multisig::Schema(&*view)
+            .fetch(proposal.wallet())
+            .ok_or_else(|| Error::MultisigNotFound)?;
+
+        if !config.is_member(self.approver()) {
+            return Err(Error::NotMultisigMember);
+        }
+
+        if proposal.is_approved_by(self.approver()) {
+            return Err(Error::AlreadyApproved);
+        }
+
+        let proposal = proposal.approve(self.approver());
+
+        if proposal.approvals().len() < config.threshold() as usize {
+            multisig::Schema(&mut *view).store_proposal(self.proposal(), proposal);
+            return Ok(());
+        }
+
+        // Threshold is reached, the proposal is no longer pending whatever
+        // the outcome of its execution.
+        multisig::Schema(&mut *view).remove_proposal(self.proposal());
+
+        let tx = multisig_tx_from_raw(proposal.tx())?;
+        execute_authorized(view, &*tx)
+    }
+}
+
+impl Processable for MultisigApprove {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_approve_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_approve_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_approve_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_approve_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_approve_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_multisig_approve_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for MultisigApprove {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        if cfg!(fuzzing) {
+            return true;
+        }
+
+        if self.verify_signature(self.approver()) {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
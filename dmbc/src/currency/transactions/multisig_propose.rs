@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::multisig;
+use currency::multisig::{MultisigMember, Proposal};
+use currency::status;
+use currency::transactions::components::{execute_authorized, multisig_tx_from_raw, FeesCalculator,
+                                         Processable};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const MULTISIG_PROPOSE_ID: u16 = 703;
+
+message! {
+    /// `multisig_propose` transaction.
+    ///
+    /// Proposes a spending transaction from a multisig wallet. The proposal
+    /// is identified by the hash of this transaction and is executed once
+    /// enough members approve it.
+    struct MultisigPropose {
+        const TYPE = SERVICE_ID;
+        const ID = MULTISIG_PROPOSE_ID;
+
+        proposer: &PublicKey,
+        wallet:   &PublicKey,
+        tx:       &[u8],
+        seed:     u64,
+    }
+}
+
+impl FeesCalculator for MultisigPropose {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+        let mut fees_table = multisig_tx_from_raw(self.tx())?.calculate_fees(view)?;
+
+        if genesis_fees.recipient() != self.proposer() {
+            *fees_table.entry(*self.proposer()).or_insert(0) += genesis_fees.transfer();
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl MultisigPropose {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut proposer = wallet::Schema(&*view).fetch(self.proposer());
+        wallet::move_coins(&mut proposer, &mut genesis, genesis_fees.transfer())?;
+
+        wallet::Schema(&mut *view).store(self.proposer(), proposer);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        let config = multisig::Schema(&*view)
+            .fetch(self.wallet())
+            .ok_or_else(|| Error::MultisigNotFound)?;
+
+        if !config.is_member(self.proposer()) {
+            return Err(Error::NotMultisigMember);
+        }
+
+        let tx = multisig_tx_from_raw(self.tx())?;
+        if status::Schema(&*view).fetch(&tx.hash()).is_some() {
+            return Err(Error::AlreadyExecuted);
+        }
+
+        // The proposer's approval may already be enough.
+        if config.threshold() <= 1 {
+            return execute_authorized(view, &*tx);
+        }
+
+        let approvals = vec![MultisigMember::new(self.proposer())];
+        let proposal = Proposal::new(self.wallet(), self.tx(), approvals);
+        multisig::Schema(&mut *view).store_proposal(&self.hash(), proposal);
+
+        Ok(())
+    }
+}
+
+impl Processable for MultisigPropose {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_propose_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_propose_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_propose_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_propose_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_propose_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_multisig_propose_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for MultisigPropose {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let tx_ok = match multisig_tx_from_raw(self.tx()) {
+            Ok(tx) => tx.spender() == *self.wallet() && tx.verify_unsigned(),
+            Err(_) => false,
+        };
+
+        if cfg!(fuzzing) {
+            return tx_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.proposer());
+
+        if tx_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::{HashMap, HashSet};
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::multisig;
+use currency::status;
+use currency::transactions::components::{execute_authorized, multisig_tx_from_raw, FeesCalculator,
+                                         PartySignature, Processable};
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const MULTISIG_SPEND_ID: u16 = 702;
+
+message! {
+    /// `multisig_spend` transaction.
+    ///
+    /// Carries a spending transaction together with the signatures of
+    /// enough multisig members to execute it right away.
+    struct MultisigSpend {
+        const TYPE = SERVICE_ID;
+        const ID = MULTISIG_SPEND_ID;
+
+        submitter:  &PublicKey,
+        wallet:     &PublicKey,
+        tx:         &[u8],
+        signatures: Vec<PartySignature>,
+    }
+}
+
+impl FeesCalculator for MultisigSpend {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        multisig_tx_from_raw(self.tx())?.calculate_fees(view)
+    }
+}
+
+impl MultisigSpend {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let config = multisig::Schema(&*view)
+            .fetch(self.wallet())
+            .ok_or_else(|| Error::MultisigNotFound)?;
+
+        let signatures = self.signatures();
+        if !signatures
+            .iter()
+            .all(|signature| config.is_member(signature.pub_key()))
+        {
+            return Err(Error::NotMultisigMember);
+        }
+
+        if signatures.len() < config.threshold() as usize {
+            return Err(Error::InsufficientSignatures);
+        }
+
+        let tx = multisig_tx_from_raw(self.tx())?;
+        execute_authorized(view, &*tx)
+    }
+}
+
+impl Processable for MultisigSpend {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_spend_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_spend_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_spend_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_spend_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_multisig_spend_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_multisig_spend_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for MultisigSpend {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let tx_ok = match multisig_tx_from_raw(self.tx()) {
+            Ok(tx) => tx.spender() == *self.wallet() && tx.verify_unsigned(),
+            Err(_) => false,
+        };
+
+        let signatures = self.signatures();
+        let mut signers = HashSet::new();
+        let unique_ok = !signatures.is_empty()
+            && signatures
+                .iter()
+                .all(|signature| signers.insert(*signature.pub_key()));
+
+        if cfg!(fuzzing) {
+            return tx_ok && unique_ok;
+        }
+
+        let signatures_ok = signatures
+            .iter()
+            .all(|signature| signature.verify(self.tx()));
+        let submitter_ok = self.verify_signature(self.submitter());
+
+        if tx_ok && unique_ok && signatures_ok && submitter_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
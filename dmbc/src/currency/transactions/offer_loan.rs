@@ -0,0 +1,175 @@
+use std::collections::{HashMap, HashSet};
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::lending;
+use currency::lending::{Loan, LoanState};
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::wallet;
+use currency::wallet::Wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const OFFER_LOAN_ID: u16 = 1001;
+
+message! {
+    /// `offer_loan` transaction.
+    ///
+    /// Offers assets for rent. Assets are held by the loan until the
+    /// offer is accepted or cancelled. The borrower has `duration` blocks
+    /// to return them, and pays `rental_fee` out of the `collateral`.
+    struct OfferLoan {
+        const TYPE = SERVICE_ID;
+        const ID = OFFER_LOAN_ID;
+
+        lender:     &PublicKey,
+        assets:     Vec<AssetBundle>,
+        collateral: u64,
+        rental_fee: u64,
+        duration:   u64,
+        seed:       u64,
+    }
+}
+
+impl FeesCalculator for OfferLoan {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.lender() {
+            fees_table.insert(*self.lender(), genesis_fees.transfer());
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl OfferLoan {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        // Compliance rules are checked before any value is moved.
+        compliance::check_wallets(&*view, Some(self.lender()))?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut lender = wallet::Schema(&*view).fetch(self.lender());
+        wallet::move_coins(&mut lender, &mut genesis, genesis_fees.transfer())?;
+
+        wallet::Schema(&mut *view).store(self.lender(), lender);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        // Escrow the offered assets.
+        let mut lender = wallet::Schema(&*view).fetch(self.lender());
+        let mut escrow = Wallet::new_empty();
+
+        wallet::move_assets(&mut lender, &mut escrow, &self.assets())?;
+
+        wallet::Schema(&mut *view).store(self.lender(), lender);
+
+        let loan = Loan::new(
+            self.lender(),
+            &PublicKey::zero(),
+            self.assets(),
+            self.collateral(),
+            self.rental_fee(),
+            self.duration(),
+            0,
+            LoanState::Offered as u8,
+        );
+        lending::Schema(&mut *view).store(&self.hash(), loan);
+
+        Ok(())
+    }
+}
+
+impl Processable for OfferLoan {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_offer_loan_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_offer_loan_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_offer_loan_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_offer_loan_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_offer_loan_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_offer_loan_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for OfferLoan {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let assets = self.assets();
+        let mut ids = HashSet::new();
+
+        let assets_ok = !assets.is_empty()
+            && assets
+                .iter()
+                .all(|asset| asset.amount() > 0 && ids.insert(asset.id()));
+        let terms_ok = self.rental_fee() <= self.collateral() && self.duration() > 0;
+
+        if cfg!(fuzzing) {
+            return assets_ok && terms_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.lender());
+
+        if assets_ok && terms_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
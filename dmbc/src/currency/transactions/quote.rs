@@ -0,0 +1,157 @@
+//! Fee quotes for transactions that are not built or signed yet.
+//!
+//! A quote builds the described transaction with an empty signature and runs
+//! its `FeesCalculator`, so the numbers are the same as for the signed one.
+
+use std::collections::HashMap;
+
+use exonum::crypto::{PublicKey, Signature};
+use exonum::storage::Fork;
+
+use currency::assets::{AssetBundle, MetaAsset, TradeAsset};
+use currency::error::Error;
+use currency::transactions::components::{FeeStrategy, FeesCalculator, ThirdPartyFees};
+use currency::transactions::exchange::{Exchange, ExchangeOffer};
+use currency::transactions::trade::TradeOffer;
+use currency::transactions::{AddAssets, DeleteAssets, Transfer};
+
+/// Description of a transaction to quote the fees for.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FeeQuoteRequest {
+    Transfer {
+        from: PublicKey,
+        to: PublicKey,
+        assets: Vec<AssetBundle>,
+    },
+    AddAssets {
+        creator: PublicKey,
+        assets: Vec<MetaAsset>,
+    },
+    DeleteAssets {
+        owner: PublicKey,
+        assets: Vec<AssetBundle>,
+    },
+    Trade {
+        buyer: PublicKey,
+        seller: PublicKey,
+        assets: Vec<TradeAsset>,
+        fee_strategy: u8,
+    },
+    Exchange {
+        sender: PublicKey,
+        sender_assets: Vec<AssetBundle>,
+        recipient: PublicKey,
+        recipient_assets: Vec<AssetBundle>,
+        fee_strategy: u8,
+    },
+}
+
+/// Fees of a described transaction.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FeeQuote {
+    /// Fees charged from each payer, as calculated by `FeesCalculator`.
+    pub payers: HashMap<PublicKey, u64>,
+    /// Fees owed to asset creators and the genesis wallet before they are
+    /// split between the payers, see `ThirdPartyFees`.
+    pub third_party: HashMap<PublicKey, u64>,
+}
+
+impl FeeQuoteRequest {
+    /// Quote the fees against the given state.
+    ///
+    /// # Errors
+    /// Returns `InvalidTransaction` for an unknown fee strategy and any error
+    /// the fee calculation of the transaction type returns.
+    pub fn quote(&self, view: &mut Fork) -> Result<FeeQuote, Error> {
+        let signature = Signature::zero();
+
+        let (payers, third_party) = match *self {
+            FeeQuoteRequest::Transfer {
+                ref from,
+                ref to,
+                ref assets,
+            } => {
+                let tx = Transfer::new_with_signature(
+                    from,
+                    to,
+                    0,
+                    assets.clone(),
+                    0,
+                    "",
+                    &signature,
+                );
+                let third_party = ThirdPartyFees::new_transfer(&*view, assets.clone())?;
+                (tx.calculate_fees(view)?, third_party)
+            }
+            FeeQuoteRequest::AddAssets {
+                ref creator,
+                ref assets,
+            } => {
+                let tx = AddAssets::new_with_signature(
+                    creator,
+                    assets.clone(),
+                    0,
+                    &signature,
+                );
+                let third_party = ThirdPartyFees::new_add_assets(&*view, assets.clone())?;
+                (tx.calculate_fees(view)?, third_party)
+            }
+            FeeQuoteRequest::DeleteAssets {
+                ref owner,
+                ref assets,
+            } => {
+                let tx = DeleteAssets::new_with_signature(owner, assets.clone(), 0, &signature);
+                let third_party = ThirdPartyFees::new_delete_assets(&*view, assets.clone())?;
+                (tx.calculate_fees(view)?, third_party)
+            }
+            FeeQuoteRequest::Trade {
+                ref buyer,
+                ref seller,
+                ref assets,
+                fee_strategy,
+            } => {
+                check_fee_strategy(fee_strategy)?;
+                let offer = TradeOffer::new(buyer, seller, assets.clone(), fee_strategy, 0, "");
+                let third_party = ThirdPartyFees::new_trade(&*view, assets)?;
+                (offer.calculate_fees(view)?, third_party)
+            }
+            FeeQuoteRequest::Exchange {
+                ref sender,
+                ref sender_assets,
+                ref recipient,
+                ref recipient_assets,
+                fee_strategy,
+            } => {
+                check_fee_strategy(fee_strategy)?;
+                let offer = ExchangeOffer::new(
+                    sender,
+                    sender_assets.clone(),
+                    0,
+                    recipient,
+                    recipient_assets.clone(),
+                    fee_strategy,
+                    0,
+                    "",
+                );
+                let tx = Exchange::new_with_signature(offer, &signature, &signature);
+                let third_party = ThirdPartyFees::new_exchange(
+                    &*view,
+                    sender_assets.iter().chain(recipient_assets).cloned(),
+                )?;
+                (tx.calculate_fees(view)?, third_party)
+            }
+        };
+
+        Ok(FeeQuote {
+            payers,
+            third_party: third_party.0,
+        })
+    }
+}
+
+fn check_fee_strategy(fee_strategy: u8) -> Result<(), Error> {
+    FeeStrategy::try_from(fee_strategy)
+        .map(|_| ())
+        .ok_or(Error::InvalidTransaction)
+}
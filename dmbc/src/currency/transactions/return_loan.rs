@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::{Hash, PublicKey};
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::lending;
+use currency::lending::LoanState;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::wallet;
+use currency::wallet::Wallet;
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const RETURN_LOAN_ID: u16 = 1003;
+
+message! {
+    /// `return_loan` transaction.
+    ///
+    /// Returns the assets to the lender and releases the collateral, minus
+    /// the rental fee paid to the lender. Loans can be returned after the
+    /// deadline as long as the lender has not claimed the collateral.
+    struct ReturnLoan {
+        const TYPE = SERVICE_ID;
+        const ID = RETURN_LOAN_ID;
+
+        borrower: &PublicKey,
+        loan:     &Hash,
+        seed:     u64,
+    }
+}
+
+impl FeesCalculator for ReturnLoan {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        Ok(HashMap::new())
+    }
+}
+
+impl ReturnLoan {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let loan = lending::Schema(&*view)
+            .fetch(self.loan())
+            .ok_or_else(|| Error::LoanNotFound)?;
+
+        if loan.loan_state() != LoanState::Active {
+            return Err(Error::LoanNotAvailable);
+        }
+
+        if loan.borrower() != self.borrower() {
+            return Err(Error::NotLoanParticipant);
+        }
+
+        // Compliance rules are checked before any value is moved.
+        compliance::check_transfer(&*view, self.borrower(), loan.lender(), &loan.assets())?;
+
+        let mut borrower = wallet::Schema(&*view).fetch(self.borrower());
+        let mut lender = wallet::Schema(&*view).fetch(loan.lender());
+        let mut collateral = Wallet::new(loan.collateral(), Vec::new());
+
+        wallet::move_assets(&mut borrower, &mut lender, &loan.assets())?;
+        wallet::move_coins(&mut collateral, &mut lender, loan.rental_fee())?;
+        wallet::move_coins(&mut collateral, &mut borrower, loan.collateral() - loan.rental_fee())?;
+
+        wallet::Schema(&mut *view).store(self.borrower(), borrower);
+        wallet::Schema(&mut *view).store(loan.lender(), lender);
+        lending::Schema(&mut *view).store(self.loan(), loan.close(LoanState::Returned));
+
+        Ok(())
+    }
+}
+
+impl Processable for ReturnLoan {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_return_loan_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_return_loan_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_return_loan_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_return_loan_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_return_loan_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_return_loan_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for ReturnLoan {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        if cfg!(fuzzing) {
+            return true;
+        }
+
+        let verify_ok = self.verify_signature(self.borrower());
+
+        if verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
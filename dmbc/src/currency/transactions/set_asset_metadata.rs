@@ -0,0 +1,150 @@
+use std::collections::{HashMap, HashSet};
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets;
+use currency::assets::{AssetId, MetadataRef};
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const SET_ASSET_METADATA_ID: u16 = 1104;
+
+message! {
+    /// `set_asset_metadata` transaction.
+    ///
+    /// Attaches off-chain metadata references to assets created from
+    /// their content hashes. Only the creator of the assets may sign it,
+    /// and a reference can be set only once.
+    struct SetAssetMetadata {
+        const TYPE = SERVICE_ID;
+        const ID = SET_ASSET_METADATA_ID;
+
+        creator:  &PublicKey,
+        metadata: Vec<MetadataRef>,
+        seed:     u64,
+    }
+}
+
+impl FeesCalculator for SetAssetMetadata {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        Ok(HashMap::new())
+    }
+}
+
+impl SetAssetMetadata {
+    /// ID of the asset committing to the metadata.
+    fn asset_id(&self, metadata: &MetadataRef) -> AssetId {
+        AssetId::from_data(&metadata.asset_data(), self.creator())
+    }
+
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        for metadata in self.metadata() {
+            let id = self.asset_id(&metadata);
+            let info = assets::Schema(&*view)
+                .fetch(&id)
+                .ok_or_else(|| Error::AssetNotFound)?;
+
+            if info.creator() != self.creator() {
+                return Err(Error::NotAssetCreator);
+            }
+
+            if assets::Schema(&*view).fetch_metadata(&id).is_some() {
+                return Err(Error::InvalidAssetInfo);
+            }
+        }
+
+        for metadata in self.metadata() {
+            let id = self.asset_id(&metadata);
+            assets::Schema(&mut *view).store_metadata(&id, metadata);
+        }
+
+        Ok(())
+    }
+}
+
+impl Processable for SetAssetMetadata {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_metadata_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_metadata_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_metadata_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_metadata_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_metadata_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_set_asset_metadata_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for SetAssetMetadata {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        // Every asset gets a single reference.
+        let metadata = self.metadata();
+        let mut ids = HashSet::new();
+        let metadata_ok = !metadata.is_empty()
+            && metadata
+                .iter()
+                .all(|m| m.verify() && ids.insert(self.asset_id(m)));
+
+        if cfg!(fuzzing) {
+            return metadata_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.creator());
+
+        if metadata_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets;
+use currency::assets::SupplyPolicy;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const SET_ASSET_SUPPLY_ID: u16 = 1103;
+
+message! {
+    /// `set_asset_supply` transaction.
+    ///
+    /// Caps or seals the supply of an asset. Only the current creator of
+    /// the asset may sign it, and the limits can be set only once.
+    struct SetAssetSupply {
+        const TYPE = SERVICE_ID;
+        const ID = SET_ASSET_SUPPLY_ID;
+
+        creator: &PublicKey,
+        policy:  SupplyPolicy,
+        seed:    u64,
+    }
+}
+
+impl FeesCalculator for SetAssetSupply {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        Ok(HashMap::new())
+    }
+}
+
+impl SetAssetSupply {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let policy = self.policy();
+        let id = policy.id();
+        let info = assets::Schema(&*view)
+            .fetch(&id)
+            .ok_or_else(|| Error::AssetNotFound)?;
+
+        if info.creator() != self.creator() {
+            return Err(Error::NotAssetCreator);
+        }
+
+        if assets::Schema(&*view).fetch_supply(&id).is_some() {
+            return Err(Error::InvalidAssetInfo);
+        }
+
+        // The units in circulation count as minted already.
+        let supply = policy.to_supply(info.amount())?;
+        assets::Schema(&mut *view).store_supply(&id, supply);
+
+        Ok(())
+    }
+}
+
+impl Processable for SetAssetSupply {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_supply_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_supply_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_supply_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_supply_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_set_asset_supply_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_set_asset_supply_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for SetAssetSupply {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        if !self.policy().verify() {
+            return false;
+        }
+
+        if cfg!(fuzzing) {
+            return true;
+        }
+
+        let verify_ok = self.verify_signature(self.creator());
+
+        if verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -8,9 +8,12 @@ use exonum::storage::Fork;
 use prometheus::{IntCounter, Histogram};
 
 use currency::assets::TradeAsset;
+use currency::compliance;
+use currency::configuration::Configuration;
 use currency::error::Error;
 use currency::status;
-use currency::transactions::components::{FeeStrategy, FeesCalculator, ThirdPartyFees};
+use currency::transactions::components::{FeeStrategy, FeesCalculator, MultisigSpendable,
+                                         Processable, ThirdPartyFees};
 use currency::wallet;
 use currency::SERVICE_ID;
 use currency::service::CONFIGURATION;
@@ -41,13 +44,12 @@ message! {
     }
 }
 
-impl FeesCalculator for Trade {
+impl FeesCalculator for TradeOffer {
     fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
-        let offer = self.offer();
         let genesis_fees = CONFIGURATION.read().unwrap().fees();
-        let fees = ThirdPartyFees::new_trade(&*view, &offer.assets())?;
+        let fees = ThirdPartyFees::new_trade(&*view, &self.assets())?;
         let fee_strategy =
-            FeeStrategy::try_from(offer.fee_strategy()).expect("fee strategy must be valid");
+            FeeStrategy::try_from(self.fee_strategy()).expect("fee strategy must be valid");
 
         let mut fees_table = HashMap::new();
 
@@ -72,31 +74,43 @@ impl FeesCalculator for Trade {
     }
 }
 
-impl Trade {
+impl FeesCalculator for Trade {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        self.offer().calculate_fees(view)
+    }
+}
+
+impl TradeOffer {
     fn payers(&self, fee_strategy: &FeeStrategy, fee: u64) -> Result<Vec<(PublicKey, u64)>, Error> {
-        let offer = self.offer();
         let payers = match *fee_strategy {
-            FeeStrategy::Recipient => vec![(*offer.buyer(), fee)],
-            FeeStrategy::Sender => vec![(*offer.seller(), fee)],
+            FeeStrategy::Recipient => vec![(*self.buyer(), fee)],
+            FeeStrategy::Sender => vec![(*self.seller(), fee)],
             FeeStrategy::RecipientAndSender => {
-                vec![(*offer.seller(), fee / 2), (*offer.buyer(), fee / 2)]
+                vec![(*self.seller(), fee / 2), (*self.buyer(), fee / 2)]
             }
             FeeStrategy::Intermediary => return Err(Error::InvalidTransaction),
         };
         Ok(payers)
     }
 
-    /// Raw bytes of the offer.
-    pub fn offer_raw(&self) -> Vec<u8> {
-        self.offer().raw
+    /// Check that the offer is well-formed. Signatures are not checked.
+    pub fn verify(&self) -> bool {
+        let wallets_ok = self.buyer() != self.seller();
+        let fee_strategy_ok = match FeeStrategy::try_from(self.fee_strategy()) {
+            Some(FeeStrategy::Recipient)
+            | Some(FeeStrategy::Sender)
+            | Some(FeeStrategy::RecipientAndSender) => true,
+            _ => false,
+        };
+
+        wallets_ok && fee_strategy_ok
     }
 
     fn can_move_assets(&self, view: &mut Fork) -> Result<(), Error> {
-        let mut wallet_buyer = wallet::Schema(&*view).fetch(self.offer().buyer());
-        let mut wallet_seller = wallet::Schema(&*view).fetch(self.offer().seller());
+        let mut wallet_buyer = wallet::Schema(&*view).fetch(self.buyer());
+        let mut wallet_seller = wallet::Schema(&*view).fetch(self.seller());
 
-        let assets = self.offer()
-                    .assets()
+        let assets = self.assets()
                     .into_iter()
                     .map(|a| a.to_bundle())
                     .collect::<Vec<_>>();
@@ -106,55 +120,73 @@ impl Trade {
         Ok(())
     }
 
-    fn process(&self, view: &mut Fork) -> Result<(), Error> {
-        info!("Processing tx: {:?}", self);
+    /// Check that the compliance rules allow the seller and the buyer to
+    /// trade the assets.
+    pub fn check_compliance(&self, view: &Fork) -> Result<(), Error> {
+        let assets = self
+            .assets()
+            .into_iter()
+            .map(|a| a.to_bundle())
+            .collect::<Vec<_>>();
+
+        compliance::check_transfer(view, self.seller(), self.buyer(), &assets)
+    }
 
+    /// Collect the blockchain fee for the offer.
+    pub fn collect_transaction_fee(&self, view: &mut Fork) -> Result<(), Error> {
         let genesis_fees = CONFIGURATION.read().unwrap().fees();
 
-        let offer = self.offer();
         let fee_strategy =
-            FeeStrategy::try_from(offer.fee_strategy()).expect("fee strategy must be valid");
+            FeeStrategy::try_from(self.fee_strategy()).expect("fee strategy must be valid");
 
         let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
-        // Collect the blockchain fee. Execution shall not continue if this fails.
         match fee_strategy {
             FeeStrategy::Recipient => {
-                let mut buyer = wallet::Schema(&*view).fetch(offer.buyer());
+                let mut buyer = wallet::Schema(&*view).fetch(self.buyer());
 
                 wallet::move_coins(&mut buyer, &mut genesis, genesis_fees.trade())?;
 
-                wallet::Schema(&mut *view).store(offer.buyer(), buyer);
+                wallet::Schema(&mut *view).store(self.buyer(), buyer);
             }
             FeeStrategy::Sender => {
-                let mut seller = wallet::Schema(&*view).fetch(offer.seller());
+                let mut seller = wallet::Schema(&*view).fetch(self.seller());
 
                 wallet::move_coins(&mut seller, &mut genesis, genesis_fees.trade())?;
 
-                wallet::Schema(&mut *view).store(offer.seller(), seller);
+                wallet::Schema(&mut *view).store(self.seller(), seller);
             }
             FeeStrategy::RecipientAndSender => {
-                let mut buyer = wallet::Schema(&*view).fetch(offer.buyer());
-                let mut seller = wallet::Schema(&*view).fetch(offer.seller());
+                let mut buyer = wallet::Schema(&*view).fetch(self.buyer());
+                let mut seller = wallet::Schema(&*view).fetch(self.seller());
 
                 wallet::move_coins(&mut seller, &mut genesis, genesis_fees.trade() / 2)?;
                 wallet::move_coins(&mut buyer, &mut genesis, genesis_fees.trade() / 2)?;
 
-                wallet::Schema(&mut *view).store(offer.seller(), seller);
-                wallet::Schema(&mut *view).store(offer.buyer(), buyer);
+                wallet::Schema(&mut *view).store(self.seller(), seller);
+                wallet::Schema(&mut *view).store(self.buyer(), buyer);
             }
             FeeStrategy::Intermediary => return Err(Error::InvalidTransaction),
         }
 
         wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
 
-        let fees = ThirdPartyFees::new_trade(&*view, &offer.assets())?;
+        Ok(())
+    }
+
+    /// Pay third party fees and move assets and coins between the buyer
+    /// and the seller.
+    pub fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        let fee_strategy =
+            FeeStrategy::try_from(self.fee_strategy()).expect("fee strategy must be valid");
+
+        let fees = ThirdPartyFees::new_trade(&*view, &self.assets())?;
 
         self.can_move_assets(view)?;
 
-        let mut wallet_buyer = wallet::Schema(&*view).fetch(offer.buyer());
-        let mut wallet_seller = wallet::Schema(&*view).fetch(offer.seller());
+        let mut wallet_buyer = wallet::Schema(&*view).fetch(self.buyer());
+        let mut wallet_seller = wallet::Schema(&*view).fetch(self.seller());
 
-        let total = offer
+        let total = self
             .assets()
             .iter()
             .map(|asset| asset.amount() * asset.price())
@@ -162,31 +194,31 @@ impl Trade {
 
         wallet::move_coins(&mut wallet_buyer, &mut wallet_seller, total)
             .or_else(|e| {
-                wallet::Schema(&mut *view).store(&offer.seller(), wallet_seller.clone());
-                wallet::Schema(&mut *view).store(&offer.buyer(), wallet_buyer.clone());
+                wallet::Schema(&mut *view).store(&self.seller(), wallet_seller.clone());
+                wallet::Schema(&mut *view).store(&self.buyer(), wallet_buyer.clone());
 
                 Err(e)
             })
             .and_then(|_| {
-                wallet::Schema(&mut *view).store(&offer.seller(), wallet_seller);
-                wallet::Schema(&mut *view).store(&offer.buyer(), wallet_buyer);
+                wallet::Schema(&mut *view).store(&self.seller(), wallet_seller);
+                wallet::Schema(&mut *view).store(&self.buyer(), wallet_buyer);
 
                 let mut updated_wallets = match fee_strategy {
-                    FeeStrategy::Recipient => fees.collect(view, offer.buyer())?,
-                    FeeStrategy::Sender => fees.collect(view, offer.seller())?,
+                    FeeStrategy::Recipient => fees.collect(view, self.buyer())?,
+                    FeeStrategy::Sender => fees.collect(view, self.seller())?,
                     FeeStrategy::RecipientAndSender => {
-                        fees.collect2(view, offer.seller(), offer.buyer())?
+                        fees.collect2(view, self.seller(), self.buyer())?
                     }
                     FeeStrategy::Intermediary => HashMap::<PublicKey, wallet::Wallet>::new(),
                 };
 
                 let mut wallet_seller = updated_wallets
-                    .remove(&offer.seller())
-                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(&offer.seller()));
+                    .remove(&self.seller())
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(&self.seller()));
                 let mut wallet_buyer = updated_wallets
-                    .remove(&offer.buyer())
-                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(&offer.buyer()));
-                let assets = offer
+                    .remove(&self.buyer())
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(&self.buyer()));
+                let assets = self
                     .assets()
                     .into_iter()
                     .map(|a| a.to_bundle())
@@ -194,8 +226,8 @@ impl Trade {
 
                 wallet::move_assets(&mut wallet_seller, &mut wallet_buyer, &assets)?;
 
-                updated_wallets.insert(*offer.seller(), wallet_seller);
-                updated_wallets.insert(*offer.buyer(), wallet_buyer);
+                updated_wallets.insert(*self.seller(), wallet_seller);
+                updated_wallets.insert(*self.buyer(), wallet_buyer);
 
                 // Save changes to the database.
                 for (key, wallet) in updated_wallets {
@@ -209,6 +241,54 @@ impl Trade {
     }
 }
 
+impl Trade {
+    /// Raw bytes of the offer.
+    pub fn offer_raw(&self) -> Vec<u8> {
+        self.offer().raw
+    }
+
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let offer = self.offer();
+
+        // Compliance rules are checked before any value is moved.
+        offer.check_compliance(view)?;
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        offer.collect_transaction_fee(view)?;
+
+        offer.process(view)
+    }
+}
+
+impl MultisigSpendable for Trade {
+    fn spender(&self) -> PublicKey {
+        *self.offer().seller()
+    }
+
+    fn verify_unsigned(&self) -> bool {
+        let offer = self.offer();
+
+        if cfg!(fuzzing) {
+            return offer.verify();
+        }
+
+        offer.verify() && self.verify_signature(offer.buyer())
+    }
+
+    fn process_authorized(&self, view: &mut Fork) -> Result<(), Error> {
+        self.process(view)
+    }
+}
+
+impl Processable for Trade {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
 lazy_static! {
     static ref VERIFY_COUNT: IntCounter = register_int_counter!(
         "dmbc_transaction_trade_verify_count",
@@ -240,14 +320,10 @@ impl Transaction for Trade {
     fn verify(&self) -> bool {
         VERIFY_COUNT.inc();
 
-        let wallets_ok = self.offer().buyer() != self.offer().seller();
-        let fee_strategy_ok = match FeeStrategy::try_from(self.offer().fee_strategy()).unwrap() {
-            FeeStrategy::Recipient | FeeStrategy::Sender | FeeStrategy::RecipientAndSender => true,
-            _ => false,
-        };
+        let offer_ok = self.offer().verify();
 
         if cfg!(fuzzing) {
-            return wallets_ok && fee_strategy_ok;
+            return offer_ok;
         }
 
         let seller_verify_ok = crypto::verify(
@@ -257,7 +333,7 @@ impl Transaction for Trade {
         );
         let buyer_verify_ok = self.verify_signature(&self.offer().buyer());
 
-        if wallets_ok && fee_strategy_ok && buyer_verify_ok && seller_verify_ok {
+        if offer_ok && buyer_verify_ok && seller_verify_ok {
             VERIFY_SUCCESS_COUNT.inc();
             true
         } else {
@@ -269,7 +345,7 @@ impl Transaction for Trade {
         EXECUTE_COUNT.inc();
         let timer = EXECUTE_DURATION.start_timer();
 
-        let result = self.process(view);
+        let result = self.process_enabled(view);
 
         if let &Ok(_) = &result {
             EXECUTE_SUCCESS_COUNT.inc();
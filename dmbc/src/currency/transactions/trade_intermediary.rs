@@ -8,10 +8,12 @@ use exonum::storage::Fork;
 use prometheus::{IntCounter, Histogram};
 
 use currency::assets::TradeAsset;
+use currency::compliance;
+use currency::configuration::Configuration;
 use currency::error::Error;
 use currency::status;
 use currency::transactions::components::Intermediary;
-use currency::transactions::components::{FeeStrategy, FeesCalculator, ThirdPartyFees};
+use currency::transactions::components::{FeeStrategy, FeesCalculator, Processable, ThirdPartyFees};
 use currency::wallet;
 use currency::SERVICE_ID;
 use currency::service::CONFIGURATION;
@@ -116,10 +118,19 @@ impl TradeIntermediary {
     fn process(&self, view: &mut Fork) -> Result<(), Error> {
         info!("Processing tx: {:?}", self);
 
-        let genesis_fees = CONFIGURATION.read().unwrap().fees();
-
         let offer = self.offer();
 
+        // Compliance rules are checked before any value is moved.
+        let assets = offer
+            .assets()
+            .into_iter()
+            .map(|a| a.to_bundle())
+            .collect::<Vec<_>>();
+        compliance::check_transfer(&*view, offer.seller(), offer.buyer(), &assets)?;
+        compliance::check_wallets(&*view, Some(offer.intermediary().wallet()))?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
         let fee_strategy =
             FeeStrategy::try_from(offer.fee_strategy()).expect("fee strategy must be valid");
 
@@ -228,6 +239,13 @@ impl TradeIntermediary {
     }
 }
 
+impl Processable for TradeIntermediary {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
 lazy_static! {
     static ref VERIFY_COUNT: IntCounter = register_int_counter!(
         "dmbc_transaction_trade_intermediary_verify_count",
@@ -291,7 +309,7 @@ impl Transaction for TradeIntermediary {
         EXECUTE_COUNT.inc();
         let timer = EXECUTE_DURATION.start_timer();
 
-        let result = self.process(view);
+        let result = self.process_enabled(view);
 
         if let &Ok(_) = &result {
             EXECUTE_SUCCESS_COUNT.inc();
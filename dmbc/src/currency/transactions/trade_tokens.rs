@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto;
+use exonum::crypto::{PublicKey, Signature};
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets::TradeAsset;
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::nft;
+use currency::nft::{TokenId, TradeToken};
+use currency::status;
+use currency::transactions::components::{FeeStrategy, FeesCalculator, MultisigSpendable,
+                                         Processable, ThirdPartyFees};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const TRADE_TOKENS_ID: u16 = 504;
+
+encoding_struct! {
+    /// Offer of a `trade_tokens` transaction.
+    struct TradeTokensOffer {
+        buyer: &PublicKey,
+        seller: &PublicKey,
+        assets: Vec<TradeAsset>,
+        tokens: Vec<TradeToken>,
+
+        fee_strategy: u8,
+        seed:         u64,
+        data_info:    &str,
+    }
+}
+
+message! {
+    /// `trade_tokens` transaction.
+    ///
+    /// Same as `trade`, but non-fungible tokens can be sold along with the
+    /// assets.
+    struct TradeTokens {
+        const TYPE = SERVICE_ID;
+        const ID = TRADE_TOKENS_ID;
+
+        offer:              TradeTokensOffer,
+        seller_signature:   &Signature,
+    }
+}
+
+impl FeesCalculator for TradeTokensOffer {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+        let fees = ThirdPartyFees::new_trade(&*view, &self.trade_assets())?;
+        let fee_strategy =
+            FeeStrategy::try_from(self.fee_strategy()).expect("fee strategy must be valid");
+
+        let mut fees_table = HashMap::new();
+
+        let payers = self.payers(&fee_strategy, genesis_fees.trade())?;
+        for (payer_key, fee) in payers {
+            if genesis_fees.recipient() != &payer_key {
+                fees_table.insert(payer_key, fee);
+            }
+        }
+
+        for (receiver_key, fee) in fees.0 {
+            let payers = self.payers(&fee_strategy, fee)?;
+
+            for (payer_key, fee) in payers {
+                if payer_key != receiver_key {
+                    *fees_table.entry(payer_key).or_insert(0) += fee;
+                }
+            }
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl FeesCalculator for TradeTokens {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        self.offer().calculate_fees(view)
+    }
+}
+
+impl TradeTokensOffer {
+    fn payers(&self, fee_strategy: &FeeStrategy, fee: u64) -> Result<Vec<(PublicKey, u64)>, Error> {
+        let payers = match *fee_strategy {
+            FeeStrategy::Recipient => vec![(*self.buyer(), fee)],
+            FeeStrategy::Sender => vec![(*self.seller(), fee)],
+            FeeStrategy::RecipientAndSender => {
+                vec![(*self.seller(), fee / 2), (*self.buyer(), fee / 2)]
+            }
+            FeeStrategy::Intermediary => return Err(Error::InvalidTransaction),
+        };
+        Ok(payers)
+    }
+
+    /// IDs of the tokens sold in the offer.
+    pub fn token_ids(&self) -> Vec<TokenId> {
+        self.tokens().iter().map(|token| token.id()).collect()
+    }
+
+    /// Assets and tokens sold in the offer.
+    ///
+    /// Tokens are sold as single units of their series, so they are
+    /// charged the fees of the series.
+    pub fn trade_assets(&self) -> Vec<TradeAsset> {
+        let mut assets = self.assets();
+        assets.extend(self.tokens().iter().map(|token| token.to_trade_asset()));
+        assets
+    }
+
+    /// Check that the offer is well-formed. Signatures are not checked.
+    pub fn verify(&self) -> bool {
+        let wallets_ok = self.buyer() != self.seller();
+        let tokens_ok = nft::unique(&self.token_ids());
+        let fee_strategy_ok = match FeeStrategy::try_from(self.fee_strategy()) {
+            Some(FeeStrategy::Recipient)
+            | Some(FeeStrategy::Sender)
+            | Some(FeeStrategy::RecipientAndSender) => true,
+            _ => false,
+        };
+
+        wallets_ok && tokens_ok && fee_strategy_ok
+    }
+
+    fn can_move_assets(&self, view: &mut Fork) -> Result<(), Error> {
+        let mut wallet_buyer = wallet::Schema(&*view).fetch(self.buyer());
+        let mut wallet_seller = wallet::Schema(&*view).fetch(self.seller());
+
+        let assets = self.assets()
+                    .into_iter()
+                    .map(|a| a.to_bundle())
+                    .collect::<Vec<_>>();
+
+        wallet::move_assets(&mut wallet_seller, &mut wallet_buyer, &assets)?;
+
+        Ok(())
+    }
+
+    /// Check that the compliance rules allow the seller and the buyer to
+    /// trade the assets.
+    pub fn check_compliance(&self, view: &Fork) -> Result<(), Error> {
+        let assets = self
+            .trade_assets()
+            .into_iter()
+            .map(|a| a.to_bundle())
+            .collect::<Vec<_>>();
+
+        compliance::check_transfer(view, self.seller(), self.buyer(), &assets)
+    }
+
+    /// Collect the blockchain fee for the offer.
+    pub fn collect_transaction_fee(&self, view: &mut Fork) -> Result<(), Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let fee_strategy =
+            FeeStrategy::try_from(self.fee_strategy()).expect("fee strategy must be valid");
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+        match fee_strategy {
+            FeeStrategy::Recipient => {
+                let mut buyer = wallet::Schema(&*view).fetch(self.buyer());
+
+                wallet::move_coins(&mut buyer, &mut genesis, genesis_fees.trade())?;
+
+                wallet::Schema(&mut *view).store(self.buyer(), buyer);
+            }
+            FeeStrategy::Sender => {
+                let mut seller = wallet::Schema(&*view).fetch(self.seller());
+
+                wallet::move_coins(&mut seller, &mut genesis, genesis_fees.trade())?;
+
+                wallet::Schema(&mut *view).store(self.seller(), seller);
+            }
+            FeeStrategy::RecipientAndSender => {
+                let mut buyer = wallet::Schema(&*view).fetch(self.buyer());
+                let mut seller = wallet::Schema(&*view).fetch(self.seller());
+
+                wallet::move_coins(&mut seller, &mut genesis, genesis_fees.trade() / 2)?;
+                wallet::move_coins(&mut buyer, &mut genesis, genesis_fees.trade() / 2)?;
+
+                wallet::Schema(&mut *view).store(self.seller(), seller);
+                wallet::Schema(&mut *view).store(self.buyer(), buyer);
+            }
+            FeeStrategy::Intermediary => return Err(Error::InvalidTransaction),
+        }
+
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        Ok(())
+    }
+
+    /// Pay third party fees and move assets and coins between the buyer
+    /// and the seller.
+    pub fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        let fee_strategy =
+            FeeStrategy::try_from(self.fee_strategy()).expect("fee strategy must be valid");
+
+        let fees = ThirdPartyFees::new_trade(&*view, &self.trade_assets())?;
+
+        self.can_move_assets(view)?;
+
+        let token_ids = self.token_ids();
+        nft::check_owner(&*view, self.seller(), &token_ids)?;
+
+        let mut wallet_buyer = wallet::Schema(&*view).fetch(self.buyer());
+        let mut wallet_seller = wallet::Schema(&*view).fetch(self.seller());
+
+        // The buyer can't afford a total that doesn't fit into the balance.
+        let mut total: u64 = 0;
+        for asset in self.trade_assets() {
+            total = asset
+                .amount()
+                .checked_mul(asset.price())
+                .and_then(|price| total.checked_add(price))
+                .ok_or(Error::InsufficientFunds)?;
+        }
+
+        wallet::move_coins(&mut wallet_buyer, &mut wallet_seller, total)
+            .or_else(|e| {
+                wallet::Schema(&mut *view).store(&self.seller(), wallet_seller.clone());
+                wallet::Schema(&mut *view).store(&self.buyer(), wallet_buyer.clone());
+
+                Err(e)
+            })
+            .and_then(|_| {
+                wallet::Schema(&mut *view).store(&self.seller(), wallet_seller);
+                wallet::Schema(&mut *view).store(&self.buyer(), wallet_buyer);
+
+                let mut updated_wallets = match fee_strategy {
+                    FeeStrategy::Recipient => fees.collect(view, self.buyer())?,
+                    FeeStrategy::Sender => fees.collect(view, self.seller())?,
+                    FeeStrategy::RecipientAndSender => {
+                        fees.collect2(view, self.seller(), self.buyer())?
+                    }
+                    FeeStrategy::Intermediary => HashMap::<PublicKey, wallet::Wallet>::new(),
+                };
+
+                let mut wallet_seller = updated_wallets
+                    .remove(&self.seller())
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(&self.seller()));
+                let mut wallet_buyer = updated_wallets
+                    .remove(&self.buyer())
+                    .unwrap_or_else(|| wallet::Schema(&*view).fetch(&self.buyer()));
+                let assets = self
+                    .assets()
+                    .into_iter()
+                    .map(|a| a.to_bundle())
+                    .collect::<Vec<_>>();
+
+                wallet::move_assets(&mut wallet_seller, &mut wallet_buyer, &assets)?;
+
+                updated_wallets.insert(*self.seller(), wallet_seller);
+                updated_wallets.insert(*self.buyer(), wallet_buyer);
+
+                // Save changes to the database.
+                for (key, wallet) in updated_wallets {
+                    wallet::Schema(&mut *view).store(&key, wallet);
+                }
+
+                nft::move_tokens(view, self.buyer(), &token_ids);
+
+                Ok(())
+            })?;
+
+        Ok(())
+    }
+}
+
+impl TradeTokens {
+    /// Raw bytes of the offer.
+    pub fn offer_raw(&self) -> Vec<u8> {
+        self.offer().raw
+    }
+
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let offer = self.offer();
+
+        // Compliance rules are checked before any value is moved.
+        offer.check_compliance(view)?;
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        offer.collect_transaction_fee(view)?;
+
+        offer.process(view)
+    }
+}
+
+impl MultisigSpendable for TradeTokens {
+    fn spender(&self) -> PublicKey {
+        *self.offer().seller()
+    }
+
+    fn verify_unsigned(&self) -> bool {
+        let offer = self.offer();
+
+        if cfg!(fuzzing) {
+            return offer.verify();
+        }
+
+        offer.verify() && self.verify_signature(offer.buyer())
+    }
+
+    fn process_authorized(&self, view: &mut Fork) -> Result<(), Error> {
+        self.process(view)
+    }
+}
+
+impl Processable for TradeTokens {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_trade_tokens_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_trade_tokens_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_trade_tokens_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_trade_tokens_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_trade_tokens_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_trade_tokens_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for TradeTokens {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let offer_ok = self.offer().verify();
+
+        if cfg!(fuzzing) {
+            return offer_ok;
+        }
+
+        let seller_verify_ok = crypto::verify(
+            self.seller_signature(),
+            &self.offer().raw,
+            self.offer().seller(),
+        );
+        let buyer_verify_ok = self.verify_signature(&self.offer().buyer());
+
+        if offer_ok && buyer_verify_ok && seller_verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -7,9 +7,12 @@ use exonum::storage::Fork;
 use prometheus::{IntCounter, Histogram};
 
 use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
 use currency::error::Error;
 use currency::status;
-use currency::transactions::components::{FeesCalculator, ThirdPartyFees};
+use currency::transactions::components::{FeesCalculator, MultisigSpendable, Processable,
+                                         ThirdPartyFees};
 use currency::wallet;
 use currency::SERVICE_ID;
 use currency::service::CONFIGURATION;
@@ -54,6 +57,9 @@ impl FeesCalculator for Transfer {
 
 impl Transfer {
     fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        // Compliance rules are checked before any value is moved.
+        compliance::check_transfer(&*view, self.from(), self.to(), &self.assets())?;
+
         let genesis_fees = CONFIGURATION.read().unwrap().fees();
 
         let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
@@ -98,6 +104,27 @@ impl Transfer {
     }
 }
 
+impl MultisigSpendable for Transfer {
+    fn spender(&self) -> PublicKey {
+        *self.from()
+    }
+
+    fn verify_unsigned(&self) -> bool {
+        self.from() != self.to()
+    }
+
+    fn process_authorized(&self, view: &mut Fork) -> Result<(), Error> {
+        self.process(view)
+    }
+}
+
+impl Processable for Transfer {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
 lazy_static! {
     static ref VERIFY_COUNT: IntCounter = register_int_counter!(
         "dmbc_transaction_transfer_verify_count",
@@ -149,7 +176,7 @@ impl Transaction for Transfer {
         EXECUTE_COUNT.inc();
         let timer = EXECUTE_DURATION.start_timer();
 
-        let result = self.process(view);
+        let result = self.process_enabled(view);
 
         if let &Ok(_) = &result {
             EXECUTE_SUCCESS_COUNT.inc();
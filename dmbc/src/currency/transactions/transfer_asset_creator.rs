@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use exonum::blockchain;
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets;
+use currency::assets::{AssetChange, AssetId};
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const TRANSFER_ASSET_CREATOR_ID: u16 = 1102;
+
+message! {
+    /// `transfer_asset_creator` transaction.
+    ///
+    /// Hands the creator role of an asset over to another key. The new
+    /// creator receives third party fees and may update them afterwards.
+    struct TransferAssetCreator {
+        const TYPE = SERVICE_ID;
+        const ID = TRANSFER_ASSET_CREATOR_ID;
+
+        creator:     &PublicKey,
+        asset_id:    AssetId,
+        new_creator: &PublicKey,
+        seed:        u64,
+    }
+}
+
+impl FeesCalculator for TransferAssetCreator {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        Ok(HashMap::new())
+    }
+}
+
+impl TransferAssetCreator {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let id = self.asset_id();
+        let info = assets::Schema(&*view)
+            .fetch(&id)
+            .ok_or_else(|| Error::AssetNotFound)?;
+
+        if info.creator() != self.creator() {
+            return Err(Error::NotAssetCreator);
+        }
+
+        // The new creator mints the asset by its data, which must not
+        // name an asset of theirs already.
+        let resolved = assets::Schema(&*view).resolve_id(info.data(), self.new_creator());
+        if resolved != id && assets::Schema(&*view).fetch(&resolved).is_some() {
+            return Err(Error::InvalidAssetInfo);
+        }
+
+        let height = blockchain::Schema::new(&*view).height().next();
+        let change = AssetChange::new(
+            &self.hash(),
+            height.0,
+            info.creator(),
+            self.new_creator(),
+            info.fees(),
+            info.fees(),
+        );
+
+        assets::Schema(&mut *view).store_handover(
+            &id,
+            info.data(),
+            info.creator(),
+            self.new_creator(),
+        );
+        assets::Schema(&mut *view).store(&id, info.transfer_creator(self.new_creator()));
+        assets::Schema(&mut *view).store_change(&id, change);
+
+        Ok(())
+    }
+}
+
+impl Processable for TransferAssetCreator {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_asset_creator_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_asset_creator_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_asset_creator_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_asset_creator_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_asset_creator_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_transfer_asset_creator_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for TransferAssetCreator {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let wallets_ok = self.creator() != self.new_creator();
+
+        if cfg!(fuzzing) {
+            return wallets_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.creator());
+
+        if wallets_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use exonum::blockchain;
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::allowance;
+use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable, ThirdPartyFees};
+use currency::wallet;
+use currency::wallet::Wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const TRANSFER_FROM_ID: u16 = 803;
+
+message! {
+    /// `transfer_from` transaction.
+    ///
+    /// Moves coins and assets out of the `from` wallet on behalf of its
+    /// owner, within the allowance granted to the spender. Fees are paid
+    /// by the spender, who submits the transaction, and do not count
+    /// towards the allowance.
+    struct TransferFrom {
+        const TYPE = SERVICE_ID;
+        const ID = TRANSFER_FROM_ID;
+
+        spender:   &PublicKey,
+        from:      &PublicKey,
+        to:        &PublicKey,
+        amount:    u64,
+        assets:    Vec<AssetBundle>,
+        seed:      u64,
+        data_info: &str,
+    }
+}
+
+impl FeesCalculator for TransferFrom {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+        let fees = ThirdPartyFees::new_transfer(&*view, self.assets())?;
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.spender() {
+            fees_table.insert(*self.spender(), genesis_fees.transfer());
+        }
+
+        for (pub_key, fee) in fees.0 {
+            if pub_key != *self.spender() {
+                *fees_table.entry(*self.spender()).or_insert(0) += fee;
+            }
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl TransferFrom {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        // Check the allowance before charging anything.
+        let allowance = allowance::Schema(&*view)
+            .fetch(self.from(), self.spender())
+            .ok_or_else(|| Error::AllowanceNotFound)?;
+
+        let height = blockchain::Schema::new(&*view).height().next();
+        if allowance.is_expired(height.0) {
+            return Err(Error::AllowanceExpired);
+        }
+
+        let allowance = allowance.spend(self.amount(), &self.assets())?;
+
+        // Compliance rules are checked before any value is moved.
+        compliance::check_transfer(&*view, self.from(), self.to(), &self.assets())?;
+
+        // The owner must be able to cover the transfer as well. Fees are
+        // paid by the spender and can only add to the owner's wallet.
+        let mut wallet_from = wallet::Schema(&*view).fetch(self.from());
+        let mut moved = Wallet::new_empty();
+        wallet::move_coins(&mut wallet_from, &mut moved, self.amount())?;
+        wallet::move_assets(&mut wallet_from, &mut moved, &self.assets())?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut spender = wallet::Schema(&*view).fetch(self.spender());
+        wallet::move_coins(&mut spender, &mut genesis, genesis_fees.transfer())?;
+
+        wallet::Schema(&mut *view).store(self.spender(), spender);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        let fees = ThirdPartyFees::new_transfer(&*view, self.assets())?;
+
+        // Operations bellow must either all succeed, or return an error without
+        // saving anything to the database.
+
+        // Process third party fees.
+        let mut updated_wallets = fees.collect(view, self.spender())?;
+
+        // Process the main transaction.
+        let mut wallet_from = updated_wallets
+            .remove(self.from())
+            .unwrap_or_else(|| wallet::Schema(&*view).fetch(self.from()));
+
+        let mut wallet_to = updated_wallets
+            .remove(self.to())
+            .unwrap_or_else(|| wallet::Schema(&*view).fetch(self.to()));
+
+        wallet::move_coins(&mut wallet_from, &mut wallet_to, self.amount())?;
+        wallet::move_assets(&mut wallet_from, &mut wallet_to, &self.assets())?;
+
+        updated_wallets.insert(*self.from(), wallet_from);
+        updated_wallets.insert(*self.to(), wallet_to);
+
+        // Save changes to the database.
+        for (key, wallet) in updated_wallets {
+            wallet::Schema(&mut *view).store(&key, wallet);
+        }
+
+        if allowance.is_empty() {
+            allowance::Schema(&mut *view).remove(self.from(), self.spender());
+        } else {
+            allowance::Schema(&mut *view).store(allowance);
+        }
+
+        Ok(())
+    }
+}
+
+impl Processable for TransferFrom {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_from_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_from_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_from_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_from_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_from_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_transfer_from_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for TransferFrom {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let wallets_ok = self.from() != self.to() && self.from() != self.spender();
+        let value_ok = self.amount() > 0 || !self.assets().is_empty();
+
+        if cfg!(fuzzing) {
+            return wallets_ok && value_ok;
+        }
+
+        let verify_ok = self.verify_signature(self.spender());
+
+        if wallets_ok && value_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
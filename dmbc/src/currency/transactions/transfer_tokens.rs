@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets::AssetBundle;
+use currency::compliance;
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::nft;
+use currency::nft::TokenId;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, MultisigSpendable, Processable,
+                                         ThirdPartyFees};
+use currency::wallet;
+use currency::SERVICE_ID;
+use currency::service::CONFIGURATION;
+
+/// Transaction ID.
+pub const TRANSFER_TOKENS_ID: u16 = 202;
+
+message! {
+    /// `transfer_tokens` transaction.
+    ///
+    /// Same as `transfer`, but non-fungible tokens can be sent along with
+    /// the coins and assets.
+    struct TransferTokens {
+        const TYPE = SERVICE_ID;
+        const ID = TRANSFER_TOKENS_ID;
+
+        from:      &PublicKey,
+        to:        &PublicKey,
+        amount:    u64,
+        assets:    Vec<AssetBundle>,
+        tokens:    Vec<TokenId>,
+        seed:      u64,
+        data_info: &str,
+    }
+}
+
+impl FeesCalculator for TransferTokens {
+    fn calculate_fees(&self, view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+        let fees = ThirdPartyFees::new_transfer(&*view, self.bundles())?;
+
+        let mut fees_table = HashMap::new();
+        if genesis_fees.recipient() != self.from() {
+            fees_table.insert(*self.from(), genesis_fees.transfer());
+        }
+
+        for (pub_key, fee) in fees.0 {
+            if pub_key != *self.from() {
+                *fees_table.entry(*self.from()).or_insert(0) += fee;
+            }
+        }
+
+        Ok(fees_table)
+    }
+}
+
+impl TransferTokens {
+    /// Bundles of the assets and of the token series sent.
+    ///
+    /// Tokens are charged the fees of their series, one unit per token.
+    pub fn bundles(&self) -> Vec<AssetBundle> {
+        let mut bundles = self.assets();
+        bundles.extend(nft::to_bundles(&self.tokens()));
+        bundles
+    }
+
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        let tokens = self.tokens();
+
+        // Compliance rules are checked before any value is moved.
+        compliance::check_transfer(&*view, self.from(), self.to(), &self.bundles())?;
+
+        let genesis_fees = CONFIGURATION.read().unwrap().fees();
+
+        let mut genesis = wallet::Schema(&*view).fetch(genesis_fees.recipient());
+
+        // Collect the blockchain fee. Execution shall not continue if this fails.
+        let mut wallet_from = wallet::Schema(&*view).fetch(self.from());
+        wallet::move_coins(&mut wallet_from, &mut genesis, genesis_fees.transfer())?;
+
+        wallet::Schema(&mut *view).store(self.from(), wallet_from);
+        wallet::Schema(&mut *view).store(genesis_fees.recipient(), genesis);
+
+        nft::check_owner(&*view, self.from(), &tokens)?;
+
+        let fees = ThirdPartyFees::new_transfer(&*view, self.bundles())?;
+
+        // Operations bellow must either all succeed, or return an error without
+        // saving anything to the database.
+
+        // Process third party fees.
+        let mut updated_wallets = fees.collect(view, self.from())?;
+
+        // Process the main transaction.
+        let mut wallet_from = updated_wallets
+            .remove(&self.from())
+            .unwrap_or_else(|| wallet::Schema(&*view).fetch(&self.from()));
+
+        let mut wallet_to = updated_wallets
+            .remove(&self.to())
+            .unwrap_or_else(|| wallet::Schema(&*view).fetch(&self.to()));
+
+        //wallet::Schema(&*view).fetch(self.to());
+        wallet::move_coins(&mut wallet_from, &mut wallet_to, self.amount())?;
+        wallet::move_assets(&mut wallet_from, &mut wallet_to, &self.assets())?;
+
+        updated_wallets.insert(*self.from(), wallet_from);
+        updated_wallets.insert(*self.to(), wallet_to);
+
+        // Save changes to the database.
+        for (key, wallet) in updated_wallets {
+            wallet::Schema(&mut *view).store(&key, wallet);
+        }
+
+        nft::move_tokens(view, self.to(), &tokens);
+
+        Ok(())
+    }
+}
+
+impl MultisigSpendable for TransferTokens {
+    fn spender(&self) -> PublicKey {
+        *self.from()
+    }
+
+    fn verify_unsigned(&self) -> bool {
+        self.from() != self.to() && nft::unique(&self.tokens())
+    }
+
+    fn process_authorized(&self, view: &mut Fork) -> Result<(), Error> {
+        self.process(view)
+    }
+}
+
+impl Processable for TransferTokens {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_tokens_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_tokens_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_tokens_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_tokens_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_transfer_tokens_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_transfer_tokens_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for TransferTokens {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        let wallets_ok = self.from() != self.to();
+        let tokens_ok = nft::unique(&self.tokens());
+
+        if cfg!(fuzzing) {
+            return wallets_ok && tokens_ok;
+        }
+
+        let verify_ok = self.verify_signature(&self.from());
+
+        if wallets_ok && tokens_ok && verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
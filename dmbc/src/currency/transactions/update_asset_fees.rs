@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use exonum::blockchain;
+use exonum::blockchain::Transaction;
+use exonum::crypto::PublicKey;
+use exonum::messages::Message;
+use exonum::storage::Fork;
+use prometheus::{IntCounter, Histogram};
+
+use currency::assets;
+use currency::assets::{AssetChange, AssetId, Fees};
+use currency::configuration::Configuration;
+use currency::error::Error;
+use currency::status;
+use currency::transactions::components::{FeesCalculator, Processable};
+use currency::SERVICE_ID;
+
+/// Transaction ID.
+pub const UPDATE_ASSET_FEES_ID: u16 = 1101;
+
+message! {
+    /// `update_asset_fees` transaction.
+    ///
+    /// Replaces the third party fees of an asset. Only the current creator
+    /// of the asset may sign it.
+    struct UpdateAssetFees {
+        const TYPE = SERVICE_ID;
+        const ID = UPDATE_ASSET_FEES_ID;
+
+        creator:  &PublicKey,
+        asset_id: AssetId,
+        fees:     Fees,
+        seed:     u64,
+    }
+}
+
+impl FeesCalculator for UpdateAssetFees {
+    fn calculate_fees(&self, _view: &mut Fork) -> Result<HashMap<PublicKey, u64>, Error> {
+        Ok(HashMap::new())
+    }
+}
+
+impl UpdateAssetFees {
+    fn process(&self, view: &mut Fork) -> Result<(), Error> {
+        info!("Processing tx: {:?}", self);
+
+        let id = self.asset_id();
+        let info = assets::Schema(&*view)
+            .fetch(&id)
+            .ok_or_else(|| Error::AssetNotFound)?;
+
+        if info.creator() != self.creator() {
+            return Err(Error::NotAssetCreator);
+        }
+
+        let height = blockchain::Schema::new(&*view).height().next();
+        let change = AssetChange::new(
+            &self.hash(),
+            height.0,
+            info.creator(),
+            info.creator(),
+            info.fees(),
+            self.fees(),
+        );
+
+        assets::Schema(&mut *view).store(&id, info.update_fees(self.fees()));
+        assets::Schema(&mut *view).store_change(&id, change);
+
+        Ok(())
+    }
+}
+
+impl Processable for UpdateAssetFees {
+    fn process_enabled(&self, view: &mut Fork) -> Result<(), Error> {
+        let enabled = Configuration::extract(&*view).check_enabled(self.raw().message_type());
+        enabled.and_then(|_| self.process(view))
+    }
+}
+
+lazy_static! {
+    static ref VERIFY_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_update_asset_fees_verify_count",
+        "Times .verify() was called on a transaction."
+    ).unwrap();
+    static ref VERIFY_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_update_asset_fees_verify_success_count",
+        "Times verification was successfull on a transaction."
+    ).unwrap();
+    static ref EXECUTE_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_update_asset_fees_execute_count",
+        "Transactions executed."
+    ).unwrap();
+    static ref EXECUTE_SUCCESS_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_update_asset_fees_execute_success_count",
+        "Times transaction execution reported a success."
+    ).unwrap();
+    static ref EXECUTE_FINISH_COUNT: IntCounter = register_int_counter!(
+        "dmbc_transaction_update_asset_fees_execute_finish_count",
+        "Times transaction has finished executing without panicking."
+    ).unwrap();
+    static ref EXECUTE_DURATION: Histogram = register_histogram!(
+        "dmbc_transaction_update_asset_fees_execute_duration_seconds",
+        "Duration of transaction execution."
+    ).unwrap();
+}
+
+impl Transaction for UpdateAssetFees {
+    fn verify(&self) -> bool {
+        VERIFY_COUNT.inc();
+
+        if cfg!(fuzzing) {
+            return true;
+        }
+
+        let verify_ok = self.verify_signature(self.creator());
+
+        if verify_ok {
+            VERIFY_SUCCESS_COUNT.inc();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn execute(&self, view: &mut Fork) {
+        EXECUTE_COUNT.inc();
+        let timer = EXECUTE_DURATION.start_timer();
+
+        let result = self.process_enabled(view);
+
+        if let &Ok(_) = &result {
+            EXECUTE_SUCCESS_COUNT.inc();
+        }
+
+        status::Schema(view).store(self.hash(), result);
+
+        timer.observe_duration();
+        EXECUTE_FINISH_COUNT.inc();
+    }
+}
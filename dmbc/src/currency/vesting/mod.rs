@@ -0,0 +1,7 @@
+//! Vesting schedules that unlock coins and assets over block heights.
+
+mod schema;
+mod vesting;
+
+pub use currency::vesting::schema::Schema;
+pub use currency::vesting::vesting::{ReleaseCurve, Vesting};
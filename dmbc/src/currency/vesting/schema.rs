@@ -0,0 +1,50 @@
+use exonum::crypto::{Hash, PublicKey};
+use exonum::storage::{Fork, MapIndex, Snapshot};
+
+use currency::vesting::Vesting;
+use currency::SERVICE_NAME;
+
+/// Schema for accessing vesting schedules.
+pub struct Schema<S>(pub S)
+where
+    S: AsRef<Snapshot>;
+
+impl<S> Schema<S>
+where
+    S: AsRef<Snapshot>,
+{
+    /// Internal `MapIndex` of vesting schedules, keyed by the hash of the
+    /// `create_vesting` transaction.
+    pub fn index(self) -> MapIndex<S, Hash, Vesting> {
+        let key = SERVICE_NAME.to_string() + ".vestings";
+        MapIndex::new(key, self.0)
+    }
+
+    /// Fetch a vesting schedule.
+    pub fn fetch(self, vesting_id: &Hash) -> Option<Vesting> {
+        self.index().get(vesting_id)
+    }
+
+    /// Fetch all vesting schedules of a beneficiary.
+    pub fn fetch_wallet_vestings(self, beneficiary: &PublicKey) -> Vec<(Hash, Vesting)> {
+        let index = self.index();
+        let vestings = index
+            .iter()
+            .filter(|&(_, ref vesting)| vesting.beneficiary() == beneficiary)
+            .collect();
+        vestings
+    }
+}
+
+impl<'a> Schema<&'a mut Fork> {
+    /// Internal `MapIndex` of vesting schedules, with mutable access.
+    pub fn index_mut(&mut self) -> MapIndex<&mut Fork, Hash, Vesting> {
+        let key = SERVICE_NAME.to_string() + ".vestings";
+        MapIndex::new(key, &mut *self.0)
+    }
+
+    /// Store a vesting schedule.
+    pub fn store(&mut self, vesting_id: &Hash, vesting: Vesting) {
+        self.index_mut().put(vesting_id, vesting);
+    }
+}
@@ -0,0 +1,126 @@
+use exonum::crypto::PublicKey;
+use extprim::u128::u128;
+
+use currency::assets::AssetBundle;
+use currency::wallet::Wallet;
+
+/// Determines how granted value unlocks between the cliff and end heights.
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum ReleaseCurve {
+    /// Value unlocks pro rata from the start height to the end height.
+    /// Nothing can be claimed before the cliff height.
+    Linear = 1,
+    /// Everything unlocks at the cliff height.
+    Cliff = 2,
+}
+
+impl ReleaseCurve {
+    /// Try converting from an u8. To be replaced when the `TryFrom` trait
+    /// is stabilised.
+    pub fn try_from(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(ReleaseCurve::Linear),
+            2 => Some(ReleaseCurve::Cliff),
+            _ => None,
+        }
+    }
+}
+
+encoding_struct! {
+    /// Coins and assets locked for a beneficiary.
+    ///
+    /// Locked value is held by the schedule itself rather than by any
+    /// wallet, and moves to the beneficiary as it is claimed.
+    struct Vesting {
+        creator:     &PublicKey,
+        beneficiary: &PublicKey,
+        start:       u64,
+        cliff:       u64,
+        end:         u64,
+        curve:       u8,
+        granted:     Wallet,
+        claimed:     Wallet,
+    }
+}
+
+/// `value * numerator / denominator`, computed in 128 bits so the product
+/// cannot overflow. The result fits for `numerator <= denominator`.
+fn scale(value: u64, numerator: u64, denominator: u64) -> u64 {
+    (u128::new(value) * u128::new(numerator) / u128::new(denominator)).low64()
+}
+
+/// Value of `minuend` that is not in `subtrahend`.
+fn subtract(minuend: &Wallet, subtrahend: &Wallet) -> Wallet {
+    let subtrahend_assets = subtrahend.assets();
+    let mut assets: Vec<AssetBundle> = minuend
+        .assets()
+        .into_iter()
+        .map(|asset| {
+            let taken = subtrahend_assets
+                .iter()
+                .find(|taken| taken.id() == asset.id())
+                .map(|taken| taken.amount())
+                .unwrap_or(0);
+            AssetBundle::new(asset.id(), asset.amount().saturating_sub(taken))
+        })
+        .collect();
+    assets.retain(|asset| asset.amount() > 0);
+
+    Wallet::new(minuend.balance().saturating_sub(subtrahend.balance()), assets)
+}
+
+impl Vesting {
+    /// Value unlocked by the given height, claimed or not.
+    pub fn unlocked(&self, height: u64) -> Wallet {
+        let curve = ReleaseCurve::try_from(self.curve()).expect("release curve must be valid");
+        let granted = self.granted();
+
+        if height < self.cliff() {
+            return Wallet::new_empty();
+        }
+        if height >= self.end() || curve == ReleaseCurve::Cliff {
+            return granted;
+        }
+
+        let elapsed = height.saturating_sub(self.start());
+        let duration = self.end() - self.start();
+
+        let mut assets: Vec<AssetBundle> = granted
+            .assets()
+            .into_iter()
+            .map(|asset| AssetBundle::new(asset.id(), scale(asset.amount(), elapsed, duration)))
+            .collect();
+        assets.retain(|asset| asset.amount() > 0);
+
+        Wallet::new(scale(granted.balance(), elapsed, duration), assets)
+    }
+
+    /// Value still locked at the given height.
+    pub fn locked(&self, height: u64) -> Wallet {
+        subtract(&self.granted(), &self.unlocked(height))
+    }
+
+    /// Value unlocked by the given height and not claimed yet.
+    pub fn claimable(&self, height: u64) -> Wallet {
+        subtract(&self.unlocked(height), &self.claimed())
+    }
+
+    /// Record value as claimed by the beneficiary.
+    pub fn claim(self, value: &Wallet) -> Self {
+        let mut claimed = self.claimed();
+        claimed.add_assets(value.assets());
+        let claimed = Wallet::new(claimed.balance() + value.balance(), claimed.assets());
+
+        Vesting::new(
+            self.creator(),
+            self.beneficiary(),
+            self.start(),
+            self.cliff(),
+            self.end(),
+            self.curve(),
+            self.granted(),
+            claimed,
+        )
+    }
+}
@@ -3,5 +3,5 @@
 mod schema;
 mod wallet;
 
-pub use currency::wallet::schema::Schema;
+pub use currency::wallet::schema::{balance_key, parse_balance_key, Schema};
 pub use currency::wallet::wallet::{move_assets, move_coins, Wallet};
@@ -1,5 +1,5 @@
-use exonum::crypto::PublicKey;
-use exonum::storage::{Fork, MapIndex, Snapshot};
+use exonum::crypto::{PublicKey, PUBLIC_KEY_LENGTH};
+use exonum::storage::{Fork, KeySetIndex, MapIndex, Snapshot};
 
 use currency::wallet::Wallet;
 use currency::SERVICE_NAME;
@@ -9,6 +9,27 @@ pub struct Schema<S>(pub S)
 where
     S: AsRef<Snapshot>;
 
+/// Key of a wallet in the balance index.
+///
+/// The balance is stored inverted and big-endian so that iterating over the
+/// index yields wallets from the largest balance down, with ties ordered by
+/// public key.
+pub fn balance_key(pub_key: &PublicKey, balance: u64) -> Vec<u8> {
+    let inverted = u64::max_value() - balance;
+    let mut key: Vec<u8> = (0..8).rev().map(|i| (inverted >> (i * 8)) as u8).collect();
+    key.extend_from_slice(pub_key.as_ref());
+    key
+}
+
+/// Split a key of the balance index into the public key and the balance.
+pub fn parse_balance_key(key: &[u8]) -> Option<(PublicKey, u64)> {
+    if key.len() != 8 + PUBLIC_KEY_LENGTH {
+        return None;
+    }
+    let inverted = key[..8].iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+    PublicKey::from_slice(&key[8..]).map(|pub_key| (pub_key, u64::max_value() - inverted))
+}
+
 impl<S> Schema<S>
 where
     S: AsRef<Snapshot>,
@@ -19,6 +40,12 @@ where
         MapIndex::new(key, self.0)
     }
 
+    /// Internal `KeySetIndex` of wallets ordered by balance, see `balance_key`.
+    pub fn balances_index(self) -> KeySetIndex<S, Vec<u8>> {
+        let key = SERVICE_NAME.to_string() + ".wallets_by_balance";
+        KeySetIndex::new(key, self.0)
+    }
+
     /// Fetch state for the specified wallet from the snapshot.
     pub fn fetch(self, pub_key: &PublicKey) -> Wallet {
         self.index()
@@ -34,16 +61,63 @@ impl<'a> Schema<&'a mut Fork> {
         MapIndex::new(key, &mut *self.0)
     }
 
+    /// Internal `KeySetIndex` of wallets ordered by balance, with mutable access.
+    pub fn balances_index_mut(&mut self) -> KeySetIndex<&mut Fork, Vec<u8>> {
+        let key = SERVICE_NAME.to_string() + ".wallets_by_balance";
+        KeySetIndex::new(key, &mut *self.0)
+    }
+
     /// Store the new state for a wallet in the database.
     pub fn store(&mut self, pub_key: &PublicKey, wallet: Wallet) {
         match (wallet.balance(), wallet.assets().len()) {
             (0, 0) => self.remove(pub_key),
-            (_, _) => self.index_mut().put(pub_key, wallet),
+            (_, _) => {
+                self.remove_balance(pub_key);
+                self.balances_index_mut().insert(balance_key(pub_key, wallet.balance()));
+                self.index_mut().put(pub_key, wallet);
+            }
         };
     }
 
     /// Remove wallet state from the database.
     pub fn remove(&mut self, pub_key: &PublicKey) {
+        self.remove_balance(pub_key);
         self.index_mut().remove(pub_key);
     }
+
+    /// Public keys of the wallets changed in the fork so far.
+    pub fn changed(&self) -> Vec<PublicKey> {
+        let key = SERVICE_NAME.to_string() + ".wallets";
+        self.0
+            .patch()
+            .iter()
+            .filter(|&(name, _)| *name == key)
+            .flat_map(|(_, changes)| changes.iter())
+            .filter_map(|(pub_key, _)| PublicKey::from_slice(pub_key))
+            .collect()
+    }
+
+    /// Add the wallets stored before the balance index existed to it.
+    ///
+    /// Run once by `Service::upgrade`, the index is kept up to date by
+    /// `Schema::store` from then on.
+    pub fn build_balances(&mut self) {
+        if self.balances_index_mut().iter().next().is_some() {
+            return;
+        }
+        let keys: Vec<Vec<u8>> = self.index_mut()
+            .iter()
+            .map(|(pub_key, wallet)| balance_key(&pub_key, wallet.balance()))
+            .collect();
+        for key in keys {
+            self.balances_index_mut().insert(key);
+        }
+    }
+
+    fn remove_balance(&mut self, pub_key: &PublicKey) {
+        let balance = self.index_mut().get(pub_key).map(|wallet| wallet.balance());
+        if let Some(balance) = balance {
+            self.balances_index_mut().remove(&balance_key(pub_key, balance));
+        }
+    }
 }
@@ -188,11 +188,60 @@ impl ExonumJson for UFract64 {
     }
 }
 
+/// Largest number of decimal places an amount can be formatted with.
+pub const MAX_PLACES: u32 = 19;
+
+fn scale(places: u32) -> Option<u64> {
+    (0..places).fold(Some(1u64), |scale, _| scale.and_then(|s| s.checked_mul(10)))
+}
+
+/// Format an amount of the smallest units as a decimal number with `places`
+/// digits after the point, leaving out trailing zeros.
+///
+/// # Panics
+/// Panics if `places` is over `MAX_PLACES`.
+pub fn format_units(amount: u64, places: u32) -> String {
+    assert!(places <= MAX_PLACES);
+    let scale = scale(places).unwrap();
+    let (integer, fraction) = (amount / scale, amount % scale);
+    if fraction == 0 {
+        return integer.to_string();
+    }
+    let fraction = format!("{:01$}", fraction, places as usize);
+    format!("{}.{}", integer, fraction.trim_right_matches('0'))
+}
+
+/// Parse a decimal number with at most `places` digits after the point into
+/// an amount of the smallest units.
+pub fn parse_units(s: &str, places: u32) -> Option<u64> {
+    let scale = scale(places)?;
+    let mut parts = s.splitn(2, '.');
+    let integer = parts.next().unwrap_or("");
+    let fraction = parts.next();
+
+    let is_number = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    let valid_fraction = fraction.map_or(true, |f| is_number(f) && f.len() <= places as usize);
+    if !is_number(integer) || !valid_fraction {
+        return None;
+    }
+
+    let fraction = fraction.map_or(0, |f| {
+        let padding = scale / 10u64.pow(f.len() as u32);
+        f.parse::<u64>().unwrap() * padding
+    });
+    integer
+        .parse::<u64>()
+        .ok()
+        .and_then(|integer| integer.checked_mul(scale))
+        .and_then(|integer| integer.checked_add(fraction))
+}
+
 #[cfg(test)]
 mod test {
     use std::string::ToString;
 
     use super::UFract64;
+    use super::{format_units, parse_units};
 
     #[test]
     fn create_from_digits() {
@@ -251,4 +300,28 @@ mod test {
         let fract = UFract64::from_digits(digits);
         assert_eq!(15241578, fract * 123_456_789);
     }
+
+    #[test]
+    fn format_amounts() {
+        assert_eq!("0", format_units(0, 8));
+        assert_eq!("1", format_units(100_000_000, 8));
+        assert_eq!("1.5", format_units(150_000_000, 8));
+        assert_eq!("0.00000001", format_units(1, 8));
+        assert_eq!("18446744073709551615", format_units(u64::max_value(), 0));
+        assert_eq!("1.8446744073709551615", format_units(u64::max_value(), 19));
+    }
+
+    #[test]
+    fn parse_amounts() {
+        assert_eq!(Some(150_000_000), parse_units("1.5", 8));
+        assert_eq!(Some(1), parse_units("0.00000001", 8));
+        assert_eq!(Some(7), parse_units("7", 0));
+        assert_eq!(Some(u64::max_value()), parse_units("18446744073709551615", 0));
+        assert_eq!(None, parse_units("18446744073709551616", 0));
+        assert_eq!(None, parse_units("0.000000001", 8));
+        assert_eq!(None, parse_units("1.", 8));
+        assert_eq!(None, parse_units(".5", 8));
+        assert_eq!(None, parse_units("-1", 8));
+        assert_eq!(None, parse_units("1e8", 8));
+    }
 }
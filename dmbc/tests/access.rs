@@ -0,0 +1,271 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate prometheus;
+extern crate serde_json;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use exonum::crypto;
+use hyper::header::{ContentType, Headers};
+use hyper::status::StatusCode;
+use iron::headers::AccessControlAllowOrigin;
+use iron::prelude::*;
+use iron::status;
+use iron_test::{request, response};
+
+use dmbc::config::Access;
+use dmbc::currency::api::access::{AccessControl, API_KEY_HEADER, PUBLIC_KEY_HEADER,
+                                  SIGNATURE_HEADER, TIMESTAMP_HEADER};
+
+const TX_BODY: &str = r#"{"message_id": 200}"#;
+
+fn endpoint(_: &mut Request) -> IronResult<Response> {
+    let mut res = Response::with((status::Ok, "{}"));
+    res.headers.set(AccessControlAllowOrigin::Any);
+    Ok(res)
+}
+
+fn chain(access: &str) -> Chain {
+    let access: Access = serde_json::from_str(access).unwrap();
+    let mut chain = Chain::new(endpoint);
+    chain.link_around(AccessControl::new(access).unwrap());
+    chain
+}
+
+fn headers(pairs: &[(&str, &str)]) -> Headers {
+    let mut headers = Headers::new();
+    for &(name, value) in pairs {
+        headers.set_raw(name.to_string(), vec![value.as_bytes().to_vec()]);
+    }
+    headers
+}
+
+fn get(chain: &Chain, path: &str, headers: Headers) -> (StatusCode, Response) {
+    let url = format!("http://localhost:3000{}", path);
+    let res = request::get(&url, headers, chain).unwrap();
+    (res.status.unwrap(), res)
+}
+
+fn post(chain: &Chain, path: &str, mut headers: Headers, body: &str) -> (StatusCode, String) {
+    let url = format!("http://localhost:3000{}", path);
+    headers.set(ContentType::json());
+    let res = request::post(&url, headers, body, chain).unwrap();
+    (res.status.unwrap(), response::extract_body_to_string(res))
+}
+
+fn rejections(name: &str) -> i64 {
+    prometheus::gather()
+        .iter()
+        .find(|family| family.get_name() == name)
+        .map_or(0, |family| family.get_metric()[0].get_counter().get_value() as i64)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn signed_headers(
+    keypair: &(crypto::PublicKey, crypto::SecretKey),
+    data: &str,
+    timestamp: u64,
+) -> Headers {
+    let (ref pub_key, ref secret_key) = *keypair;
+    let signature = crypto::sign(data.as_bytes(), secret_key);
+    headers(&[
+        (PUBLIC_KEY_HEADER, &pub_key.to_hex()),
+        (TIMESTAMP_HEADER, &timestamp.to_string()),
+        (SIGNATURE_HEADER, &signature.to_hex()),
+    ])
+}
+
+#[test]
+fn access_open_by_default() {
+    let chain = chain("{}");
+
+    let (status, res) = get(&chain, "/v1/wallets", Headers::new());
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        res.headers.get::<AccessControlAllowOrigin>(),
+        Some(&AccessControlAllowOrigin::Any)
+    );
+
+    let (status, _) = post(&chain, "/v1/transactions", Headers::new(), TX_BODY);
+    assert_eq!(status, StatusCode::Ok);
+}
+
+#[test]
+fn access_api_key() {
+    let chain = chain(
+        r#"{"groups": [{
+            "routes": ["POST /v1/transactions", "/v2/transactions"],
+            "api_keys": ["secret"]
+        }]}"#,
+    );
+    let unauthorized = "dmbc_api_access_unauthorized_rejections_total";
+    let before = rejections(unauthorized);
+
+    let (status, body) = post(&chain, "/v1/transactions", Headers::new(), TX_BODY);
+    assert_eq!(status, StatusCode::Unauthorized);
+    assert_eq!(body, "{\n  \"Err\": \"Unauthorized\"\n}");
+
+    let (status, _) = post(
+        &chain,
+        "/v1/transactions",
+        headers(&[(API_KEY_HEADER, "guess")]),
+        TX_BODY,
+    );
+    assert_eq!(status, StatusCode::Unauthorized);
+
+    let (status, body) = post(&chain, "/v2/transactions/raw", Headers::new(), TX_BODY);
+    assert_eq!(status, StatusCode::Unauthorized);
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["error"]["code"], "unauthorized");
+
+    assert!(rejections(unauthorized) >= before + 3);
+
+    let (status, _) = post(
+        &chain,
+        "/v1/transactions",
+        headers(&[(API_KEY_HEADER, "secret")]),
+        TX_BODY,
+    );
+    assert_eq!(status, StatusCode::Ok);
+
+    let (status, _) = get(&chain, "/v1/transactions/disabled", Headers::new());
+    assert_eq!(status, StatusCode::Ok);
+}
+
+#[test]
+fn access_signed_request() {
+    let keypair = crypto::gen_keypair();
+    let chain = chain(&format!(
+        r#"{{"groups": [{{"routes": ["POST /v1/transactions"], "public_keys": ["{}"]}}]}}"#,
+        keypair.0.to_hex()
+    ));
+
+    let timestamp = now();
+    let data = format!("POST\n/v1/transactions\n{}\n{}", timestamp, TX_BODY);
+    let (status, _) = post(
+        &chain,
+        "/v1/transactions",
+        signed_headers(&keypair, &data, timestamp),
+        TX_BODY,
+    );
+    assert_eq!(status, StatusCode::Ok);
+
+    let (status, _) = post(
+        &chain,
+        "/v1/transactions",
+        signed_headers(&keypair, &data, timestamp),
+        r#"{"message_id": 201}"#,
+    );
+    assert_eq!(status, StatusCode::Unauthorized);
+
+    let stale = timestamp - 3600;
+    let data = format!("POST\n/v1/transactions\n{}\n{}", stale, TX_BODY);
+    let (status, _) = post(
+        &chain,
+        "/v1/transactions",
+        signed_headers(&keypair, &data, stale),
+        TX_BODY,
+    );
+    assert_eq!(status, StatusCode::Unauthorized);
+
+    let stranger = crypto::gen_keypair();
+    let data = format!("POST\n/v1/transactions\n{}\n{}", timestamp, TX_BODY);
+    let (status, _) = post(
+        &chain,
+        "/v1/transactions",
+        signed_headers(&stranger, &data, timestamp),
+        TX_BODY,
+    );
+    assert_eq!(status, StatusCode::Unauthorized);
+}
+
+#[test]
+fn access_rate_limits() {
+    let chain = chain(
+        r#"{
+            "ip_rate": 0.01, "ip_burst": 2,
+            "key_rate": 0.01, "key_burst": 1,
+            "groups": [{"routes": ["/v1/blocks"], "api_keys": ["secret"]}]
+        }"#,
+    );
+    let rate_limited = "dmbc_api_access_rate_limited_rejections_total";
+    let before = rejections(rate_limited);
+
+    for _ in 0..2 {
+        let (status, _) = get(&chain, "/v1/wallets", Headers::new());
+        assert_eq!(status, StatusCode::Ok);
+    }
+    let (status, res) = get(&chain, "/v1/wallets", Headers::new());
+    assert_eq!(status, StatusCode::TooManyRequests);
+    assert!(res.headers.get_raw("Retry-After").is_some());
+
+    // Requests with a key count against the key, not the address.
+    let (status, _) = get(&chain, "/v1/wallets", headers(&[(API_KEY_HEADER, "secret")]));
+    assert_eq!(status, StatusCode::Ok);
+    let (status, _) = get(&chain, "/v1/wallets", headers(&[(API_KEY_HEADER, "secret")]));
+    assert_eq!(status, StatusCode::TooManyRequests);
+
+    assert!(rejections(rate_limited) >= before + 2);
+}
+
+#[test]
+fn access_cors_allowlist() {
+    let chain = chain(r#"{"cors_origins": ["https://app.example.com"]}"#);
+    let origin_rejections = "dmbc_api_access_origin_rejections_total";
+    let before = rejections(origin_rejections);
+
+    let (status, res) = get(
+        &chain,
+        "/v1/wallets",
+        headers(&[("Origin", "https://app.example.com")]),
+    );
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        res.headers.get::<AccessControlAllowOrigin>(),
+        Some(&AccessControlAllowOrigin::Value(
+            "https://app.example.com".to_string()
+        ))
+    );
+
+    let (status, _) = get(
+        &chain,
+        "/v1/wallets",
+        headers(&[("Origin", "https://evil.example.com")]),
+    );
+    assert_eq!(status, StatusCode::Forbidden);
+    assert!(rejections(origin_rejections) >= before + 1);
+
+    let (status, res) = get(&chain, "/v1/wallets", Headers::new());
+    assert_eq!(status, StatusCode::Ok);
+    assert!(res.headers.get::<AccessControlAllowOrigin>().is_none());
+}
+
+#[test]
+fn access_invalid_config() {
+    let config = |access: &str| {
+        let access: Access = serde_json::from_str(access).unwrap();
+        AccessControl::new(access).err().map(|e| (e.entry, e.value))
+    };
+
+    assert_eq!(
+        config(r#"{"groups": [{"routes": ["GET /v1/wallets extra"]}]}"#),
+        Some((
+            "access.groups.routes".to_string(),
+            "GET /v1/wallets extra".to_string()
+        ))
+    );
+    assert_eq!(
+        config(r#"{"groups": [{"routes": ["/v1/wallets"], "public_keys": ["123"]}]}"#),
+        Some(("access.groups.public_keys".to_string(), "123".to_string()))
+    );
+    assert_eq!(config(r#"{"groups": [{"routes": ["GET /v1/wallets"]}]}"#), None);
+}
@@ -0,0 +1,214 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::search::AssetSearchResponse;
+use dmbc::currency::assets::{AssetBundle, AssetId};
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+
+fn found_ids(response: AssetSearchResponse) -> Vec<AssetId> {
+    response.unwrap().assets.into_iter().map(|a| a.id).collect()
+}
+
+#[test]
+fn search_assets() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (other_key, other_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .add_wallet_value(&other_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+    let knife = r#"{"name": "Karambit Knife", "rarity": "covert"}"#;
+    let rifle = r#"{"name": "Kar98k Rifle", "rarity": "classified"}"#;
+    let gloves = r#"{"name": "Driver Gloves", "rarity": "covert"}"#;
+
+    let tx_creator = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_assets()
+        .add_asset(knife, 5, fees.clone())
+        .add_asset(rifle, 5, fees.clone())
+        .seed(1)
+        .build();
+
+    let tx_other = transaction::Builder::new()
+        .keypair(other_key, other_secret)
+        .tx_add_assets()
+        .add_asset(gloves, 5, fees.clone())
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_creator);
+    api.post_tx(&tx_other);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_creator);
+    assert_eq!(tx_status, Ok(Ok(())));
+    let (_, tx_status) = api.get_tx_status(&tx_other);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let knife_id = AssetId::from_data(knife, &creator_key);
+    let rifle_id = AssetId::from_data(rifle, &creator_key);
+    let gloves_id = AssetId::from_data(gloves, &other_key);
+
+    let mut covert = vec![knife_id, gloves_id];
+    covert.sort_by_key(|id| id.to_string());
+
+    // Full-text queries match all words, regardless of case.
+    let (status, response): (StatusCode, AssetSearchResponse) =
+        api.get_with_status("/v1/search/assets?q=Covert");
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(found_ids(response), covert);
+
+    let (_, response): (StatusCode, AssetSearchResponse) =
+        api.get_with_status("/v1/search/assets?q=covert%20knife");
+    assert_eq!(found_ids(response), vec![knife_id]);
+
+    // Prefix queries match the beginning of any word.
+    let (_, response): (StatusCode, AssetSearchResponse) =
+        api.get_with_status("/v1/search/assets?prefix=kar");
+    let mut kar = vec![knife_id, rifle_id];
+    kar.sort_by_key(|id| id.to_string());
+    assert_eq!(found_ids(response), kar);
+
+    let (_, response): (StatusCode, AssetSearchResponse) = api.get_with_status(&format!(
+        "/v1/search/assets?q=covert&creator={}",
+        other_key.to_hex()
+    ));
+    assert_eq!(found_ids(response), vec![gloves_id]);
+
+    let (_, response): (StatusCode, AssetSearchResponse) =
+        api.get_with_status("/v1/search/assets?q=covert&offset=0&limit=1");
+    let body = response.unwrap();
+    assert_eq!(body.total, 2);
+    assert_eq!(body.count, 1);
+
+    // Deleted assets are no longer found.
+    let tx_delete = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_del_assets()
+        .add_asset_value(AssetBundle::new(knife_id, 5))
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_delete);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_delete);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, response): (StatusCode, AssetSearchResponse) =
+        api.get_with_status("/v1/search/assets?q=covert");
+    assert_eq!(found_ids(response), vec![gloves_id]);
+}
+
+#[test]
+fn search_assets_changed() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (other_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+    let rifle = r#"{"name": "Dragon Lore Rifle"}"#;
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_assets()
+        .add_asset(rifle, 5, fees.clone())
+        .seed(1)
+        .build();
+
+    // Token series are searchable by their name.
+    let tx_mint = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_mint_tokens()
+        .name("Dragon Lore Card")
+        .fees(fees)
+        .add_token(1, "first edition")
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    api.post_tx(&tx_mint);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+    let (_, tx_status) = api.get_tx_status(&tx_mint);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let rifle_id = AssetId::from_data(rifle, &creator_key);
+    let series_id = AssetId::from_series("Dragon Lore Card", &creator_key);
+
+    let (_, response): (StatusCode, AssetSearchResponse) =
+        api.get_with_status("/v1/search/assets?q=dragon%20card");
+    assert_eq!(found_ids(response), vec![series_id]);
+
+    // The creator filter follows the creator role.
+    let tx_transfer_creator = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_transfer_asset_creator()
+        .asset_id(rifle_id)
+        .new_creator(other_key)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_transfer_creator);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_creator);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, response): (StatusCode, AssetSearchResponse) = api.get_with_status(&format!(
+        "/v1/search/assets?q=dragon&creator={}",
+        creator_key.to_hex()
+    ));
+    assert_eq!(found_ids(response), vec![series_id]);
+
+    let (_, response): (StatusCode, AssetSearchResponse) = api.get_with_status(&format!(
+        "/v1/search/assets?creator={}",
+        other_key.to_hex()
+    ));
+    assert_eq!(found_ids(response), vec![rifle_id]);
+}
+
+#[test]
+fn search_assets_invalid_request() {
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let (status, response): (StatusCode, AssetSearchResponse) =
+        api.get_with_status("/v1/search/assets");
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::IncorrectRequest));
+
+    let (status, response): (StatusCode, AssetSearchResponse) =
+        api.get_with_status("/v1/search/assets?creator=badkey");
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::WalletHexInvalid));
+}
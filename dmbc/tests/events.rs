@@ -0,0 +1,195 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use iron::headers::Headers;
+use iron_test::{request, response};
+use exonum::crypto;
+use exonum::messages::Message;
+use exonum_testkit::TestKitApi;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::SERVICE_NAME;
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::events::EventsResponse;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::events::Event;
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+
+fn get_events(api: &TestKitApi, query: &str, headers: Headers) -> (StatusCode, String) {
+    let url = format!(
+        "http://localhost:3000/api/services/{}/v1/events?{}",
+        SERVICE_NAME, query
+    );
+    let response = request::get(&url, headers, api.public_handler()).unwrap();
+    let status = response.status.unwrap();
+    (status, response::extract_body_to_string(response))
+}
+
+fn parse_events(body: &str) -> Vec<Event> {
+    body.lines()
+        .filter(|line| line.starts_with("data: "))
+        .map(|line| serde_json::from_str(&line["data: ".len()..]).unwrap())
+        .collect()
+}
+
+#[test]
+fn events_of_committed_blocks() {
+    let fixed = 10;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, fixed);
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+    let (other_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+    let height = testkit.height().0;
+    testkit.create_block();
+
+    let query = format!(
+        "blocks=true&tx={}&wallet={},{}&from_height={}&to_height={}",
+        tx_transfer.hash().to_hex(),
+        recipient_key.to_hex(),
+        other_key.to_hex(),
+        height,
+        height + 1
+    );
+    let (status, body) = get_events(&api, &query, Headers::new());
+    assert_eq!(status, StatusCode::Ok);
+
+    let events = parse_events(&body);
+    assert_eq!(events.len(), 4);
+    match events[0] {
+        Event::Block {
+            height: h,
+            tx_count,
+            ..
+        } => {
+            assert_eq!(h, height);
+            assert_eq!(tx_count, 1);
+        }
+        ref event => panic!("Unexpected event {:?}", event),
+    }
+    assert_eq!(
+        events[1],
+        Event::Transaction {
+            height,
+            tx_hash: tx_transfer.hash(),
+            status: Ok(()),
+        }
+    );
+    assert_eq!(
+        events[2],
+        Event::Wallet {
+            height,
+            pub_key: recipient_key,
+        }
+    );
+    match events[3] {
+        Event::Block { height: h, .. } => assert_eq!(h, height + 1),
+        ref event => panic!("Unexpected event {:?}", event),
+    }
+
+    // Resuming after the first block only tells the second one.
+    let query = format!("blocks=true&to_height={}", height + 1);
+    let mut headers = Headers::new();
+    headers.set_raw("Last-Event-ID", vec![height.to_string().into_bytes()]);
+    let (status, body) = get_events(&api, &query, headers);
+    assert_eq!(status, StatusCode::Ok);
+
+    let events = parse_events(&body);
+    assert_eq!(events.len(), 1);
+    match events[0] {
+        Event::Block { height: h, .. } => assert_eq!(h, height + 1),
+        ref event => panic!("Unexpected event {:?}", event),
+    }
+    assert!(body.lines().any(|line| line == format!("id: {}", height + 1)));
+
+    // Reconnecting after the last block ends the stream.
+    let mut headers = Headers::new();
+    headers.set_raw("Last-Event-ID", vec![(height + 1).to_string().into_bytes()]);
+    let (status, _) = get_events(&api, &query, headers);
+    assert_eq!(status, StatusCode::NoContent);
+}
+
+#[test]
+fn events_resumed_with_from_height() {
+    let mut testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+    testkit.create_block();
+    let height = testkit.height().0;
+    testkit.create_block();
+    testkit.create_block();
+
+    // A reconnecting client repeats the query, the last event id wins.
+    let query = format!("blocks=true&from_height={}&to_height={}", height, height + 2);
+    let mut headers = Headers::new();
+    headers.set_raw("Last-Event-ID", vec![(height + 1).to_string().into_bytes()]);
+    let (status, body) = get_events(&api, &query, headers);
+    assert_eq!(status, StatusCode::Ok);
+
+    let events = parse_events(&body);
+    assert_eq!(events.len(), 1);
+    match events[0] {
+        Event::Block { height: h, .. } => assert_eq!(h, height + 2),
+        ref event => panic!("Unexpected event {:?}", event),
+    }
+
+    let mut headers = Headers::new();
+    headers.set_raw("Last-Event-ID", vec![(height + 2).to_string().into_bytes()]);
+    let (status, _) = get_events(&api, &query, headers);
+    assert_eq!(status, StatusCode::NoContent);
+}
+
+#[test]
+fn events_after_current_block() {
+    let mut testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+    testkit.create_block();
+    let height = testkit.height().0;
+
+    // The response ends right away, telling where to resume.
+    let (status, body) = get_events(&api, "blocks=true", Headers::new());
+    assert_eq!(status, StatusCode::Ok);
+    assert!(parse_events(&body).is_empty());
+    assert!(body.lines().any(|line| line == format!("id: {}", height)));
+}
+
+#[test]
+fn events_invalid_subscription() {
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let (status, body) = get_events(&api, "to_height=1", Headers::new());
+    let response: EventsResponse = serde_json::from_str(&body).unwrap();
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::IncorrectRequest));
+
+    let (status, body) = get_events(&api, "wallet=123&to_height=1", Headers::new());
+    let response: EventsResponse = serde_json::from_str(&body).unwrap();
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::WalletHexInvalid));
+}
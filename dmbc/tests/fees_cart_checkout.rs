@@ -0,0 +1,111 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::collections::HashMap;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::fees::FeesResponseBody;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::error::Error;
+use dmbc::currency::transactions::components::FeeStrategy;
+use dmbc::currency::assets::TradeAsset;
+
+#[test]
+fn fees_for_cart_checkout() {
+    let transaction_fee = 1000;
+    let fixed = 10;
+    let units = 2;
+    let price_per_unit = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, transaction_fee, 0);
+
+    let (creator_pub_key, _) = crypto::gen_keypair();
+    let (seller1_public_key, seller1_secret_key) = crypto::gen_keypair();
+    let (seller2_public_key, seller2_secret_key) = crypto::gen_keypair();
+    let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+
+    let (asset1, info1) = dmbc_testkit::create_asset("asset1", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pub_key);
+    let (asset2, info2) = dmbc_testkit::create_asset("asset2", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pub_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_asset_to_wallet(&seller1_public_key, (asset1.clone(), info1))
+        .add_asset_to_wallet(&seller2_public_key, (asset2.clone(), info2))
+        .create();
+    let api = testkit.api();
+
+    let tx_cart = transaction::Builder::new()
+        .keypair(buyer_public_key, buyer_secret_key)
+        .tx_cart_checkout()
+        .add_offer(
+            seller1_public_key,
+            seller1_secret_key,
+            vec![TradeAsset::from_bundle(asset1, price_per_unit)],
+            FeeStrategy::Recipient,
+        )
+        .add_offer(
+            seller2_public_key,
+            seller2_secret_key,
+            vec![TradeAsset::from_bundle(asset2, price_per_unit)],
+            FeeStrategy::Sender,
+        )
+        .seed(12)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_cart);
+    let mut expected = HashMap::new();
+    let expected_fee = transaction_fee + fixed * units;
+    expected.insert(buyer_public_key, expected_fee);
+    expected.insert(seller2_public_key, expected_fee);
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Ok(FeesResponseBody { fees: expected })));
+}
+
+#[test]
+fn fees_for_cart_checkout_asset_not_found() {
+    let transaction_fee = 1000;
+    let fixed = 10;
+    let units = 2;
+    let price_per_unit = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, transaction_fee, 0);
+
+    let (creator_pub_key, _) = crypto::gen_keypair();
+    let (seller_public_key, seller_secret_key) = crypto::gen_keypair();
+    let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+
+    let (asset, _) = dmbc_testkit::create_asset("asset", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pub_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_cart = transaction::Builder::new()
+        .keypair(buyer_public_key, buyer_secret_key)
+        .tx_cart_checkout()
+        .add_offer(
+            seller_public_key,
+            seller_secret_key,
+            vec![TradeAsset::from_bundle(asset, price_per_unit)],
+            FeeStrategy::Recipient,
+        )
+        .seed(12)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_cart);
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::AssetNotFound)));
+}
@@ -0,0 +1,107 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::collections::HashMap;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::fees::FeesResponseBody;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::error::Error;
+use dmbc::currency::transactions::components::FeeStrategy;
+
+#[test]
+fn fees_for_multi_exchange_recipient_and_sender() {
+    let transaction_fee = 900;
+    let fixed = 10;
+    let units = 4;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, transaction_fee, 0, 0);
+
+    let (creator_pk, _) = crypto::gen_keypair();
+    let (alice_pk, alice_sk) = crypto::gen_keypair();
+    let (bob_pk, bob_sk) = crypto::gen_keypair();
+    let (carol_pk, carol_sk) = crypto::gen_keypair();
+
+    let (asset1, info1) = dmbc_testkit::create_asset("asset1", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pk);
+    let (asset2, info2) = dmbc_testkit::create_asset("asset2", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pk);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_asset_to_wallet(&alice_pk, (asset1.clone(), info1))
+        .add_asset_to_wallet(&bob_pk, (asset2.clone(), info2))
+        .create();
+    let api = testkit.api();
+
+    let tx_multi_exchange = transaction::Builder::new()
+        .keypair(alice_pk, alice_sk.clone())
+        .tx_multi_exchange()
+        .add_leg(alice_pk, bob_pk, 0, vec![asset1])
+        .add_leg(bob_pk, carol_pk, 0, vec![asset2])
+        .add_leg(carol_pk, alice_pk, 100, vec![])
+        .signer(alice_pk, alice_sk)
+        .signer(bob_pk, bob_sk)
+        .signer(carol_pk, carol_sk)
+        .fee_strategy(FeeStrategy::RecipientAndSender)
+        .seed(1)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_multi_exchange);
+
+    // Blockchain fee is split between all three participants, asset fees
+    // are split between both sides of the leg that moves the asset.
+    let fee_share = transaction_fee / 3;
+    let asset_fee_share = units * fixed / 2;
+    let mut expected = HashMap::new();
+    expected.insert(alice_pk, fee_share + asset_fee_share);
+    expected.insert(bob_pk, fee_share + 2 * asset_fee_share);
+    expected.insert(carol_pk, fee_share + asset_fee_share);
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Ok(FeesResponseBody { fees: expected })));
+}
+
+#[test]
+fn fees_for_multi_exchange_asset_not_found() {
+    let transaction_fee = 900;
+    let fixed = 10;
+    let units = 4;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, transaction_fee, 0, 0);
+
+    let (creator_pk, _) = crypto::gen_keypair();
+    let (alice_pk, alice_sk) = crypto::gen_keypair();
+    let (bob_pk, bob_sk) = crypto::gen_keypair();
+
+    let (asset, _) = dmbc_testkit::create_asset("asset", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pk);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_multi_exchange = transaction::Builder::new()
+        .keypair(alice_pk, alice_sk.clone())
+        .tx_multi_exchange()
+        .add_leg(alice_pk, bob_pk, 0, vec![asset])
+        .add_leg(bob_pk, alice_pk, 100, vec![])
+        .signer(alice_pk, alice_sk)
+        .signer(bob_pk, bob_sk)
+        .fee_strategy(FeeStrategy::Sender)
+        .seed(1)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_multi_exchange);
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::AssetNotFound)));
+}
@@ -0,0 +1,174 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::collections::HashMap;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use exonum::crypto::Hash;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::fees::FeesResponseBody;
+use dmbc::currency::assets::AssetBundle;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::multisig::{MultisigConfig, MultisigMember};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::error::Error;
+
+#[test]
+fn fees_for_multisig_spend() {
+    let transaction_fee = 1000;
+    let units = 3;
+    let fixed = 10;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+
+    let (creator_key, _) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+    let (member1_key, member1_sec_key) = crypto::gen_keypair();
+    let (member2_key, member2_sec_key) = crypto::gen_keypair();
+
+    let config = MultisigConfig::new(
+        vec![MultisigMember::new(&member1_key), MultisigMember::new(&member2_key)],
+        2,
+    );
+    let address = config.address();
+
+    let (asset, info) = dmbc_testkit::create_asset("asset", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_asset_to_wallet(&address, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(address, member1_sec_key.clone())
+        .tx_transfer()
+        .recipient(recipient_key)
+        .add_asset_value(AssetBundle::new(asset.id(), units))
+        .build();
+
+    let tx_spend = transaction::Builder::new()
+        .keypair(member1_key, member1_sec_key.clone())
+        .tx_multisig_spend()
+        .wallet(address)
+        .tx_value(&tx_transfer)
+        .signer(member1_key, member1_sec_key)
+        .signer(member2_key, member2_sec_key)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_spend);
+
+    // Fees are paid by the multisig wallet, as for the inner transaction.
+    let mut expected = HashMap::new();
+    expected.insert(address, transaction_fee + units * fixed);
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Ok(FeesResponseBody { fees: expected })));
+}
+
+#[test]
+fn fees_for_multisig_approve_proposal_not_found() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 1000);
+
+    let (member_key, member_sec_key) = crypto::gen_keypair();
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_approve = transaction::Builder::new()
+        .keypair(member_key, member_sec_key)
+        .tx_multisig_approve()
+        .proposal(Hash::zero())
+        .build();
+
+    let (status, response) = api.post_fee(&tx_approve);
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::ProposalNotFound)));
+}
+
+#[test]
+fn fees_for_multisig_propose() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+
+    let (recipient_key, _) = crypto::gen_keypair();
+    let (member1_key, member1_sec_key) = crypto::gen_keypair();
+    let (member2_key, _) = crypto::gen_keypair();
+
+    let config = MultisigConfig::new(
+        vec![MultisigMember::new(&member1_key), MultisigMember::new(&member2_key)],
+        2,
+    );
+    let address = config.address();
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(address, member1_sec_key.clone())
+        .tx_transfer()
+        .recipient(recipient_key)
+        .amount(10)
+        .build();
+
+    let tx_propose = transaction::Builder::new()
+        .keypair(member1_key, member1_sec_key)
+        .tx_multisig_propose()
+        .wallet(address)
+        .tx_value(&tx_transfer)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_propose);
+
+    // The proposer pays for the proposal, the wallet for the inner transaction.
+    let mut expected = HashMap::new();
+    expected.insert(address, transaction_fee);
+    expected.insert(member1_key, transaction_fee);
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Ok(FeesResponseBody { fees: expected })));
+}
+
+#[test]
+fn fees_for_create_multisig() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+
+    let (creator_key, creator_sec_key) = crypto::gen_keypair();
+    let (member_key, _) = crypto::gen_keypair();
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(creator_key, creator_sec_key)
+        .tx_create_multisig()
+        .add_member(creator_key)
+        .add_member(member_key)
+        .threshold(2)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_create);
+
+    let mut expected = HashMap::new();
+    expected.insert(creator_key, transaction_fee);
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Ok(FeesResponseBody { fees: expected })));
+}
@@ -0,0 +1,151 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::collections::HashMap;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::fees::FeeQuoteResponse;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::error::Error;
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::transactions::components::FeeStrategy;
+use dmbc::currency::transactions::quote::FeeQuoteRequest;
+use dmbc::currency::assets::TradeAsset;
+
+#[test]
+fn fee_quote_for_trade() {
+    let transaction_fee = 1000;
+    let fixed = 10;
+    let units = 2;
+    let price_per_unit = 1000;
+    let meta_data = "asset";
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, transaction_fee, 0);
+
+    let (creator_pub_key, _) = crypto::gen_keypair();
+    let (seller_public_key, seller_secret_key) = crypto::gen_keypair();
+    let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset(meta_data, units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pub_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_asset_to_wallet(&seller_public_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let trade_asset = TradeAsset::from_bundle(asset, price_per_unit);
+    let request = FeeQuoteRequest::Trade {
+        buyer: buyer_public_key,
+        seller: seller_public_key,
+        assets: vec![trade_asset.clone()],
+        fee_strategy: FeeStrategy::RecipientAndSender as u8,
+    };
+
+    let (status, response): (StatusCode, FeeQuoteResponse) =
+        api.post_with_status("/v1/fees/quote", &request);
+    assert_eq!(status, StatusCode::Ok);
+    let quote = response.unwrap().unwrap();
+
+    // The quote matches the fees of the signed transaction.
+    let tx_trade = transaction::Builder::new()
+        .keypair(buyer_public_key, buyer_secret_key)
+        .tx_trade_assets()
+        .add_asset_value(trade_asset)
+        .seller(seller_public_key, seller_secret_key)
+        .fee_strategy(FeeStrategy::RecipientAndSender)
+        .seed(12)
+        .build();
+
+    let (_, response) = api.post_fee(&tx_trade);
+    assert_eq!(quote.payers, response.unwrap().unwrap().fees);
+
+    let mut third_party = HashMap::new();
+    third_party.insert(creator_pub_key, fixed * units);
+    assert_eq!(quote.third_party, third_party);
+}
+
+#[test]
+fn fee_quote_for_transfer() {
+    let transaction_fee = 1000;
+    let fixed = 10;
+    let units = 5;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+
+    let (creator_key, _) = crypto::gen_keypair();
+    let (sender_key, _) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("asset", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_asset_to_wallet(&sender_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let request = FeeQuoteRequest::Transfer {
+        from: sender_key,
+        to: recipient_key,
+        assets: vec![asset],
+    };
+
+    let (status, response): (StatusCode, FeeQuoteResponse) =
+        api.post_with_status("/v1/fees/quote", &request);
+    assert_eq!(status, StatusCode::Ok);
+    let quote = response.unwrap().unwrap();
+
+    let mut payers = HashMap::new();
+    payers.insert(sender_key, transaction_fee + fixed * units);
+    assert_eq!(quote.payers, payers);
+
+    let mut third_party = HashMap::new();
+    third_party.insert(creator_key, fixed * units);
+    assert_eq!(quote.third_party, third_party);
+}
+
+#[test]
+fn fee_quote_errors() {
+    let (creator_key, _) = crypto::gen_keypair();
+    let (seller_key, _) = crypto::gen_keypair();
+    let (buyer_key, _) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("asset", 2, dmbc_testkit::asset_fees(10, "0.0".parse().unwrap()), &creator_key);
+    let (missing, _) = dmbc_testkit::create_asset("missing", 2, dmbc_testkit::asset_fees(10, "0.0".parse().unwrap()), &creator_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .add_asset_to_wallet(&seller_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let request = FeeQuoteRequest::Trade {
+        buyer: buyer_key,
+        seller: seller_key,
+        assets: vec![TradeAsset::from_bundle(asset, 100)],
+        fee_strategy: 42,
+    };
+    let (status, response): (StatusCode, FeeQuoteResponse) =
+        api.post_with_status("/v1/fees/quote", &request);
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::InvalidTransaction)));
+
+    let request = FeeQuoteRequest::Transfer {
+        from: seller_key,
+        to: buyer_key,
+        assets: vec![missing],
+    };
+    let (status, response): (StatusCode, FeeQuoteResponse) =
+        api.post_with_status("/v1/fees/quote", &request);
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::AssetNotFound)));
+}
@@ -0,0 +1,116 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::collections::HashMap;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::fees::FeesResponseBody;
+use dmbc::currency::assets::AssetBundle;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+
+#[test]
+fn fees_for_transfer_from() {
+    let transaction_fee = 1000;
+    let units = 4;
+    let fixed = 10;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+
+    let (creator_key, _) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+    let (owner_key, _) = crypto::gen_keypair();
+    let (spender_key, spender_secret) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("asset", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_asset_to_wallet(&owner_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer_from = transaction::Builder::new()
+        .keypair(spender_key, spender_secret)
+        .tx_transfer_from()
+        .owner(owner_key)
+        .recipient(recipient_key)
+        .add_asset_value(AssetBundle::new(asset.id(), units))
+        .build();
+
+    let (status, response) = api.post_fee(&tx_transfer_from);
+
+    // The spender pays, not the owner.
+    let mut expected = HashMap::new();
+    expected.insert(spender_key, transaction_fee + units * fixed);
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Ok(FeesResponseBody { fees: expected })));
+}
+
+#[test]
+fn fees_for_approve() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+
+    let (owner_key, owner_secret) = crypto::gen_keypair();
+    let (spender_key, _) = crypto::gen_keypair();
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_approve = transaction::Builder::new()
+        .keypair(owner_key, owner_secret)
+        .tx_approve()
+        .spender(spender_key)
+        .amount(100)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_approve);
+
+    let mut expected = HashMap::new();
+    expected.insert(owner_key, transaction_fee);
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Ok(FeesResponseBody { fees: expected })));
+}
+
+#[test]
+fn fees_for_revoke_approval() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+
+    let (owner_key, owner_secret) = crypto::gen_keypair();
+    let (spender_key, _) = crypto::gen_keypair();
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_revoke = transaction::Builder::new()
+        .keypair(owner_key, owner_secret)
+        .tx_revoke_approval()
+        .spender(spender_key)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_revoke);
+
+    let mut expected = HashMap::new();
+    expected.insert(owner_key, transaction_fee);
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Ok(FeesResponseBody { fees: expected })));
+}
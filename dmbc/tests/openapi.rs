@@ -0,0 +1,165 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+use hyper::status::StatusCode;
+use serde_json::Value;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::openapi::{self, openapi_path};
+
+/// Reads every `.rs` file under the directory of the crate.
+fn sources(dir: &str) -> Vec<String> {
+    fn walk(dir: &Path, out: &mut Vec<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                walk(&path, out);
+            } else if path.extension().map_or(false, |ext| ext == "rs") {
+                let mut source = String::new();
+                File::open(&path)
+                    .unwrap()
+                    .read_to_string(&mut source)
+                    .unwrap();
+                out.push(source);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(&Path::new(env!("CARGO_MANIFEST_DIR")).join(dir), &mut out);
+    out
+}
+
+/// The string literal `source` starts with, after whitespace.
+fn string_literal(source: &str) -> Option<&str> {
+    let source = source.trim_left();
+    if !source.starts_with('"') {
+        return None;
+    }
+    source[1..].find('"').map(|end| &source[1..end + 1])
+}
+
+/// Methods and paths of the routes the handlers wire.
+fn wired_routes() -> Vec<(String, String)> {
+    let mut routes = Vec::new();
+    for source in sources("src/currency/api") {
+        for (start, _) in source.match_indices("router.") {
+            let call = &source[start + "router.".len()..];
+            let method: String = call.chars().take_while(|c| c.is_alphabetic()).collect();
+            if method == "options" || !call[method.len()..].starts_with('(') {
+                continue;
+            }
+            if let Some(path) = string_literal(&call[method.len() + 1..]) {
+                routes.push((method, path.to_string()));
+            }
+        }
+    }
+    routes
+}
+
+/// Names of the `message!` transaction types.
+fn message_types() -> Vec<String> {
+    let mut names = Vec::new();
+    for source in sources("src/currency/transactions") {
+        for (start, _) in source.match_indices("message!") {
+            let name = source[start..]
+                .lines()
+                .map(|line| line.trim_left())
+                .find(|line| line.starts_with("struct "))
+                .map(|line| {
+                    line["struct ".len()..]
+                        .chars()
+                        .take_while(|c| c.is_alphanumeric())
+                        .collect::<String>()
+                });
+            names.extend(name);
+        }
+    }
+    names
+}
+
+fn collect_refs(value: &Value, refs: &mut Vec<String>) {
+    match *value {
+        Value::Object(ref map) => {
+            if let Some(&Value::String(ref reference)) = map.get("$ref") {
+                refs.push(reference.clone());
+            }
+            map.values().for_each(|v| collect_refs(v, refs));
+        }
+        Value::Array(ref values) => values.iter().for_each(|v| collect_refs(v, refs)),
+        _ => (),
+    }
+}
+
+#[test]
+fn openapi_served() {
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let (status, response): (StatusCode, Value) = api.get_with_status("/v1/openapi.json");
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, openapi::document());
+    assert_eq!(response["openapi"], openapi::OPENAPI_VERSION);
+}
+
+#[test]
+fn openapi_describes_every_route() {
+    let document = openapi::document();
+    let routes = wired_routes();
+    assert!(!routes.is_empty());
+
+    let missing: Vec<String> = routes
+        .iter()
+        .filter(|&&(ref method, ref path)| {
+            document["paths"][openapi_path(path)][method.as_str()].is_null()
+        })
+        .map(|&(ref method, ref path)| format!("{} {}", method.to_uppercase(), path))
+        .collect();
+    assert!(missing.is_empty(), "routes without a spec: {:?}", missing);
+}
+
+#[test]
+fn openapi_describes_every_transaction() {
+    let document = openapi::document();
+    let schemas = &document["components"]["schemas"];
+    let messages = message_types();
+    assert!(!messages.is_empty());
+
+    let missing: Vec<&String> = messages
+        .iter()
+        .filter(|name| schemas[name.as_str()].is_null())
+        .collect();
+    assert!(missing.is_empty(), "transactions without a schema: {:?}", missing);
+    assert_eq!(
+        schemas["Transaction"]["oneOf"].as_array().unwrap().len(),
+        messages.len()
+    );
+}
+
+#[test]
+fn openapi_references_resolve() {
+    let document = openapi::document();
+    let mut refs = Vec::new();
+    collect_refs(&document, &mut refs);
+
+    let dangling: Vec<&String> = refs
+        .iter()
+        .filter(|reference| {
+            let name = reference.trim_left_matches("#/components/schemas/");
+            document["components"]["schemas"][name].is_null()
+        })
+        .collect();
+    assert!(dangling.is_empty(), "unresolved references: {:?}", dangling);
+}
@@ -16,8 +16,9 @@ use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
 
 use dmbc::currency::configuration::{Configuration, TransactionFees};
 use dmbc::currency::transactions::builders::transaction;
-use dmbc::currency::assets::{MetaAsset, AssetBundle, AssetInfo};
+use dmbc::currency::assets::{MetaAsset, AssetBundle, AssetId, AssetInfo, Supply};
 use dmbc::currency::error::Error;
+use dmbc::currency::api::asset::AssetSupplyResponse;
 use dmbc::currency::api::error::ApiError;
 use dmbc::currency::api::transaction::TransactionResponse;
 use dmbc::currency::wallet::Wallet;
@@ -482,3 +483,266 @@ fn add_assets_transaction_too_large() {
     let (_, tx_status) = api.get_tx_status(&tx_add_assets);
     assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
 }
+
+#[test]
+fn add_assets_supply_cap() {
+    let fixed = 10;
+    let meta_data = "limited";
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_public_key, creator_secret_key) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_public_key, Wallet::new(100_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap());
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key.clone())
+        .tx_add_assets()
+        .add_asset(meta_data, 7, fees.clone())
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let asset_id = AssetId::from_data(meta_data, &creator_public_key);
+    let tx_set_supply = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key.clone())
+        .tx_set_asset_supply()
+        .asset_id(asset_id)
+        .max_supply(10)
+        .build();
+
+    api.post_tx(&tx_set_supply);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_set_supply);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (status, response): (StatusCode, AssetSupplyResponse) =
+        api.get_with_status(&format!("/v1/assets/{}/supply", asset_id.to_string()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Some(Supply::new(10, false, 7))));
+
+    // Minting past the cap is rejected.
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key.clone())
+        .tx_add_assets()
+        .add_asset(meta_data, 4, fees.clone())
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Err(Error::SupplyCapExceeded)));
+
+    // Deleted units don't free up the cap.
+    let tx_delete_assets = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key.clone())
+        .tx_del_assets()
+        .add_asset_value(AssetBundle::new(asset_id, 7))
+        .seed(3)
+        .build();
+
+    api.post_tx(&tx_delete_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_delete_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key)
+        .tx_add_assets()
+        .add_asset(meta_data, 4, fees)
+        .seed(4)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Err(Error::SupplyCapExceeded)));
+
+    let assets = api.get_wallet_assets(&creator_public_key);
+    assert!(assets.is_empty());
+}
+
+#[test]
+fn add_assets_sealed() {
+    let fixed = 10;
+    let meta_data = "sealed";
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_public_key, creator_secret_key) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_public_key, Wallet::new(100_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap());
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key.clone())
+        .tx_add_assets()
+        .add_asset(meta_data, 5, fees.clone())
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let asset_id = AssetId::from_data(meta_data, &creator_public_key);
+    let tx_set_supply = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key.clone())
+        .tx_set_asset_supply()
+        .asset_id(asset_id)
+        .sealed(true)
+        .build();
+
+    api.post_tx(&tx_set_supply);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_set_supply);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key)
+        .tx_add_assets()
+        .add_asset(meta_data, 1, fees)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Err(Error::AssetSealed)));
+
+    let assets = api.get_wallet_assets(&creator_public_key)
+        .iter()
+        .map(|a| a.into())
+        .collect::<Vec<AssetBundle>>();
+    assert_eq!(assets, vec![AssetBundle::new(asset_id, 5)]);
+}
+
+#[test]
+fn set_asset_supply_once() {
+    let fixed = 10;
+    let meta_data = "asset";
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_public_key, creator_secret_key) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset(meta_data, 5, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_public_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_public_key, Wallet::new(100_000, vec![]))
+        .add_asset_to_wallet(&creator_public_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    // The cap can't be below the units already minted.
+    let tx_set_supply = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key.clone())
+        .tx_set_asset_supply()
+        .asset_id(asset.id())
+        .max_supply(4)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_set_supply);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_set_supply);
+    assert_eq!(tx_status, Ok(Err(Error::SupplyCapExceeded)));
+
+    let tx_set_supply = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key.clone())
+        .tx_set_asset_supply()
+        .asset_id(asset.id())
+        .max_supply(10)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_set_supply);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_set_supply);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (status, response): (StatusCode, AssetSupplyResponse) =
+        api.get_with_status(&format!("/v1/assets/{}/supply", asset.id().to_string()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Some(Supply::new(10, false, 5))));
+
+    // Limits can't be changed once set.
+    let tx_set_supply = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key)
+        .tx_set_asset_supply()
+        .asset_id(asset.id())
+        .max_supply(20)
+        .seed(3)
+        .build();
+
+    api.post_tx(&tx_set_supply);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_set_supply);
+    assert_eq!(tx_status, Ok(Err(Error::InvalidAssetInfo)));
+}
+
+#[test]
+fn set_asset_supply_not_creator() {
+    let fixed = 10;
+    let meta_data = "asset";
+
+    let (creator_public_key, creator_secret_key) = crypto::gen_keypair();
+    let (other_public_key, other_secret_key) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset(meta_data, 5, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_public_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .add_asset_to_wallet(&creator_public_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_set_supply = transaction::Builder::new()
+        .keypair(other_public_key, other_secret_key)
+        .tx_set_asset_supply()
+        .asset_id(asset.id())
+        .sealed(true)
+        .build();
+
+    api.post_tx(&tx_set_supply);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_set_supply);
+    assert_eq!(tx_status, Ok(Err(Error::NotAssetCreator)));
+
+    // A policy must limit the supply.
+    let tx_set_supply = transaction::Builder::new()
+        .keypair(creator_public_key, creator_secret_key)
+        .tx_set_asset_supply()
+        .asset_id(asset.id())
+        .build();
+
+    let (status, response) = api.post_tx(&tx_set_supply);
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+}
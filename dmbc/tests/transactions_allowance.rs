@@ -0,0 +1,378 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::assets::AssetBundle;
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::wallet::{AllowanceInfo, WalletAllowancesResponse,
+                                  WalletAllowancesResponseBody};
+use dmbc::currency::error::Error;
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn transfer_from() {
+    let fixed = 10;
+    let transaction_fee = 100;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let units = 5;
+    let balance = 10_000;
+    let allowed = 1_000;
+    let amount = 400;
+
+    let (owner_key, owner_secret) = crypto::gen_keypair();
+    let (spender_key, spender_secret) = crypto::gen_keypair();
+    let (creator_key, _) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("asset", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&owner_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&spender_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&owner_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_approve = transaction::Builder::new()
+        .keypair(owner_key, owner_secret)
+        .tx_approve()
+        .spender(spender_key)
+        .amount(allowed)
+        .add_asset_value(AssetBundle::new(asset.id(), 3))
+        .build();
+
+    let tx_hash = tx_approve.hash();
+
+    let (status, response) = api.post_tx(&tx_approve);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_approve);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_transfer_from = transaction::Builder::new()
+        .keypair(spender_key, spender_secret)
+        .tx_transfer_from()
+        .owner(owner_key)
+        .recipient(recipient_key)
+        .amount(amount)
+        .add_asset_value(AssetBundle::new(asset.id(), 2))
+        .build();
+
+    api.post_tx(&tx_transfer_from);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_from);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The owner pays for the approval. Fees of the transfer are paid by the
+    // spender and do not count towards the allowance.
+    let owner_wallet = api.get_wallet(&owner_key);
+    let spender_wallet = api.get_wallet(&spender_key);
+    let recipient_wallet = api.get_wallet(&recipient_key);
+    let recipient_assets = api.get_wallet_assets(&recipient_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(owner_wallet.balance, balance - transaction_fee - amount);
+    assert_eq!(spender_wallet.balance, balance - transaction_fee - 2 * fixed);
+    assert_eq!(recipient_wallet.balance, amount);
+    assert_eq!(recipient_assets, vec![AssetBundle::new(asset.id(), 2)]);
+
+    let (status, response): (StatusCode, WalletAllowancesResponse) =
+        api.get_with_status(&format!("/v1/wallets/{}/allowances", owner_key.to_string()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        response,
+        Ok(WalletAllowancesResponseBody {
+            total: 1,
+            count: 1,
+            allowances: vec![AllowanceInfo {
+                spender: spender_key,
+                amount: allowed - amount,
+                assets: vec![AssetBundle::new(asset.id(), 1)],
+                expires_at: 0,
+            }],
+        })
+    );
+}
+
+#[test]
+fn transfer_from_exceeds_allowance() {
+    let transaction_fee = 100;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 10_000;
+
+    let (owner_key, owner_secret) = crypto::gen_keypair();
+    let (spender_key, spender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&owner_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_approve = transaction::Builder::new()
+        .keypair(owner_key, owner_secret)
+        .tx_approve()
+        .spender(spender_key)
+        .amount(500)
+        .build();
+
+    api.post_tx(&tx_approve);
+    testkit.create_block();
+
+    let tx_transfer_from = transaction::Builder::new()
+        .keypair(spender_key, spender_secret)
+        .tx_transfer_from()
+        .owner(owner_key)
+        .recipient(recipient_key)
+        .amount(501)
+        .build();
+
+    api.post_tx(&tx_transfer_from);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_from);
+    assert_eq!(tx_status, Ok(Err(Error::AllowanceExceeded)));
+
+    // Only the approval is charged to the owner.
+    let owner_wallet = api.get_wallet(&owner_key);
+    let recipient_wallet = api.get_wallet(&recipient_key);
+    assert_eq!(owner_wallet.balance, balance - transaction_fee);
+    assert_eq!(recipient_wallet.balance, 0);
+}
+
+#[test]
+fn transfer_from_insufficient_funds() {
+    let transaction_fee = 100;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 10_000;
+
+    let (owner_key, owner_secret) = crypto::gen_keypair();
+    let (spender_key, spender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&owner_key, Wallet::new(200, vec![]))
+        .add_wallet_value(&spender_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_approve = transaction::Builder::new()
+        .keypair(owner_key, owner_secret)
+        .tx_approve()
+        .spender(spender_key)
+        .amount(500)
+        .build();
+
+    api.post_tx(&tx_approve);
+    testkit.create_block();
+
+    let tx_transfer_from = transaction::Builder::new()
+        .keypair(spender_key, spender_secret)
+        .tx_transfer_from()
+        .owner(owner_key)
+        .recipient(recipient_key)
+        .amount(300)
+        .build();
+
+    api.post_tx(&tx_transfer_from);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_from);
+    assert_eq!(tx_status, Ok(Err(Error::InsufficientFunds)));
+
+    // Fees are not collected when the transfer can not go through.
+    let owner_wallet = api.get_wallet(&owner_key);
+    let spender_wallet = api.get_wallet(&spender_key);
+    assert_eq!(owner_wallet.balance, 200 - transaction_fee);
+    assert_eq!(spender_wallet.balance, balance);
+}
+
+#[test]
+fn transfer_from_nothing() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (owner_key, _) = crypto::gen_keypair();
+    let (spender_key, spender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer_from = transaction::Builder::new()
+        .keypair(spender_key, spender_secret)
+        .tx_transfer_from()
+        .owner(owner_key)
+        .recipient(recipient_key)
+        .build();
+
+    let (status, response) = api.post_tx(&tx_transfer_from);
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+}
+
+#[test]
+fn transfer_from_revoked() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 10_000;
+
+    let (owner_key, owner_secret) = crypto::gen_keypair();
+    let (spender_key, spender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&owner_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_approve = transaction::Builder::new()
+        .keypair(owner_key, owner_secret.clone())
+        .tx_approve()
+        .spender(spender_key)
+        .amount(500)
+        .build();
+
+    api.post_tx(&tx_approve);
+    testkit.create_block();
+
+    let tx_revoke = transaction::Builder::new()
+        .keypair(owner_key, owner_secret.clone())
+        .tx_revoke_approval()
+        .spender(spender_key)
+        .build();
+
+    api.post_tx(&tx_revoke);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_revoke);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_transfer_from = transaction::Builder::new()
+        .keypair(spender_key, spender_secret)
+        .tx_transfer_from()
+        .owner(owner_key)
+        .recipient(recipient_key)
+        .amount(100)
+        .build();
+
+    api.post_tx(&tx_transfer_from);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_from);
+    assert_eq!(tx_status, Ok(Err(Error::AllowanceNotFound)));
+
+    // Revoking twice fails.
+    let tx_revoke = transaction::Builder::new()
+        .keypair(owner_key, owner_secret)
+        .tx_revoke_approval()
+        .spender(spender_key)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_revoke);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_revoke);
+    assert_eq!(tx_status, Ok(Err(Error::AllowanceNotFound)));
+
+    let (_, response): (StatusCode, WalletAllowancesResponse) =
+        api.get_with_status(&format!("/v1/wallets/{}/allowances", owner_key.to_string()));
+    assert_eq!(response.unwrap().total, 0);
+}
+
+#[test]
+fn transfer_from_expired() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 10_000;
+
+    let (owner_key, owner_secret) = crypto::gen_keypair();
+    let (spender_key, spender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&owner_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    // The allowance is committed at height 2 and can not be used from height 3.
+    let tx_approve = transaction::Builder::new()
+        .keypair(owner_key, owner_secret)
+        .tx_approve()
+        .spender(spender_key)
+        .amount(500)
+        .expires_at(3)
+        .build();
+
+    api.post_tx(&tx_approve);
+    testkit.create_block();
+
+    let tx_transfer_from = transaction::Builder::new()
+        .keypair(spender_key, spender_secret)
+        .tx_transfer_from()
+        .owner(owner_key)
+        .recipient(recipient_key)
+        .amount(100)
+        .build();
+
+    api.post_tx(&tx_transfer_from);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_from);
+    assert_eq!(tx_status, Ok(Err(Error::AllowanceExpired)));
+
+    let owner_wallet = api.get_wallet(&owner_key);
+    assert_eq!(owner_wallet.balance, balance);
+}
+
+#[test]
+fn approve_self() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (owner_key, owner_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_approve = transaction::Builder::new()
+        .keypair(owner_key, owner_secret)
+        .tx_approve()
+        .spender(owner_key)
+        .amount(500)
+        .build();
+
+    let (status, response) = api.post_tx(&tx_approve);
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_approve);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+}
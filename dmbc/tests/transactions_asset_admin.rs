@@ -0,0 +1,409 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::collections::HashMap;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::asset::{AssetHistoryResponse, AssetResponse};
+use dmbc::currency::api::fees::FeesResponseBody;
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::error::Error;
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn update_asset_fees() {
+    let transaction_fee = 100;
+    let units = 2;
+    let old_fixed = 10;
+    let new_fixed = 30;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (holder_key, holder_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let old_fees = dmbc_testkit::asset_fees(old_fixed, "0.0".parse().unwrap());
+    let new_fees = dmbc_testkit::asset_fees(new_fixed, "0.0".parse().unwrap());
+    let (asset, info) = dmbc_testkit::create_asset("asset", units, old_fees.clone(), &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_asset_to_wallet(&holder_key, (asset.clone(), info.clone()))
+        .create();
+    let api = testkit.api();
+
+    let tx_update = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_update_asset_fees()
+        .asset_id(asset.id())
+        .fees(new_fees.clone())
+        .seed(1)
+        .build();
+    let tx_hash = tx_update.hash();
+
+    let (status, response) = api.post_tx(&tx_update);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_update);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", asset.id().to_string()));
+    let updated = response.unwrap().unwrap();
+    assert_eq!(updated.fees(), new_fees);
+    assert_eq!(updated.creator(), &creator_key);
+    assert_eq!(updated.amount(), info.amount());
+
+    let (status, response): (StatusCode, AssetHistoryResponse) =
+        api.get_with_status(&format!("/v1/assets/{}/history", asset.id().to_string()));
+    assert_eq!(status, StatusCode::Ok);
+
+    let history = response.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].tx_hash(), &tx_hash);
+    assert_eq!(history[0].height(), 2);
+    assert_eq!(history[0].old_fees(), old_fees);
+    assert_eq!(history[0].new_fees(), new_fees);
+
+    // third party fees are taken from the updated asset info
+    let tx_transfer = transaction::Builder::new()
+        .keypair(holder_key, holder_secret)
+        .tx_transfer()
+        .add_asset_value(asset)
+        .recipient(recipient_key)
+        .seed(2)
+        .build();
+
+    let (status, response) = api.post_fee(&tx_transfer);
+
+    let mut expected = HashMap::new();
+    expected.insert(holder_key, transaction_fee + units * new_fixed);
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Ok(FeesResponseBody { fees: expected })));
+}
+
+#[test]
+fn update_asset_fees_not_creator() {
+    let (creator_key, _) = crypto::gen_keypair();
+    let (other_key, other_secret) = crypto::gen_keypair();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+    let (asset, info) = dmbc_testkit::create_asset("asset", 1, fees, &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .add_asset_to_wallet(&creator_key, (asset.clone(), info.clone()))
+        .create();
+    let api = testkit.api();
+
+    let tx_update = transaction::Builder::new()
+        .keypair(other_key, other_secret)
+        .tx_update_asset_fees()
+        .asset_id(asset.id())
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .build();
+
+    api.post_tx(&tx_update);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_update);
+    assert_eq!(tx_status, Ok(Err(Error::NotAssetCreator)));
+
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", asset.id().to_string()));
+    assert_eq!(response, Ok(Some(info)));
+
+    let (_, response): (StatusCode, AssetHistoryResponse) =
+        api.get_with_status(&format!("/v1/assets/{}/history", asset.id().to_string()));
+    assert_eq!(response, Ok(vec![]));
+}
+
+#[test]
+fn update_asset_fees_asset_not_found() {
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+    let (asset, _) = dmbc_testkit::create_asset("asset", 1, fees.clone(), &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let tx_update = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_update_asset_fees()
+        .asset_id(asset.id())
+        .fees(fees)
+        .build();
+
+    api.post_tx(&tx_update);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_update);
+    assert_eq!(tx_status, Ok(Err(Error::AssetNotFound)));
+}
+
+#[test]
+fn transfer_asset_creator() {
+    let transaction_fee = 100;
+    let fixed = 10;
+    let balance = 1_000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (new_creator_key, new_creator_secret) = crypto::gen_keypair();
+    let (holder_key, holder_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let fees = dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap());
+    let (asset, info) = dmbc_testkit::create_asset("asset", 1, fees.clone(), &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&holder_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&holder_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer_creator = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_transfer_asset_creator()
+        .asset_id(asset.id())
+        .new_creator(new_creator_key)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_transfer_creator);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_creator);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", asset.id().to_string()));
+    assert_eq!(response.unwrap().unwrap().creator(), &new_creator_key);
+
+    // the previous creator has lost the role
+    let tx_update = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_update_asset_fees()
+        .asset_id(asset.id())
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .build();
+
+    api.post_tx(&tx_update);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_update);
+    assert_eq!(tx_status, Ok(Err(Error::NotAssetCreator)));
+
+    let new_fees = dmbc_testkit::asset_fees(fixed * 2, "0.0".parse().unwrap());
+    let tx_update = transaction::Builder::new()
+        .keypair(new_creator_key, new_creator_secret)
+        .tx_update_asset_fees()
+        .asset_id(asset.id())
+        .fees(new_fees.clone())
+        .build();
+
+    api.post_tx(&tx_update);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_update);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // third party fees go to the new creator
+    let tx_transfer = transaction::Builder::new()
+        .keypair(holder_key, holder_secret)
+        .tx_transfer()
+        .add_asset_value(asset.clone())
+        .recipient(recipient_key)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let holder = api.get_wallet(&holder_key);
+    assert_eq!(holder.balance, balance - transaction_fee - fixed * 2);
+
+    let new_creator = api.get_wallet(&new_creator_key);
+    assert_eq!(new_creator.balance, fixed * 2);
+
+    let creator = api.get_wallet(&creator_key);
+    assert_eq!(creator.balance, 0);
+
+    let (_, response): (StatusCode, AssetHistoryResponse) =
+        api.get_with_status(&format!("/v1/assets/{}/history", asset.id().to_string()));
+    let history = response.unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].tx_hash(), &tx_transfer_creator.hash());
+    assert_eq!(history[0].old_creator(), &creator_key);
+    assert_eq!(history[0].new_creator(), &new_creator_key);
+    assert_eq!(history[1].old_creator(), &new_creator_key);
+    assert_eq!(history[1].old_fees(), fees);
+    assert_eq!(history[1].new_fees(), new_fees);
+}
+
+#[test]
+fn transfer_asset_creator_to_self() {
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+    let (asset, info) = dmbc_testkit::create_asset("asset", 1, fees, &creator_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .add_asset_to_wallet(&creator_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer_creator = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_transfer_asset_creator()
+        .asset_id(asset.id())
+        .new_creator(creator_key)
+        .build();
+
+    let (status, response) = api.post_tx(&tx_transfer_creator);
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+}
+
+#[test]
+fn transfer_asset_creator_old_creator_can_not_add() {
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (new_creator_key, _) = crypto::gen_keypair();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+    let (asset, info) = dmbc_testkit::create_asset("asset", 1, fees.clone(), &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .add_asset_to_wallet(&creator_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer_creator = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_transfer_asset_creator()
+        .asset_id(asset.id())
+        .new_creator(new_creator_key)
+        .build();
+
+    api.post_tx(&tx_transfer_creator);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_creator);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_assets()
+        .add_asset("asset", 1, fees.clone())
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Err(Error::NotAssetCreator)));
+
+    // Deleting every unit does not release the creator role.
+    let tx_delete_assets = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_del_assets()
+        .add_asset_value(asset.clone())
+        .build();
+
+    api.post_tx(&tx_delete_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_delete_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", asset.id().to_string()));
+    let info = response.unwrap().unwrap();
+    assert_eq!(info.amount(), 0);
+    assert_eq!(info.creator(), &new_creator_key);
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_add_assets()
+        .add_asset("asset", 1, fees)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Err(Error::NotAssetCreator)));
+}
+
+#[test]
+fn transfer_asset_creator_new_creator_can_add() {
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (new_creator_key, new_creator_secret) = crypto::gen_keypair();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+    let (asset, info) = dmbc_testkit::create_asset("asset", 1, fees.clone(), &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .add_asset_to_wallet(&creator_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer_creator = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_transfer_asset_creator()
+        .asset_id(asset.id())
+        .new_creator(new_creator_key)
+        .build();
+
+    api.post_tx(&tx_transfer_creator);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_creator);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The new creator mints more units of the same asset.
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(new_creator_key, new_creator_secret)
+        .tx_add_assets()
+        .add_asset("asset", 2, fees)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", asset.id().to_string()));
+    let info = response.unwrap().unwrap();
+    assert_eq!(info.amount(), 3);
+    assert_eq!(info.creator(), &new_creator_key);
+}
@@ -0,0 +1,144 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::env;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use exonum::encoding::serialize::encode_hex;
+use exonum::messages::Message;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::transaction::{TransactionResponse, TxBatchPostResponse};
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+
+const BATCH_MAX_SIZE: usize = 3;
+
+fn limit_batch_size() {
+    env::set_var("API_BATCH_MAX_SIZE", BATCH_MAX_SIZE.to_string());
+}
+
+#[test]
+fn batch_transactions() {
+    limit_batch_size();
+
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 10);
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+    let (_, wrong_secret_key) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(public_key, secret_key.clone())
+        .tx_add_assets()
+        .add_asset("asset", 5, dmbc_testkit::asset_fees(10, "0.0".parse().unwrap()))
+        .seed(1)
+        .build();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key.clone())
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .seed(2)
+        .build();
+
+    let tx_transfer_raw = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .amount(50)
+        .recipient(recipient_key)
+        .seed(4)
+        .build();
+
+    let tx_bad_signature = transaction::Builder::new()
+        .keypair(public_key, wrong_secret_key)
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .seed(3)
+        .build();
+
+    let body = serde_json::to_string(&vec![
+        serde_json::to_value(&tx_add_assets).unwrap(),
+        serde_json::to_value(&tx_transfer).unwrap(),
+        serde_json::to_value(&tx_bad_signature).unwrap(),
+        serde_json::Value::String(encode_hex(tx_transfer_raw.raw())),
+    ]).unwrap();
+
+    let (status, response): (StatusCode, TxBatchPostResponse) =
+        api.post_raw_with_status("/v1/transactions/batch", &body);
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(
+        response,
+        Ok(vec![
+            Ok(Ok(TransactionResponse { tx_hash: tx_add_assets.hash() })),
+            Ok(Ok(TransactionResponse { tx_hash: tx_transfer.hash() })),
+            Err(ApiError::TransactionSignatureInvalid),
+            Ok(Ok(TransactionResponse { tx_hash: tx_transfer_raw.hash() })),
+        ])
+    );
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+    let (_, tx_status) = api.get_tx_status(&tx_bad_signature);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_raw);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    assert_eq!(api.get_wallet(&recipient_key).balance, 150);
+}
+
+#[test]
+fn batch_transactions_invalid() {
+    limit_batch_size();
+
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    // Items that aren't transactions are reported in place.
+    let body = r#"[{"foo": "bar"}, {"service_id": 2, "message_id": 65535}, "not a hex", "00"]"#;
+    let (status, response): (StatusCode, TxBatchPostResponse) =
+        api.post_raw_with_status("/v1/transactions/batch", body);
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(
+        response,
+        Ok(vec![
+            Err(ApiError::TransactionMalformed),
+            Err(ApiError::TransactionTypeUnknown),
+            Err(ApiError::TransactionHexInvalid),
+            Err(ApiError::TransactionLengthInvalid),
+        ])
+    );
+
+    let body = serde_json::to_string(&vec![serde_json::Value::Null; BATCH_MAX_SIZE + 1]).unwrap();
+    let (status, response): (StatusCode, TxBatchPostResponse) =
+        api.post_raw_with_status("/v1/transactions/batch", &body);
+    assert_eq!(status, StatusCode::PayloadTooLarge);
+    assert_eq!(response, Err(ApiError::BatchTooLarge));
+
+    let (status, response): (StatusCode, TxBatchPostResponse) =
+        api.post_raw_with_status("/v1/transactions/batch", r#"{"foo": "bar"}"#);
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::IncorrectRequest));
+}
@@ -0,0 +1,308 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::assets::{TradeAsset, AssetBundle};
+use dmbc::currency::error::Error;
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::wallet::Wallet;
+use dmbc::currency::transactions::components::FeeStrategy;
+
+#[test]
+fn cart_checkout_two_sellers() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, transaction_fee, 0);
+    let fixed = 10;
+    let balance = 100_000;
+    let units1 = 3;
+    let units2 = 2;
+    let price1 = 500;
+    let price2 = 300;
+
+    let (seller1_public_key, seller1_secret_key) = crypto::gen_keypair();
+    let (seller2_public_key, seller2_secret_key) = crypto::gen_keypair();
+    let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+
+    let (asset1, info1) = dmbc_testkit::create_asset("asset1", units1, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &seller1_public_key);
+    let (asset2, info2) = dmbc_testkit::create_asset("asset2", units2, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &seller2_public_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&buyer_public_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&seller1_public_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&seller2_public_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&seller1_public_key, (asset1.clone(), info1))
+        .add_asset_to_wallet(&seller2_public_key, (asset2.clone(), info2))
+        .create();
+    let api = testkit.api();
+
+    let genesis_balance = api.get_wallet(&dmbc_testkit::default_genesis_key()).balance;
+
+    let tx_cart = transaction::Builder::new()
+        .keypair(buyer_public_key, buyer_secret_key)
+        .tx_cart_checkout()
+        .add_offer(
+            seller1_public_key,
+            seller1_secret_key,
+            vec![TradeAsset::from_bundle(asset1.clone(), price1)],
+            FeeStrategy::Recipient,
+        )
+        .add_offer(
+            seller2_public_key,
+            seller2_secret_key,
+            vec![TradeAsset::from_bundle(asset2.clone(), price2)],
+            FeeStrategy::Sender,
+        )
+        .seed(1)
+        .build();
+
+    let tx_hash = tx_cart.hash();
+
+    let (status, response) = api.post_tx(&tx_cart);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_cart);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let seller1_wallet = api.get_wallet(&seller1_public_key);
+    let seller2_wallet = api.get_wallet(&seller2_public_key);
+    let buyer_wallet = api.get_wallet(&buyer_public_key);
+    let genesis_wallet = api.get_wallet(&dmbc_testkit::default_genesis_key());
+
+    let expected_seller1_balance = balance + units1 * price1 + units1 * fixed;
+    let expected_seller2_balance = balance + units2 * price2 - transaction_fee;
+    let expected_buyer_balance = balance - units1 * price1 - units1 * fixed - transaction_fee
+        - units2 * price2;
+    let expected_genesis_balance = genesis_balance + 2 * transaction_fee;
+
+    assert_eq!(seller1_wallet.balance, expected_seller1_balance);
+    assert_eq!(seller2_wallet.balance, expected_seller2_balance);
+    assert_eq!(buyer_wallet.balance, expected_buyer_balance);
+    assert_eq!(genesis_wallet.balance, expected_genesis_balance);
+
+    let buyer_assets = api.get_wallet_assets(&buyer_public_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert!(seller1_wallet.assets_count == 0);
+    assert!(seller2_wallet.assets_count == 0);
+    assert_eq!(buyer_assets, vec![asset1, asset2]);
+}
+
+#[test]
+fn cart_checkout_is_atomic() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, transaction_fee, 0);
+    let fixed = 10;
+    let balance = 100_000;
+    let units = 3;
+    let price = 500;
+
+    let (seller1_public_key, seller1_secret_key) = crypto::gen_keypair();
+    let (seller2_public_key, seller2_secret_key) = crypto::gen_keypair();
+    let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+
+    let (asset1, info1) = dmbc_testkit::create_asset("asset1", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &seller1_public_key);
+    let (asset2, info2) = dmbc_testkit::create_asset("asset2", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &seller2_public_key);
+
+    // The second seller does not own the asset being sold.
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&buyer_public_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&seller1_public_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&seller2_public_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&seller1_public_key, (asset1.clone(), info1))
+        .add_asset_info(&asset2.id(), info2)
+        .create();
+    let api = testkit.api();
+
+    let genesis_balance = api.get_wallet(&dmbc_testkit::default_genesis_key()).balance;
+
+    let tx_cart = transaction::Builder::new()
+        .keypair(buyer_public_key, buyer_secret_key)
+        .tx_cart_checkout()
+        .add_offer(
+            seller1_public_key,
+            seller1_secret_key,
+            vec![TradeAsset::from_bundle(asset1.clone(), price)],
+            FeeStrategy::Recipient,
+        )
+        .add_offer(
+            seller2_public_key,
+            seller2_secret_key,
+            vec![TradeAsset::from_bundle(asset2.clone(), price)],
+            FeeStrategy::Recipient,
+        )
+        .seed(1)
+        .build();
+
+    let tx_hash = tx_cart.hash();
+
+    let (status, response) = api.post_tx(&tx_cart);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_cart);
+    assert_eq!(tx_status, Ok(Err(Error::InsufficientAssets)));
+
+    let seller1_wallet = api.get_wallet(&seller1_public_key);
+    let seller2_wallet = api.get_wallet(&seller2_public_key);
+    let buyer_wallet = api.get_wallet(&buyer_public_key);
+    let genesis_wallet = api.get_wallet(&dmbc_testkit::default_genesis_key());
+
+    // Only the blockchain fees are collected.
+    assert_eq!(seller1_wallet.balance, balance);
+    assert_eq!(seller2_wallet.balance, balance);
+    assert_eq!(buyer_wallet.balance, balance - 2 * transaction_fee);
+    assert_eq!(genesis_wallet.balance, genesis_balance + 2 * transaction_fee);
+
+    let seller1_assets = api.get_wallet_assets(&seller1_public_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(seller1_assets, vec![asset1]);
+    assert!(buyer_wallet.assets_count == 0);
+}
+
+#[test]
+fn cart_checkout_offer_for_other_buyer() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, transaction_fee, 0);
+    let fixed = 10;
+    let balance = 100_000;
+    let units = 3;
+    let price = 500;
+
+    let (seller_public_key, seller_secret_key) = crypto::gen_keypair();
+    let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+    let (other_public_key, other_secret_key) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("asset", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &seller_public_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&buyer_public_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&seller_public_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&seller_public_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    // Offer is made out to a different buyer.
+    let item = transaction::Builder::new()
+        .keypair(other_public_key, other_secret_key)
+        .tx_cart_checkout()
+        .add_offer(
+            seller_public_key,
+            seller_secret_key,
+            vec![TradeAsset::from_bundle(asset.clone(), price)],
+            FeeStrategy::Recipient,
+        )
+        .build()
+        .items()
+        .remove(0);
+
+    let tx_cart = transaction::Builder::new()
+        .keypair(buyer_public_key, buyer_secret_key)
+        .tx_cart_checkout()
+        .add_item_value(item)
+        .seed(1)
+        .build();
+
+    let (status, response) = api.post_tx(&tx_cart);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_cart);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+
+    let seller_wallet = api.get_wallet(&seller_public_key);
+    let buyer_wallet = api.get_wallet(&buyer_public_key);
+
+    assert_eq!(seller_wallet.balance, balance);
+    assert_eq!(buyer_wallet.balance, balance);
+    assert!(seller_wallet.assets_count > 0);
+    assert!(buyer_wallet.assets_count == 0);
+}
+
+#[test]
+fn cart_checkout_offer_replayed() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, transaction_fee, 0);
+    let fixed = 10;
+    let balance = 100_000;
+    let units = 6;
+    let price = 500;
+
+    let (seller_public_key, seller_secret_key) = crypto::gen_keypair();
+    let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("asset", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &seller_public_key);
+    let offered = AssetBundle::new(asset.id(), 3);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&buyer_public_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&seller_public_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&seller_public_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_cart = transaction::Builder::new()
+        .keypair(buyer_public_key, buyer_secret_key.clone())
+        .tx_cart_checkout()
+        .add_offer(
+            seller_public_key,
+            seller_secret_key,
+            vec![TradeAsset::from_bundle(offered.clone(), price)],
+            FeeStrategy::Recipient,
+        )
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_cart);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_cart);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The same signed offer in a new cart must not sell again.
+    let tx_replay = transaction::Builder::new()
+        .keypair(buyer_public_key, buyer_secret_key)
+        .tx_cart_checkout()
+        .add_item_value(tx_cart.items().remove(0))
+        .seed(2)
+        .build();
+
+    let buyer_balance = api.get_wallet(&buyer_public_key).balance;
+
+    api.post_tx(&tx_replay);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_replay);
+    assert_eq!(tx_status, Ok(Err(Error::OfferExecuted)));
+
+    let buyer_assets = api.get_wallet_assets(&buyer_public_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    let seller_assets = api.get_wallet_assets(&seller_public_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(api.get_wallet(&buyer_public_key).balance, buyer_balance);
+    assert_eq!(buyer_assets, vec![offered.clone()]);
+    assert_eq!(seller_assets, vec![offered]);
+}
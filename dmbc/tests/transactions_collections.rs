@@ -0,0 +1,334 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use exonum::crypto::Hash;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::asset::AssetResponse;
+use dmbc::currency::api::assets_intern::CollectionIdResponse;
+use dmbc::currency::api::collection::{CollectionAssetsResponse, CollectionResponse,
+                                      CollectionSupplyResponse};
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::assets::{AssetId, Collection, CollectionSupply};
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::error::Error;
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn collection_assets_inherit_fees() {
+    let transaction_fee = 10;
+    let config_fees = TransactionFees::with_default_key(transaction_fee, 0, 0, 0, 0, 0);
+    let balance = 1_000;
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let collection_fees = dmbc_testkit::asset_fees(50, "0.0".parse().unwrap());
+    let own_fees = dmbc_testkit::asset_fees(5, "0.0".parse().unwrap());
+
+    let tx_game = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_create_collection()
+        .name("game")
+        .data("shooter")
+        .fees(collection_fees.clone())
+        .build();
+
+    let tx_hash = tx_game.hash();
+
+    let (status, response) = api.post_tx(&tx_game);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_game);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let game_id = Collection::id_from("game", &creator_key);
+
+    let tx_season = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_create_collection()
+        .parent(game_id)
+        .name("season 1")
+        .fees(collection_fees.clone())
+        .build();
+
+    api.post_tx(&tx_season);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_season);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let season_id = Collection::id_from("season 1", &creator_key);
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_assets()
+        .add_asset("knife", 3, own_fees.clone())
+        .add_asset("gloves", 2, own_fees.clone())
+        .add_asset("badge", 1, own_fees.clone())
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_add_to_collection = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_add_to_collection()
+        .add_asset("knife", season_id, false)
+        .add_asset("gloves", season_id, true)
+        .add_asset("badge", game_id, false)
+        .build();
+
+    api.post_tx(&tx_add_to_collection);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_to_collection);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let creator = api.get_wallet(&creator_key);
+    assert_eq!(creator.balance, balance - 4 * transaction_fee);
+
+    // Members take the fees of their collection unless they set their own.
+    let knife_id = AssetId::from_data("knife", &creator_key);
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", knife_id.to_string()));
+    assert_eq!(response.unwrap().unwrap().fees(), collection_fees);
+
+    let gloves_id = AssetId::from_data("gloves", &creator_key);
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", gloves_id.to_string()));
+    assert_eq!(response.unwrap().unwrap().fees(), own_fees);
+
+    let (status, response): (StatusCode, CollectionResponse) =
+        api.get_with_status(&format!("/v1/collections/{}", season_id.to_hex()));
+    assert_eq!(status, StatusCode::Ok);
+    let season = response.unwrap();
+    assert_eq!(season.parent(), &game_id);
+    assert_eq!(season.name(), "season 1");
+
+    // Nested collections are included in the listing of their parent.
+    let (status, response): (StatusCode, CollectionAssetsResponse) =
+        api.get_with_status(&format!("/v1/collections/{}/assets", game_id.to_hex()));
+    assert_eq!(status, StatusCode::Ok);
+    let body = response.unwrap();
+    assert_eq!(body.total, 3);
+    let mut ids = body.assets.iter().map(|asset| asset.id).collect::<Vec<AssetId>>();
+    ids.sort_by_key(|id| id.to_string());
+    let mut expected = vec![AssetId::from_data("badge", &creator_key), knife_id, gloves_id];
+    expected.sort_by_key(|id| id.to_string());
+    assert_eq!(ids, expected);
+
+    let (status, response): (StatusCode, CollectionAssetsResponse) =
+        api.get_with_status(&format!("/v1/collections/{}/assets", season_id.to_hex()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response.unwrap().total, 2);
+
+    let (status, response): (StatusCode, CollectionSupplyResponse) =
+        api.get_with_status(&format!("/v1/collections/{}/supply", game_id.to_hex()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(CollectionSupply { assets: 3, amount: 6 }));
+
+    let (status, response): (StatusCode, CollectionIdResponse) = api.get_with_status(&format!(
+        "/v1/intern/collections/{}/{}",
+        creator_key.to_hex(),
+        "game"
+    ));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response.unwrap().collections["game"], game_id.to_hex());
+}
+
+#[test]
+fn create_collection_errors() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (other_key, other_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .add_wallet_value(&other_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+
+    let tx_orphan = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_create_collection()
+        .parent(crypto::hash(b"missing"))
+        .name("orphan")
+        .fees(fees.clone())
+        .build();
+
+    let tx_game = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_create_collection()
+        .name("game")
+        .fees(fees.clone())
+        .seed(1)
+        .build();
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(other_key, other_secret.clone())
+        .tx_add_assets()
+        .add_asset("knife", 1, fees.clone())
+        .build();
+
+    api.post_tx(&tx_orphan);
+    api.post_tx(&tx_game);
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_orphan);
+    assert_eq!(tx_status, Ok(Err(Error::CollectionNotFound)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_game);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_game_again = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_create_collection()
+        .name("game")
+        .fees(fees.clone())
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_game_again);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_game_again);
+    assert_eq!(tx_status, Ok(Err(Error::CollectionExists)));
+
+    // Only the creator of a collection can nest collections or place
+    // assets in it.
+    let game_id = Collection::id_from("game", &creator_key);
+
+    let tx_season = transaction::Builder::new()
+        .keypair(other_key, other_secret.clone())
+        .tx_create_collection()
+        .parent(game_id)
+        .name("season 1")
+        .fees(fees.clone())
+        .build();
+
+    let tx_add_to_collection = transaction::Builder::new()
+        .keypair(other_key, other_secret)
+        .tx_add_to_collection()
+        .add_asset("knife", game_id, false)
+        .build();
+
+    api.post_tx(&tx_season);
+    api.post_tx(&tx_add_to_collection);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_season);
+    assert_eq!(tx_status, Ok(Err(Error::NotCollectionCreator)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_to_collection);
+    assert_eq!(tx_status, Ok(Err(Error::NotCollectionCreator)));
+
+    let (status, response): (StatusCode, CollectionResponse) =
+        api.get_with_status(&format!("/v1/collections/{}", Hash::zero().to_hex()));
+    assert_eq!(status, StatusCode::NotFound);
+    assert_eq!(response, Err(ApiError::CollectionNotFound));
+}
+
+#[test]
+fn add_to_collection_errors() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+
+    let tx_game = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_create_collection()
+        .name("game")
+        .fees(fees.clone())
+        .build();
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_assets()
+        .add_asset("knife", 1, fees.clone())
+        .build();
+
+    api.post_tx(&tx_game);
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let game_id = Collection::id_from("game", &creator_key);
+
+    let tx_missing = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_to_collection()
+        .add_asset("gloves", game_id, false)
+        .build();
+
+    let tx_knife = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_to_collection()
+        .add_asset("knife", game_id, false)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_missing);
+    api.post_tx(&tx_knife);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_missing);
+    assert_eq!(tx_status, Ok(Err(Error::AssetNotFound)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_knife);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // An asset belongs to a single collection.
+    let tx_knife_again = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_add_to_collection()
+        .add_asset("knife", game_id, true)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_knife_again);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_knife_again);
+    assert_eq!(tx_status, Ok(Err(Error::InvalidAssetInfo)));
+}
@@ -0,0 +1,278 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::assets::AssetBundle;
+use dmbc::currency::error::Error;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn freeze_and_unfreeze_wallet() {
+    let transaction_fee = 10;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 1_000;
+    let amount = 100;
+
+    let (officer_key, officer_secret) = crypto::gen_keypair();
+    let (sender_key, sender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(
+            Configuration::new(config_fees).with_compliance_officers(vec![officer_key]),
+        )
+        .add_wallet_value(&sender_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_freeze = transaction::Builder::new()
+        .keypair(officer_key, officer_secret.clone())
+        .tx_freeze_wallet()
+        .wallet(sender_key)
+        .reason("court order")
+        .build();
+
+    api.post_tx(&tx_freeze);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_freeze);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret.clone())
+        .tx_transfer()
+        .amount(amount)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Err(Error::WalletFrozen)));
+
+    // Nothing is charged to a frozen wallet.
+    let sender = api.get_wallet(&sender_key);
+    assert_eq!(sender.balance, balance);
+
+    let tx_unfreeze = transaction::Builder::new()
+        .keypair(officer_key, officer_secret)
+        .tx_unfreeze_wallet()
+        .wallet(sender_key)
+        .build();
+
+    api.post_tx(&tx_unfreeze);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_unfreeze);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret)
+        .tx_transfer()
+        .amount(amount)
+        .recipient(recipient_key)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let sender = api.get_wallet(&sender_key);
+    let recipient = api.get_wallet(&recipient_key);
+    assert_eq!(sender.balance, balance - transaction_fee - amount);
+    assert_eq!(recipient.balance, amount);
+}
+
+#[test]
+fn frozen_wallet_can_not_receive() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 1_000;
+
+    let (officer_key, officer_secret) = crypto::gen_keypair();
+    let (sender_key, sender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(
+            Configuration::new(config_fees).with_compliance_officers(vec![officer_key]),
+        )
+        .add_wallet_value(&sender_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_freeze = transaction::Builder::new()
+        .keypair(officer_key, officer_secret)
+        .tx_freeze_wallet()
+        .wallet(recipient_key)
+        .build();
+
+    api.post_tx(&tx_freeze);
+    testkit.create_block();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret)
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Err(Error::WalletFrozen)));
+
+    let recipient = api.get_wallet(&recipient_key);
+    assert_eq!(recipient.balance, 0);
+}
+
+#[test]
+fn freeze_wallet_not_officer() {
+    let (officer_key, _) = crypto::gen_keypair();
+    let (other_key, other_secret) = crypto::gen_keypair();
+    let (wallet_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(
+            Configuration::default().with_compliance_officers(vec![officer_key]),
+        )
+        .create();
+    let api = testkit.api();
+
+    let tx_freeze = transaction::Builder::new()
+        .keypair(other_key, other_secret)
+        .tx_freeze_wallet()
+        .wallet(wallet_key)
+        .build();
+
+    api.post_tx(&tx_freeze);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_freeze);
+    assert_eq!(tx_status, Ok(Err(Error::NotComplianceOfficer)));
+}
+
+#[test]
+fn restricted_asset_requires_allowlisted_holder() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (officer_key, officer_secret) = crypto::gen_keypair();
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (sender_key, sender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+    let registry = crypto::hash("kyc".as_bytes());
+
+    let (asset, info) = dmbc_testkit::create_asset("licensed", 2, dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()), &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(
+            Configuration::new(config_fees).with_compliance_officers(vec![officer_key]),
+        )
+        .add_asset_to_wallet(&sender_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_restrict = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_set_asset_restriction()
+        .asset_id(asset.id())
+        .registry(registry)
+        .build();
+
+    api.post_tx(&tx_restrict);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_restrict);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let bundle = AssetBundle::new(asset.id(), 1);
+    let tx_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret.clone())
+        .tx_transfer()
+        .add_asset_value(bundle.clone())
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Err(Error::HolderNotAllowed)));
+
+    let tx_allow = transaction::Builder::new()
+        .keypair(officer_key, officer_secret)
+        .tx_update_allowlist()
+        .registry(registry)
+        .holder(recipient_key)
+        .allowed(true)
+        .build();
+
+    api.post_tx(&tx_allow);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_allow);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret)
+        .tx_transfer()
+        .add_asset_value(bundle.clone())
+        .recipient(recipient_key)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let recipient_assets = api.get_wallet_assets(&recipient_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(recipient_assets, vec![bundle]);
+}
+
+#[test]
+fn set_asset_restriction_not_creator() {
+    let (creator_key, _) = crypto::gen_keypair();
+    let (other_key, other_secret) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("licensed", 1, dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()), &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .add_asset_to_wallet(&creator_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_restrict = transaction::Builder::new()
+        .keypair(other_key, other_secret)
+        .tx_set_asset_restriction()
+        .asset_id(asset.id())
+        .registry(crypto::hash("kyc".as_bytes()))
+        .build();
+
+    let (status, _) = api.post_tx(&tx_restrict);
+    testkit.create_block();
+    assert_eq!(status, StatusCode::Created);
+
+    let (_, tx_status) = api.get_tx_status(&tx_restrict);
+    assert_eq!(tx_status, Ok(Err(Error::NotAssetCreator)));
+}
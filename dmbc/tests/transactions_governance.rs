@@ -0,0 +1,170 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKit, DmbcTestKitApi};
+
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::transaction::{DisabledTransactionsResponse, TransactionResponse,
+                                        TxDisabledResponse};
+use dmbc::currency::api::v2::{Envelope, ErrorBody};
+use dmbc::currency::configuration::{Configuration, DisabledTransaction, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::transactions::{EXCHANGE_INTERMEDIARY_ID, TRANSFER_ID};
+use dmbc::currency::error::Error;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn disabled_transaction_refused_by_api() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 1_000;
+
+    let (sender_key, sender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(
+            Configuration::new(config_fees)
+                .disable_transaction(TRANSFER_ID, "double spend in transfer"),
+        )
+        .add_wallet_value(&sender_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret.clone())
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .build();
+
+    let (status, response): (StatusCode, TxDisabledResponse) =
+        api.post_with_status("/v1/transactions", &tx_transfer);
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(
+        response,
+        TxDisabledResponse {
+            error: Error::TransactionDisabled,
+            message_id: TRANSFER_ID,
+            reason: "double spend in transfer".to_string(),
+        }
+    );
+
+    let (status, response): (StatusCode, Envelope<TransactionResponse>) =
+        api.post_with_status("/v2/transactions", &tx_transfer);
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::Forbidden);
+    assert_eq!(
+        response,
+        Envelope::Error(ErrorBody {
+            code: "transaction_disabled".to_string(),
+            message: "transaction type disabled: double spend in transfer".to_string(),
+        })
+    );
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+
+    // The reason is published along with the switch.
+    let (status, response): (StatusCode, DisabledTransactionsResponse) =
+        api.get_with_status("/v1/transactions/disabled");
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        response,
+        Ok(vec![
+            DisabledTransaction {
+                message_id: TRANSFER_ID,
+                reason: "double spend in transfer".to_string(),
+            },
+        ])
+    );
+
+    // Other transaction types are not affected.
+    let tx_multi_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret)
+        .tx_multi_transfer()
+        .add_output(recipient_key, 100, vec![], "payment")
+        .build();
+
+    let (status, response) = api.post_tx(&tx_multi_transfer);
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash: tx_multi_transfer.hash() })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_multi_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+}
+
+#[test]
+fn disabled_transaction_rejected_on_execution() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 10);
+    let balance = 1_000;
+    let amount = 100;
+
+    let (sender_key, sender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees.clone()))
+        .add_wallet_value(&sender_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret.clone())
+        .tx_transfer()
+        .amount(amount)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+
+    let (status, _) = api.post_tx(&tx_transfer);
+    assert_eq!(status, StatusCode::Created);
+
+    // The switch is voted in before the transaction gets into a block.
+    testkit.set_configuration(
+        Configuration::new(config_fees.clone()).disable_transaction(TRANSFER_ID, "under review"),
+    );
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Err(Error::TransactionDisabled)));
+
+    let sender = api.get_wallet(&sender_key);
+    assert_eq!(sender.balance, balance);
+
+    // Enabling the type again lets new transactions through.
+    testkit.set_configuration(
+        Configuration::new(config_fees).disable_transaction(EXCHANGE_INTERMEDIARY_ID, "bug"),
+    );
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret)
+        .tx_transfer()
+        .amount(amount)
+        .recipient(recipient_key)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let sender = api.get_wallet(&sender_key);
+    assert_eq!(sender.balance, balance - amount - 10);
+}
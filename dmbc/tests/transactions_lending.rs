@@ -0,0 +1,389 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::assets::AssetBundle;
+use dmbc::currency::api::lending::{LoanResponse, LoansResponse};
+use dmbc::currency::error::Error;
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::lending::LoanState;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn lend_and_return() {
+    let transaction_fee = 10;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 1_000;
+    let collateral = 500;
+    let rental_fee = 50;
+
+    let (lender_key, lender_secret) = crypto::gen_keypair();
+    let (borrower_key, borrower_secret) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("sword", 1, dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()), &lender_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&lender_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&lender_key, (asset.clone(), info))
+        .add_wallet_value(&borrower_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_offer = transaction::Builder::new()
+        .keypair(lender_key, lender_secret)
+        .tx_offer_loan()
+        .add_asset_value(asset.clone())
+        .collateral(collateral)
+        .rental_fee(rental_fee)
+        .duration(5)
+        .build();
+    let loan_id = tx_offer.hash();
+
+    let (status, response) = api.post_tx(&tx_offer);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash: loan_id })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_offer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The offered assets are held by the loan.
+    let lender_wallet = api.get_wallet(&lender_key);
+    assert_eq!(lender_wallet.balance, balance - transaction_fee);
+    assert_eq!(lender_wallet.assets_count, 0);
+
+    let tx_accept = transaction::Builder::new()
+        .keypair(borrower_key, borrower_secret.clone())
+        .tx_accept_loan()
+        .loan(loan_id)
+        .build();
+
+    api.post_tx(&tx_accept);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_accept);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let borrower_wallet = api.get_wallet(&borrower_key);
+    let borrower_assets = api.get_wallet_assets(&borrower_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(borrower_wallet.balance, balance - transaction_fee - collateral);
+    assert_eq!(borrower_assets, vec![asset.clone()]);
+
+    let lender_wallet = api.get_wallet(&lender_key);
+    assert_eq!(lender_wallet.assets_count, 0);
+
+    let (status, response): (StatusCode, LoanResponse) =
+        api.get_with_status(&format!("/v1/loans/{}", loan_id.to_string()));
+    assert_eq!(status, StatusCode::Ok);
+
+    let loan = response.unwrap();
+    assert_eq!(loan.borrower, Some(borrower_key));
+    assert_eq!(loan.deadline, Some(3 + 5));
+    assert_eq!(loan.state, LoanState::Active as u8);
+
+    let tx_return = transaction::Builder::new()
+        .keypair(borrower_key, borrower_secret)
+        .tx_return_loan()
+        .loan(loan_id)
+        .build();
+
+    api.post_tx(&tx_return);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_return);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The collateral is released minus the rental fee.
+    let borrower_wallet = api.get_wallet(&borrower_key);
+    assert_eq!(borrower_wallet.balance, balance - transaction_fee - rental_fee);
+    assert_eq!(borrower_wallet.assets_count, 0);
+
+    let lender_wallet = api.get_wallet(&lender_key);
+    let lender_assets = api.get_wallet_assets(&lender_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(lender_wallet.balance, balance - transaction_fee + rental_fee);
+    assert_eq!(lender_assets, vec![asset]);
+
+    let (_, response): (StatusCode, LoansResponse) =
+        api.get_with_status(&format!("/v1/wallets/{}/loans", lender_key.to_string()));
+    let loans = response.unwrap();
+    assert_eq!(loans.total, 1);
+    assert_eq!(loans.loans[0].state, LoanState::Returned as u8);
+}
+
+#[test]
+fn claim_collateral() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 1_000;
+    let collateral = 500;
+
+    let (lender_key, lender_secret) = crypto::gen_keypair();
+    let (borrower_key, borrower_secret) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("sword", 1, dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()), &lender_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_asset_to_wallet(&lender_key, (asset.clone(), info))
+        .add_wallet_value(&borrower_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_offer = transaction::Builder::new()
+        .keypair(lender_key, lender_secret.clone())
+        .tx_offer_loan()
+        .add_asset_value(asset.clone())
+        .collateral(collateral)
+        .rental_fee(10)
+        .duration(1)
+        .build();
+    let loan_id = tx_offer.hash();
+
+    api.post_tx(&tx_offer);
+    testkit.create_block();
+
+    // Accepted at height 3, due at height 4.
+    let tx_accept = transaction::Builder::new()
+        .keypair(borrower_key, borrower_secret.clone())
+        .tx_accept_loan()
+        .loan(loan_id)
+        .build();
+
+    api.post_tx(&tx_accept);
+    testkit.create_block();
+
+    let tx_claim = transaction::Builder::new()
+        .keypair(lender_key, lender_secret.clone())
+        .tx_claim_collateral()
+        .loan(loan_id)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_claim);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_claim);
+    assert_eq!(tx_status, Ok(Err(Error::LoanNotOverdue)));
+
+    let tx_claim = transaction::Builder::new()
+        .keypair(lender_key, lender_secret)
+        .tx_claim_collateral()
+        .loan(loan_id)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_claim);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_claim);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The borrower keeps the assets and loses the collateral.
+    let lender_wallet = api.get_wallet(&lender_key);
+    let borrower_wallet = api.get_wallet(&borrower_key);
+    let borrower_assets = api.get_wallet_assets(&borrower_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(lender_wallet.balance, collateral);
+    assert_eq!(borrower_wallet.balance, balance - collateral);
+    assert_eq!(borrower_assets, vec![asset]);
+
+    // Too late to return.
+    let tx_return = transaction::Builder::new()
+        .keypair(borrower_key, borrower_secret)
+        .tx_return_loan()
+        .loan(loan_id)
+        .build();
+
+    api.post_tx(&tx_return);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_return);
+    assert_eq!(tx_status, Ok(Err(Error::LoanNotAvailable)));
+}
+
+#[test]
+fn accept_loan_insufficient_collateral() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 100;
+
+    let (lender_key, lender_secret) = crypto::gen_keypair();
+    let (borrower_key, borrower_secret) = crypto::gen_keypair();
+    let (rich_key, rich_secret) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("sword", 1, dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()), &lender_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_asset_to_wallet(&lender_key, (asset.clone(), info))
+        .add_wallet_value(&borrower_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&rich_key, Wallet::new(10_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_offer = transaction::Builder::new()
+        .keypair(lender_key, lender_secret)
+        .tx_offer_loan()
+        .add_asset_value(asset.clone())
+        .collateral(500)
+        .duration(1)
+        .build();
+    let loan_id = tx_offer.hash();
+
+    api.post_tx(&tx_offer);
+    testkit.create_block();
+
+    let tx_accept = transaction::Builder::new()
+        .keypair(borrower_key, borrower_secret)
+        .tx_accept_loan()
+        .loan(loan_id)
+        .build();
+
+    api.post_tx(&tx_accept);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_accept);
+    assert_eq!(tx_status, Ok(Err(Error::InsufficientFunds)));
+
+    // The assets stay in escrow.
+    let borrower_wallet = api.get_wallet(&borrower_key);
+    let borrower_assets = api.get_wallet_assets(&borrower_key);
+    let lender_assets = api.get_wallet_assets(&lender_key);
+    assert_eq!(borrower_wallet.balance, balance);
+    assert!(borrower_assets.is_empty());
+    assert!(lender_assets.is_empty());
+
+    // The offer is still open for someone else.
+    let tx_accept = transaction::Builder::new()
+        .keypair(rich_key, rich_secret.clone())
+        .tx_accept_loan()
+        .loan(loan_id)
+        .build();
+
+    api.post_tx(&tx_accept);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_accept);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // But only once.
+    let tx_accept = transaction::Builder::new()
+        .keypair(rich_key, rich_secret)
+        .tx_accept_loan()
+        .loan(loan_id)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_accept);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_accept);
+    assert_eq!(tx_status, Ok(Err(Error::LoanNotAvailable)));
+}
+
+#[test]
+fn cancel_loan() {
+    let transaction_fee = 10;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 1_000;
+
+    let (lender_key, lender_secret) = crypto::gen_keypair();
+    let (borrower_key, borrower_secret) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("sword", 1, dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()), &lender_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&lender_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&lender_key, (asset.clone(), info))
+        .add_wallet_value(&borrower_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_offer = transaction::Builder::new()
+        .keypair(lender_key, lender_secret.clone())
+        .tx_offer_loan()
+        .add_asset_value(asset.clone())
+        .collateral(500)
+        .duration(5)
+        .build();
+    let loan_id = tx_offer.hash();
+
+    api.post_tx(&tx_offer);
+    testkit.create_block();
+
+    // Only the lender can withdraw the offer.
+    let tx_cancel = transaction::Builder::new()
+        .keypair(borrower_key, borrower_secret.clone())
+        .tx_cancel_loan()
+        .loan(loan_id)
+        .build();
+
+    api.post_tx(&tx_cancel);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_cancel);
+    assert_eq!(tx_status, Ok(Err(Error::NotLoanParticipant)));
+
+    let tx_cancel = transaction::Builder::new()
+        .keypair(lender_key, lender_secret.clone())
+        .tx_cancel_loan()
+        .loan(loan_id)
+        .build();
+
+    api.post_tx(&tx_cancel);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_cancel);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The escrowed assets are back with the lender.
+    let lender_wallet = api.get_wallet(&lender_key);
+    let lender_assets = api.get_wallet_assets(&lender_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(lender_wallet.balance, balance - 2 * transaction_fee);
+    assert_eq!(lender_assets, vec![asset]);
+
+    let (_, response): (StatusCode, LoanResponse) =
+        api.get_with_status(&format!("/v1/loans/{}", loan_id.to_string()));
+    assert_eq!(response.unwrap().state, LoanState::Cancelled as u8);
+
+    // A cancelled offer can not be accepted or cancelled again.
+    let tx_accept = transaction::Builder::new()
+        .keypair(borrower_key, borrower_secret)
+        .tx_accept_loan()
+        .loan(loan_id)
+        .build();
+
+    api.post_tx(&tx_accept);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_accept);
+    assert_eq!(tx_status, Ok(Err(Error::LoanNotAvailable)));
+
+    let tx_cancel = transaction::Builder::new()
+        .keypair(lender_key, lender_secret)
+        .tx_cancel_loan()
+        .loan(loan_id)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_cancel);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_cancel);
+    assert_eq!(tx_status, Ok(Err(Error::LoanNotAvailable)));
+}
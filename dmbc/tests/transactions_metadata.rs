@@ -0,0 +1,228 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::env;
+
+use hyper::status::StatusCode;
+use iron::headers::Headers;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::asset::{AssetMetadataResponse, AssetResponse};
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::metadata::{MetadataResponse, MetadataResponseBody};
+use dmbc::currency::assets::{AssetId, MetadataRef};
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::error::Error;
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+
+fn use_temp_store() {
+    let path = env::temp_dir().join("dmbc-test-metadata");
+    env::set_var("METADATA_PATH", path.to_str().unwrap());
+}
+
+#[test]
+fn metadata_upload_and_fetch() {
+    use_temp_store();
+
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let content = r#"{"name": "knife", "rarity": "covert"}"#;
+    let content_hash = crypto::hash(content.as_bytes());
+
+    // The upload is refused if the content doesn't match the hash.
+    let wrong_hash = crypto::hash(b"something else");
+    let (status, response): (StatusCode, MetadataResponse) = api.post_raw_with_status2(
+        &format!("/v1/metadata/{}", wrong_hash.to_hex()),
+        Headers::new(),
+        content,
+    );
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::MetadataHashMismatch));
+
+    let (status, response): (StatusCode, MetadataResponse) = api.post_raw_with_status2(
+        &format!("/v1/metadata/{}", content_hash.to_hex()),
+        Headers::new(),
+        content,
+    );
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(MetadataResponseBody { content_hash }));
+
+    let (status, response): (StatusCode, serde_json::Value) =
+        api.get_with_status(&format!("/v1/metadata/{}", content_hash.to_hex()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, serde_json::from_str::<serde_json::Value>(content).unwrap());
+
+    let (status, response): (StatusCode, MetadataResponse) =
+        api.get_with_status(&format!("/v1/metadata/{}", wrong_hash.to_hex()));
+    assert_eq!(status, StatusCode::NotFound);
+    assert_eq!(response, Err(ApiError::MetadataNotFound));
+
+    let (status, response): (StatusCode, MetadataResponse) =
+        api.get_with_status("/v1/metadata/badhash");
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::MetadataHashInvalid));
+}
+
+#[test]
+fn add_assets_with_offchain_metadata() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+    let content_hash = crypto::hash(b"{\"name\": \"knife\"}");
+    let metadata = MetadataRef::new(&content_hash, "application/json", "ipfs://knife");
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_assets()
+        .add_asset_metadata(metadata.clone(), 5, fees.clone())
+        .add_asset("inline", 5, fees.clone())
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // Only the hash is kept on-chain, and the asset ID is derived from it.
+    let id = AssetId::from_data(&content_hash.to_hex(), &creator_key);
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", id.to_string()));
+    assert_eq!(response.unwrap().unwrap().data(), content_hash.to_hex());
+
+    let tx_set_metadata = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_set_asset_metadata()
+        .add_metadata(metadata.clone())
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_set_metadata);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_set_metadata);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (status, response): (StatusCode, AssetMetadataResponse) =
+        api.get_with_status(&format!("/v1/assets/{}/metadata", id.to_string()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response, Ok(Some(metadata.clone())));
+
+    let inline_id = AssetId::from_data("inline", &creator_key);
+    let (_, response): (StatusCode, AssetMetadataResponse) =
+        api.get_with_status(&format!("/v1/assets/{}/metadata", inline_id.to_string()));
+    assert_eq!(response, Ok(None));
+
+    // More units can be added, but the reference can't change.
+    let tx_more = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_assets()
+        .add_asset_metadata(metadata, 5, fees)
+        .seed(3)
+        .build();
+
+    let changed = MetadataRef::new(&content_hash, "text/plain", "https://example.com/knife");
+    let tx_changed = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_set_asset_metadata()
+        .add_metadata(changed)
+        .seed(4)
+        .build();
+
+    api.post_tx(&tx_more);
+    api.post_tx(&tx_changed);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_more);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, tx_status) = api.get_tx_status(&tx_changed);
+    assert_eq!(tx_status, Ok(Err(Error::InvalidAssetInfo)));
+
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", id.to_string()));
+    assert_eq!(response.unwrap().unwrap().amount(), 10);
+}
+
+#[test]
+fn set_asset_metadata_errors() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (other_key, other_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .add_wallet_value(&other_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(10, "0.0".parse().unwrap());
+    let content_hash = crypto::hash(b"{\"name\": \"glove\"}");
+    let metadata = MetadataRef::new(&content_hash, "application/json", "ipfs://glove");
+
+    // The asset must exist before a reference is attached to it.
+    let tx_missing = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_set_asset_metadata()
+        .add_metadata(metadata.clone())
+        .seed(1)
+        .build();
+
+    let tx_add_assets = transaction::Builder::new()
+        .keypair(other_key, other_secret.clone())
+        .tx_add_assets()
+        .add_asset_metadata(metadata.clone(), 5, fees)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_missing);
+    api.post_tx(&tx_add_assets);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_missing);
+    assert_eq!(tx_status, Ok(Err(Error::AssetNotFound)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_add_assets);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The asset is found under the key that created it.
+    let tx_set_metadata = transaction::Builder::new()
+        .keypair(other_key, other_secret)
+        .tx_set_asset_metadata()
+        .add_metadata(metadata.clone())
+        .seed(3)
+        .build();
+
+    api.post_tx(&tx_set_metadata);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_set_metadata);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let id = AssetId::from_data(&content_hash.to_hex(), &other_key);
+    let (_, response): (StatusCode, AssetMetadataResponse) =
+        api.get_with_status(&format!("/v1/assets/{}/metadata", id.to_string()));
+    assert_eq!(response, Ok(Some(metadata)));
+}
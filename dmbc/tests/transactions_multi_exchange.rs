@@ -0,0 +1,274 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::assets::AssetBundle;
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::error::Error;
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::wallet::Wallet;
+use dmbc::currency::transactions::components::FeeStrategy;
+
+#[test]
+fn multi_exchange_three_parties_fee_from_sender() {
+    let transaction_fee = 900;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, transaction_fee, 0, 0);
+    let fixed = 10;
+    let balance = 100_000;
+    let units1 = 5;
+    let units2 = 3;
+    let value = 700;
+
+    let (alice_pk, alice_sk) = crypto::gen_keypair();
+    let (bob_pk, bob_sk) = crypto::gen_keypair();
+    let (carol_pk, carol_sk) = crypto::gen_keypair();
+    let (creator_pk, _) = crypto::gen_keypair();
+
+    let (asset1, info1) = dmbc_testkit::create_asset("asset1", units1, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pk);
+    let (asset2, info2) = dmbc_testkit::create_asset("asset2", units2, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pk);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&alice_pk, Wallet::new(balance, vec![]))
+        .add_wallet_value(&bob_pk, Wallet::new(balance, vec![]))
+        .add_wallet_value(&carol_pk, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&alice_pk, (asset1.clone(), info1))
+        .add_asset_to_wallet(&bob_pk, (asset2.clone(), info2))
+        .create();
+    let api = testkit.api();
+    let genesis_balance = api.get_wallet(&dmbc_testkit::default_genesis_key()).balance;
+
+    let tx_multi_exchange = transaction::Builder::new()
+        .keypair(carol_pk, carol_sk.clone())
+        .tx_multi_exchange()
+        .add_leg(alice_pk, bob_pk, 0, vec![asset1.clone()])
+        .add_leg(bob_pk, carol_pk, 0, vec![asset2.clone()])
+        .add_leg(carol_pk, alice_pk, value, vec![])
+        .signer(alice_pk, alice_sk)
+        .signer(bob_pk, bob_sk)
+        .signer(carol_pk, carol_sk)
+        .fee_strategy(FeeStrategy::Sender)
+        .seed(1)
+        .build();
+
+    let tx_hash = tx_multi_exchange.hash();
+
+    let (status, response) = api.post_tx(&tx_multi_exchange);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_multi_exchange);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let alice_wallet = api.get_wallet(&alice_pk);
+    let bob_wallet = api.get_wallet(&bob_pk);
+    let carol_wallet = api.get_wallet(&carol_pk);
+    let genesis_wallet = api.get_wallet(&dmbc_testkit::default_genesis_key());
+    let creator_wallet = api.get_wallet(&creator_pk);
+
+    let fee_share = transaction_fee / 3;
+    assert_eq!(alice_wallet.balance, balance - fee_share - units1 * fixed + value);
+    assert_eq!(bob_wallet.balance, balance - fee_share - units2 * fixed);
+    assert_eq!(carol_wallet.balance, balance - fee_share - value);
+    assert_eq!(genesis_wallet.balance, genesis_balance + transaction_fee);
+    assert_eq!(creator_wallet.balance, units1 * fixed + units2 * fixed);
+
+    let bob_assets = api.get_wallet_assets(&bob_pk).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    let carol_assets = api.get_wallet_assets(&carol_pk).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert!(alice_wallet.assets_count == 0);
+    assert_eq!(bob_assets, vec![asset1]);
+    assert_eq!(carol_assets, vec![asset2]);
+}
+
+#[test]
+fn multi_exchange_is_atomic() {
+    let transaction_fee = 900;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, transaction_fee, 0, 0);
+    let fixed = 10;
+    let balance = 100_000;
+    let units = 5;
+
+    let (alice_pk, alice_sk) = crypto::gen_keypair();
+    let (bob_pk, bob_sk) = crypto::gen_keypair();
+    let (carol_pk, carol_sk) = crypto::gen_keypair();
+    let (creator_pk, _) = crypto::gen_keypair();
+
+    let (asset1, info1) = dmbc_testkit::create_asset("asset1", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pk);
+    let (asset2, info2) = dmbc_testkit::create_asset("asset2", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pk);
+
+    // Bob does not own the asset he is giving away.
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&alice_pk, Wallet::new(balance, vec![]))
+        .add_wallet_value(&bob_pk, Wallet::new(balance, vec![]))
+        .add_wallet_value(&carol_pk, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&alice_pk, (asset1.clone(), info1))
+        .add_asset_info(&asset2.id(), info2)
+        .create();
+    let api = testkit.api();
+    let genesis_balance = api.get_wallet(&dmbc_testkit::default_genesis_key()).balance;
+
+    let tx_multi_exchange = transaction::Builder::new()
+        .keypair(alice_pk, alice_sk.clone())
+        .tx_multi_exchange()
+        .add_leg(alice_pk, bob_pk, 0, vec![asset1.clone()])
+        .add_leg(bob_pk, carol_pk, 0, vec![asset2.clone()])
+        .add_leg(carol_pk, alice_pk, 100, vec![])
+        .signer(alice_pk, alice_sk)
+        .signer(bob_pk, bob_sk)
+        .signer(carol_pk, carol_sk)
+        .fee_strategy(FeeStrategy::Sender)
+        .seed(1)
+        .build();
+
+    let tx_hash = tx_multi_exchange.hash();
+
+    let (status, response) = api.post_tx(&tx_multi_exchange);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_multi_exchange);
+    assert_eq!(tx_status, Ok(Err(Error::InsufficientAssets)));
+
+    let alice_wallet = api.get_wallet(&alice_pk);
+    let bob_wallet = api.get_wallet(&bob_pk);
+    let carol_wallet = api.get_wallet(&carol_pk);
+    let genesis_wallet = api.get_wallet(&dmbc_testkit::default_genesis_key());
+    let creator_wallet = api.get_wallet(&creator_pk);
+
+    // Only the blockchain fee is collected.
+    let fee_share = transaction_fee / 3;
+    assert_eq!(alice_wallet.balance, balance - fee_share);
+    assert_eq!(bob_wallet.balance, balance - fee_share);
+    assert_eq!(carol_wallet.balance, balance - fee_share);
+    assert_eq!(genesis_wallet.balance, genesis_balance + transaction_fee);
+    assert_eq!(creator_wallet.balance, 0);
+
+    let alice_assets = api.get_wallet_assets(&alice_pk).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(alice_assets, vec![asset1]);
+    assert!(bob_wallet.assets_count == 0);
+}
+
+#[test]
+fn multi_exchange_missing_signature() {
+    let transaction_fee = 900;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, transaction_fee, 0, 0);
+    let fixed = 10;
+    let balance = 100_000;
+    let units = 5;
+
+    let (alice_pk, alice_sk) = crypto::gen_keypair();
+    let (bob_pk, bob_sk) = crypto::gen_keypair();
+    let (carol_pk, _) = crypto::gen_keypair();
+    let (creator_pk, _) = crypto::gen_keypair();
+
+    let (asset1, info1) = dmbc_testkit::create_asset("asset1", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pk);
+    let (asset2, info2) = dmbc_testkit::create_asset("asset2", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_pk);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&alice_pk, Wallet::new(balance, vec![]))
+        .add_wallet_value(&bob_pk, Wallet::new(balance, vec![]))
+        .add_wallet_value(&carol_pk, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&alice_pk, (asset1.clone(), info1))
+        .add_asset_to_wallet(&bob_pk, (asset2.clone(), info2))
+        .create();
+    let api = testkit.api();
+
+    // Carol gives coins away but never signs the offer.
+    let tx_multi_exchange = transaction::Builder::new()
+        .keypair(alice_pk, alice_sk.clone())
+        .tx_multi_exchange()
+        .add_leg(alice_pk, bob_pk, 0, vec![asset1.clone()])
+        .add_leg(bob_pk, carol_pk, 0, vec![asset2.clone()])
+        .add_leg(carol_pk, alice_pk, 100, vec![])
+        .signer(alice_pk, alice_sk)
+        .signer(bob_pk, bob_sk)
+        .fee_strategy(FeeStrategy::Sender)
+        .seed(1)
+        .build();
+
+    let (status, response) = api.post_tx(&tx_multi_exchange);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_multi_exchange);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+
+    let alice_wallet = api.get_wallet(&alice_pk);
+    let carol_wallet = api.get_wallet(&carol_pk);
+    assert_eq!(alice_wallet.balance, balance);
+    assert_eq!(carol_wallet.balance, balance);
+    assert!(alice_wallet.assets_count > 0);
+}
+
+#[test]
+fn multi_exchange_offer_replayed() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 100_000;
+
+    let (alice_pk, alice_sk) = crypto::gen_keypair();
+    let (bob_pk, bob_sk) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&alice_pk, Wallet::new(balance, vec![]))
+        .add_wallet_value(&bob_pk, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let exchange = |submitter_pk: crypto::PublicKey, submitter_sk: crypto::SecretKey| {
+        transaction::Builder::new()
+            .keypair(submitter_pk, submitter_sk)
+            .tx_multi_exchange()
+            .add_leg(alice_pk, bob_pk, 100, vec![])
+            .add_leg(bob_pk, alice_pk, 50, vec![])
+            .signer(alice_pk, alice_sk.clone())
+            .signer(bob_pk, bob_sk.clone())
+            .fee_strategy(FeeStrategy::Sender)
+            .seed(1)
+            .build()
+    };
+
+    let tx_alice = exchange(alice_pk, alice_sk.clone());
+    api.post_tx(&tx_alice);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_alice);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The same signed offer submitted by another participant.
+    let tx_bob = exchange(bob_pk, bob_sk.clone());
+    assert!(tx_alice.hash() != tx_bob.hash());
+    api.post_tx(&tx_bob);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_bob);
+    assert_eq!(tx_status, Ok(Err(Error::OfferExecuted)));
+
+    assert_eq!(api.get_wallet(&alice_pk).balance, balance - 50);
+    assert_eq!(api.get_wallet(&bob_pk).balance, balance + 50);
+}
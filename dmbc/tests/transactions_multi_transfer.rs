@@ -0,0 +1,176 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::transactions::MAX_TRANSFER_OUTPUTS;
+use dmbc::currency::assets::AssetBundle;
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::error::Error;
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn multi_transfer() {
+    let fixed = 10;
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let units = 5;
+    let balance = 100_000;
+    let amount1 = 300;
+    let amount2 = 200;
+
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (creator_key, _) = crypto::gen_keypair();
+    let (recipient1_key, _) = crypto::gen_keypair();
+    let (recipient2_key, _) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("asset", units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &creator_key);
+    let asset1 = AssetBundle::new(asset.id(), 2);
+    let asset2 = AssetBundle::new(asset.id(), 3);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&public_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+    let genesis_balance = api.get_wallet(&dmbc_testkit::default_genesis_key()).balance;
+
+    let tx_multi_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_multi_transfer()
+        .add_output(recipient1_key, amount1, vec![asset1.clone()], "first")
+        .add_output(recipient2_key, amount2, vec![asset2.clone()], "second")
+        .seed(42)
+        .build();
+
+    let tx_hash = tx_multi_transfer.hash();
+
+    let (status, response) = api.post_tx(&tx_multi_transfer);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_multi_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let recipient1_wallet = api.get_wallet(&recipient1_key);
+    let recipient1_assets = api.get_wallet_assets(&recipient1_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(recipient1_wallet.balance, amount1);
+    assert_eq!(recipient1_assets, vec![asset1]);
+
+    let recipient2_wallet = api.get_wallet(&recipient2_key);
+    let recipient2_assets = api.get_wallet_assets(&recipient2_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(recipient2_wallet.balance, amount2);
+    assert_eq!(recipient2_assets, vec![asset2]);
+
+    // The blockchain fee is paid once for the whole transaction.
+    let sender_wallet = api.get_wallet(&public_key);
+    let expected_balance = balance - transaction_fee - units * fixed - amount1 - amount2;
+    assert_eq!(sender_wallet.balance, expected_balance);
+    assert!(sender_wallet.assets_count == 0);
+
+    let genesis_wallet = api.get_wallet(&dmbc_testkit::default_genesis_key());
+    let creator_wallet = api.get_wallet(&creator_key);
+    assert_eq!(genesis_wallet.balance, genesis_balance + transaction_fee);
+    assert_eq!(creator_wallet.balance, units * fixed);
+}
+
+#[test]
+fn multi_transfer_is_atomic() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 2000;
+
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient1_key, _) = crypto::gen_keypair();
+    let (recipient2_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    // Enough for the first output, but not for the second.
+    let tx_multi_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_multi_transfer()
+        .add_output(recipient1_key, 600, vec![], "")
+        .add_output(recipient2_key, 600, vec![], "")
+        .seed(42)
+        .build();
+
+    let tx_hash = tx_multi_transfer.hash();
+
+    let (status, response) = api.post_tx(&tx_multi_transfer);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_multi_transfer);
+    assert_eq!(tx_status, Ok(Err(Error::InsufficientFunds)));
+
+    let sender_wallet = api.get_wallet(&public_key);
+    let recipient1_wallet = api.get_wallet(&recipient1_key);
+    let recipient2_wallet = api.get_wallet(&recipient2_key);
+    assert_eq!(sender_wallet.balance, balance - transaction_fee);
+    assert_eq!(recipient1_wallet.balance, 0);
+    assert_eq!(recipient2_wallet.balance, 0);
+}
+
+#[test]
+fn multi_transfer_too_many_outputs() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 100_000;
+
+    let (public_key, secret_key) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let mut builder = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_multi_transfer()
+        .seed(42);
+    for _ in 0..MAX_TRANSFER_OUTPUTS + 1 {
+        let (recipient_key, _) = crypto::gen_keypair();
+        builder = builder.add_output(recipient_key, 1, vec![], "");
+    }
+    let tx_multi_transfer = builder.build();
+
+    let (status, response) = api.post_tx(&tx_multi_transfer);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_multi_transfer);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+
+    let sender_wallet = api.get_wallet(&public_key);
+    assert_eq!(sender_wallet.balance, balance);
+}
@@ -0,0 +1,446 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use exonum::crypto::{PublicKey, SecretKey};
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::multisig::{MultisigConfig, MultisigMember};
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::multisig::{MultisigResponse, ProposalResponse, ProposalsResponse};
+use dmbc::currency::error::Error;
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::wallet::Wallet;
+
+fn members(count: usize) -> Vec<(PublicKey, SecretKey)> {
+    (0..count).map(|_| crypto::gen_keypair()).collect()
+}
+
+fn multisig_address(members: &[(PublicKey, SecretKey)], threshold: u8) -> PublicKey {
+    let members = members
+        .iter()
+        .map(|&(ref pk, _)| MultisigMember::new(pk))
+        .collect();
+    MultisigConfig::new(members, threshold).address()
+}
+
+#[test]
+fn create_multisig() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let members = members(3);
+    let address = multisig_address(&members, 2);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_create_multisig()
+        .add_member(members[0].0)
+        .add_member(members[1].0)
+        .add_member(members[2].0)
+        .threshold(2)
+        .build();
+
+    let tx_hash = tx_create.hash();
+
+    let (status, response) = api.post_tx(&tx_create);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_create);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (status, response): (StatusCode, MultisigResponse) =
+        api.get_with_status(&format!("/v1/multisig/{}", address.to_string()));
+    assert_eq!(status, StatusCode::Ok);
+
+    let info = response.unwrap();
+    assert_eq!(info.address, address);
+    assert_eq!(info.threshold, 2);
+    assert_eq!(info.members, members.iter().map(|m| m.0).collect::<Vec<_>>());
+
+    // The same wallet can not be created twice.
+    let tx_create = transaction::Builder::new()
+        .keypair(members[1].0, members[1].1.clone())
+        .tx_create_multisig()
+        .add_member(members[0].0)
+        .add_member(members[1].0)
+        .add_member(members[2].0)
+        .threshold(2)
+        .build();
+
+    api.post_tx(&tx_create);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_create);
+    assert_eq!(tx_status, Ok(Err(Error::MultisigExists)));
+}
+
+#[test]
+fn create_multisig_invalid_threshold() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let members = members(2);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_create_multisig()
+        .add_member(members[0].0)
+        .add_member(members[1].0)
+        .threshold(3)
+        .build();
+
+    let (status, response) = api.post_tx(&tx_create);
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_create);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+}
+
+#[test]
+fn multisig_spend() {
+    let transaction_fee = 100;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 10_000;
+    let amount = 1_000;
+
+    let members = members(3);
+    let address = multisig_address(&members, 2);
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&address, Wallet::new(balance, vec![]))
+        .add_wallet_value(&members[0].0, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_create_multisig()
+        .add_member(members[0].0)
+        .add_member(members[1].0)
+        .add_member(members[2].0)
+        .threshold(2)
+        .build();
+    api.post_tx(&tx_create);
+    testkit.create_block();
+
+    // The inner transaction is signed by a member, but its signature is not
+    // checked: the multisig wallet has no secret key.
+    let tx_transfer = transaction::Builder::new()
+        .keypair(address, members[0].1.clone())
+        .tx_transfer()
+        .recipient(recipient_key)
+        .amount(amount)
+        .build();
+
+    let tx_spend = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_multisig_spend()
+        .wallet(address)
+        .tx_value(&tx_transfer)
+        .signer(members[0].0, members[0].1.clone())
+        .signer(members[2].0, members[2].1.clone())
+        .build();
+
+    let tx_hash = tx_spend.hash();
+
+    let (status, response) = api.post_tx(&tx_spend);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_spend);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The inner transaction is tracked under its own hash.
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let multisig_wallet = api.get_wallet(&address);
+    let recipient_wallet = api.get_wallet(&recipient_key);
+    assert_eq!(multisig_wallet.balance, balance - transaction_fee - amount);
+    assert_eq!(recipient_wallet.balance, amount);
+
+    // The same inner transaction can not be replayed.
+    let tx_spend = transaction::Builder::new()
+        .keypair(members[1].0, members[1].1.clone())
+        .tx_multisig_spend()
+        .wallet(address)
+        .tx_value(&tx_transfer)
+        .signer(members[1].0, members[1].1.clone())
+        .signer(members[2].0, members[2].1.clone())
+        .build();
+
+    api.post_tx(&tx_spend);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_spend);
+    assert_eq!(tx_status, Ok(Err(Error::AlreadyExecuted)));
+
+    let recipient_wallet = api.get_wallet(&recipient_key);
+    assert_eq!(recipient_wallet.balance, amount);
+}
+
+#[test]
+fn multisig_spend_insufficient_signatures() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 10_000;
+
+    let members = members(3);
+    let address = multisig_address(&members, 2);
+    let (recipient_key, _) = crypto::gen_keypair();
+    let (outsider_key, outsider_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&address, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_create_multisig()
+        .add_member(members[0].0)
+        .add_member(members[1].0)
+        .add_member(members[2].0)
+        .threshold(2)
+        .build();
+    api.post_tx(&tx_create);
+    testkit.create_block();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(address, members[0].1.clone())
+        .tx_transfer()
+        .recipient(recipient_key)
+        .amount(1_000)
+        .build();
+
+    let tx_spend = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_multisig_spend()
+        .wallet(address)
+        .tx_value(&tx_transfer)
+        .signer(members[0].0, members[0].1.clone())
+        .build();
+
+    api.post_tx(&tx_spend);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_spend);
+    assert_eq!(tx_status, Ok(Err(Error::InsufficientSignatures)));
+
+    let tx_spend = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_multisig_spend()
+        .wallet(address)
+        .tx_value(&tx_transfer)
+        .signer(members[0].0, members[0].1.clone())
+        .signer(outsider_key, outsider_secret)
+        .build();
+
+    api.post_tx(&tx_spend);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_spend);
+    assert_eq!(tx_status, Ok(Err(Error::NotMultisigMember)));
+
+    let multisig_wallet = api.get_wallet(&address);
+    let recipient_wallet = api.get_wallet(&recipient_key);
+    assert_eq!(multisig_wallet.balance, balance);
+    assert_eq!(recipient_wallet.balance, 0);
+}
+
+#[test]
+fn multisig_propose_approve() {
+    let transaction_fee = 100;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 10_000;
+    let amount = 1_000;
+
+    let members = members(3);
+    let address = multisig_address(&members, 2);
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&address, Wallet::new(balance, vec![]))
+        .add_wallet_value(&members[0].0, Wallet::new(balance, vec![]))
+        .add_wallet_value(&members[1].0, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_create_multisig()
+        .add_member(members[0].0)
+        .add_member(members[1].0)
+        .add_member(members[2].0)
+        .threshold(2)
+        .build();
+    api.post_tx(&tx_create);
+    testkit.create_block();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(address, members[0].1.clone())
+        .tx_transfer()
+        .recipient(recipient_key)
+        .amount(amount)
+        .build();
+
+    let tx_propose = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_multisig_propose()
+        .wallet(address)
+        .tx_value(&tx_transfer)
+        .build();
+    let proposal_id = tx_propose.hash();
+
+    api.post_tx(&tx_propose);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_propose);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (status, response): (StatusCode, ProposalsResponse) =
+        api.get_with_status(&format!("/v1/multisig/{}/proposals", address.to_string()));
+    assert_eq!(status, StatusCode::Ok);
+
+    let proposals = response.unwrap();
+    assert_eq!(proposals.total, 1);
+    assert_eq!(proposals.proposals[0].id, proposal_id);
+    assert_eq!(proposals.proposals[0].approvals, vec![members[0].0]);
+
+    // Approving twice does not count.
+    let tx_approve = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_multisig_approve()
+        .proposal(proposal_id)
+        .build();
+
+    api.post_tx(&tx_approve);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_approve);
+    assert_eq!(tx_status, Ok(Err(Error::AlreadyApproved)));
+
+    let recipient_wallet = api.get_wallet(&recipient_key);
+    assert_eq!(recipient_wallet.balance, 0);
+
+    // The second approval reaches the threshold and executes the transfer.
+    let tx_approve = transaction::Builder::new()
+        .keypair(members[1].0, members[1].1.clone())
+        .tx_multisig_approve()
+        .proposal(proposal_id)
+        .build();
+
+    api.post_tx(&tx_approve);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_approve);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let multisig_wallet = api.get_wallet(&address);
+    let recipient_wallet = api.get_wallet(&recipient_key);
+    assert_eq!(multisig_wallet.balance, balance - transaction_fee - amount);
+    assert_eq!(recipient_wallet.balance, amount);
+
+    // Creating the wallet, proposals and approvals pay the transaction fee,
+    // even when rejected.
+    let proposer_wallet = api.get_wallet(&members[0].0);
+    let approver_wallet = api.get_wallet(&members[1].0);
+    assert_eq!(proposer_wallet.balance, balance - 3 * transaction_fee);
+    assert_eq!(approver_wallet.balance, balance - transaction_fee);
+
+    let (status, response): (StatusCode, ProposalResponse) =
+        api.get_with_status(&format!("/v1/proposals/{}", proposal_id.to_string()));
+    assert_eq!(status, StatusCode::NotFound);
+    assert_eq!(response, Err(ApiError::ProposalNotFound));
+}
+
+#[test]
+fn multisig_approve_not_member() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let members = members(2);
+    let address = multisig_address(&members, 2);
+    let (recipient_key, _) = crypto::gen_keypair();
+    let (outsider_key, outsider_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&address, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_create_multisig()
+        .add_member(members[0].0)
+        .add_member(members[1].0)
+        .threshold(2)
+        .build();
+    api.post_tx(&tx_create);
+    testkit.create_block();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(address, members[0].1.clone())
+        .tx_transfer()
+        .recipient(recipient_key)
+        .amount(500)
+        .build();
+
+    let tx_propose = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_multisig_propose()
+        .wallet(address)
+        .tx_value(&tx_transfer)
+        .build();
+    api.post_tx(&tx_propose);
+    testkit.create_block();
+
+    let tx_approve = transaction::Builder::new()
+        .keypair(outsider_key, outsider_secret)
+        .tx_multisig_approve()
+        .proposal(tx_propose.hash())
+        .build();
+
+    api.post_tx(&tx_approve);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_approve);
+    assert_eq!(tx_status, Ok(Err(Error::NotMultisigMember)));
+
+    let recipient_wallet = api.get_wallet(&recipient_key);
+    assert_eq!(recipient_wallet.balance, 0);
+}
@@ -0,0 +1,154 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use exonum::encoding::serialize::encode_hex;
+use exonum::messages::Message;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::transaction::{RawTransactionRequest, TransactionResponse,
+                                       TxPostResponse};
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+
+fn post_raw(api: &exonum_testkit::TestKitApi, hex: String) -> (StatusCode, TxPostResponse) {
+    api.post_with_status("/v1/transactions/raw", &RawTransactionRequest { hex })
+}
+
+#[test]
+fn raw_transaction() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 10);
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+
+    let (status, response) = post_raw(&api, encode_hex(tx_transfer.raw()));
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(
+        response,
+        Ok(Ok(TransactionResponse {
+            tx_hash: tx_transfer.hash(),
+        }))
+    );
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+    assert_eq!(api.get_wallet(&recipient_key).balance, 100);
+}
+
+#[test]
+fn raw_transaction_invalid() {
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+    let (_, wrong_secret_key) = crypto::gen_keypair();
+
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key.clone())
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+    let bytes = tx_transfer.raw().as_ref().to_vec();
+
+    let (status, response) = post_raw(&api, "not a hex".to_string());
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::TransactionHexInvalid));
+
+    let (status, response) = post_raw(&api, encode_hex(&bytes[..bytes.len() - 1]));
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::TransactionLengthInvalid));
+
+    let mut unknown_type = bytes.clone();
+    unknown_type[2] = 0xff;
+    unknown_type[3] = 0xff;
+    let (status, response) = post_raw(&api, encode_hex(&unknown_type));
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::TransactionTypeUnknown));
+
+    let tx_bad_signature = transaction::Builder::new()
+        .keypair(public_key, wrong_secret_key)
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+    let (status, response) = post_raw(&api, encode_hex(tx_bad_signature.raw()));
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::TransactionSignatureInvalid));
+
+    // Properly signed, but failing the rest of the checks.
+    let tx_self_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .amount(100)
+        .recipient(public_key)
+        .seed(2)
+        .build();
+    let (status, response) = post_raw(&api, encode_hex(tx_self_transfer.raw()));
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::TransactionMalformed));
+}
+
+#[test]
+fn json_transaction_unknown_type() {
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+
+    let mut body = serde_json::to_value(&tx_transfer).unwrap();
+    body["message_id"] = serde_json::Value::from(0xffff);
+
+    let (status, response): (StatusCode, TxPostResponse) =
+        api.post_raw_with_status("/v1/transactions", &body.to_string());
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::TransactionTypeUnknown));
+
+    let (status, response): (StatusCode, TxPostResponse) = api.post_tx(&tx_transfer);
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(
+        response,
+        Ok(Ok(TransactionResponse {
+            tx_hash: tx_transfer.hash(),
+        }))
+    );
+}
@@ -0,0 +1,278 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use exonum::messages::Message;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::transaction::{AssetDiff, SimulationResponse, WalletDiff};
+use dmbc::currency::assets::TradeAsset;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::error::Error;
+use dmbc::currency::multisig::{MultisigConfig, MultisigMember};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::transactions::components::FeeStrategy;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn simulate_transfer() {
+    let fixed = 10;
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let meta_data = "asset";
+    let units = 5;
+    let balance = 100_000;
+
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset(meta_data, units, dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap()), &public_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&public_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .add_asset_value(asset.clone())
+        .recipient(recipient_key)
+        .seed(42)
+        .build();
+
+    let (status, response): (StatusCode, SimulationResponse) =
+        api.post_with_status("/v1/transactions/simulate", &tx_transfer);
+    assert_eq!(status, StatusCode::Ok);
+
+    let body = response.unwrap();
+    assert_eq!(body.tx_hash, tx_transfer.hash());
+    assert!(body.verified);
+    assert_eq!(body.status, Ok(()));
+
+    let (_, fees) = api.post_fee(&tx_transfer);
+    assert_eq!(body.fees, fees.unwrap().unwrap().fees);
+
+    let sender = body.wallets.iter().find(|w| w.pub_key == public_key).unwrap();
+    assert_eq!(sender.balance_before, balance);
+    assert_eq!(sender.balance_after, balance - transaction_fee);
+    assert_eq!(
+        sender.assets,
+        vec![AssetDiff { id: asset.id(), before: units, after: 0 }]
+    );
+
+    let recipient = body.wallets.iter().find(|w| w.pub_key == recipient_key).unwrap();
+    assert_eq!(
+        *recipient,
+        WalletDiff {
+            pub_key: recipient_key,
+            balance_before: 0,
+            balance_after: 0,
+            assets: vec![AssetDiff { id: asset.id(), before: 0, after: units }],
+        }
+    );
+
+    let genesis_key = dmbc_testkit::default_genesis_key();
+    let genesis = body.wallets.iter().find(|w| w.pub_key == genesis_key).unwrap();
+    assert_eq!(genesis.balance_after - genesis.balance_before, transaction_fee);
+
+    // Nothing was committed or broadcast.
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+    assert_eq!(api.get_wallet(&public_key).balance, balance);
+    assert_eq!(api.get_wallet(&recipient_key).assets_count, 0);
+}
+
+#[test]
+fn simulate_failing_transfer() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 100_000;
+
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let (asset, _) = dmbc_testkit::create_asset("asset", 5, dmbc_testkit::asset_fees(10, "0.0".parse().unwrap()), &public_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .add_asset_value(asset)
+        .recipient(recipient_key)
+        .seed(42)
+        .build();
+
+    let (status, response): (StatusCode, SimulationResponse) =
+        api.post_with_status("/v1/transactions/simulate", &tx_transfer);
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response.unwrap().status, Err(Error::AssetNotFound));
+
+    let (status, response): (StatusCode, SimulationResponse) =
+        api.post_raw_with_status("/v1/transactions/simulate", "{}");
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::IncorrectRequest));
+}
+
+#[test]
+fn simulate_cart_checkout() {
+    let transaction_fee = 1000;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, transaction_fee, 0);
+    let fixed = 10;
+    let balance = 100_000;
+    let units = 3;
+    let price = 500;
+
+    let (seller1_public_key, seller1_secret_key) = crypto::gen_keypair();
+    let (seller2_public_key, seller2_secret_key) = crypto::gen_keypair();
+    let (buyer_public_key, buyer_secret_key) = crypto::gen_keypair();
+
+    let fees = dmbc_testkit::asset_fees(fixed, "0.0".parse().unwrap());
+    let (asset1, info1) =
+        dmbc_testkit::create_asset("asset1", units, fees.clone(), &seller1_public_key);
+    let (asset2, info2) = dmbc_testkit::create_asset("asset2", units, fees, &seller2_public_key);
+
+    let testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&buyer_public_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&seller1_public_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&seller2_public_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&seller1_public_key, (asset1.clone(), info1))
+        .add_asset_to_wallet(&seller2_public_key, (asset2.clone(), info2))
+        .create();
+    let api = testkit.api();
+
+    let tx_cart = transaction::Builder::new()
+        .keypair(buyer_public_key, buyer_secret_key)
+        .tx_cart_checkout()
+        .add_offer(
+            seller1_public_key,
+            seller1_secret_key,
+            vec![TradeAsset::from_bundle(asset1.clone(), price)],
+            FeeStrategy::Recipient,
+        )
+        .add_offer(
+            seller2_public_key,
+            seller2_secret_key,
+            vec![TradeAsset::from_bundle(asset2.clone(), price)],
+            FeeStrategy::Recipient,
+        )
+        .seed(1)
+        .build();
+
+    // The checkout runs its offers atomically, which needs a checkpoint.
+    let (status, response): (StatusCode, SimulationResponse) =
+        api.post_with_status("/v1/transactions/simulate", &tx_cart);
+    assert_eq!(status, StatusCode::Ok);
+
+    let body = response.unwrap();
+    assert!(body.verified);
+    assert_eq!(body.status, Ok(()));
+
+    let buyer = body.wallets.iter().find(|w| w.pub_key == buyer_public_key).unwrap();
+    assert_eq!(
+        buyer.balance_after,
+        balance - 2 * units * (price + fixed) - 2 * transaction_fee
+    );
+    let mut bought = vec![
+        AssetDiff { id: asset1.id(), before: 0, after: units },
+        AssetDiff { id: asset2.id(), before: 0, after: units },
+    ];
+    bought.sort_by_key(|diff| diff.id.to_string());
+    let mut assets = buyer.assets.clone();
+    assets.sort_by_key(|diff| diff.id.to_string());
+    assert_eq!(assets, bought);
+
+    let (_, tx_status) = api.get_tx_status(&tx_cart);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+    assert_eq!(api.get_wallet(&buyer_public_key).balance, balance);
+}
+
+#[test]
+fn simulate_multisig_spend() {
+    let transaction_fee = 100;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 10_000;
+    let amount = 1_000;
+
+    let members: Vec<_> = (0..2).map(|_| crypto::gen_keypair()).collect();
+    let config = MultisigConfig::new(
+        members.iter().map(|&(ref pk, _)| MultisigMember::new(pk)).collect(),
+        2,
+    );
+    let address = config.address();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&address, Wallet::new(balance, vec![]))
+        .add_wallet_value(&members[0].0, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_create_multisig()
+        .add_member(members[0].0)
+        .add_member(members[1].0)
+        .threshold(2)
+        .build();
+    api.post_tx(&tx_create);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_create);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(address, members[0].1.clone())
+        .tx_transfer()
+        .recipient(recipient_key)
+        .amount(amount)
+        .build();
+
+    let tx_spend = transaction::Builder::new()
+        .keypair(members[0].0, members[0].1.clone())
+        .tx_multisig_spend()
+        .wallet(address)
+        .tx_value(&tx_transfer)
+        .signer(members[0].0, members[0].1.clone())
+        .signer(members[1].0, members[1].1.clone())
+        .build();
+
+    // The inner transaction is executed atomically as well.
+    let (status, response): (StatusCode, SimulationResponse) =
+        api.post_with_status("/v1/transactions/simulate", &tx_spend);
+    assert_eq!(status, StatusCode::Ok);
+
+    let body = response.unwrap();
+    assert!(body.verified);
+    assert_eq!(body.status, Ok(()));
+
+    let multisig = body.wallets.iter().find(|w| w.pub_key == address).unwrap();
+    assert_eq!(multisig.balance_before, balance);
+    assert_eq!(multisig.balance_after, balance - transaction_fee - amount);
+
+    let recipient = body.wallets.iter().find(|w| w.pub_key == recipient_key).unwrap();
+    assert_eq!(recipient.balance_after, amount);
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+    assert_eq!(api.get_wallet(&address).balance, balance);
+}
@@ -0,0 +1,88 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::time::{Duration, Instant};
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use exonum::messages::Message;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::transaction::StatusResponse;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn status_pending() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 10);
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+
+    let (status, _) = api.post_tx(&tx_transfer);
+    assert_eq!(status, StatusCode::Created);
+
+    let (status, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(status, StatusCode::Accepted);
+    assert_eq!(tx_status, Err(ApiError::TransactionPending));
+
+    let start = Instant::now();
+    let endpoint = format!("/v1/transactions/{}?wait=1", tx_transfer.hash().to_hex());
+    let (status, tx_status): (StatusCode, StatusResponse) = api.get_with_status(&endpoint);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(status, StatusCode::Accepted);
+    assert_eq!(tx_status, Err(ApiError::TransactionPending));
+
+    testkit.create_block();
+
+    let (status, tx_status): (StatusCode, StatusResponse) = api.get_with_status(&endpoint);
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(tx_status, Ok(Ok(())));
+}
+
+#[test]
+fn status_wait_unknown() {
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .amount(100)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+
+    let start = Instant::now();
+    let endpoint = format!("/v1/transactions/{}?wait=1", tx_transfer.hash().to_hex());
+    let (status, tx_status): (StatusCode, StatusResponse) = api.get_with_status(&endpoint);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(status, StatusCode::NotFound);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+}
@@ -0,0 +1,579 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::asset::AssetResponse;
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::token::{TokenResponse, TokensResponse};
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::assets::AssetId;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::error::Error;
+use dmbc::currency::nft::{Token, TokenId};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn mint_tokens() {
+    let transaction_fee = 100;
+    let config_fees = TransactionFees::with_default_key(transaction_fee, 0, 0, 0, 0, 0);
+    let balance = 1_000;
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (receiver_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_mint = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_mint_tokens()
+        .receiver(receiver_key)
+        .name("card")
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .add_token(1, "first edition")
+        .add_token(2, "second edition")
+        .build();
+
+    let tx_hash = tx_mint.hash();
+
+    let (status, response) = api.post_tx(&tx_mint);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_mint);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let creator = api.get_wallet(&creator_key);
+    assert_eq!(creator.balance, balance - transaction_fee);
+
+    let asset_id = tx_mint.asset_id();
+    let (status, response): (StatusCode, TokenResponse) =
+        api.get_with_status(&format!("/v1/tokens/{}/1", asset_id.to_string()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        response,
+        Ok(Token::new(
+            TokenId::new(asset_id, 1),
+            &creator_key,
+            &receiver_key,
+            &tx_hash,
+            "first edition",
+        ))
+    );
+
+    let (status, response): (StatusCode, TokensResponse) =
+        api.get_with_status(&format!("/v1/wallets/{}/tokens", receiver_key.to_string()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response.unwrap().total, 2);
+
+    let (status, response): (StatusCode, TokensResponse) = api.get_with_status(&format!(
+        "/v1/assets/{}/tokens?offset=1&limit=10",
+        asset_id.to_string()
+    ));
+    assert_eq!(status, StatusCode::Ok);
+    let body = response.unwrap();
+    assert_eq!(body.total, 2);
+    assert_eq!(body.count, 1);
+
+    let (status, response): (StatusCode, TokenResponse) =
+        api.get_with_status(&format!("/v1/tokens/{}/3", asset_id.to_string()));
+    assert_eq!(status, StatusCode::NotFound);
+    assert_eq!(response, Err(ApiError::TokenNotFound));
+}
+
+#[test]
+fn mint_tokens_serial_exists() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_mint = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_mint_tokens()
+        .name("card")
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .add_token(1, "original")
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_mint);
+    testkit.create_block();
+
+    let tx_mint_again = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_mint_tokens()
+        .name("card")
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .add_token(1, "copy")
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_mint_again);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_mint_again);
+    assert_eq!(tx_status, Ok(Err(Error::TokenExists)));
+
+    let (_, response): (StatusCode, TokenResponse) =
+        api.get_with_status(&format!("/v1/tokens/{}/1", tx_mint.asset_id().to_string()));
+    assert_eq!(response.unwrap().data(), "original");
+}
+
+#[test]
+fn mint_tokens_series_asset() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(0, "0.0".parse().unwrap());
+
+    // An asset and a series of the same name don't share an id.
+    let tx_add = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_add_assets()
+        .add_asset("card", 1, fees.clone())
+        .build();
+
+    let tx_mint = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_mint_tokens()
+        .name("card")
+        .fees(fees.clone())
+        .add_token(1, "first")
+        .add_token(2, "second")
+        .seed(1)
+        .build();
+
+    assert_ne!(AssetId::from_data("card", &creator_key), tx_mint.asset_id());
+
+    api.post_tx(&tx_add);
+    api.post_tx(&tx_mint);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_add);
+    assert_eq!(tx_status, Ok(Ok(())));
+    let (_, tx_status) = api.get_tx_status(&tx_mint);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_mint_more = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_mint_tokens()
+        .name("card")
+        .fees(fees)
+        .add_token(3, "third")
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_mint_more);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_mint_more);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, response): (StatusCode, AssetResponse) =
+        api.get_with_status(&format!("/v1/assets/{}", tx_mint.asset_id().to_string()));
+    let info = response.unwrap().unwrap();
+    assert_eq!(info.creator(), &creator_key);
+    assert_eq!(info.data(), "card");
+    assert_eq!(info.amount(), 3);
+}
+
+#[test]
+fn mint_tokens_supply_cap() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let fees = dmbc_testkit::asset_fees(0, "0.0".parse().unwrap());
+
+    let tx_mint = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_mint_tokens()
+        .name("card")
+        .fees(fees.clone())
+        .add_token(1, "first")
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_mint);
+    testkit.create_block();
+
+    let tx_set_supply = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_set_asset_supply()
+        .asset_id(tx_mint.asset_id())
+        .max_supply(2)
+        .build();
+
+    api.post_tx(&tx_set_supply);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_set_supply);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let tx_mint_over = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_mint_tokens()
+        .name("card")
+        .fees(fees)
+        .add_token(2, "second")
+        .add_token(3, "third")
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_mint_over);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_mint_over);
+    assert_eq!(tx_status, Ok(Err(Error::SupplyCapExceeded)));
+
+    let (_, response): (StatusCode, TokenResponse) =
+        api.get_with_status(&format!("/v1/tokens/{}/2", tx_mint.asset_id().to_string()));
+    assert_eq!(response, Err(ApiError::TokenNotFound));
+}
+
+#[test]
+fn transfer_token() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_mint = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_mint_tokens()
+        .name("card")
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .add_token(7, "lucky")
+        .build();
+
+    api.post_tx(&tx_mint);
+    testkit.create_block();
+
+    let token_id = TokenId::new(tx_mint.asset_id(), 7);
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(creator_key, creator_secret.clone())
+        .tx_transfer_tokens()
+        .add_token(token_id.clone())
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, response): (StatusCode, TokenResponse) =
+        api.get_with_status(&format!("/v1/tokens/{}/7", tx_mint.asset_id().to_string()));
+    let token = response.unwrap();
+    assert_eq!(token.owner(), &recipient_key);
+    assert_eq!(token.creator(), &creator_key);
+    assert_eq!(token.data(), "lucky");
+
+    // The previous owner can't spend the token anymore.
+    let tx_transfer_back = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_transfer_tokens()
+        .add_token(token_id)
+        .recipient(recipient_key)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_transfer_back);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer_back);
+    assert_eq!(tx_status, Ok(Err(Error::NotTokenOwner)));
+}
+
+#[test]
+fn transfer_token_series_fees() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let series_fee = 10;
+    let balance = 1_000;
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (holder_key, holder_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&holder_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_mint = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_mint_tokens()
+        .receiver(holder_key)
+        .name("card")
+        .fees(dmbc_testkit::asset_fees(series_fee, "0.0".parse().unwrap()))
+        .add_token(1, "first")
+        .add_token(2, "second")
+        .build();
+
+    api.post_tx(&tx_mint);
+    testkit.create_block();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(holder_key, holder_secret)
+        .tx_transfer_tokens()
+        .add_token(TokenId::new(tx_mint.asset_id(), 1))
+        .add_token(TokenId::new(tx_mint.asset_id(), 2))
+        .recipient(recipient_key)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let creator = api.get_wallet(&creator_key);
+    let holder = api.get_wallet(&holder_key);
+    assert_eq!(creator.balance, balance + series_fee * 2);
+    assert_eq!(holder.balance, balance - series_fee * 2);
+}
+
+#[test]
+fn transfer_token_not_found() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (sender_key, sender_secret) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&sender_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let (asset, _) = dmbc_testkit::create_asset(
+        "card",
+        1,
+        dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()),
+        &sender_key,
+    );
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(sender_key, sender_secret)
+        .tx_transfer_tokens()
+        .add_token(TokenId::new(asset.id(), 1))
+        .recipient(recipient_key)
+        .build();
+
+    api.post_tx(&tx_transfer);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_transfer);
+    assert_eq!(tx_status, Ok(Err(Error::TokenNotFound)));
+}
+
+#[test]
+fn trade_token() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 1_000;
+    let price = 300;
+
+    let (seller_key, seller_secret) = crypto::gen_keypair();
+    let (buyer_key, buyer_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&seller_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&buyer_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_mint = transaction::Builder::new()
+        .keypair(seller_key, seller_secret.clone())
+        .tx_mint_tokens()
+        .name("painting")
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .add_token(1, "sunset")
+        .build();
+
+    api.post_tx(&tx_mint);
+    testkit.create_block();
+
+    let tx_trade = transaction::Builder::new()
+        .keypair(buyer_key, buyer_secret)
+        .tx_trade_tokens()
+        .add_token(TokenId::new(tx_mint.asset_id(), 1), price)
+        .seller(seller_key, seller_secret)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_trade);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_trade);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let seller = api.get_wallet(&seller_key);
+    let buyer = api.get_wallet(&buyer_key);
+    assert_eq!(seller.balance, balance + price);
+    assert_eq!(buyer.balance, balance - price);
+
+    let (_, response): (StatusCode, TokenResponse) =
+        api.get_with_status(&format!("/v1/tokens/{}/1", tx_mint.asset_id().to_string()));
+    assert_eq!(response.unwrap().owner(), &buyer_key);
+}
+
+#[test]
+fn trade_tokens_total_overflow() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 1_000;
+
+    let (seller_key, seller_secret) = crypto::gen_keypair();
+    let (buyer_key, buyer_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&seller_key, Wallet::new(balance, vec![]))
+        .add_wallet_value(&buyer_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_mint = transaction::Builder::new()
+        .keypair(seller_key, seller_secret.clone())
+        .tx_mint_tokens()
+        .name("painting")
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .add_token(1, "sunset")
+        .add_token(2, "sunrise")
+        .build();
+
+    api.post_tx(&tx_mint);
+    testkit.create_block();
+
+    let tx_trade = transaction::Builder::new()
+        .keypair(buyer_key, buyer_secret)
+        .tx_trade_tokens()
+        .add_token(TokenId::new(tx_mint.asset_id(), 1), u64::max_value())
+        .add_token(TokenId::new(tx_mint.asset_id(), 2), 1)
+        .seller(seller_key, seller_secret)
+        .build();
+
+    api.post_tx(&tx_trade);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_trade);
+    assert_eq!(tx_status, Ok(Err(Error::InsufficientFunds)));
+
+    let (_, response): (StatusCode, TokenResponse) =
+        api.get_with_status(&format!("/v1/tokens/{}/1", tx_mint.asset_id().to_string()));
+    assert_eq!(response.unwrap().owner(), &seller_key);
+}
+
+#[test]
+fn exchange_tokens() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (sender_key, sender_secret) = crypto::gen_keypair();
+    let (recipient_key, recipient_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&sender_key, Wallet::new(1_000, vec![]))
+        .add_wallet_value(&recipient_key, Wallet::new(1_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_mint_sender = transaction::Builder::new()
+        .keypair(sender_key, sender_secret.clone())
+        .tx_mint_tokens()
+        .name("card")
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .add_token(1, "red")
+        .build();
+
+    let tx_mint_recipient = transaction::Builder::new()
+        .keypair(recipient_key, recipient_secret.clone())
+        .tx_mint_tokens()
+        .name("card")
+        .fees(dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()))
+        .add_token(1, "blue")
+        .build();
+
+    api.post_tx(&tx_mint_sender);
+    api.post_tx(&tx_mint_recipient);
+    testkit.create_block();
+
+    let sender_token = TokenId::new(tx_mint_sender.asset_id(), 1);
+    let recipient_token = TokenId::new(tx_mint_recipient.asset_id(), 1);
+
+    let tx_exchange = transaction::Builder::new()
+        .keypair(recipient_key, recipient_secret)
+        .tx_exchange_tokens()
+        .sender(sender_key)
+        .sender_secret(sender_secret)
+        .sender_add_token(sender_token)
+        .recipient_add_token(recipient_token)
+        .build();
+
+    api.post_tx(&tx_exchange);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_exchange);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let (_, response): (StatusCode, TokensResponse) =
+        api.get_with_status(&format!("/v1/wallets/{}/tokens", sender_key.to_string()));
+    let tokens = response.unwrap().tokens;
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].data(), "blue");
+
+    let (_, response): (StatusCode, TokensResponse) =
+        api.get_with_status(&format!("/v1/wallets/{}/tokens", recipient_key.to_string()));
+    let tokens = response.unwrap().tokens;
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].data(), "red");
+}
@@ -0,0 +1,298 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use hyper::status::StatusCode;
+use exonum::messages::Message;
+use exonum::crypto;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::transactions::MAX_VESTING_END;
+use dmbc::currency::assets::AssetBundle;
+use dmbc::currency::api::error::ApiError;
+use dmbc::currency::api::wallet::{VestedValue, WalletVestingResponse};
+use dmbc::currency::error::Error;
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::vesting::ReleaseCurve;
+use dmbc::currency::wallet::Wallet;
+
+#[test]
+fn vesting_linear() {
+    let transaction_fee = 100;
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, transaction_fee);
+    let balance = 10_000;
+    let granted = 1_000;
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (beneficiary_key, beneficiary_secret) = crypto::gen_keypair();
+
+    let (asset, info) = dmbc_testkit::create_asset("asset", 4, dmbc_testkit::asset_fees(0, "0.0".parse().unwrap()), &creator_key);
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(balance, vec![]))
+        .add_asset_to_wallet(&creator_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    // Committed at height 2, unlocks linearly until height 6 with a cliff at 4.
+    let tx_create = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_create_vesting()
+        .beneficiary(beneficiary_key)
+        .amount(granted)
+        .add_asset_value(asset.clone())
+        .cliff(4)
+        .end(6)
+        .curve(ReleaseCurve::Linear)
+        .build();
+    let vesting_id = tx_create.hash();
+
+    let (status, response) = api.post_tx(&tx_create);
+    testkit.create_block();
+
+    // check post response
+    assert_eq!(status, StatusCode::Created);
+    assert_eq!(response, Ok(Ok(TransactionResponse { tx_hash: vesting_id })));
+
+    let (_, tx_status) = api.get_tx_status(&tx_create);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    // The granted value leaves the creator wallet right away.
+    let creator_wallet = api.get_wallet(&creator_key);
+    assert_eq!(creator_wallet.balance, balance - transaction_fee - granted);
+    assert_eq!(creator_wallet.assets_count, 0);
+
+    // Height 3 is before the cliff.
+    let tx_claim = transaction::Builder::new()
+        .keypair(beneficiary_key, beneficiary_secret.clone())
+        .tx_claim_vested()
+        .vesting(vesting_id)
+        .seed(1)
+        .build();
+
+    api.post_tx(&tx_claim);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_claim);
+    assert_eq!(tx_status, Ok(Err(Error::NothingToClaim)));
+
+    // Height 4 unlocks half of the value.
+    let tx_claim = transaction::Builder::new()
+        .keypair(beneficiary_key, beneficiary_secret.clone())
+        .tx_claim_vested()
+        .vesting(vesting_id)
+        .seed(2)
+        .build();
+
+    api.post_tx(&tx_claim);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_claim);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let beneficiary_wallet = api.get_wallet(&beneficiary_key);
+    let beneficiary_assets = api.get_wallet_assets(&beneficiary_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(beneficiary_wallet.balance, granted / 2);
+    assert_eq!(beneficiary_assets, vec![AssetBundle::new(asset.id(), 2)]);
+
+    // As of height 5, three quarters are unlocked.
+    let (status, response): (StatusCode, WalletVestingResponse) =
+        api.get_with_status(&format!("/v1/wallets/{}/vesting", beneficiary_key.to_string()));
+    assert_eq!(status, StatusCode::Ok);
+
+    let response = response.unwrap();
+    assert_eq!(response.total, 1);
+
+    let info = &response.vesting[0];
+    assert_eq!(info.id, vesting_id);
+    assert_eq!(info.start, 2);
+    assert_eq!(
+        info.locked,
+        VestedValue { amount: 250, assets: vec![AssetBundle::new(asset.id(), 1)] }
+    );
+    assert_eq!(
+        info.claimable,
+        VestedValue { amount: 250, assets: vec![AssetBundle::new(asset.id(), 1)] }
+    );
+    assert_eq!(
+        info.claimed,
+        VestedValue { amount: 500, assets: vec![AssetBundle::new(asset.id(), 2)] }
+    );
+
+    // Claim the rest once the schedule has ended.
+    testkit.create_block();
+
+    let tx_claim = transaction::Builder::new()
+        .keypair(beneficiary_key, beneficiary_secret)
+        .tx_claim_vested()
+        .vesting(vesting_id)
+        .seed(3)
+        .build();
+
+    api.post_tx(&tx_claim);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_claim);
+    assert_eq!(tx_status, Ok(Ok(())));
+
+    let beneficiary_wallet = api.get_wallet(&beneficiary_key);
+    let beneficiary_assets = api.get_wallet_assets(&beneficiary_key).iter().map(|a| a.into()).collect::<Vec<AssetBundle>>();
+    assert_eq!(beneficiary_wallet.balance, granted);
+    assert_eq!(beneficiary_assets, vec![asset]);
+}
+
+#[test]
+fn claim_vested_not_beneficiary() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 10_000;
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (beneficiary_key, _) = crypto::gen_keypair();
+    let (other_key, other_secret) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_create_vesting()
+        .beneficiary(beneficiary_key)
+        .amount(1_000)
+        .curve(ReleaseCurve::Cliff)
+        .build();
+
+    api.post_tx(&tx_create);
+    testkit.create_block();
+
+    let tx_claim = transaction::Builder::new()
+        .keypair(other_key, other_secret)
+        .tx_claim_vested()
+        .vesting(tx_create.hash())
+        .build();
+
+    api.post_tx(&tx_claim);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_claim);
+    assert_eq!(tx_status, Ok(Err(Error::NotVestingBeneficiary)));
+
+    let other_wallet = api.get_wallet(&other_key);
+    assert_eq!(other_wallet.balance, 0);
+}
+
+#[test]
+fn create_vesting_insufficient_funds() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+    let balance = 500;
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (beneficiary_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(balance, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_create_vesting()
+        .beneficiary(beneficiary_key)
+        .amount(1_000)
+        .cliff(10)
+        .end(20)
+        .curve(ReleaseCurve::Linear)
+        .build();
+
+    api.post_tx(&tx_create);
+    testkit.create_block();
+
+    let (_, tx_status) = api.get_tx_status(&tx_create);
+    assert_eq!(tx_status, Ok(Err(Error::InsufficientFunds)));
+
+    let creator_wallet = api.get_wallet(&creator_key);
+    assert_eq!(creator_wallet.balance, balance);
+
+    let (_, response): (StatusCode, WalletVestingResponse) =
+        api.get_with_status(&format!("/v1/wallets/{}/vesting", beneficiary_key.to_string()));
+    assert_eq!(response.unwrap().total, 0);
+}
+
+#[test]
+fn create_vesting_cliff_after_end() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (beneficiary_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(10_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_create_vesting()
+        .beneficiary(beneficiary_key)
+        .amount(1_000)
+        .cliff(20)
+        .end(10)
+        .curve(ReleaseCurve::Linear)
+        .build();
+
+    let (status, response) = api.post_tx(&tx_create);
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_create);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+}
+
+#[test]
+fn create_vesting_end_too_far() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 0);
+
+    let (creator_key, creator_secret) = crypto::gen_keypair();
+    let (beneficiary_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&creator_key, Wallet::new(10_000, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_create = transaction::Builder::new()
+        .keypair(creator_key, creator_secret)
+        .tx_create_vesting()
+        .beneficiary(beneficiary_key)
+        .amount(1_000)
+        .cliff(20)
+        .end(MAX_VESTING_END + 1)
+        .curve(ReleaseCurve::Linear)
+        .build();
+
+    let (status, response) = api.post_tx(&tx_create);
+    testkit.create_block();
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Ok(Err(Error::UnableToVerifyTransaction)));
+
+    let (_, tx_status) = api.get_tx_status(&tx_create);
+    assert_eq!(tx_status, Err(ApiError::TransactionNotFound));
+}
@@ -0,0 +1,263 @@
+extern crate dmbc;
+extern crate exonum;
+extern crate exonum_testkit;
+extern crate hyper;
+extern crate iron;
+extern crate iron_test;
+extern crate serde_json;
+extern crate mount;
+
+pub mod dmbc_testkit;
+
+use std::collections::HashMap;
+
+use hyper::status::StatusCode;
+use exonum::crypto;
+use exonum::messages::Message;
+use dmbc_testkit::{DmbcTestApiBuilder, DmbcTestKitApi};
+
+use dmbc::currency::api::transaction::TransactionResponse;
+use dmbc::currency::api::v2::{Envelope, ErrorBody};
+use dmbc::currency::api::v2::asset::AssetInfoBody;
+use dmbc::currency::api::v2::fees::FeesBody;
+use dmbc::currency::api::v2::transaction::{TxState, TxStatusBody};
+use dmbc::currency::api::v2::wallet::{AssetAmountBody, WalletAssetsBody, WalletBody, WalletsBody};
+use dmbc::currency::assets::AssetId;
+use dmbc::currency::configuration::{Configuration, TransactionFees};
+use dmbc::currency::transactions::builders::transaction;
+use dmbc::currency::wallet::Wallet;
+use dmbc::currency::SERVICE_ID;
+use dmbc::decimal::UFract64;
+
+fn error(code: &str, message: &str) -> Envelope<serde_json::Value> {
+    Envelope::Error(ErrorBody {
+        code: code.to_string(),
+        message: message.to_string(),
+    })
+}
+
+#[test]
+fn v2_wallet() {
+    let (pub_key, _) = crypto::gen_keypair();
+    let (asset, info) = dmbc_testkit::create_asset(
+        "asset",
+        250,
+        dmbc_testkit::asset_fees(10, "0.0".parse().unwrap()),
+        &pub_key,
+    );
+
+    let testkit = DmbcTestApiBuilder::new()
+        .add_wallet_value(&pub_key, Wallet::new(1_050, vec![]))
+        .add_asset_to_wallet(&pub_key, (asset.clone(), info))
+        .create();
+    let api = testkit.api();
+
+    let (status, response): (StatusCode, Envelope<WalletBody>) =
+        api.get_with_status(&format!("/v2/wallets/{}?decimals=2", pub_key.to_hex()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        response,
+        Envelope::Data(WalletBody {
+            balance: "10.5".to_string(),
+            assets_count: 1,
+        })
+    );
+
+    let (status, response): (StatusCode, Envelope<WalletAssetsBody>) =
+        api.get_with_status(&format!("/v2/wallets/{}/assets", pub_key.to_hex()));
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        response,
+        Envelope::Data(WalletAssetsBody {
+            total: 1,
+            count: 1,
+            assets: vec![AssetAmountBody {
+                id: asset.id(),
+                amount: "250".to_string(),
+            }],
+        })
+    );
+
+    let (status, response): (StatusCode, Envelope<WalletsBody>) =
+        api.get_with_status("/v2/wallets?decimals=2&min_balance=10.5");
+    assert_eq!(status, StatusCode::Ok);
+    match response {
+        Envelope::Data(body) => {
+            assert!(body.wallets.iter().any(|entry| entry.pub_key == pub_key));
+            assert!(body.wallets.iter().all(|entry| entry.balance.parse::<f64>().unwrap() >= 10.5));
+        }
+        Envelope::Error(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn v2_wallet_errors() {
+    let (pub_key, _) = crypto::gen_keypair();
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let (status, response): (StatusCode, Envelope<serde_json::Value>) =
+        api.get_with_status("/v2/wallets/123");
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, error("wallet_hex_invalid", "wallet hex invalid"));
+
+    let (status, response): (StatusCode, Envelope<serde_json::Value>) =
+        api.get_with_status(&format!("/v2/wallets/{}?decimals=20", pub_key.to_hex()));
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, error("incorrect_request", "incorrect request"));
+
+    let (status, response): (StatusCode, Envelope<serde_json::Value>) =
+        api.get_with_status("/v2/wallets?decimals=2&min_balance=0.001");
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, error("incorrect_request", "incorrect request"));
+}
+
+#[test]
+fn v2_asset() {
+    let (pub_key, _) = crypto::gen_keypair();
+    let (asset, info) = dmbc_testkit::create_asset(
+        "asset",
+        5,
+        dmbc_testkit::asset_fees(1_500, "0.25".parse().unwrap()),
+        &pub_key,
+    );
+
+    let testkit = DmbcTestApiBuilder::new()
+        .add_asset_to_wallet(&pub_key, (asset.clone(), info.clone()))
+        .create();
+    let api = testkit.api();
+
+    let (status, response): (StatusCode, Envelope<AssetInfoBody>) =
+        api.get_with_status(&format!("/v2/assets/{}?decimals=3", asset.id().to_string()));
+    assert_eq!(status, StatusCode::Ok);
+    match response {
+        Envelope::Data(body) => {
+            assert_eq!(body.creator, pub_key);
+            assert_eq!(body.amount, "0.005");
+            assert_eq!(body.fees.trade.fixed, "1.5");
+            assert_eq!(
+                body.fees.transfer.fraction.parse::<UFract64>().unwrap(),
+                "0.25".parse().unwrap()
+            );
+        }
+        Envelope::Error(e) => panic!("Unexpected error {:?}", e),
+    }
+
+    let unknown = AssetId::from_data("unknown", &pub_key);
+    let (status, response): (StatusCode, Envelope<serde_json::Value>) =
+        api.get_with_status(&format!("/v2/assets/{}", unknown.to_string()));
+    assert_eq!(status, StatusCode::NotFound);
+    assert_eq!(response, error("asset_id_not_found", "asset id not found"));
+}
+
+#[test]
+fn v2_transaction() {
+    let config_fees = TransactionFees::with_default_key(0, 0, 0, 0, 0, 10);
+    let (public_key, secret_key) = crypto::gen_keypair();
+    let (recipient_key, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .with_configuration(Configuration::new(config_fees))
+        .add_wallet_value(&public_key, Wallet::new(100, vec![]))
+        .create();
+    let api = testkit.api();
+
+    let tx_transfer = transaction::Builder::new()
+        .keypair(public_key, secret_key.clone())
+        .tx_transfer()
+        .amount(50)
+        .recipient(recipient_key)
+        .seed(1)
+        .build();
+    let tx_overdraft = transaction::Builder::new()
+        .keypair(public_key, secret_key)
+        .tx_transfer()
+        .amount(1_000)
+        .recipient(recipient_key)
+        .seed(2)
+        .build();
+
+    let (status, response): (StatusCode, Envelope<FeesBody>) =
+        api.post_with_status("/v2/fees/transactions?decimals=1", &tx_transfer);
+    assert_eq!(status, StatusCode::Ok);
+    let mut fees = HashMap::new();
+    fees.insert(public_key, "1".to_string());
+    assert_eq!(response, Envelope::Data(FeesBody { fees }));
+
+    let (status, response): (StatusCode, Envelope<TransactionResponse>) =
+        api.post_with_status("/v2/transactions", &tx_transfer);
+    assert_eq!(status, StatusCode::Accepted);
+    assert_eq!(
+        response,
+        Envelope::Data(TransactionResponse {
+            tx_hash: tx_transfer.hash(),
+        })
+    );
+    let (status, _): (StatusCode, Envelope<TransactionResponse>) =
+        api.post_with_status("/v2/transactions", &tx_overdraft);
+    assert_eq!(status, StatusCode::Accepted);
+
+    let endpoint = format!("/v2/transactions/{}", tx_transfer.hash().to_hex());
+    let (status, response): (StatusCode, Envelope<TxStatusBody>) = api.get_with_status(&endpoint);
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        response,
+        Envelope::Data(TxStatusBody {
+            tx_hash: tx_transfer.hash(),
+            state: TxState::Pending,
+            error: None,
+        })
+    );
+
+    testkit.create_block();
+
+    let (status, response): (StatusCode, Envelope<TxStatusBody>) = api.get_with_status(&endpoint);
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        response,
+        Envelope::Data(TxStatusBody {
+            tx_hash: tx_transfer.hash(),
+            state: TxState::Committed,
+            error: None,
+        })
+    );
+
+    let endpoint = format!("/v2/transactions/{}", tx_overdraft.hash().to_hex());
+    let (status, response): (StatusCode, Envelope<TxStatusBody>) = api.get_with_status(&endpoint);
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        response,
+        Envelope::Data(TxStatusBody {
+            tx_hash: tx_overdraft.hash(),
+            state: TxState::Failed,
+            error: Some(ErrorBody {
+                code: "insufficient_funds".to_string(),
+                message: "insufficient funds".to_string(),
+            }),
+        })
+    );
+}
+
+#[test]
+fn v2_transaction_errors() {
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let body = format!(r#"{{"service_id": {}, "message_id": 200, "body": {{}}}}"#, SERVICE_ID);
+    let (status, response): (StatusCode, Envelope<serde_json::Value>) =
+        api.post_raw_with_status("/v2/transactions", &body);
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(
+        response,
+        error("transaction_type_unknown", "transaction type unknown")
+    );
+
+    let endpoint = format!("/v2/transactions/{}", crypto::hash(&[1, 2, 3]).to_hex());
+    let (status, response): (StatusCode, Envelope<serde_json::Value>) =
+        api.get_with_status(&endpoint);
+    assert_eq!(status, StatusCode::NotFound);
+    assert_eq!(
+        response,
+        error("transaction_not_found", "transaction not found")
+    );
+}
@@ -9,18 +9,17 @@ extern crate mount;
 
 pub mod dmbc_testkit;
 
-use std::collections::HashMap;
-
 use hyper::status::StatusCode;
 use exonum::crypto;
 use dmbc_testkit::{DmbcTestKit, DmbcTestApiBuilder, DmbcTestKitApi};
 
 use dmbc::currency::api::wallet::{self, ExtendedAsset, WalletAssetsResponse, WalletAssetsResponseBody,
-                                  WalletInfo, WalletResponse, WalletsResponse, WalletsResponseBody, 
-                                  WalletAssetResponse};
-use dmbc::currency::wallet::Wallet;
+                                  WalletEntry, WalletInfo, WalletResponse, WalletsResponse,
+                                  WalletsResponseBody, WalletAssetResponse};
+use dmbc::currency::wallet::{self as currency_wallet, Wallet};
 use dmbc::currency::assets::AssetId;
 use dmbc::currency::api::error::ApiError;
+use dmbc::currency::Service;
 
 #[test]
 fn wallet() {
@@ -73,29 +72,24 @@ fn wallets() {
     let genesis = testkit.fetch_wallet(&genesis_key);
     let genesis_count_assets = genesis.assets().len() as u64;
 
-    let mut wallets = HashMap::new();
-    wallets.insert(
-        genesis_key,
-        WalletInfo {
+    let mut wallets = vec![
+        WalletEntry {
+            pub_key: genesis_key,
             balance: genesis.balance(),
             assets_count: genesis_count_assets,
         },
-    );
-    wallets.insert(
-        pub_key1,
-        WalletInfo {
+        WalletEntry {
+            pub_key: pub_key1,
             balance: balance,
             assets_count: 1,
         },
-    );
-    wallets.insert(
-        pub_key2,
-        WalletInfo {
+        WalletEntry {
+            pub_key: pub_key2,
             balance: balance,
             assets_count: 1,
         },
-    );
-    let total = wallets.len() as u64;
+    ];
+    wallets.sort_by_key(|entry| entry.pub_key);
     let count = wallets.len() as u64;
 
     let (status, response): (StatusCode, WalletsResponse) = api.get_with_status(
@@ -106,8 +100,8 @@ fn wallets() {
     assert_eq!(
         response,
         Ok(WalletsResponseBody {
-            total,
             count,
+            next: None,
             wallets
         })
     );
@@ -132,19 +126,140 @@ fn wallets_pagination() {
 
     let api = testkit.api();
 
-    let total = 3;
-    let count = 1;
+    let mut keys = vec![dmbc_testkit::default_genesis_key(), pub_key1, pub_key2];
+    keys.sort();
+
+    // Walk the list one wallet at a time, following the cursors.
+    let mut listed = Vec::new();
+    let mut cursor = None;
+    loop {
+        let url = match cursor {
+            Some(ref cursor) => format!("/v1/wallets?limit=1&cursor={}", cursor),
+            None => "/v1/wallets?limit=1".to_string(),
+        };
+        let (status, response): (StatusCode, WalletsResponse) = api.get_with_status(&url);
+        assert_eq!(status, StatusCode::Ok);
+
+        let body = response.unwrap();
+        assert_eq!(body.count, 1);
+        listed.extend(body.wallets.into_iter().map(|entry| entry.pub_key));
+        cursor = body.next;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    assert_eq!(listed, keys);
+}
+
+#[test]
+fn wallets_sorted_by_balance() {
+    let (pub_key1, _) = crypto::gen_keypair();
+    let (pub_key2, _) = crypto::gen_keypair();
+    let (pub_key3, _) = crypto::gen_keypair();
+
+    let testkit = DmbcTestApiBuilder::new()
+        .add_wallet_value(&pub_key1, Wallet::new(100, vec![]))
+        .add_wallet_value(&pub_key2, Wallet::new(300, vec![]))
+        .add_wallet_value(&pub_key3, Wallet::new(200, vec![]))
+        .create();
+
+    let api = testkit.api();
+    let genesis_key = dmbc_testkit::default_genesis_key();
 
     let (status, response): (StatusCode, WalletsResponse) = api.get_with_status(
-        "/v1/wallets?offset=0&limit=1",
+        "/v1/wallets?sort=balance&limit=2"
     );
-
     assert_eq!(status, StatusCode::Ok);
-    assert!(response.is_ok());
 
     let body = response.unwrap();
-    assert_eq!(body.total, total);
-    assert_eq!(body.count, count);
+    let keys: Vec<_> = body.wallets.iter().map(|entry| entry.pub_key).collect();
+    assert_eq!(keys, vec![genesis_key, pub_key2]);
+
+    let (_, response): (StatusCode, WalletsResponse) = api.get_with_status(&format!(
+        "/v1/wallets?sort=balance&limit=2&cursor={}",
+        body.next.unwrap()
+    ));
+    let body = response.unwrap();
+    let keys: Vec<_> = body.wallets.iter().map(|entry| entry.pub_key).collect();
+    assert_eq!(keys, vec![pub_key3, pub_key1]);
+    assert_eq!(body.next, None);
+
+    // Wallets under the minimum balance are left out in either order.
+    let (_, response): (StatusCode, WalletsResponse) = api.get_with_status(
+        "/v1/wallets?sort=balance&min_balance=200"
+    );
+    let keys: Vec<_> = response.unwrap().wallets.iter().map(|entry| entry.pub_key).collect();
+    assert_eq!(keys, vec![genesis_key, pub_key2, pub_key3]);
+
+    let (_, response): (StatusCode, WalletsResponse) = api.get_with_status(
+        "/v1/wallets?min_balance=200"
+    );
+    let body = response.unwrap();
+    assert_eq!(body.count, 3);
+    assert!(body.wallets.iter().all(|entry| entry.pub_key != pub_key1));
+}
+
+
+#[test]
+fn wallets_balance_index_upgrade() {
+    let (pub_key1, _) = crypto::gen_keypair();
+    let (pub_key2, _) = crypto::gen_keypair();
+
+    let mut testkit = DmbcTestApiBuilder::new()
+        .add_wallet_value(&pub_key1, Wallet::new(100, vec![]))
+        .add_wallet_value(&pub_key2, Wallet::new(300, vec![]))
+        .create();
+
+    // Drop the balance index, as for wallets stored before it existed.
+    {
+        let blockchain = testkit.blockchain_mut();
+        let mut fork = blockchain.fork();
+        currency_wallet::Schema(&mut fork).balances_index_mut().clear();
+        assert!(blockchain.merge(fork.into_patch()).is_ok());
+    }
+
+    // The upgrade fills the index in.
+    {
+        let blockchain = testkit.blockchain_mut();
+        let mut fork = blockchain.fork();
+        Service::upgrade(&mut fork);
+        assert!(blockchain.merge(fork.into_patch()).is_ok());
+    }
+
+    let fork = testkit.blockchain_mut().fork();
+    assert_eq!(currency_wallet::Schema(&fork).balances_index().iter().count(), 3);
+
+    let genesis_key = dmbc_testkit::default_genesis_key();
+    let sorted = vec![genesis_key, pub_key2, pub_key1];
+
+    let (_, response): (StatusCode, WalletsResponse) =
+        testkit.api().get_with_status("/v1/wallets?sort=balance");
+    let keys: Vec<_> = response.unwrap().wallets.iter().map(|entry| entry.pub_key).collect();
+    assert_eq!(keys, sorted);
+}
+#[test]
+fn wallets_invalid_cursor() {
+    let testkit = DmbcTestApiBuilder::new().create();
+    let api = testkit.api();
+
+    let (status, response): (StatusCode, WalletsResponse) = api.get_with_status(
+        "/v1/wallets?cursor=1234"
+    );
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::WalletCursorInvalid));
+
+    let (status, response): (StatusCode, WalletsResponse) = api.get_with_status(
+        "/v1/wallets?sort=balance&cursor=1234"
+    );
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::WalletCursorInvalid));
+
+    let (status, response): (StatusCode, WalletsResponse) = api.get_with_status(
+        "/v1/wallets?sort=name"
+    );
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(response, Err(ApiError::IncorrectRequest));
 }
 
 #[test]